@@ -53,3 +53,891 @@ impl Default for SystemMirror {
         }
     }
 }
+
+/// Keeps the retry policy of the tasks held by the executor,
+/// And tracks the one-shot runs (retries and runs triggered by upstream tasks)
+/// That have been handed to `DelayTimer`.
+#[derive(Debug)]
+pub(crate) struct RetryKeeper {
+    inner: RwLock<RetryKeeperInner>,
+    retry_task_id_generator: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct RetryKeeperInner {
+    // task-id -> task-package, only tasks with `retry_times` > 0 are kept.
+    task_packages: HashMap<u64, TaskPackage>,
+    // one-shot-task-id -> one-shot run.
+    retry_tasks: HashMap<u64, OneShotRun>,
+}
+
+/// The original task, the attempt and the run that a task in `DelayTimer` stands for.
+#[derive(Debug, Clone)]
+pub(crate) struct OneShotRun {
+    pub(crate) task_id: u64,
+    pub(crate) attempt: i16,
+    pub(crate) run_id: i64,
+    // The record of the run in `DelayTimer`, known once the run has started.
+    record_id: i64,
+    // Kept for one-shot runs, so that a triggered run can be retried
+    // Even if the executor does not hold the task.
+    task_package: Option<TaskPackage>,
+}
+
+impl OneShotRun {
+    fn first_run(task_id: u64) -> OneShotRun {
+        OneShotRun {
+            task_id,
+            attempt: 1,
+            run_id: 0,
+            record_id: 0,
+            task_package: None,
+        }
+    }
+}
+
+// One-shot runs use ids far away from the task-ids allocated by the scheduler.
+const RETRY_TASK_ID_BASE: u64 = 1 << 62;
+
+impl RetryKeeper {
+    pub(crate) async fn register(&self, task_package: &TaskPackage) {
+        let mut inner = self.inner.write().await;
+        let task_id = task_package.id as u64;
+
+        if task_package.retry_times > 0 {
+            inner.task_packages.insert(task_id, task_package.clone());
+        } else {
+            inner.task_packages.remove(&task_id);
+        }
+    }
+
+    // Forget the task and return the one-shot runs still waiting in `DelayTimer`.
+    pub(crate) async fn unregister(&self, task_id: u64) -> Vec<u64> {
+        let mut inner = self.inner.write().await;
+        inner.task_packages.remove(&task_id);
+
+        let pending_retry_task_ids: Vec<u64> = inner
+            .retry_tasks
+            .iter()
+            .filter(|(_, run)| run.task_id == task_id)
+            .map(|(retry_task_id, _)| *retry_task_id)
+            .collect();
+
+        pending_retry_task_ids.iter().for_each(|retry_task_id| {
+            inner.retry_tasks.remove(retry_task_id);
+        });
+
+        pending_retry_task_ids
+    }
+
+    // Map the task-id reported by `DelayTimer` back to the original task, the attempt and the run.
+    pub(crate) async fn resolve(&self, timer_task_id: u64) -> OneShotRun {
+        self.inner
+            .read()
+            .await
+            .retry_tasks
+            .get(&timer_task_id)
+            .cloned()
+            .unwrap_or_else(|| OneShotRun::first_run(timer_task_id))
+    }
+
+    // Note the record a one-shot run started with, so that the run can be cancelled through it.
+    pub(crate) async fn on_run_start(&self, timer_task_id: u64, record_id: i64) {
+        if let Some(run) = self.inner.write().await.retry_tasks.get_mut(&timer_task_id) {
+            run.record_id = record_id;
+        }
+    }
+
+    // The id in `DelayTimer` of the run of the task with the record,
+    // It is the task-id itself unless the run is a retry or a triggered run.
+    pub(crate) async fn timer_task_id_of(&self, task_id: u64, record_id: i64) -> u64 {
+        self.inner
+            .read()
+            .await
+            .retry_tasks
+            .iter()
+            .find(|(_, run)| run.task_id == task_id && run.record_id == record_id)
+            .map(|(one_shot_task_id, _)| *one_shot_task_id)
+            .unwrap_or(task_id)
+    }
+
+    // Build a one-shot run for a trigger sent by the scheduler, it should be added to `DelayTimer`.
+    pub(crate) async fn trigger(&self, task_trigger: TaskTrigger) -> Result<Task, CommonError> {
+        let TaskTrigger {
+            task_package,
+            run_id,
+            ..
+        } = task_trigger;
+
+        let trigger_task_id =
+            RETRY_TASK_ID_BASE + self.retry_task_id_generator.fetch_add(1, Ordering::SeqCst);
+        let trigger_task = task_package.build_one_shot_task(trigger_task_id, 1)?;
+
+        self.inner.write().await.retry_tasks.insert(
+            trigger_task_id,
+            OneShotRun {
+                task_id: task_package.id as u64,
+                attempt: 1,
+                run_id,
+                record_id: 0,
+                task_package: Some(task_package),
+            },
+        );
+        Ok(trigger_task)
+    }
+
+    // Called when a run ends, if the run failed and the task still has retries left,
+    // The next retry is returned and should be added to `DelayTimer`.
+    // The retries share the run of the first attempt, whose record-id starts the run if needed.
+    pub(crate) async fn on_run_end(
+        &self,
+        timer_task_id: u64,
+        record_id: i64,
+        failed: bool,
+    ) -> Option<Result<Task, CommonError>> {
+        let mut inner = self.inner.write().await;
+
+        let run = inner
+            .retry_tasks
+            .remove(&timer_task_id)
+            .unwrap_or_else(|| OneShotRun::first_run(timer_task_id));
+
+        if !failed {
+            return None;
+        }
+
+        let task_id = run.task_id;
+        let task_package = run
+            .task_package
+            .or_else(|| inner.task_packages.get(&task_id).cloned())?;
+        if run.attempt > task_package.retry_times {
+            return None;
+        }
+
+        let retry_task_id =
+            RETRY_TASK_ID_BASE + self.retry_task_id_generator.fetch_add(1, Ordering::SeqCst);
+        let retry_task = task_package.build_retry_task(retry_task_id);
+
+        if retry_task.is_ok() {
+            let run_id = if run.run_id != 0 {
+                run.run_id
+            } else {
+                record_id
+            };
+
+            inner.retry_tasks.insert(
+                retry_task_id,
+                OneShotRun {
+                    task_id,
+                    attempt: run.attempt + 1,
+                    run_id,
+                    record_id: 0,
+                    task_package: Some(task_package),
+                },
+            );
+        }
+        Some(retry_task)
+    }
+}
+
+impl Default for RetryKeeper {
+    fn default() -> RetryKeeper {
+        let inner = RwLock::new(RetryKeeperInner::default());
+        let retry_task_id_generator = AtomicU64::new(0);
+
+        RetryKeeper {
+            inner,
+            retry_task_id_generator,
+        }
+    }
+}
+
+/// The tasks currently held by the executor,
+/// Their ids are reported to the scheduler on each health check so that it can repair the differences.
+///
+/// When a `TaskStore` is set, the tasks are persisted as well and reloaded when the executor restarts,
+/// The reloaded ones start at once with the binding persisted along with them,
+/// Or are held until the executor is bound again when there is none.
+#[derive(Debug, Default)]
+pub(crate) struct TaskRegistry {
+    // task-id -> task-package.
+    task_packages: RwLock<HashMap<u64, TaskPackage>>,
+    // task-id -> the utc offset the task was built with, for the tasks in named time zones.
+    schedule_offsets: RwLock<HashMap<u64, i32>>,
+    // The persisted tasks waiting to be started.
+    restored_task_packages: RwLock<Vec<TaskPackage>>,
+    store: Option<TaskStore>,
+}
+
+impl TaskRegistry {
+    pub(crate) fn new(store: Option<TaskStore>) -> TaskRegistry {
+        TaskRegistry {
+            task_packages: RwLock::new(HashMap::new()),
+            schedule_offsets: RwLock::new(HashMap::new()),
+            restored_task_packages: RwLock::new(Vec::new()),
+            store,
+        }
+    }
+
+    pub(crate) async fn contains(&self, task_id: u64) -> bool {
+        self.task_packages.read().await.contains_key(&task_id)
+    }
+
+    pub(crate) async fn insert(&self, signed_task_package: &SignedTaskPackage) {
+        let task_package = &signed_task_package.task_package;
+        self.task_packages
+            .write()
+            .await
+            .insert(task_package.id as u64, task_package.clone());
+        self.record_schedule_offset(task_package).await;
+
+        if let Some(store) = self.store.as_ref() {
+            store
+                .save(signed_task_package)
+                .await
+                .map_err(|e| error!("Failed to persist the task {}: {}", task_package.id, e))
+                .ok();
+        }
+    }
+
+    pub(crate) async fn remove(&self, task_id: u64) {
+        self.task_packages.write().await.remove(&task_id);
+        self.schedule_offsets.write().await.remove(&task_id);
+
+        if let Some(store) = self.store.as_ref() {
+            store
+                .delete(task_id)
+                .await
+                .map_err(|e| error!("Failed to delete the persisted task {}: {}", task_id, e))
+                .ok();
+        }
+    }
+
+    pub(crate) async fn task_ids(&self) -> Vec<i64> {
+        self.task_packages
+            .read()
+            .await
+            .keys()
+            .map(|task_id| *task_id as i64)
+            .collect()
+    }
+
+    /// Load the persisted tasks, they wait in the registry until `take_restored`.
+    pub(crate) async fn restore(&self) -> usize {
+        let store = match self.store.as_ref() {
+            Some(store) => store,
+            None => return 0,
+        };
+
+        // The token is gone after a restart, so the signatures cannot be verified again.
+        // The store is local to the executor and only holds packages verified when accepted.
+        let mut restored_task_packages = self.restored_task_packages.write().await;
+        restored_task_packages.extend(
+            store
+                .load_all()
+                .await
+                .into_iter()
+                .map(|SignedTaskPackage { task_package, .. }| task_package),
+        );
+        restored_task_packages.len()
+    }
+
+    /// The last binding of the executor, persisted along with the tasks.
+    pub(crate) async fn restored_bind_request(&self) -> Option<BindRequest> {
+        self.store.as_ref()?.load_bind_request().await
+    }
+
+    /// Persist the binding, so that the restored tasks can start before the executor is bound again.
+    pub(crate) async fn save_bind_request(&self, bind_request: &BindRequest) {
+        if let Some(store) = self.store.as_ref() {
+            store
+                .save_bind_request(bind_request)
+                .await
+                .map_err(|e| error!("Failed to persist the binding: {}", e))
+                .ok();
+        }
+    }
+
+    /// Take the restored tasks that the scheduler has not pushed again in the meantime,
+    /// They are held from now on without being persisted again.
+    pub(crate) async fn take_restored(&self) -> Vec<TaskPackage> {
+        let restored_task_packages: Vec<TaskPackage> = self
+            .restored_task_packages
+            .write()
+            .await
+            .drain(..)
+            .collect();
+
+        let mut taken_task_packages = Vec::new();
+        for task_package in restored_task_packages {
+            if self.contains(task_package.id as u64).await {
+                continue;
+            }
+
+            self.task_packages
+                .write()
+                .await
+                .insert(task_package.id as u64, task_package.clone());
+            self.record_schedule_offset(&task_package).await;
+            taken_task_packages.push(task_package);
+        }
+
+        taken_task_packages
+    }
+
+    /// The tasks whose named time zone has another utc offset at their next fire than they were built with,
+    /// Such as across a daylight saving transition. The new offsets are recorded.
+    pub(crate) async fn drifted_task_packages(&self) -> Vec<TaskPackage> {
+        let task_packages = self.task_packages.read().await;
+        let mut schedule_offsets = self.schedule_offsets.write().await;
+
+        let mut drifted_task_packages = Vec::new();
+        for (task_id, schedule_offset) in schedule_offsets.iter_mut() {
+            let task_package = match task_packages.get(task_id) {
+                Some(task_package) => task_package,
+                None => continue,
+            };
+
+            if follow_schedule_offset(task_package, schedule_offset) {
+                drifted_task_packages.push(task_package.clone());
+            }
+        }
+
+        drifted_task_packages
+    }
+
+    /// The task in a named time zone, when the utc offset of its next fire is another than
+    /// It was built with. Checked as each of its runs starts, So the next fire already keeps the new offset.
+    pub(crate) async fn drifted_task_package(&self, task_id: u64) -> Option<TaskPackage> {
+        let task_packages = self.task_packages.read().await;
+        let mut schedule_offsets = self.schedule_offsets.write().await;
+
+        let task_package = task_packages.get(&task_id)?;
+        let schedule_offset = schedule_offsets.get_mut(&task_id)?;
+        follow_schedule_offset(task_package, schedule_offset).then(|| task_package.clone())
+    }
+
+    async fn record_schedule_offset(&self, task_package: &TaskPackage) {
+        let task_id = task_package.id as u64;
+        let mut schedule_offsets = self.schedule_offsets.write().await;
+        match task_package.schedule_offset() {
+            Ok(Some(offset)) => {
+                schedule_offsets.insert(task_id, offset);
+            }
+            _ => {
+                schedule_offsets.remove(&task_id);
+            }
+        }
+    }
+}
+
+// Records the utc offset of the next fire, Returns `true` when it differs from the recorded one.
+fn follow_schedule_offset(task_package: &TaskPackage, schedule_offset: &mut i32) -> bool {
+    match task_package.schedule_offset() {
+        Ok(Some(offset)) if offset != *schedule_offset => {
+            *schedule_offset = offset;
+            true
+        }
+        _ => false,
+    }
+}
+
+// The last binding of the executor, next to the tasks in `executor_task_store_dir`.
+const BIND_REQUEST_FILE: &str = "bind_request.json";
+
+/// An on-disk store of the signed task-packages accepted by the executor,
+/// One json file per task in the directory of `executor_task_store_dir`,
+/// Along with the last binding that the record ids of their runs are generated from.
+#[derive(Debug)]
+pub(crate) struct TaskStore {
+    dir: PathBuf,
+}
+
+impl TaskStore {
+    /// Returns `None` when `executor_task_store_dir` is not set.
+    pub(crate) fn from_conf(dir: Option<PathBuf>) -> Option<TaskStore> {
+        let dir = dir?;
+
+        std::fs::create_dir_all(&dir).expect("Failed to create `executor_task_store_dir`.");
+        Some(TaskStore { dir })
+    }
+
+    // Write to a temporary file first, so that a crash never leaves a truncated package behind.
+    async fn save(&self, signed_task_package: &SignedTaskPackage) -> Result<(), CommonError> {
+        let task_id = signed_task_package.task_package.id as u64;
+        let temporary_path = self.dir.join(format!("{}.json.tmp", task_id));
+
+        fs::write(&temporary_path, serde_json::to_vec(signed_task_package)?).await?;
+        fs::rename(&temporary_path, self.task_path(task_id)).await?;
+        Ok(())
+    }
+
+    async fn save_bind_request(&self, bind_request: &BindRequest) -> Result<(), CommonError> {
+        let temporary_path = self.dir.join(format!("{}.tmp", BIND_REQUEST_FILE));
+
+        fs::write(&temporary_path, serde_json::to_vec(bind_request)?).await?;
+        fs::rename(&temporary_path, self.dir.join(BIND_REQUEST_FILE)).await?;
+        Ok(())
+    }
+
+    async fn load_bind_request(&self) -> Option<BindRequest> {
+        let bytes = fs::read(self.dir.join(BIND_REQUEST_FILE)).await.ok()?;
+        serde_json::from_slice(&bytes)
+            .map_err(|e| error!("Failed to load the persisted binding: {}", e))
+            .ok()
+    }
+
+    async fn delete(&self, task_id: u64) -> Result<(), CommonError> {
+        match fs::remove_file(self.task_path(task_id)).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    // Files that cannot be read are skipped, so one broken package does not block the others.
+    async fn load_all(&self) -> Vec<SignedTaskPackage> {
+        let mut signed_task_packages = Vec::new();
+
+        let mut entries = match fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                error!("Failed to read `executor_task_store_dir`: {}", e);
+                return signed_task_packages;
+            }
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path
+                .extension()
+                .map_or(true, |extension| extension != "json")
+                || path.ends_with(BIND_REQUEST_FILE)
+            {
+                continue;
+            }
+
+            let signed_task_package: Result<SignedTaskPackage, CommonError> = fs::read(&path)
+                .await
+                .map_err(CommonError::from)
+                .and_then(|bytes| Ok(serde_json::from_slice(&bytes)?));
+
+            match signed_task_package {
+                Ok(signed_task_package) => signed_task_packages.push(signed_task_package),
+                Err(e) => error!("Failed to load the persisted task {:?}: {}", path, e),
+            }
+        }
+
+        signed_task_packages
+    }
+
+    fn task_path(&self, task_id: u64) -> PathBuf {
+        self.dir.join(format!("{}.json", task_id))
+    }
+}
+
+// The first retry of a batch that failed to be delivered waits for it, each further one waits twice as long.
+const OUTBOX_RETRY_BASE_INTERVAL: Duration = Duration::from_secs(1);
+const OUTBOX_RETRY_MAX_INTERVAL: Duration = Duration::from_secs(60);
+// Batches that still cannot be delivered after it are dropped,
+// The scheduler marks their runs as lost once it finds out they no longer run.
+const OUTBOX_RETENTION_SECONDS: u64 = 24 * 60 * 60;
+
+/// The events waiting to be delivered to the scheduler, in the order they happened.
+///
+/// A batch that fails to be delivered is retried with exponential backoff and the later ones wait for it,
+/// When `executor_outbox_dir` is set, the batches are persisted and delivered after the executor restarts.
+/// The events are kept while the executor is not bound, they are stamped with the processor once it is.
+#[derive(Debug, Default)]
+pub(crate) struct EventOutbox {
+    batches: VecDeque<OutboxBatch>,
+    dir: Option<PathBuf>,
+    next_seq: u64,
+    failures: u32,
+    next_attempt: Option<Instant>,
+    dropped_events: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OutboxBatch {
+    seq: u64,
+    created_time: u64,
+    events: Vec<ExecutorEvent>,
+}
+
+impl EventOutbox {
+    /// Reload the batches persisted in `executor_outbox_dir`, if set.
+    pub(crate) async fn from_conf(dir: Option<PathBuf>) -> EventOutbox {
+        let mut outbox = EventOutbox::default();
+        let dir: PathBuf = match dir {
+            Some(dir) => dir,
+            None => return outbox,
+        };
+
+        std::fs::create_dir_all(&dir).expect("Failed to create `executor_outbox_dir`.");
+        outbox.batches = load_outbox_batches(&dir).await.into();
+        outbox.next_seq = outbox.batches.back().map_or(0, |batch| batch.seq + 1);
+        outbox.dir = Some(dir);
+        outbox
+    }
+
+    pub(crate) async fn push(&mut self, events: Vec<ExecutorEvent>) {
+        let batch = OutboxBatch {
+            seq: self.next_seq,
+            created_time: timestamp(),
+            events,
+        };
+        self.next_seq += 1;
+
+        if let Some(dir) = self.dir.as_ref() {
+            save_outbox_batch(dir, &batch)
+                .await
+                .map_err(|e| error!("Failed to persist the event batch {}: {}", batch.seq, e))
+                .ok();
+        }
+        self.batches.push_back(batch);
+    }
+
+    /// Deliver the batches in order until one fails, unless the last failure is still backing off.
+    pub(crate) async fn deliver(
+        &mut self,
+        scheduler: Option<&BindRequest>,
+        token: Option<&str>,
+        client: &RequestClient,
+    ) {
+        if self.next_attempt.map_or(false, |t| Instant::now() < t) {
+            return;
+        }
+
+        while let Some(batch) = self.batches.front() {
+            let age = timestamp().saturating_sub(batch.created_time);
+            if age > OUTBOX_RETENTION_SECONDS {
+                self.dropped_events += batch.events.len() as u64;
+                error!(
+                    "Drop the event batch {} of {} events that could not be delivered for {} seconds ({} events dropped since the executor started), the records: {:?}",
+                    batch.seq,
+                    batch.events.len(),
+                    age,
+                    self.dropped_events,
+                    batch.events.iter().map(|e| e.id).collect::<Vec<i64>>()
+                );
+                debug!("The dropped events: {:?}", &batch.events);
+                self.pop_front().await;
+                continue;
+            }
+
+            // Nothing is delivered until the executor is bound, which is not a failure to back off from.
+            let scheduler_ref = match scheduler {
+                Some(scheduler_ref) => scheduler_ref,
+                None => return,
+            };
+
+            let mut events = batch.events.clone();
+            for event in events.iter_mut() {
+                event.executor_processor_host = scheduler_ref.executor_processor_host.clone();
+                event.executor_processor_id = scheduler_ref.executor_processor_id;
+                event.executor_processor_name = scheduler_ref.executor_processor_name.clone();
+            }
+
+            let delivery_result = match Into::<ExecutorEventCollection>::into(events).sign(token) {
+                Ok(executor_event_collection) => {
+                    send_event_collection(scheduler, executor_event_collection, client).await
+                }
+                Err(e) => Err(e),
+            };
+
+            match delivery_result {
+                Ok(()) => {
+                    self.failures = 0;
+                    self.next_attempt = None;
+                    self.pop_front().await;
+                }
+                Err(e) => {
+                    let backoff = OUTBOX_RETRY_BASE_INTERVAL
+                        .checked_mul(1 << self.failures.min(16))
+                        .map_or(OUTBOX_RETRY_MAX_INTERVAL, |backoff| {
+                            backoff.min(OUTBOX_RETRY_MAX_INTERVAL)
+                        });
+                    error!(
+                        "Failed to deliver the event batch {}, retry in {:?}: {}",
+                        batch.seq, backoff, e
+                    );
+                    self.failures += 1;
+                    self.next_attempt = Some(Instant::now() + backoff);
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn pop_front(&mut self) {
+        if let (Some(batch), Some(dir)) = (self.batches.pop_front(), self.dir.as_ref()) {
+            match fs::remove_file(outbox_batch_path(dir, batch.seq)).await {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    error!("Failed to delete the event batch {}: {}", batch.seq, e)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// Write to a temporary file first, so that a crash never leaves a truncated batch behind.
+async fn save_outbox_batch(dir: &Path, batch: &OutboxBatch) -> Result<(), CommonError> {
+    let temporary_path = dir.join(format!("{}.json.tmp", batch.seq));
+
+    fs::write(&temporary_path, serde_json::to_vec(batch)?).await?;
+    fs::rename(&temporary_path, outbox_batch_path(dir, batch.seq)).await?;
+    Ok(())
+}
+
+// Files that cannot be read are skipped, so one broken batch does not block the others.
+async fn load_outbox_batches(dir: &Path) -> Vec<OutboxBatch> {
+    let mut batches = Vec::new();
+
+    let mut entries = match fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) => {
+            error!("Failed to read `executor_outbox_dir`: {}", e);
+            return batches;
+        }
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path
+            .extension()
+            .map_or(true, |extension| extension != "json")
+        {
+            continue;
+        }
+
+        let batch: Result<OutboxBatch, CommonError> = fs::read(&path)
+            .await
+            .map_err(CommonError::from)
+            .and_then(|bytes| Ok(serde_json::from_slice(&bytes)?));
+
+        match batch {
+            Ok(batch) => batches.push(batch),
+            Err(e) => error!("Failed to load the event batch {:?}: {}", path, e),
+        }
+    }
+
+    batches.sort_by_key(|batch| batch.seq);
+    batches
+}
+
+fn outbox_batch_path(dir: &Path, seq: u64) -> PathBuf {
+    // Padded so that the files list in the order of the batches.
+    dir.join(format!("{:020}.json", seq))
+}
+
+async fn send_event_collection(
+    scheduler: Option<&BindRequest>,
+    executor_event_collection: SignedExecutorEventCollection,
+    client: &RequestClient,
+) -> Result<(), CommonError> {
+    let scheduler_ref = scheduler.ok_or_else(|| {
+        CommonError::DisPass(String::from("The executor is not bound to a scheduler."))
+    })?;
+
+    debug!(
+        "Event collection - {:?}",
+        &executor_event_collection.event_collection
+    );
+
+    let response = client
+        .post(&scheduler_ref.scheduler_host)
+        .json(&executor_event_collection)
+        .send()
+        .await?
+        .json::<UnifiedResponseMessages<usize>>()
+        .await?;
+    debug!("delicate-schduler response: {:?}", &response);
+
+    Into::<Result<usize, CommonError>>::into(response).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use delicate_utils::consensus_message::http_task::TaskKind;
+    use futures::executor::block_on;
+
+    fn task_package(id: i64, retry_times: i16) -> TaskPackage {
+        TaskPackage {
+            id,
+            command: String::from("echo delicate"),
+            retry_times,
+            kind: TaskKind::Shell as i16,
+            ..Default::default()
+        }
+    }
+
+    // The one-shot id of the next retry, if the run is retried.
+    async fn retry(
+        retry_keeper: &RetryKeeper,
+        timer_task_id: u64,
+        record_id: i64,
+        failed: bool,
+    ) -> Option<u64> {
+        retry_keeper
+            .on_run_end(timer_task_id, record_id, failed)
+            .await
+            .map(|retry_task| retry_task.expect("The retry is built.").task_id)
+    }
+
+    #[test]
+    fn test_retry_keeper_retries_failed_runs() {
+        block_on(async {
+            let retry_keeper = RetryKeeper::default();
+            retry_keeper.register(&task_package(7, 2)).await;
+
+            // A successful run and a task without retries are not retried.
+            assert_eq!(retry(&retry_keeper, 7, 100, false).await, None);
+            assert_eq!(retry(&retry_keeper, 8, 100, true).await, None);
+
+            let first_retry = retry(&retry_keeper, 7, 101, true).await.unwrap();
+            assert!(first_retry >= RETRY_TASK_ID_BASE);
+            let run = retry_keeper.resolve(first_retry).await;
+            assert_eq!((run.task_id, run.attempt, run.run_id), (7, 2, 101));
+
+            // The retries share the run of the first attempt.
+            let second_retry = retry(&retry_keeper, first_retry, 102, true).await.unwrap();
+            assert_ne!(second_retry, first_retry);
+            let run = retry_keeper.resolve(second_retry).await;
+            assert_eq!((run.task_id, run.attempt, run.run_id), (7, 3, 101));
+
+            // The last attempt is not retried, and its run is forgotten.
+            assert_eq!(retry(&retry_keeper, second_retry, 103, true).await, None);
+            assert_eq!(
+                retry_keeper.resolve(second_retry).await.task_id,
+                second_retry
+            );
+
+            // The regular runs of the task resolve to themselves.
+            let run = retry_keeper.resolve(7).await;
+            assert_eq!((run.task_id, run.attempt, run.run_id), (7, 1, 0));
+        });
+    }
+
+    #[test]
+    fn test_retry_keeper_retries_triggered_runs() {
+        block_on(async {
+            let retry_keeper = RetryKeeper::default();
+            // The executor does not hold the task, the trigger carries it.
+            let task_trigger = TaskTrigger {
+                task_package: task_package(9, 1),
+                run_id: 50,
+                ..Default::default()
+            };
+
+            let triggered = retry_keeper.trigger(task_trigger).await.unwrap().task_id;
+            assert!(triggered >= RETRY_TASK_ID_BASE);
+            let run = retry_keeper.resolve(triggered).await;
+            assert_eq!((run.task_id, run.attempt, run.run_id), (9, 1, 50));
+
+            let retried = retry(&retry_keeper, triggered, 201, true).await.unwrap();
+            let run = retry_keeper.resolve(retried).await;
+            assert_eq!((run.task_id, run.attempt, run.run_id), (9, 2, 50));
+            assert_eq!(retry(&retry_keeper, retried, 202, true).await, None);
+        });
+    }
+
+    #[test]
+    fn test_retry_keeper_forgets_one_shot_runs_that_end_without_failing() {
+        block_on(async {
+            let retry_keeper = RetryKeeper::default();
+            retry_keeper.register(&task_package(7, 2)).await;
+
+            let retried = retry(&retry_keeper, 7, 101, true).await.unwrap();
+            let task_trigger = TaskTrigger {
+                task_package: task_package(7, 1),
+                run_id: 50,
+                ..Default::default()
+            };
+            let triggered = retry_keeper.trigger(task_trigger).await.unwrap().task_id;
+
+            // E.g. the calendar skipped the runs, they are over and not retried.
+            assert_eq!(retry(&retry_keeper, retried, 102, false).await, None);
+            assert_eq!(retry(&retry_keeper, triggered, 103, false).await, None);
+
+            assert_eq!(retry_keeper.resolve(retried).await.task_id, retried);
+            assert_eq!(retry_keeper.resolve(triggered).await.task_id, triggered);
+            assert!(retry_keeper.unregister(7).await.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_retry_keeper_cancels_one_shot_runs() {
+        block_on(async {
+            let retry_keeper = RetryKeeper::default();
+            retry_keeper.register(&task_package(7, 1)).await;
+
+            let retried = retry(&retry_keeper, 7, 101, true).await.unwrap();
+            let task_trigger = TaskTrigger {
+                task_package: task_package(7, 0),
+                run_id: 50,
+                ..Default::default()
+            };
+            let triggered = retry_keeper.trigger(task_trigger).await.unwrap().task_id;
+
+            // The records of the one-shot runs are known once they start.
+            assert_eq!(retry_keeper.timer_task_id_of(7, 102).await, 7);
+            retry_keeper.on_run_start(retried, 102).await;
+            retry_keeper.on_run_start(triggered, 103).await;
+            assert_eq!(retry_keeper.timer_task_id_of(7, 102).await, retried);
+            assert_eq!(retry_keeper.timer_task_id_of(7, 103).await, triggered);
+            // Regular runs are cancelled through the task-id.
+            assert_eq!(retry_keeper.timer_task_id_of(7, 104).await, 7);
+            assert_eq!(retry_keeper.timer_task_id_of(8, 102).await, 8);
+
+            // Removing the task cancels the one-shot runs still pending.
+            let mut pending = retry_keeper.unregister(7).await;
+            pending.sort_unstable();
+            assert_eq!(pending, vec![retried, triggered]);
+            assert_eq!(retry_keeper.timer_task_id_of(7, 102).await, 7);
+            assert!(retry_keeper.unregister(7).await.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_task_store_keeps_the_binding_next_to_the_tasks() {
+        let dir = std::env::temp_dir().join(format!("delicate-task-store-{}", std::process::id()));
+        let runtime = Builder::new_current_thread().enable_all().build().unwrap();
+
+        runtime.block_on(async {
+            let task_registry = TaskRegistry::new(TaskStore::from_conf(Some(dir.clone())));
+            assert_eq!(task_registry.restored_bind_request().await, None);
+
+            let bind_request = BindRequest {
+                executor_processor_id: 3,
+                executor_machine_id: 0b01_0010_0011,
+                ..Default::default()
+            };
+            task_registry.save_bind_request(&bind_request).await;
+            task_registry
+                .insert(&SignedTaskPackage {
+                    task_package: task_package(7, 0),
+                    ..Default::default()
+                })
+                .await;
+
+            // After a restart the tasks are found along with the binding, which is not a task.
+            let task_registry = TaskRegistry::new(TaskStore::from_conf(Some(dir.clone())));
+            assert_eq!(task_registry.restore().await, 1);
+            assert_eq!(
+                task_registry.restored_bind_request().await,
+                Some(bind_request)
+            );
+            let restored: Vec<i64> = task_registry
+                .take_restored()
+                .await
+                .iter()
+                .map(|task_package| task_package.id)
+                .collect();
+            assert_eq!(restored, vec![7]);
+        });
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,221 @@
+use crate::prelude::*;
+
+use delicate_utils::conf::{ConfigProblems, ValidateConfig};
+use std::convert::TryFrom;
+use std::net::ToSocketAddrs;
+
+/// The environment variable naming the configuration file of the executor, it is optional.
+pub(crate) const EXECUTOR_CONFIG_FILE: &str = "EXECUTOR_CONFIG_FILE";
+
+/// The configuration of the executor, the keys are the names of the environment variables
+/// Without the `DELICATE_` prefix in lower case.
+///
+/// Only `log_level` is applied again on `SIGHUP`, the others need a restart.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub(crate) struct ExecutorConfig {
+    pub(crate) executor_listening_address: String,
+    pub(crate) log_level: String,
+    /// Directory where the executor persists the tasks it accepts, they start again when it restarts.
+    pub(crate) executor_task_store_dir: Option<PathBuf>,
+    /// Directory where the executor keeps the events the scheduler has not accepted yet.
+    pub(crate) executor_outbox_dir: Option<PathBuf>,
+    /// A cgroup v2 directory delegated to the executor.
+    pub(crate) executor_cgroup_root: Option<PathBuf>,
+    /// The output kept of each stream of a run in KiB, when the task sets no limit.
+    pub(crate) executor_output_limit: usize,
+    /// A directory the whole output of a truncated stream is written to.
+    pub(crate) executor_output_spool_dir: Option<PathBuf>,
+    pub(crate) security_level: u16,
+    pub(crate) security_public_key: Option<PathBuf>,
+}
+
+impl Default for ExecutorConfig {
+    fn default() -> Self {
+        ExecutorConfig {
+            executor_listening_address: String::new(),
+            log_level: String::from("info"),
+            executor_task_store_dir: None,
+            executor_outbox_dir: None,
+            executor_cgroup_root: None,
+            executor_output_limit: 30,
+            executor_output_spool_dir: None,
+            security_level: 0,
+            security_public_key: None,
+        }
+    }
+}
+
+impl ExecutorConfig {
+    pub(crate) fn log_level(&self) -> Level {
+        Level::from_str(&self.log_level).expect("The log level is validated.")
+    }
+
+    pub(crate) fn security_level(&self) -> SecurityLevel {
+        SecurityLevel::try_from(self.security_level).expect("The security level is validated.")
+    }
+}
+
+impl ValidateConfig for ExecutorConfig {
+    fn legacy_keys() -> &'static [&'static str] {
+        &["executor_listening_address", "log_level"]
+    }
+
+    fn validate(&self, problems: &mut ConfigProblems) {
+        problems.require(
+            "executor_listening_address",
+            &self.executor_listening_address,
+        );
+        if !self.executor_listening_address.trim().is_empty() {
+            problems.check(
+                self.executor_listening_address.to_socket_addrs().is_ok(),
+                format!(
+                    "`executor_listening_address` ({}) is not an address to listen on.",
+                    self.executor_listening_address
+                ),
+            );
+        }
+
+        problems.check(
+            Level::from_str(&self.log_level).is_ok(),
+            format!(
+                "`log_level` ({}) is none of `error`, `warn`, `info`, `debug` and `trace`.",
+                self.log_level
+            ),
+        );
+        problems.check(
+            self.executor_output_limit > 0,
+            "`executor_output_limit` must be greater than 0.",
+        );
+
+        for (key, dir) in [
+            ("executor_cgroup_root", &self.executor_cgroup_root),
+            ("executor_output_spool_dir", &self.executor_output_spool_dir),
+        ] {
+            if let Some(dir) = dir {
+                problems.check(
+                    dir.is_dir(),
+                    format!("`{}` ({}) is not a directory.", key, dir.display()),
+                );
+            }
+        }
+
+        match SecurityLevel::try_from(self.security_level) {
+            Ok(SecurityLevel::Normal) => match self.security_public_key.as_ref() {
+                Some(key) => problems.check(
+                    key.is_file(),
+                    format!("`security_public_key` ({}) is not a file.", key.display()),
+                ),
+                None => problems.check(
+                    false,
+                    "`security_public_key` is required when `security_level` is 1.",
+                ),
+            },
+            Ok(SecurityLevel::ZeroRestriction) => {}
+            Err(_) => problems.check(
+                false,
+                format!(
+                    "`security_level` ({}) is neither 0 nor 1.",
+                    self.security_level
+                ),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_conf() -> ExecutorConfig {
+        ExecutorConfig {
+            executor_listening_address: String::from("127.0.0.1:9080"),
+            ..ExecutorConfig::default()
+        }
+    }
+
+    fn problems(conf: &ExecutorConfig) -> Vec<String> {
+        let mut problems = ConfigProblems::default();
+        conf.validate(&mut problems);
+        problems.problems().to_vec()
+    }
+
+    type ConfChange = Box<dyn FnOnce(&mut ExecutorConfig)>;
+
+    #[test]
+    fn test_defaults() {
+        let conf = ExecutorConfig::default();
+
+        assert_eq!(conf.log_level(), Level::INFO);
+        assert_eq!(conf.executor_output_limit, 30);
+        assert!(conf.executor_task_store_dir.is_none());
+        assert!(matches!(
+            conf.security_level(),
+            SecurityLevel::ZeroRestriction
+        ));
+        assert_eq!(
+            problems(&conf),
+            vec![String::from("`executor_listening_address` is required.")]
+        );
+    }
+
+    #[test]
+    fn test_valid_conf() {
+        assert!(problems(&valid_conf()).is_empty());
+
+        let mut conf = valid_conf();
+        conf.executor_output_spool_dir = Some(std::env::temp_dir());
+        assert!(problems(&conf).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_conf() {
+        let invalid_changes: Vec<(&str, ConfChange)> = vec![
+            (
+                "executor_listening_address",
+                Box::new(|c| c.executor_listening_address = String::from("localhost")),
+            ),
+            (
+                "log_level",
+                Box::new(|c| c.log_level = String::from("verbose")),
+            ),
+            (
+                "executor_output_limit",
+                Box::new(|c| c.executor_output_limit = 0),
+            ),
+            (
+                "executor_cgroup_root",
+                Box::new(|c| c.executor_cgroup_root = Some(PathBuf::from("/nonexistent/cgroup"))),
+            ),
+            (
+                "executor_output_spool_dir",
+                Box::new(|c| {
+                    c.executor_output_spool_dir = Some(PathBuf::from("/nonexistent/spool"))
+                }),
+            ),
+            ("security_level", Box::new(|c| c.security_level = 2)),
+            ("security_public_key", Box::new(|c| c.security_level = 1)),
+            (
+                "security_public_key",
+                Box::new(|c| {
+                    c.security_level = 1;
+                    c.security_public_key = Some(PathBuf::from("/nonexistent/public.pem"));
+                }),
+            ),
+        ];
+
+        for (key, change) in invalid_changes {
+            let mut conf = valid_conf();
+            change(&mut conf);
+
+            let problems = problems(&conf);
+            assert_eq!(problems.len(), 1, "{}: {:?}", key, problems);
+            assert!(
+                problems[0].contains(&format!("`{}`", key)),
+                "{}: {:?}",
+                key,
+                problems
+            );
+        }
+    }
+}
@@ -1,91 +1,186 @@
 #![allow(clippy::async_yields_async)]
 mod component;
+mod config;
 mod prelude;
 use prelude::*;
 
 #[handler]
-#[instrument(skip(executor_conf, shared_delay_timer, signed_task_package), fields(task_package = signed_task_package.task_package.id))]
+#[instrument(skip(executor_conf, shared_delay_timer, retry_keeper, task_registry, signed_task_package), fields(task_package = signed_task_package.task_package.id))]
 async fn create_task(
     Json(signed_task_package): Json<SignedTaskPackage>,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+    task_registry: Data<&Arc<TaskRegistry>>,
 ) -> Json<UnitUnifiedResponseMessages> {
-    let response: UnitUnifiedResponseMessages =
-        Into::into(pre_create_task(signed_task_package, shared_delay_timer, executor_conf).await);
+    let response: UnitUnifiedResponseMessages = Into::into(
+        pre_create_task(
+            signed_task_package,
+            shared_delay_timer,
+            executor_conf,
+            retry_keeper,
+            task_registry,
+        )
+        .await,
+    );
 
     Json(response)
 }
 
-pub async fn pre_create_task(
+pub(crate) async fn pre_create_task(
     signed_task_package: SignedTaskPackage,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+    task_registry: Data<&Arc<TaskRegistry>>,
 ) -> Result<(), CommonError> {
     info!("pre_create_task: {}", &signed_task_package.task_package);
     let guard = executor_conf.get_bind_scheduler_token_ref().await;
     let token = guard.as_ref().map(|s| s.deref());
-    let task = signed_task_package
-        .get_task_package_after_verify(token)
-        .map(TryInto::<Task>::try_into)??;
+    signed_task_package.verify(token)?;
+    let task_package = signed_task_package.task_package.clone();
+    let task_id = task_package.id as u64;
+    retry_keeper.register(&task_package).await;
+    let task: Task = task_package.try_into()?;
+
+    // The scheduler pushes the tasks again when it re-binds or repairs the executor,
+    // A task that is already held is replaced instead of added twice.
+    if task_registry.contains(task_id).await {
+        shared_delay_timer.update_task(task)?;
+    } else {
+        shared_delay_timer.add_task(task)?;
+    }
 
-    Ok(shared_delay_timer.add_task(task)?)
+    task_registry.insert(&signed_task_package).await;
+    Ok(())
 }
 
 #[handler]
-#[instrument(skip(executor_conf, shared_delay_timer, signed_task_package), fields(task_package = signed_task_package.task_package.id))]
+#[instrument(skip(executor_conf, shared_delay_timer, retry_keeper, task_registry, signed_task_package), fields(task_package = signed_task_package.task_package.id))]
 async fn update_task(
     Json(signed_task_package): Json<SignedTaskPackage>,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+    task_registry: Data<&Arc<TaskRegistry>>,
 ) -> Json<UnitUnifiedResponseMessages> {
-    let response: UnitUnifiedResponseMessages =
-        Into::into(pre_update_task(signed_task_package, shared_delay_timer, executor_conf).await);
+    let response: UnitUnifiedResponseMessages = Into::into(
+        pre_update_task(
+            signed_task_package,
+            shared_delay_timer,
+            executor_conf,
+            retry_keeper,
+            task_registry,
+        )
+        .await,
+    );
 
     Json(response)
 }
 
-pub async fn pre_update_task(
+pub(crate) async fn pre_update_task(
     signed_task_package: SignedTaskPackage,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+    task_registry: Data<&Arc<TaskRegistry>>,
 ) -> Result<(), CommonError> {
     info!("pre_update_task: {}", &signed_task_package.task_package);
     let guard = executor_conf.get_bind_scheduler_token_ref().await;
     let token = guard.as_ref().map(|s| s.deref());
-    let task = signed_task_package
-        .get_task_package_after_verify(token)
-        .map(TryInto::<Task>::try_into)??;
-
-    Ok(shared_delay_timer.update_task(task)?)
+    signed_task_package.verify(token)?;
+    let task_package = signed_task_package.task_package.clone();
+    retry_keeper.register(&task_package).await;
+    let task: Task = task_package.try_into()?;
+
+    shared_delay_timer.update_task(task)?;
+    task_registry.insert(&signed_task_package).await;
+    Ok(())
 }
 
 #[handler]
-#[instrument(skip(executor_conf, shared_delay_timer, signed_task_unit), fields(task_id = signed_task_unit.task_unit.task_id))]
+#[instrument(skip(executor_conf, shared_delay_timer, retry_keeper, task_registry, signed_task_unit), fields(task_id = signed_task_unit.task_unit.task_id))]
 async fn remove_task(
     Json(signed_task_unit): Json<SignedTaskUnit>,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+    task_registry: Data<&Arc<TaskRegistry>>,
 ) -> Json<UnitUnifiedResponseMessages> {
-    let response: UnitUnifiedResponseMessages =
-        pre_remove_task(signed_task_unit, shared_delay_timer, executor_conf)
-            .await
-            .into();
+    let response: UnitUnifiedResponseMessages = pre_remove_task(
+        signed_task_unit,
+        shared_delay_timer,
+        executor_conf,
+        retry_keeper,
+        task_registry,
+    )
+    .await
+    .into();
     Json(response)
 }
 
-pub async fn pre_remove_task(
+pub(crate) async fn pre_remove_task(
     signed_task_unit: SignedTaskUnit,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+    task_registry: Data<&Arc<TaskRegistry>>,
 ) -> Result<(), CommonError> {
     info!("pre_remove_task: {}", &signed_task_unit);
 
     let guard = executor_conf.get_bind_scheduler_token_ref().await;
     let token = guard.as_ref().map(|s| s.deref());
     let task_unit = signed_task_unit.get_task_unit_after_verify(token)?;
+
+    // Retries that have not been triggered yet are removed along with the task.
+    for retry_task_id in retry_keeper.unregister(task_unit.task_id as u64).await {
+        shared_delay_timer.remove_task(retry_task_id).ok();
+        forget_routine_outputs(retry_task_id);
+        forget_live_outputs(retry_task_id);
+    }
+    task_registry.remove(task_unit.task_id as u64).await;
+    forget_routine_outputs(task_unit.task_id as u64);
+    forget_live_outputs(task_unit.task_id as u64);
+
     Ok(shared_delay_timer.remove_task(task_unit.task_id as u64)?)
 }
 
+#[handler]
+#[instrument(skip(executor_conf, shared_delay_timer, retry_keeper, signed_task_trigger), fields(task_trigger = signed_task_trigger.task_trigger.to_string().deref()))]
+async fn trigger_task(
+    Json(signed_task_trigger): Json<SignedTaskTrigger>,
+    shared_delay_timer: Data<&Arc<DelayTimer>>,
+    executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+) -> Json<UnitUnifiedResponseMessages> {
+    let response: UnitUnifiedResponseMessages = pre_trigger_task(
+        signed_task_trigger,
+        shared_delay_timer,
+        executor_conf,
+        retry_keeper,
+    )
+    .await
+    .into();
+    Json(response)
+}
+
+pub(crate) async fn pre_trigger_task(
+    signed_task_trigger: SignedTaskTrigger,
+    shared_delay_timer: Data<&Arc<DelayTimer>>,
+    executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+) -> Result<(), CommonError> {
+    info!("pre_trigger_task: {}", &signed_task_trigger);
+
+    let guard = executor_conf.get_bind_scheduler_token_ref().await;
+    let token = guard.as_ref().map(|s| s.deref());
+    let task_trigger = signed_task_trigger.get_task_trigger_after_verify(token)?;
+
+    // The triggered run is a one-shot task, the task held by the executor keeps its own schedule.
+    let task = retry_keeper.trigger(task_trigger).await?;
+    Ok(shared_delay_timer.add_task(task)?)
+}
+
 #[handler]
 #[instrument(skip(executor_conf, shared_delay_timer, signed_task_unit), fields(task_id = signed_task_unit.task_unit.task_id))]
 async fn advance_task(
@@ -100,7 +195,7 @@ async fn advance_task(
     Json(response)
 }
 
-pub async fn pre_advance_task(
+pub(crate) async fn pre_advance_task(
     signed_task_unit: SignedTaskUnit,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
@@ -113,23 +208,29 @@ pub async fn pre_advance_task(
 }
 
 #[handler]
-#[instrument(skip(executor_conf, shared_delay_timer, signed_cancel_task_record), fields(cancel_task_record = signed_cancel_task_record.cancel_task_record.to_string().deref()))]
+#[instrument(skip(executor_conf, shared_delay_timer, retry_keeper, signed_cancel_task_record), fields(cancel_task_record = signed_cancel_task_record.cancel_task_record.to_string().deref()))]
 async fn cancel_task(
     Json(signed_cancel_task_record): Json<SignedCancelTaskRecord>,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
 ) -> Json<UnitUnifiedResponseMessages> {
-    let response: UnitUnifiedResponseMessages =
-        pre_cancel_task(signed_cancel_task_record, shared_delay_timer, executor_conf)
-            .await
-            .into();
+    let response: UnitUnifiedResponseMessages = pre_cancel_task(
+        signed_cancel_task_record,
+        shared_delay_timer,
+        executor_conf,
+        retry_keeper,
+    )
+    .await
+    .into();
     Json(response)
 }
 
-pub async fn pre_cancel_task(
+pub(crate) async fn pre_cancel_task(
     signed_cancel_task_record: SignedCancelTaskRecord,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
 ) -> Result<(), CommonError> {
     info!("pre_cancel_task: {}", &signed_cancel_task_record);
 
@@ -137,10 +238,72 @@ pub async fn pre_cancel_task(
     let token = guard.as_ref().map(|s| s.deref());
     let cancel_task_record =
         signed_cancel_task_record.get_cancel_task_record_after_verify(token)?;
-    Ok(shared_delay_timer.cancel_task(
-        cancel_task_record.task_id as u64,
-        cancel_task_record.record_id,
-    )?)
+
+    // Retries and triggered runs are scheduled under one-shot ids rather than the task-id.
+    let timer_task_id = retry_keeper
+        .timer_task_id_of(
+            cancel_task_record.task_id as u64,
+            cancel_task_record.record_id,
+        )
+        .await;
+    Ok(shared_delay_timer.cancel_task(timer_task_id, cancel_task_record.record_id)?)
+}
+
+#[handler]
+#[instrument(skip(executor_conf, signed_tail_task_record), fields(tail_task_record = signed_tail_task_record.tail_task_record.to_string().deref()))]
+async fn tail_task_output(
+    Json(signed_tail_task_record): Json<SignedTailTaskRecord>,
+    executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+) -> Json<UnifiedResponseMessages<TaskRecordOutput>> {
+    let response: UnifiedResponseMessages<TaskRecordOutput> =
+        pre_tail_task_output(signed_tail_task_record, executor_conf)
+            .await
+            .into();
+    Json(response)
+}
+
+pub(crate) async fn pre_tail_task_output(
+    signed_tail_task_record: SignedTailTaskRecord,
+    executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+) -> Result<TaskRecordOutput, CommonError> {
+    debug!("pre_tail_task_output: {}", &signed_tail_task_record);
+
+    let guard = executor_conf.get_bind_scheduler_token_ref().await;
+    let token = guard.as_ref().map(|s| s.deref());
+    let tail_task_record = signed_tail_task_record.get_tail_task_record_after_verify(token)?;
+
+    read_live_output(tail_task_record.record_id, tail_task_record.offset).ok_or_else(|| {
+        CommonError::DisPass(format!(
+            "The output of record {} is not held by this executor.",
+            tail_task_record.record_id
+        ))
+    })
+}
+
+#[handler]
+#[instrument(skip(executor_conf, signed_task_record_probe), fields(task_record_probe = signed_task_record_probe.task_record_probe.to_string().deref()))]
+async fn probe_task_records(
+    Json(signed_task_record_probe): Json<SignedTaskRecordProbe>,
+    executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+) -> Json<UnifiedResponseMessages<Vec<i64>>> {
+    let response: UnifiedResponseMessages<Vec<i64>> =
+        pre_probe_task_records(signed_task_record_probe, executor_conf)
+            .await
+            .into();
+    Json(response)
+}
+
+pub(crate) async fn pre_probe_task_records(
+    signed_task_record_probe: SignedTaskRecordProbe,
+    executor_conf: Data<&Arc<ExecutorSecurityConf>>,
+) -> Result<Vec<i64>, CommonError> {
+    info!("pre_probe_task_records: {}", &signed_task_record_probe);
+
+    let guard = executor_conf.get_bind_scheduler_token_ref().await;
+    let token = guard.as_ref().map(|s| s.deref());
+    let task_record_probe = signed_task_record_probe.get_task_record_probe_after_verify(token)?;
+
+    Ok(running_records(&task_record_probe.record_ids))
 }
 
 #[allow(dead_code)]
@@ -154,12 +317,13 @@ async fn maintenance(
 
 // Health Screening
 #[handler]
-#[instrument(skip(req, signed_health_screen_unit, executor_conf, system_mirror), fields(time = signed_health_screen_unit.health_screen_unit.time))]
+#[instrument(skip(req, signed_health_screen_unit, executor_conf, system_mirror, task_registry), fields(time = signed_health_screen_unit.health_screen_unit.time))]
 async fn health_screen(
     req: &Request,
     Json(signed_health_screen_unit): Json<SignedHealthScreenUnit>,
     executor_conf: Data<&Arc<ExecutorSecurityConf>>,
     system_mirror: Data<&Arc<SystemMirror>>,
+    task_registry: Data<&Arc<TaskRegistry>>,
 ) -> Json<UnifiedResponseMessages<HealthCheckPackage>> {
     let guard = executor_conf.get_bind_scheduler_token_ref().await;
     let token = guard.as_ref().map(|s| s.deref());
@@ -175,10 +339,12 @@ async fn health_screen(
             .await
             .clone()
             .unwrap_or_default();
+        let task_ids = Some(task_registry.task_ids().await);
 
         let health_check_package = HealthCheckPackage {
             system_snapshot,
             bind_request,
+            task_ids,
         };
         return Json(
             UnifiedResponseMessages::<HealthCheckPackage>::success_with_data(health_check_package),
@@ -192,12 +358,14 @@ async fn health_screen(
 }
 
 #[handler]
-#[instrument(skip(request_bind_scheduler, security_conf, shared_delay_timer), fields(bind_scheduler = request_bind_scheduler.bind_request.to_string().deref()))]
+#[instrument(skip(request_bind_scheduler, security_conf, shared_delay_timer, retry_keeper, task_registry), fields(bind_scheduler = request_bind_scheduler.bind_request.to_string().deref()))]
 // Or set security level, no authentication at level 0, public and private keys required at level 1.
 async fn bind_executor(
     Json(request_bind_scheduler): Json<SignedBindRequest>,
     security_conf: Data<&Arc<ExecutorSecurityConf>>,
     shared_delay_timer: Data<&Arc<DelayTimer>>,
+    retry_keeper: Data<&Arc<RetryKeeper>>,
+    task_registry: Data<&Arc<TaskRegistry>>,
 ) -> Json<UnifiedResponseMessages<EncryptedBindResponse>> {
     info!("{}", &request_bind_scheduler.bind_request);
 
@@ -207,18 +375,16 @@ async fn bind_executor(
 
         let token: Option<String> = security_conf.generate_token();
 
-        // Take 10 bits from executor_machine_id and do machine_id and node_id in two groups.
-
-        let executor_machine_id = bind_request.executor_machine_id;
-        let extractor: i16 = 0b00_0001_1111;
-        let node_id = executor_machine_id & extractor;
-        let machine_id = (executor_machine_id >> 5) & extractor;
-
-        shared_delay_timer.update_id_generator_conf(machine_id as i32, node_id as i32);
+        update_id_generator_conf(&shared_delay_timer, bind_request.executor_machine_id);
+        task_registry.save_bind_request(&bind_request).await;
 
         *security_conf.get_bind_scheduler_inner_mut().await = Some(bind_request);
         *security_conf.get_bind_scheduler_token_mut().await = token.clone();
 
+        // The record ids of the runs come from the machine id above,
+        // So the tasks restored without a persisted binding start only now.
+        restore_tasks(&shared_delay_timer, &retry_keeper, &task_registry).await;
+
         let bind_response = BindResponse {
             time: timestamp() as i64,
             token,
@@ -238,7 +404,15 @@ async fn bind_executor(
 fn main() -> AnyResult<()> {
     // Loads environment variables.
     dotenv().ok();
-    init_logger();
+
+    // The configuration is checked as a whole before anything starts, its problems are reported together.
+    let conf = load_config::<ExecutorConfig>(EXECUTOR_CONFIG_FILE)?;
+    let log_level_reloader = init_logger(conf.log_level());
+    set_output_defaults(
+        conf.executor_output_limit,
+        conf.executor_output_spool_dir.clone(),
+    );
+    set_cgroup_root(conf.executor_cgroup_root.clone());
 
     let raw_runtime = Builder::new_multi_thread()
         .thread_name_fn(|| {
@@ -260,14 +434,17 @@ fn main() -> AnyResult<()> {
             .at("/api/task/create", post(create_task))
             .at("/api/task/remove", post(remove_task))
             .at("/api/task/advance", post(advance_task))
+            .at("/api/task/trigger", post(trigger_task))
             .at("/api/task_instance/kill", post(cancel_task))
+            .at("/api/task_instance/output", post(tail_task_output))
+            .at("/api/task_instance/probe", post(probe_task_records))
             .at("/api/executor/health_screen", post(health_screen))
             .at("/api/executor/bind", post(bind_executor));
 
-        let app = init_executor(route, arc_runtime_cloned).await;
-        let address = env::var("EXECUTOR_LISTENING_ADDRESS")
-            .expect("Without `EXECUTOR_LISTENING_ADDRESS` set in .env");
-        let listener = TcpListener::bind(address);
+        let app = init_executor(route, arc_runtime_cloned, &conf).await?;
+        launch_config_reloader(log_level_reloader);
+
+        let listener = TcpListener::bind(conf.executor_listening_address);
         let server = Server::new(listener);
         Ok(server.run(app).await?)
     });
@@ -275,73 +452,201 @@ fn main() -> AnyResult<()> {
     block_result
 }
 
-fn init_logger() {
-    let log_level: Level =
-        FromStr::from_str(&env::var("LOG_LEVEL").unwrap_or_else(|_| String::from("info")))
-            .expect("Log level acquired fail.");
+// Applies a log level other than the one the logger is initialized with.
+type LogLevelReloader = Box<dyn Fn(Level) + Send + Sync>;
 
-    FmtSubscriber::builder()
+fn init_logger(log_level: Level) -> LogLevelReloader {
+    let builder = FmtSubscriber::builder()
         // will be written to stdout.
-        .with_max_level(log_level)
+        .with_env_filter(level_filter(log_level))
         .with_thread_names(true)
-        // completes the builder.
-        .init();
+        .with_filter_reloading();
+    let reload_handle = builder.reload_handle();
+
+    // completes the builder.
+    builder.init();
+
+    Box::new(move |log_level| {
+        reload_handle
+            .reload(level_filter(log_level))
+            .map_err(|e| error!("Failed to reload the log level: {}", e))
+            .ok();
+    })
+}
+
+// The filter the reload handle swaps, every target is logged at `log_level`.
+fn level_filter(log_level: Level) -> EnvFilter {
+    EnvFilter::default().add_directive(LevelFilter::from_level(log_level).into())
+}
+
+// The configuration is loaded again on `SIGHUP`, only the log level is applied without a restart.
+fn launch_config_reloader(log_level_reloader: LogLevelReloader) {
+    launch_reload_on_hangup(EXECUTOR_CONFIG_FILE, move |conf: ExecutorConfig| {
+        log_level_reloader(conf.log_level());
+    });
 }
-async fn init_executor(app: Route, arc_runtime: Arc<Runtime>) -> impl Endpoint {
+
+async fn init_executor(
+    app: Route,
+    arc_runtime: Arc<Runtime>,
+    conf: &ExecutorConfig,
+) -> AnyResult<impl Endpoint> {
     let mut delay_timer = DelayTimerBuilder::default()
         .tokio_runtime_shared_by_custom(arc_runtime)
         .enable_status_report()
         .build();
     let request_client = RequestClient::new();
-    let arc_security_conf = Arc::new(ExecutorSecurityConf::default());
+    let arc_security_conf = Arc::new(ExecutorSecurityConf::new(
+        conf.security_level(),
+        conf.security_public_key.as_deref(),
+    )?);
+    let arc_retry_keeper = Arc::new(RetryKeeper::default());
 
     let shared_security_conf: AddData<Arc<ExecutorSecurityConf>> =
         AddData::new(arc_security_conf.clone());
     let shared_system_mirror: AddData<Arc<SystemMirror>> =
         AddData::new(Arc::new(SystemMirror::default()));
     let shared_request_client = AddData::new(request_client.clone());
-    launch_status_reporter(&mut delay_timer, arc_security_conf, request_client);
-    let shared_delay_timer: AddData<Arc<DelayTimer>> = AddData::new(Arc::new(delay_timer));
+    let shared_retry_keeper: AddData<Arc<RetryKeeper>> = AddData::new(arc_retry_keeper.clone());
+    let arc_task_registry = Arc::new(TaskRegistry::new(TaskStore::from_conf(
+        conf.executor_task_store_dir.clone(),
+    )));
+    let shared_task_registry: AddData<Arc<TaskRegistry>> = AddData::new(arc_task_registry.clone());
 
-    app.with(shared_delay_timer)
+    let status_reporter_option = delay_timer.take_status_reporter();
+    let arc_delay_timer = Arc::new(delay_timer);
+    // The restored tasks run even while the scheduler is unreachable,
+    // Their events wait in the outbox until the executor is bound again.
+    let restored_num = arc_task_registry.restore().await;
+    match arc_task_registry.restored_bind_request().await {
+        Some(bind_request) => {
+            update_id_generator_conf(&arc_delay_timer, bind_request.executor_machine_id);
+            restore_tasks(&arc_delay_timer, &arc_retry_keeper, &arc_task_registry).await;
+        }
+        None if restored_num > 0 => {
+            info!(
+                "{} persisted tasks are held until the executor is bound again.",
+                restored_num
+            );
+        }
+        None => {}
+    }
+    launch_schedule_offset_keeper(arc_delay_timer.clone(), arc_task_registry.clone());
+    launch_status_reporter(
+        status_reporter_option,
+        arc_delay_timer.clone(),
+        arc_task_registry,
+        arc_retry_keeper,
+        arc_security_conf,
+        request_client,
+        conf.executor_outbox_dir.clone(),
+    );
+    let shared_delay_timer: AddData<Arc<DelayTimer>> = AddData::new(arc_delay_timer);
+
+    Ok(app
+        .with(shared_delay_timer)
         .with(shared_security_conf)
         .with(shared_system_mirror)
         .with(shared_request_client)
+        .with(shared_retry_keeper)
+        .with(shared_task_registry))
+}
+// Take 10 bits from executor_machine_id and do machine_id and node_id in two groups.
+fn update_id_generator_conf(delay_timer: &DelayTimer, executor_machine_id: i16) {
+    let extractor: i16 = 0b00_0001_1111;
+    let node_id = executor_machine_id & extractor;
+    let machine_id = (executor_machine_id >> 5) & extractor;
+
+    delay_timer.update_id_generator_conf(machine_id as i32, node_id as i32);
+}
+
+// Start the tasks persisted before the executor restarted,
+// So that they keep running even if the scheduler does not push them again.
+async fn restore_tasks(
+    delay_timer: &DelayTimer,
+    retry_keeper: &RetryKeeper,
+    task_registry: &TaskRegistry,
+) {
+    for task_package in task_registry.take_restored().await {
+        info!("Restore the persisted task: {}", &task_package);
+        retry_keeper.register(&task_package).await;
+
+        let task_id = task_package.id;
+        let restore_result: Result<(), CommonError> = TryInto::<Task>::try_into(task_package)
+            .and_then(|task| Ok(delay_timer.add_task(task)?));
+
+        if let Err(e) = restore_result {
+            error!("Failed to restore the task {}: {}", task_id, e);
+            task_registry.remove(task_id as u64).await;
+        }
+    }
+}
+
+// `DelayTimer` follows the tasks in named time zones with the utc offset of their next fire,
+// They are built again once the offset changes so that the runs keep to the wall-clock time.
+// The status reporter re-arms a task as each of its runs starts, This is the fallback
+// For the tasks that have not run since the offset changed, Such as after a restore.
+fn launch_schedule_offset_keeper(delay_timer: Arc<DelayTimer>, task_registry: Arc<TaskRegistry>) {
+    tokio_spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+
+            for task_package in task_registry.drifted_task_packages().await {
+                rearm_task(&delay_timer, task_package);
+            }
+        }
+    });
+}
+
+fn rearm_task(delay_timer: &DelayTimer, task_package: TaskPackage) {
+    let task_id = task_package.id;
+    let update_result: Result<(), CommonError> =
+        TryInto::<Task>::try_into(task_package).and_then(|task| Ok(delay_timer.update_task(task)?));
+
+    if let Err(e) = update_result {
+        error!("Failed to follow the time zone of task {}: {}", task_id, e);
+    }
 }
+
 fn launch_status_reporter(
-    delay_timer: &mut DelayTimer,
+    status_reporter_option: Option<StatusReporter>,
+    delay_timer: Arc<DelayTimer>,
+    task_registry: Arc<TaskRegistry>,
+    retry_keeper: Arc<RetryKeeper>,
     shared_security_conf: Arc<ExecutorSecurityConf>,
     client: RequestClient,
+    outbox_dir: Option<PathBuf>,
 ) {
-    let status_reporter_option = delay_timer.take_status_reporter();
-
     if let Some(status_reporter) = status_reporter_option {
         tokio_spawn(async move {
             // After taking the lock, get the resource quickly and release the lock.
 
             let mut token: Option<String> = None;
             let mut scheduler: Option<BindRequest> = None;
+            let mut outbox = EventOutbox::from_conf(outbox_dir).await;
 
             loop {
                 let f = async {
                     fresh_scheduler_conf(&shared_security_conf, &mut token, &mut scheduler).await;
 
-                    let events = collect_events(&status_reporter, scheduler.as_ref()).await?;
-
-                    if events.is_empty() {
-                        return Ok(());
+                    let events = collect_events(
+                        &status_reporter,
+                        &delay_timer,
+                        &task_registry,
+                        &retry_keeper,
+                    )
+                    .await?;
+
+                    // The events are kept until the scheduler accepts them, those of the runs before
+                    // The executor is bound as well. The batches left by an earlier failure
+                    // Are retried even when there is nothing new.
+                    if !events.is_empty() {
+                        outbox.push(events).await;
                     }
-
-                    if let Ok(executor_event_collection) =
-                        Into::<ExecutorEventCollection>::into(events).sign(token.as_deref())
-                    {
-                        send_event_collection(
-                            scheduler.as_ref(),
-                            executor_event_collection,
-                            &client,
-                        )
+                    outbox
+                        .deliver(scheduler.as_ref(), token.as_deref(), &client)
                         .await;
-                    }
 
                     Ok(())
                 };
@@ -392,7 +697,9 @@ async fn fresh_scheduler_conf(
 
 async fn collect_events(
     status_reporter: &StatusReporter,
-    scheduler: Option<&BindRequest>,
+    delay_timer: &DelayTimer,
+    task_registry: &TaskRegistry,
+    retry_keeper: &RetryKeeper,
 ) -> Result<Vec<ExecutorEvent>, NewCommonError> {
     let mut events: Vec<ExecutorEvent> = Vec::new();
     for _i in 0..10 {
@@ -411,7 +718,19 @@ async fn collect_events(
                 ));
             }
             Ok(Ok(event)) => {
-                scheduler.map(|conf| convert_event(event, conf).map(|e| events.push(e)));
+                if let Some((timer_task_id, event)) = convert_event(event, retry_keeper).await {
+                    handle_retry(timer_task_id, &event, delay_timer, retry_keeper).await;
+                    // The next fire of a task in a named time zone is known as its run starts,
+                    // A daylight saving transition before it is followed right away.
+                    if event.event_type == EventType::TaskPerform as i16 {
+                        if let Some(task_package) =
+                            task_registry.drifted_task_package(timer_task_id).await
+                        {
+                            rearm_task(delay_timer, task_package);
+                        }
+                    }
+                    events.push(event);
+                }
             }
         }
     }
@@ -419,67 +738,88 @@ async fn collect_events(
     Ok(events)
 }
 
-async fn send_event_collection(
-    scheduler: Option<&BindRequest>,
-    executor_event_collection: SignedExecutorEventCollection,
-    client: &RequestClient,
-) {
-    if let Some(scheduler_ref) = scheduler.as_ref() {
-        debug!(
-            "Event collection - {:?}",
-            &executor_event_collection.event_collection
-        );
-
-        if let Ok(response) = client
-            .post(&scheduler_ref.scheduler_host)
-            .json(&executor_event_collection)
-            .send()
-            .await
-            .map_err(|e| {
-                error!(
-                    "Failed to send the event collection: {} - {} - {:?}",
-                    e, &scheduler_ref, &executor_event_collection.event_collection
-                )
-            })
-        {
-            response
-                .bytes()
-                .await
-                .map(|b| debug!("delicate-schduler response: {:?}", b))
-                .ok();
-        }
-    }
-}
-
-fn convert_event(public_event: PublicEvent, conf: &BindRequest) -> Option<ExecutorEvent> {
-    let mut event = delicate_utils::consensus_message::task_log::ExecutorEvent {
-        executor_processor_host: conf.executor_processor_host.clone(),
-        executor_processor_id: conf.executor_processor_id,
-        executor_processor_name: conf.executor_processor_name.clone(),
-        ..Default::default()
-    };
+async fn convert_event(
+    public_event: PublicEvent,
+    retry_keeper: &RetryKeeper,
+) -> Option<(u64, ExecutorEvent)> {
+    let mut event = ExecutorEvent::default();
 
-    match public_event {
+    let timer_task_id = match public_event {
         PublicEvent::FinishTask(mut body) => {
+            let timer_task_id = body.get_task_id();
             event.id = body.get_record_id();
-            event.task_id = body.get_task_id() as i64;
             event.event_type = EventType::TaskFinish as i16;
-            event.output = body.get_finish_output().map(|o| o.into());
+            // Http runs and processes with options leave their output aside, `DelayTimer` has none for them.
+            event.output = take_routine_output(timer_task_id)
+                .or_else(|| body.get_finish_output().map(|o| o.into()));
+            match event.output {
+                Some(FinishOutput::ResourceExceededOutput(_)) => {
+                    event.event_type = EventType::ResourceExceeded as i16;
+                }
+                Some(FinishOutput::SkippedOutput(_)) => {
+                    event.event_type = EventType::TaskSkipped as i16;
+                }
+                _ => {}
+            }
+            timer_task_id
         }
         PublicEvent::RemoveTask(_) => {
             return None;
         }
         PublicEvent::RunningTask(task_id, record_id) => {
             event.id = record_id;
-            event.task_id = task_id as i64;
             event.event_type = EventType::TaskPerform as i16;
+            bind_live_output(task_id, record_id);
+            retry_keeper.on_run_start(task_id, record_id).await;
+            task_id
         }
         PublicEvent::TimeoutTask(task_id, record_id) => {
             event.id = record_id;
-            event.task_id = task_id as i64;
             event.event_type = EventType::TaskTimeout as i16;
+            task_id
         }
-    }
+    };
+
+    // One-shot runs use their own id in `DelayTimer`, report them as the original task.
+    let run = retry_keeper.resolve(timer_task_id).await;
+    event.task_id = run.task_id as i64;
+    event.attempt = run.attempt;
+    event.run_id = run.run_id;
+
+    Some((timer_task_id, event))
+}
+
+// When a run ends with a non-zero exit, an exception, a timeout or an exceeded resource limit,
+// A one-shot retry is handed to `DelayTimer` if the task still has retries left.
+// Every other end of a run, such as a run skipped by the calendar, ends its one-shot run as well.
+async fn handle_retry(
+    timer_task_id: u64,
+    event: &ExecutorEvent,
+    delay_timer: &DelayTimer,
+    retry_keeper: &RetryKeeper,
+) {
+    let failed = match Into::<EventType>::into(event.event_type) {
+        EventType::TaskPerform => return,
+        EventType::TaskFinish => event.output.as_ref().map_or(false, |o| o.is_failure()),
+        EventType::TaskTimeout | EventType::ResourceExceeded => true,
+        EventType::TaskSkipped | EventType::Unknown => false,
+    };
 
-    Some(event)
+    match retry_keeper
+        .on_run_end(timer_task_id, event.id, failed)
+        .await
+    {
+        Some(Ok(retry_task)) => {
+            info!(
+                "Task-id:{} record-id:{} attempt:{} failed, schedule the next attempt.",
+                event.task_id, event.id, event.attempt
+            );
+            delay_timer
+                .add_task(retry_task)
+                .map_err(|e| error!("Failed to add the retry task: {}", e))
+                .ok();
+        }
+        Some(Err(e)) => error!("Failed to build the retry task: {}", e),
+        None => {}
+    }
 }
@@ -1,4 +1,7 @@
-pub(crate) use crate::component::SystemMirror;
+pub(crate) use crate::component::{
+    EventOutbox, RetryKeeper, SystemMirror, TaskRegistry, TaskStore,
+};
+pub(crate) use crate::config::{ExecutorConfig, EXECUTOR_CONFIG_FILE};
 
 pub(crate) use async_lock::RwLock;
 
@@ -11,30 +14,44 @@ pub(crate) use delicate_utils::consensus_message::service_binding::{
     BindRequest, BindResponse, EncryptedBindResponse, SignedBindRequest,
 };
 
+pub(crate) use delicate_utils::conf::{launch_reload_on_hangup, load_config};
 pub(crate) use delicate_utils::consensus_message::executor_processor::*;
 pub(crate) use delicate_utils::consensus_message::health_check::*;
+pub(crate) use delicate_utils::consensus_message::live_output::{
+    bind_live_output, forget_live_outputs, read_live_output, running_records,
+};
+pub(crate) use delicate_utils::consensus_message::output_limit::set_output_defaults;
+pub(crate) use delicate_utils::consensus_message::resource_limit::set_cgroup_root;
 pub(crate) use delicate_utils::consensus_message::task::*;
 pub(crate) use delicate_utils::consensus_message::task_log::*;
+// `delay_timer` has a `FinishOutput` of its own, the events carry this one.
+pub(crate) use delicate_utils::consensus_message::task_log::FinishOutput;
+pub(crate) use delicate_utils::consensus_message::task_routine::{
+    forget_routine_outputs, take_routine_output,
+};
 pub(crate) use delicate_utils::helper_utils::get_unique_id_string;
 pub(crate) use delicate_utils::prelude::*;
 
 pub(crate) use crate::delay_timer::utils::status_report::StatusReporter;
 pub(crate) use delicate_utils::uniform_data::UnifiedResponseMessages;
 
+pub(crate) use tokio::fs;
 pub(crate) use tokio::runtime::{Builder, Runtime};
 pub(crate) use tokio::spawn as tokio_spawn;
 pub(crate) use tokio::time::{timeout as tokio_timeout, Timeout as TokioTimeout};
+pub(crate) use tracing::level_filters::LevelFilter;
 pub(crate) use tracing::{debug, error, info, instrument, span, Instrument, Level};
-pub(crate) use tracing_subscriber::FmtSubscriber;
+pub(crate) use tracing_subscriber::{EnvFilter, FmtSubscriber};
 
+pub(crate) use std::collections::{HashMap, VecDeque};
 pub(crate) use std::convert::{Into, TryInto};
-pub(crate) use std::env;
 pub(crate) use std::fmt::Debug;
 pub(crate) use std::ops::Deref;
+pub(crate) use std::path::{Path, PathBuf};
 pub(crate) use std::str::FromStr;
-pub(crate) use std::sync::atomic::{AtomicUsize, Ordering};
+pub(crate) use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 pub(crate) use std::sync::Arc;
-pub(crate) use std::time::Duration;
+pub(crate) use std::time::{Duration, Instant};
 
 pub(crate) use sysinfo::{RefreshKind, System, SystemExt};
 
@@ -45,4 +62,5 @@ pub(crate) use poem::{
 };
 
 pub(crate) use reqwest::Client as RequestClient;
+pub(crate) use serde::{Deserialize, Serialize};
 pub(crate) type UnitUnifiedResponseMessages = UnifiedResponseMessages<()>;
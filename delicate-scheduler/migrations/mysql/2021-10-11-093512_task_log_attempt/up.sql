@@ -0,0 +1 @@
+ALTER TABLE `task_log` ADD `attempt` smallint(6) NOT NULL DEFAULT '1' COMMENT 'Attempt number of the run, the first run is 1 and each retry adds 1';
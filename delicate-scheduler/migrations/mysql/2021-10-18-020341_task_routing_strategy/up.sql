@@ -0,0 +1 @@
+ALTER TABLE `task` ADD `routing_strategy` smallint(6) NOT NULL DEFAULT '1' COMMENT 'Routing strategy across bound executors 1:Broadcast 2:WeightedRandom 3:RoundRobin 4:ConsistentHash 5:LeastLoaded';
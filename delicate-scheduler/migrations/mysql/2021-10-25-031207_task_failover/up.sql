@@ -0,0 +1,13 @@
+CREATE TABLE `task_failover` (
+  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id',
+  `task_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'Task-id',
+  `origin_bind_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The binding whose executor became abnormal',
+  `origin_executor_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The abnormal executor-processor id',
+  `target_bind_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The binding that took over the task',
+  `target_executor_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The executor-processor id that took over the task',
+  `created_time` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT 'Failover time',
+  PRIMARY KEY (`id`),
+  KEY `task_id_idx` (`task_id`) USING BTREE,
+  KEY `origin_executor_id_idx` (`origin_executor_id`) USING BTREE,
+  KEY `target_executor_id_idx` (`target_executor_id`) USING BTREE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT 'Tasks taken over from abnormal executors';
@@ -0,0 +1,24 @@
+CREATE TABLE `task_dependency` (
+  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id',
+  `task_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The downstream task-id',
+  `upstream_task_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The upstream task-id',
+  `trigger_condition` smallint(6) NOT NULL DEFAULT '1' COMMENT 'When the downstream task is triggered, 1: upstream succeeded, 2: upstream failed',
+  `created_time` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT 'Creation time',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `task_upstream_idx` (`task_id`, `upstream_task_id`) USING BTREE,
+  KEY `upstream_task_id_idx` (`upstream_task_id`) USING BTREE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT 'Dependencies between tasks';
+
+CREATE TABLE `task_dependency_trigger` (
+  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id',
+  `run_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The run shared by the tasks of a dependency chain',
+  `task_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The triggered task-id',
+  `upstream_task_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The upstream task-id',
+  `upstream_record_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The upstream task-log id whose end triggered the task',
+  `created_time` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT 'Trigger time',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `run_task_idx` (`run_id`, `task_id`) USING BTREE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT 'Downstream tasks triggered in each run';
+
+ALTER TABLE `task_log` ADD `run_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The run shared by the tasks of a dependency chain, the record-id of its first task';
+ALTER TABLE `task_log` ADD INDEX `run_id_idx` (`run_id`);
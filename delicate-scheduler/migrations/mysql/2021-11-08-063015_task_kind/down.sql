@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `task` DROP `kind`;
+ALTER TABLE `task` MODIFY `command` varchar(256) NOT NULL COMMENT 'Task execute command';
+ALTER TABLE `task_log` MODIFY `command` varchar(256) NOT NULL COMMENT 'Task command (snapshot)';
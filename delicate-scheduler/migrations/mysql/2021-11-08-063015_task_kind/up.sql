@@ -0,0 +1,3 @@
+ALTER TABLE `task` ADD `kind` smallint(6) NOT NULL DEFAULT '1' COMMENT 'Task kind 1:Shell 2:Http';
+ALTER TABLE `task` MODIFY `command` varchar(2048) NOT NULL COMMENT 'Task execute command, or the json http request of http tasks';
+ALTER TABLE `task_log` MODIFY `command` varchar(2048) NOT NULL COMMENT 'Task command (snapshot)';
@@ -0,0 +1,5 @@
+ALTER TABLE `task` ADD `env` varchar(2048) NOT NULL DEFAULT '' COMMENT 'Environment variables of the child process, a json object';
+ALTER TABLE `task` ADD `working_directory` varchar(256) NOT NULL DEFAULT '' COMMENT 'Working directory of the child process';
+ALTER TABLE `task` ADD `run_as_user` varchar(64) NOT NULL DEFAULT '' COMMENT 'Unix user the child process runs as';
+ALTER TABLE `task` ADD `run_as_group` varchar(64) NOT NULL DEFAULT '' COMMENT 'Unix group the child process runs as';
+ALTER TABLE `task` ADD `stdin` varchar(4096) NOT NULL DEFAULT '' COMMENT 'Payload written to the stdin of the child process';
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `task` DROP `cpu_limit`;
+ALTER TABLE `task` DROP `memory_limit`;
+ALTER TABLE `task` DROP `pids_limit`;
+ALTER TABLE `task` DROP `wall_clock_limit`;
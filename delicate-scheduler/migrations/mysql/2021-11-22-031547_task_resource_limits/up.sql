@@ -0,0 +1,4 @@
+ALTER TABLE `task` ADD `cpu_limit` int(11) NOT NULL DEFAULT '0' COMMENT 'Cpu limit of the child process in millicores, 0 is unlimited';
+ALTER TABLE `task` ADD `memory_limit` int(11) NOT NULL DEFAULT '0' COMMENT 'Memory limit of the child process in MiB, 0 is unlimited';
+ALTER TABLE `task` ADD `pids_limit` int(11) NOT NULL DEFAULT '0' COMMENT 'Process number limit of the child process, 0 is unlimited';
+ALTER TABLE `task` ADD `wall_clock_limit` int(11) NOT NULL DEFAULT '0' COMMENT 'Wall-clock limit of the child process in seconds, 0 is unlimited';
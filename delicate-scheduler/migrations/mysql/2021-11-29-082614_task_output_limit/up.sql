@@ -0,0 +1,3 @@
+ALTER TABLE `task` ADD `output_limit` int(11) NOT NULL DEFAULT '0' COMMENT 'Kept output of each stream of the child process in KiB, 0 is the executor default';
+ALTER TABLE `task_log_extend` ADD `stdout_spool` varchar(512) NOT NULL DEFAULT '' COMMENT 'The file on the executor holding the whole stdout when it was truncated';
+ALTER TABLE `task_log_extend` ADD `stderr_spool` varchar(512) NOT NULL DEFAULT '' COMMENT 'The file on the executor holding the whole stderr when it was truncated';
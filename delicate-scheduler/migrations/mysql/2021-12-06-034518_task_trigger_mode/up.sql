@@ -0,0 +1 @@
+ALTER TABLE `task` ADD `trigger_mode` smallint(6) NOT NULL DEFAULT '1' COMMENT 'Who evaluates the schedule 1:Executor 2:Scheduler';
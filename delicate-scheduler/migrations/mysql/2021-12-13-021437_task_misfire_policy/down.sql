@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE `task` DROP `misfire_policy`;
+ALTER TABLE `task` DROP `misfire_limit`;
@@ -0,0 +1,31 @@
+CREATE TABLE `calendar` (
+  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id',
+  `name` varchar(128) NOT NULL COMMENT 'Calendar name',
+  `description` varchar(128) NOT NULL DEFAULT '' COMMENT 'Calendar description',
+  `time_zone` smallint(6) NOT NULL DEFAULT '2' COMMENT 'The time zone of the excluded windows 1:Utc 2:Local',
+  `business_days_only` smallint(6) NOT NULL DEFAULT '0' COMMENT 'Whether Saturdays and Sundays are excluded 0:No 1:Yes',
+  `created_time` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT 'Creation time',
+  `updated_time` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP COMMENT 'Update time',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `name_idx` (`name`) USING BTREE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT 'Named calendars of the times tasks do not run in';
+
+CREATE TABLE `calendar_window` (
+  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id',
+  `calendar_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'Calendar id',
+  `start_time` datetime NOT NULL COMMENT 'The start of the window (inclusive), in the time zone of the calendar',
+  `end_time` datetime NOT NULL COMMENT 'The end of the window (exclusive), in the time zone of the calendar',
+  `description` varchar(128) NOT NULL DEFAULT '' COMMENT 'The holiday or the blackout',
+  PRIMARY KEY (`id`),
+  KEY `calendar_id_idx` (`calendar_id`) USING BTREE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT 'The holidays and blackout windows of the calendars';
+
+ALTER TABLE `task` ADD `calendar_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'The calendar whose excluded times the task skips, 0: none';
+
+INSERT INTO `casbin_rule` (`ptype`, `v0`, `v1`, `v2`) VALUES ('p', 'developer', 'calendar', 'list');
+INSERT INTO `casbin_rule` (`ptype`, `v0`, `v1`, `v2`) VALUES ('p', 'developer', 'calendar', 'detail');
+INSERT INTO `casbin_rule` (`ptype`, `v0`, `v1`, `v2`) VALUES ('p', 'task_admin', 'calendar', 'list');
+INSERT INTO `casbin_rule` (`ptype`, `v0`, `v1`, `v2`) VALUES ('p', 'task_admin', 'calendar', 'detail');
+INSERT INTO `casbin_rule` (`ptype`, `v0`, `v1`, `v2`) VALUES ('p', 'task_admin', 'calendar', 'create');
+INSERT INTO `casbin_rule` (`ptype`, `v0`, `v1`, `v2`) VALUES ('p', 'task_admin', 'calendar', 'update');
+INSERT INTO `casbin_rule` (`ptype`, `v0`, `v1`, `v2`) VALUES ('p', 'task_admin', 'calendar', 'delete');
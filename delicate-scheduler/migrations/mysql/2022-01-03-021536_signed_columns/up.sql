@@ -0,0 +1,18 @@
+ALTER TABLE `user` MODIFY `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'user-id';
+ALTER TABLE `user` MODIFY `status` smallint(6) NOT NULL DEFAULT '1' COMMENT 'User Status: 2:Deleted';
+ALTER TABLE `user_auth` MODIFY `user_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'user-id';
+ALTER TABLE `user_auth` MODIFY `identity_type` smallint(6) NOT NULL DEFAULT '1' COMMENT '1:Mobie-number 2:Email 3:Username 4:LDAP 5:Other-OAuth';
+ALTER TABLE `user_auth` MODIFY `status` smallint(6) NOT NULL DEFAULT '1' COMMENT 'Authentication status: 1:active, 2:disabled';
+ALTER TABLE `user_login_log` MODIFY `user_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'user-id';
+ALTER TABLE `user_login_log` MODIFY `login_type` smallint(6) NOT NULL DEFAULT '3' COMMENT 'Login method 1:Mobie-number 2:Email 3:Username 4:LDAP 5:Other-OAuth etc. 81:Logout';
+ALTER TABLE `user_login_log` MODIFY `command` smallint(6) NOT NULL DEFAULT '1' COMMENT 'Operation type 1:Login success 2:Logout success 3:Login failure 4:Logout failure';
+ALTER TABLE `user_register_log` MODIFY `user_id` bigint(20) NOT NULL COMMENT 'user-id';
+ALTER TABLE `user_register_log` MODIFY `register_method` smallint(6) NOT NULL COMMENT 'Registration method 1:Mobie-number 2:Email 3:Username';
+ALTER TABLE `user_info_update` MODIFY `user_id` bigint(20) NOT NULL COMMENT 'user-id';
+ALTER TABLE `operation_log` MODIFY `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id';
+ALTER TABLE `operation_log` MODIFY `table_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'Operation table id';
+ALTER TABLE `operation_log` MODIFY `operation_type` smallint(6) NOT NULL DEFAULT '1' COMMENT 'Operation type: 1 add 2 modify 3 delete';
+ALTER TABLE `operation_log` MODIFY `user_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'Operation user id';
+ALTER TABLE `operation_log_detail` MODIFY `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id';
+ALTER TABLE `operation_log_detail` MODIFY `operation_log_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'Operation log id';
+ALTER TABLE `casbin_rule` MODIFY `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id';
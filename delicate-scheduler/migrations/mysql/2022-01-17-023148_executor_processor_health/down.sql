@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM `casbin_rule` WHERE `ptype` = 'p' AND `v1` = 'executor_processor' AND `v2` = 'health_history';
+DROP TABLE `executor_processor_health`;
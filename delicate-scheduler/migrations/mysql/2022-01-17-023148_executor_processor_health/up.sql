@@ -0,0 +1,14 @@
+CREATE TABLE `executor_processor_health` (
+  `id` bigint(20) NOT NULL AUTO_INCREMENT COMMENT 'Self-incrementing id',
+  `executor_processor_id` bigint(20) NOT NULL DEFAULT '0' COMMENT 'Executor processor id',
+  `result` smallint(6) NOT NULL DEFAULT '1' COMMENT 'The result of the probe 1:Healthy 2:Missed',
+  `status` smallint(6) NOT NULL DEFAULT '2' COMMENT 'The status of the processor after the probe 2:Enabled 3:Abnormal',
+  `response_time` int(11) NOT NULL DEFAULT '0' COMMENT 'Milliseconds the probe took',
+  `error` varchar(255) NOT NULL DEFAULT '' COMMENT 'Why the probe was missed',
+  `created_time` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT 'Probe time',
+  PRIMARY KEY (`id`),
+  KEY `executor_processor_id_idx` (`executor_processor_id`) USING BTREE,
+  KEY `created_time_idx` (`created_time`) USING BTREE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT 'The health-check probes of the executor processors';
+
+INSERT INTO `casbin_rule` (`ptype`, `v0`, `v1`, `v2`) VALUES ('p', 'processor_admin', 'executor_processor', 'health_history');
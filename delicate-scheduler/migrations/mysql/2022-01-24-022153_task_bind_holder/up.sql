@@ -0,0 +1 @@
+ALTER TABLE `task_bind` ADD `holder` smallint(6) NOT NULL DEFAULT '0' COMMENT 'Whether the member holds the routed task 0:No 1:Yes';
@@ -0,0 +1,14 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE task;
+DROP TABLE task_log;
+DROP TABLE task_log_extend;
+DROP TABLE task_bind;
+DROP TABLE executor_processor;
+DROP TABLE executor_group;
+DROP TABLE executor_processor_bind;
+DROP TABLE "user";
+DROP TABLE user_auth;
+DROP TABLE user_login_log;
+DROP TABLE user_register_log;
+DROP TABLE user_info_update;
+DROP FUNCTION delicate_set_updated_time();
@@ -0,0 +1,167 @@
+-- Keeps the `updated_time` of a row current, as `ON UPDATE CURRENT_TIMESTAMP` does on mysql.
+CREATE OR REPLACE FUNCTION delicate_set_updated_time() RETURNS trigger AS $$
+BEGIN
+    IF (
+        NEW IS DISTINCT FROM OLD AND
+        NEW.updated_time IS NOT DISTINCT FROM OLD.updated_time
+    ) THEN
+        NEW.updated_time := current_timestamp;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TABLE task (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Task name
+  description VARCHAR(128) NOT NULL, -- Task description
+  command VARCHAR(256) NOT NULL, -- Task execute command
+  frequency VARCHAR(256) NOT NULL, -- Task frequency
+  cron_expression VARCHAR(256) NOT NULL, -- Task cron expression
+  timeout SMALLINT NOT NULL DEFAULT 0, -- Task Timeout
+  retry_times SMALLINT NOT NULL DEFAULT 0, -- Task retry times
+  retry_interval SMALLINT NOT NULL DEFAULT 0, -- Task retest interval
+  maximum_parallel_runnable_num SMALLINT NOT NULL DEFAULT 0, -- Maximum number of parallel tasks
+  tag VARCHAR(32) NOT NULL DEFAULT '', -- Task tag
+  status SMALLINT NOT NULL DEFAULT 1, -- Task status
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Task creation time
+  deleted_time TIMESTAMP NULL DEFAULT NULL -- Task deletion time
+);
+
+CREATE TABLE task_log (
+  id BIGSERIAL PRIMARY KEY, -- Run record id generated by the task in the executor
+  task_id BIGINT NOT NULL DEFAULT 0, -- Task-id
+  name VARCHAR(128) NOT NULL, -- Task name (snapshot)
+  description VARCHAR(128) NOT NULL, -- Task description (snapshot)
+  command VARCHAR(256) NOT NULL, -- Task command (snapshot)
+  frequency VARCHAR(256) NOT NULL, -- Task frequency (snapshot)
+  cron_expression VARCHAR(256) NOT NULL, -- Task cron-expression (snapshot)
+  maximum_parallel_runnable_num SMALLINT NOT NULL DEFAULT 0, -- Maximum number of parallel tasks(snapshot)
+  tag VARCHAR(32) NOT NULL DEFAULT '', -- Task tag (snapshot)
+  status SMALLINT NOT NULL DEFAULT 1, -- Status
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Task log creation time
+  updated_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Update Task log time
+  executor_processor_id BIGINT NOT NULL, -- Executor processor id
+  executor_processor_name VARCHAR(255) NOT NULL, -- Executor processor name (snapshot)
+  executor_processor_host VARCHAR(32) NOT NULL DEFAULT '' -- Executor processor host
+);
+CREATE INDEX task_log_task_id_idx ON task_log (task_id);
+CREATE INDEX task_log_executor_processor_id_idx ON task_log (executor_processor_id);
+CREATE INDEX task_log_created_time_idx ON task_log (created_time);
+CREATE INDEX task_log_updated_time_idx ON task_log (updated_time);
+CREATE TRIGGER set_updated_time BEFORE UPDATE ON task_log FOR EACH ROW EXECUTE PROCEDURE delicate_set_updated_time();
+
+CREATE TABLE task_log_extend (
+  id BIGSERIAL PRIMARY KEY, -- Run record id generated by the task in the executor
+  task_id BIGINT NOT NULL DEFAULT 0, -- Task-id
+  stdout TEXT NOT NULL, -- process-child-stdout or http response
+  stderr TEXT NOT NULL -- process-child-stderr
+);
+CREATE INDEX task_log_extend_task_id_idx ON task_log_extend (task_id);
+
+CREATE TABLE executor_processor (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Executor-processor name
+  host VARCHAR(32) NOT NULL DEFAULT '', -- Executor-processor host
+  machine_id SMALLINT NOT NULL DEFAULT 0, -- Unique machine id of the executor, Max is 1024
+  description VARCHAR(128) NOT NULL, -- Executor-processor description
+  tag VARCHAR(32) NOT NULL DEFAULT '', -- Executor-processor tag
+  status SMALLINT NOT NULL DEFAULT 1, -- Status
+  token VARCHAR(128) NOT NULL DEFAULT '', -- The token that is dynamically generated when the executor binds to the scheduler and is used for api authentication.
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Executor-processor creation time
+  deleted_time TIMESTAMP NULL DEFAULT NULL, -- Executor-processor delition time
+  CONSTRAINT executor_processor_machine_id_idx UNIQUE (machine_id)
+);
+
+CREATE TABLE executor_group (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Executor-group name
+  description VARCHAR(128) NOT NULL, -- Executor-group description
+  tag VARCHAR(32) NOT NULL DEFAULT '', -- Executor-group tag
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Executor-group creation time
+  deleted_time TIMESTAMP NULL DEFAULT NULL -- Executor-group delition time
+);
+
+CREATE TABLE executor_processor_bind (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Executor-group-processor name
+  group_id BIGINT NOT NULL DEFAULT 0, -- Executor group id
+  executor_id BIGINT NOT NULL DEFAULT 0, -- Executor id
+  weight SMALLINT NOT NULL DEFAULT 0, -- Execution weights of tasks between executor in a group
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Executor-processor-group creation time
+  CONSTRAINT executor_processor_bind_only UNIQUE (group_id, executor_id)
+);
+
+CREATE TABLE task_bind (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  task_id BIGINT NOT NULL DEFAULT 0, -- Task-id
+  bind_id BIGINT NOT NULL DEFAULT 0, -- Bind-id (executor_processor_group_id)
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP -- Binding creation time
+);
+CREATE INDEX task_bind_task_id_idx ON task_bind (task_id);
+CREATE INDEX task_bind_bind_id_idx ON task_bind (bind_id);
+
+CREATE TABLE user_auth (
+  id BIGSERIAL PRIMARY KEY,
+  user_id BIGINT NOT NULL DEFAULT 0, -- user-id
+  identity_type SMALLINT NOT NULL DEFAULT 1, -- 1:Mobie-number 2:Email 3:Username 4:LDAP 5:Other-OAuth
+  identifier VARCHAR(32) NOT NULL DEFAULT '', -- Mobie-number Email Username or unique identifier for third party applications
+  certificate VARCHAR(128) NOT NULL DEFAULT '', -- Password credentials (the station saves the password, the station does not save or save the token)
+  status SMALLINT NOT NULL DEFAULT 1, -- Authentication status: 1:active, 2:disabled
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Binding time
+  updated_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Update binding time
+  CONSTRAINT user_auth_only UNIQUE (user_id, identity_type),
+  CONSTRAINT user_auth_identifier_idx UNIQUE (identifier)
+);
+CREATE INDEX user_auth_user_id_idx ON user_auth (user_id);
+CREATE TRIGGER set_updated_time BEFORE UPDATE ON user_auth FOR EACH ROW EXECUTE PROCEDURE delicate_set_updated_time();
+COMMENT ON TABLE user_auth IS 'User authorization';
+
+CREATE TABLE "user" (
+  id BIGSERIAL PRIMARY KEY, -- user-id
+  user_name VARCHAR(32) NOT NULL DEFAULT '', -- User account, must be unique
+  nick_name VARCHAR(32) NOT NULL DEFAULT '', -- User nickname
+  mobile VARCHAR(16) NOT NULL DEFAULT '', -- Mobie-number(unique)
+  email VARCHAR(100) NOT NULL DEFAULT '', -- Email(unique)
+  face VARCHAR(255) NOT NULL DEFAULT '', -- Avatar
+  status SMALLINT NOT NULL DEFAULT 1, -- User Status: 2:Deleted
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Creation time
+  updated_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Modify time
+  CONSTRAINT user_name_idx UNIQUE (user_name),
+  CONSTRAINT user_mobile_idx UNIQUE (mobile),
+  CONSTRAINT user_email_idx UNIQUE (email)
+);
+CREATE TRIGGER set_updated_time BEFORE UPDATE ON "user" FOR EACH ROW EXECUTE PROCEDURE delicate_set_updated_time();
+COMMENT ON TABLE "user" IS 'User Base Information';
+
+CREATE TABLE user_login_log (
+  id BIGSERIAL PRIMARY KEY,
+  user_id BIGINT NOT NULL DEFAULT 0, -- user-id
+  login_type SMALLINT NOT NULL DEFAULT 1, -- Login method Third party/email/mobile etc.
+  command SMALLINT NOT NULL DEFAULT 1, -- Operation type 1:Login success 2:Logout success 3:Login failure 4:Logout failure
+  lastip VARCHAR(32) NOT NULL DEFAULT '', -- Login-ip
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP -- Creation time
+);
+CREATE INDEX user_login_log_user_id_type_time_idx ON user_login_log (user_id, login_type, created_time);
+CREATE INDEX user_login_log_create_time_idx ON user_login_log (created_time);
+COMMENT ON TABLE user_login_log IS 'Login Log';
+
+CREATE TABLE user_register_log (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  user_id BIGINT NOT NULL, -- user-id
+  register_method SMALLINT NOT NULL, -- Registration method 1:Mobie-number 2:Email 3:Username
+  register_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Registration Time
+  register_ip VARCHAR(32) NOT NULL DEFAULT '' -- Registered IP
+);
+COMMENT ON TABLE user_register_log IS 'User Registration Log';
+
+CREATE TABLE user_info_update (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  user_id BIGINT NOT NULL, -- user-id
+  attribute_name VARCHAR(30) NOT NULL, -- Attribute Name
+  attribute_old_val VARCHAR(30) NOT NULL DEFAULT '', -- attribute corresponds to the old value
+  attribute_new_val VARCHAR(30) NOT NULL DEFAULT '', -- attribute corresponds to the new value
+  updated_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP -- Modify time
+);
+CREATE TRIGGER set_updated_time BEFORE UPDATE ON user_info_update FOR EACH ROW EXECUTE PROCEDURE delicate_set_updated_time();
+COMMENT ON TABLE user_info_update IS 'User Modification Information';
@@ -0,0 +1,25 @@
+CREATE TABLE operation_log (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  name VARCHAR(64) NOT NULL DEFAULT '', -- Operation module name
+  table_id BIGINT NOT NULL DEFAULT 0, -- Operation table id
+  operation_type SMALLINT NOT NULL DEFAULT 1, -- Operation type: 1 add 2 modify 3 delete
+  user_id BIGINT NOT NULL DEFAULT 0, -- Operation user id
+  user_name VARCHAR(64) NOT NULL DEFAULT '', -- Operation user name
+  operation_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP -- Operation time
+);
+CREATE INDEX operation_log_table_id_idx ON operation_log (table_id);
+CREATE INDEX operation_log_operation_time_idx ON operation_log (operation_time);
+CREATE INDEX operation_log_user_id_type_time_idx ON operation_log (user_id, operation_type, operation_time);
+COMMENT ON TABLE operation_log IS 'User Operation Log Record Table';
+
+CREATE TABLE operation_log_detail (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  operation_log_id BIGINT NOT NULL DEFAULT 0, -- Operation log id
+  column_comment TEXT NOT NULL, -- Field Description
+  "values" TEXT NOT NULL -- Values
+);
+CREATE INDEX operation_log_detail_operation_log_id_idx ON operation_log_detail (operation_log_id);
+COMMENT ON TABLE operation_log_detail IS 'Operation log details table';
+
+ALTER TABLE user_login_log ADD user_name VARCHAR(64) NOT NULL DEFAULT ''; -- Login user name
+ALTER TABLE user_login_log ALTER COLUMN login_type SET DEFAULT 3; -- Login method 1:Mobie-number 2:Email 3:Username 4:LDAP 5:Other-OAuth etc. 81:Logout
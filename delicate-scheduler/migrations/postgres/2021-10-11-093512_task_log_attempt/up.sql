@@ -0,0 +1 @@
+ALTER TABLE task_log ADD attempt SMALLINT NOT NULL DEFAULT 1; -- Attempt number of the run, the first run is 1 and each retry adds 1
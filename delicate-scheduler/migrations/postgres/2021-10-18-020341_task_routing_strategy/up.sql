@@ -0,0 +1 @@
+ALTER TABLE task ADD routing_strategy SMALLINT NOT NULL DEFAULT 1; -- Routing strategy across bound executors 1:Broadcast 2:WeightedRandom 3:RoundRobin 4:ConsistentHash 5:LeastLoaded
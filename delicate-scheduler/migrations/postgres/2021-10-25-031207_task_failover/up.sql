@@ -0,0 +1,13 @@
+CREATE TABLE task_failover (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  task_id BIGINT NOT NULL DEFAULT 0, -- Task-id
+  origin_bind_id BIGINT NOT NULL DEFAULT 0, -- The binding whose executor became abnormal
+  origin_executor_id BIGINT NOT NULL DEFAULT 0, -- The abnormal executor-processor id
+  target_bind_id BIGINT NOT NULL DEFAULT 0, -- The binding that took over the task
+  target_executor_id BIGINT NOT NULL DEFAULT 0, -- The executor-processor id that took over the task
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP -- Failover time
+);
+CREATE INDEX task_failover_task_id_idx ON task_failover (task_id);
+CREATE INDEX task_failover_origin_executor_id_idx ON task_failover (origin_executor_id);
+CREATE INDEX task_failover_target_executor_id_idx ON task_failover (target_executor_id);
+COMMENT ON TABLE task_failover IS 'Tasks taken over from abnormal executors';
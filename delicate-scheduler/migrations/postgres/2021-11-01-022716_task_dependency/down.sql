@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX task_log_run_id_idx;
+ALTER TABLE task_log DROP run_id;
+DROP TABLE task_dependency_trigger;
+DROP TABLE task_dependency;
@@ -0,0 +1,24 @@
+CREATE TABLE task_dependency (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  task_id BIGINT NOT NULL DEFAULT 0, -- The downstream task-id
+  upstream_task_id BIGINT NOT NULL DEFAULT 0, -- The upstream task-id
+  trigger_condition SMALLINT NOT NULL DEFAULT 1, -- When the downstream task is triggered, 1: upstream succeeded, 2: upstream failed
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Creation time
+  CONSTRAINT task_dependency_task_upstream_idx UNIQUE (task_id, upstream_task_id)
+);
+CREATE INDEX task_dependency_upstream_task_id_idx ON task_dependency (upstream_task_id);
+COMMENT ON TABLE task_dependency IS 'Dependencies between tasks';
+
+CREATE TABLE task_dependency_trigger (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  run_id BIGINT NOT NULL DEFAULT 0, -- The run shared by the tasks of a dependency chain
+  task_id BIGINT NOT NULL DEFAULT 0, -- The triggered task-id
+  upstream_task_id BIGINT NOT NULL DEFAULT 0, -- The upstream task-id
+  upstream_record_id BIGINT NOT NULL DEFAULT 0, -- The upstream task-log id whose end triggered the task
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Trigger time
+  CONSTRAINT task_dependency_trigger_run_task_idx UNIQUE (run_id, task_id)
+);
+COMMENT ON TABLE task_dependency_trigger IS 'Downstream tasks triggered in each run';
+
+ALTER TABLE task_log ADD run_id BIGINT NOT NULL DEFAULT 0; -- The run shared by the tasks of a dependency chain, the record-id of its first task
+CREATE INDEX task_log_run_id_idx ON task_log (run_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE task DROP kind;
+ALTER TABLE task ALTER COLUMN command TYPE VARCHAR(256);
+ALTER TABLE task_log ALTER COLUMN command TYPE VARCHAR(256);
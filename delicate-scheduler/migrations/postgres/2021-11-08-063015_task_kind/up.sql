@@ -0,0 +1,3 @@
+ALTER TABLE task ADD kind SMALLINT NOT NULL DEFAULT 1; -- Task kind 1:Shell 2:Http
+ALTER TABLE task ALTER COLUMN command TYPE VARCHAR(2048); -- Task execute command, or the json http request of http tasks
+ALTER TABLE task_log ALTER COLUMN command TYPE VARCHAR(2048); -- Task command (snapshot)
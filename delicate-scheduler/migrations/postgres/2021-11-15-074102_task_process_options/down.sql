@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE task DROP env;
+ALTER TABLE task DROP working_directory;
+ALTER TABLE task DROP run_as_user;
+ALTER TABLE task DROP run_as_group;
+ALTER TABLE task DROP stdin;
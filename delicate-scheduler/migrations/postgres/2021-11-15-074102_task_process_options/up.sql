@@ -0,0 +1,5 @@
+ALTER TABLE task ADD env VARCHAR(2048) NOT NULL DEFAULT ''; -- Environment variables of the child process, a json object
+ALTER TABLE task ADD working_directory VARCHAR(256) NOT NULL DEFAULT ''; -- Working directory of the child process
+ALTER TABLE task ADD run_as_user VARCHAR(64) NOT NULL DEFAULT ''; -- Unix user the child process runs as
+ALTER TABLE task ADD run_as_group VARCHAR(64) NOT NULL DEFAULT ''; -- Unix group the child process runs as
+ALTER TABLE task ADD stdin VARCHAR(4096) NOT NULL DEFAULT ''; -- Payload written to the stdin of the child process
@@ -0,0 +1,4 @@
+ALTER TABLE task ADD cpu_limit INTEGER NOT NULL DEFAULT 0; -- Cpu limit of the child process in millicores, 0 is unlimited
+ALTER TABLE task ADD memory_limit INTEGER NOT NULL DEFAULT 0; -- Memory limit of the child process in MiB, 0 is unlimited
+ALTER TABLE task ADD pids_limit INTEGER NOT NULL DEFAULT 0; -- Process number limit of the child process, 0 is unlimited
+ALTER TABLE task ADD wall_clock_limit INTEGER NOT NULL DEFAULT 0; -- Wall-clock limit of the child process in seconds, 0 is unlimited
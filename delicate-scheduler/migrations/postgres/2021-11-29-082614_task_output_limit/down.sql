@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE task DROP output_limit;
+ALTER TABLE task_log_extend DROP stdout_spool;
+ALTER TABLE task_log_extend DROP stderr_spool;
@@ -0,0 +1,3 @@
+ALTER TABLE task ADD output_limit INTEGER NOT NULL DEFAULT 0; -- Kept output of each stream of the child process in KiB, 0 is the executor default
+ALTER TABLE task_log_extend ADD stdout_spool VARCHAR(512) NOT NULL DEFAULT ''; -- The file on the executor holding the whole stdout when it was truncated
+ALTER TABLE task_log_extend ADD stderr_spool VARCHAR(512) NOT NULL DEFAULT ''; -- The file on the executor holding the whole stderr when it was truncated
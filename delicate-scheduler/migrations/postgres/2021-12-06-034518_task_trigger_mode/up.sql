@@ -0,0 +1 @@
+ALTER TABLE task ADD trigger_mode SMALLINT NOT NULL DEFAULT 1; -- Who evaluates the schedule 1:Executor 2:Scheduler
@@ -0,0 +1,2 @@
+ALTER TABLE task ADD misfire_policy SMALLINT NOT NULL DEFAULT 1; -- Runs fired for the schedules missed while the task was not scheduled 1:Ignore 2:FireOnce 3:FireAll
+ALTER TABLE task ADD misfire_limit SMALLINT NOT NULL DEFAULT 0; -- The most missed runs fired by FireAll
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM casbin_rule WHERE ptype = 'p' AND v1 = 'calendar';
+ALTER TABLE task DROP calendar_id;
+DROP TABLE calendar_window;
+DROP TABLE calendar;
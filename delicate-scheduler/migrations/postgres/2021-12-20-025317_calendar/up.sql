@@ -0,0 +1,32 @@
+CREATE TABLE calendar (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Calendar name
+  description VARCHAR(128) NOT NULL DEFAULT '', -- Calendar description
+  time_zone SMALLINT NOT NULL DEFAULT 2, -- The time zone of the excluded windows 1:Utc 2:Local
+  business_days_only SMALLINT NOT NULL DEFAULT 0, -- Whether Saturdays and Sundays are excluded 0:No 1:Yes
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Creation time
+  updated_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP, -- Update time
+  CONSTRAINT calendar_name_idx UNIQUE (name)
+);
+CREATE TRIGGER set_updated_time BEFORE UPDATE ON calendar FOR EACH ROW EXECUTE PROCEDURE delicate_set_updated_time();
+COMMENT ON TABLE calendar IS 'Named calendars of the times tasks do not run in';
+
+CREATE TABLE calendar_window (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  calendar_id BIGINT NOT NULL DEFAULT 0, -- Calendar id
+  start_time TIMESTAMP NOT NULL, -- The start of the window (inclusive), in the time zone of the calendar
+  end_time TIMESTAMP NOT NULL, -- The end of the window (exclusive), in the time zone of the calendar
+  description VARCHAR(128) NOT NULL DEFAULT '' -- The holiday or the blackout
+);
+CREATE INDEX calendar_window_calendar_id_idx ON calendar_window (calendar_id);
+COMMENT ON TABLE calendar_window IS 'The holidays and blackout windows of the calendars';
+
+ALTER TABLE task ADD calendar_id BIGINT NOT NULL DEFAULT 0; -- The calendar whose excluded times the task skips, 0: none
+
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'calendar', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'calendar', 'detail');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'detail');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'create');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'update');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'delete');
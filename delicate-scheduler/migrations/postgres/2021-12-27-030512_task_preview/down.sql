@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM casbin_rule WHERE ptype = 'p' AND v1 = 'task' AND v2 = 'preview';
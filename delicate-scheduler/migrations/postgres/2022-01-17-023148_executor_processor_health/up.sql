@@ -0,0 +1,14 @@
+CREATE TABLE executor_processor_health (
+  id BIGSERIAL PRIMARY KEY, -- Self-incrementing id
+  executor_processor_id BIGINT NOT NULL DEFAULT 0, -- Executor processor id
+  result SMALLINT NOT NULL DEFAULT 1, -- The result of the probe 1:Healthy 2:Missed
+  status SMALLINT NOT NULL DEFAULT 2, -- The status of the processor after the probe 2:Enabled 3:Abnormal
+  response_time INTEGER NOT NULL DEFAULT 0, -- Milliseconds the probe took
+  error VARCHAR(255) NOT NULL DEFAULT '', -- Why the probe was missed
+  created_time TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP -- Probe time
+);
+CREATE INDEX executor_processor_health_executor_processor_id_idx ON executor_processor_health (executor_processor_id);
+CREATE INDEX executor_processor_health_created_time_idx ON executor_processor_health (created_time);
+COMMENT ON TABLE executor_processor_health IS 'The health-check probes of the executor processors';
+
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'processor_admin', 'executor_processor', 'health_history');
@@ -0,0 +1,21 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE task;
+DROP TABLE task_log;
+DROP TABLE task_log_extend;
+DROP TABLE executor_processor;
+DROP TABLE executor_group;
+DROP TABLE executor_processor_bind;
+DROP TABLE task_bind;
+DROP TABLE task_failover;
+DROP TABLE task_dependency;
+DROP TABLE task_dependency_trigger;
+DROP TABLE calendar;
+DROP TABLE calendar_window;
+DROP TABLE user_auth;
+DROP TABLE user;
+DROP TABLE user_login_log;
+DROP TABLE user_register_log;
+DROP TABLE user_info_update;
+DROP TABLE operation_log;
+DROP TABLE operation_log_detail;
+DROP TABLE casbin_rule;
@@ -0,0 +1,350 @@
+-- The whole schema at once, sqlite is a new backend with nothing to upgrade.
+-- The times are the local time of the scheduler, as the session time zone of mysql and postgres.
+--
+-- It is the schema of the mysql and postgres migrations up to `2022-01-03-021536_signed_columns`,
+-- Squashed in the order they were run. A later change of the schema gets a migration of the same name
+-- In all three directories, rather than an edit of this file, so the sqlite history goes on from here.
+--
+-- To check it against the other backends, run the migrations of each into an empty database
+-- And compare the output of `diesel print-schema` (the `diesel_*.toml` of the backend),
+-- The primary keys and the defaults are written the sqlite way, the tables and columns are the same.
+-- Regenerating it means squashing those same migrations again, `down.sql` drops every table.
+
+CREATE TABLE task (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Task name
+  description VARCHAR(128) NOT NULL, -- Task description
+  command VARCHAR(2048) NOT NULL, -- Task execute command, or the json http request of http tasks
+  frequency VARCHAR(256) NOT NULL, -- Task frequency
+  cron_expression VARCHAR(256) NOT NULL, -- Task cron expression
+  timeout SMALLINT NOT NULL DEFAULT 0, -- Task Timeout
+  retry_times SMALLINT NOT NULL DEFAULT 0, -- Task retry times
+  retry_interval SMALLINT NOT NULL DEFAULT 0, -- Task retest interval
+  maximum_parallel_runnable_num SMALLINT NOT NULL DEFAULT 0, -- Maximum number of parallel tasks
+  tag VARCHAR(32) NOT NULL DEFAULT '', -- Task tag
+  status SMALLINT NOT NULL DEFAULT 1, -- Task status
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Task creation time
+  deleted_time TIMESTAMP NULL DEFAULT NULL, -- Task deletion time
+  routing_strategy SMALLINT NOT NULL DEFAULT 1, -- Routing strategy across bound executors 1:Broadcast 2:WeightedRandom 3:RoundRobin 4:ConsistentHash 5:LeastLoaded
+  kind SMALLINT NOT NULL DEFAULT 1, -- Task kind 1:Shell 2:Http
+  env VARCHAR(2048) NOT NULL DEFAULT '', -- Environment variables of the child process, a json object
+  working_directory VARCHAR(256) NOT NULL DEFAULT '', -- Working directory of the child process
+  run_as_user VARCHAR(64) NOT NULL DEFAULT '', -- Unix user the child process runs as
+  run_as_group VARCHAR(64) NOT NULL DEFAULT '', -- Unix group the child process runs as
+  stdin VARCHAR(4096) NOT NULL DEFAULT '', -- Payload written to the stdin of the child process
+  cpu_limit INTEGER NOT NULL DEFAULT 0, -- Cpu limit of the child process in millicores, 0 is unlimited
+  memory_limit INTEGER NOT NULL DEFAULT 0, -- Memory limit of the child process in MiB, 0 is unlimited
+  pids_limit INTEGER NOT NULL DEFAULT 0, -- Process number limit of the child process, 0 is unlimited
+  wall_clock_limit INTEGER NOT NULL DEFAULT 0, -- Wall-clock limit of the child process in seconds, 0 is unlimited
+  output_limit INTEGER NOT NULL DEFAULT 0, -- Kept output of each stream of the child process in KiB, 0 is the executor default
+  trigger_mode SMALLINT NOT NULL DEFAULT 1, -- Who evaluates the schedule 1:Executor 2:Scheduler
+  misfire_policy SMALLINT NOT NULL DEFAULT 1, -- Runs fired for the schedules missed while the task was not scheduled 1:Ignore 2:FireOnce 3:FireAll
+  misfire_limit SMALLINT NOT NULL DEFAULT 0, -- The most missed runs fired by FireAll
+  calendar_id BIGINT NOT NULL DEFAULT 0 -- The calendar whose excluded times the task skips, 0: none
+);
+
+CREATE TABLE task_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Run record id generated by the task in the executor
+  task_id BIGINT NOT NULL DEFAULT 0, -- Task-id
+  name VARCHAR(128) NOT NULL, -- Task name (snapshot)
+  description VARCHAR(128) NOT NULL, -- Task description (snapshot)
+  command VARCHAR(2048) NOT NULL, -- Task command (snapshot)
+  frequency VARCHAR(256) NOT NULL, -- Task frequency (snapshot)
+  cron_expression VARCHAR(256) NOT NULL, -- Task cron-expression (snapshot)
+  maximum_parallel_runnable_num SMALLINT NOT NULL DEFAULT 0, -- Maximum number of parallel tasks(snapshot)
+  tag VARCHAR(32) NOT NULL DEFAULT '', -- Task tag (snapshot)
+  status SMALLINT NOT NULL DEFAULT 1, -- Status
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Task log creation time
+  updated_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Update Task log time
+  executor_processor_id BIGINT NOT NULL, -- Executor processor id
+  executor_processor_name VARCHAR(255) NOT NULL, -- Executor processor name (snapshot)
+  executor_processor_host VARCHAR(32) NOT NULL DEFAULT '', -- Executor processor host
+  attempt SMALLINT NOT NULL DEFAULT 1, -- Attempt number of the run, the first run is 1 and each retry adds 1
+  run_id BIGINT NOT NULL DEFAULT 0 -- The run shared by the tasks of a dependency chain, the record-id of its first task
+);
+CREATE INDEX task_log_task_id_idx ON task_log (task_id);
+CREATE INDEX task_log_executor_processor_id_idx ON task_log (executor_processor_id);
+CREATE INDEX task_log_created_time_idx ON task_log (created_time);
+CREATE INDEX task_log_updated_time_idx ON task_log (updated_time);
+CREATE INDEX task_log_run_id_idx ON task_log (run_id);
+
+CREATE TABLE task_log_extend (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Run record id generated by the task in the executor
+  task_id BIGINT NOT NULL DEFAULT 0, -- Task-id
+  stdout TEXT NOT NULL, -- process-child-stdout or http response
+  stderr TEXT NOT NULL, -- process-child-stderr
+  stdout_spool VARCHAR(512) NOT NULL DEFAULT '', -- The file on the executor holding the whole stdout when it was truncated
+  stderr_spool VARCHAR(512) NOT NULL DEFAULT '' -- The file on the executor holding the whole stderr when it was truncated
+);
+CREATE INDEX task_log_extend_task_id_idx ON task_log_extend (task_id);
+
+CREATE TABLE executor_processor (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Executor-processor name
+  host VARCHAR(32) NOT NULL DEFAULT '', -- Executor-processor host
+  machine_id SMALLINT NOT NULL DEFAULT 0, -- Unique machine id of the executor, Max is 1024
+  description VARCHAR(128) NOT NULL, -- Executor-processor description
+  tag VARCHAR(32) NOT NULL DEFAULT '', -- Executor-processor tag
+  status SMALLINT NOT NULL DEFAULT 1, -- Status
+  token VARCHAR(128) NOT NULL DEFAULT '', -- The token that is dynamically generated when the executor binds to the scheduler and is used for api authentication.
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Executor-processor creation time
+  deleted_time TIMESTAMP NULL DEFAULT NULL -- Executor-processor delition time
+);
+CREATE UNIQUE INDEX executor_processor_machine_id_idx ON executor_processor (machine_id);
+
+CREATE TABLE executor_group (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Executor-group name
+  description VARCHAR(128) NOT NULL, -- Executor-group description
+  tag VARCHAR(32) NOT NULL DEFAULT '', -- Executor-group tag
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Executor-group creation time
+  deleted_time TIMESTAMP NULL DEFAULT NULL -- Executor-group delition time
+);
+
+CREATE TABLE executor_processor_bind (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Executor-group-processor name
+  group_id BIGINT NOT NULL DEFAULT 0, -- Executor group id
+  executor_id BIGINT NOT NULL DEFAULT 0, -- Executor id
+  weight SMALLINT NOT NULL DEFAULT 0, -- Execution weights of tasks between executor in a group
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Executor-processor-group creation time
+);
+CREATE UNIQUE INDEX executor_processor_bind_only ON executor_processor_bind (group_id, executor_id);
+
+CREATE TABLE task_bind (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  task_id BIGINT NOT NULL DEFAULT 0, -- Task-id
+  bind_id BIGINT NOT NULL DEFAULT 0, -- Bind-id (executor_processor_group_id)
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Binding creation time
+);
+CREATE INDEX task_bind_task_id_idx ON task_bind (task_id);
+CREATE INDEX task_bind_bind_id_idx ON task_bind (bind_id);
+
+CREATE TABLE task_failover (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  task_id BIGINT NOT NULL DEFAULT 0, -- Task-id
+  origin_bind_id BIGINT NOT NULL DEFAULT 0, -- The binding whose executor became abnormal
+  origin_executor_id BIGINT NOT NULL DEFAULT 0, -- The abnormal executor-processor id
+  target_bind_id BIGINT NOT NULL DEFAULT 0, -- The binding that took over the task
+  target_executor_id BIGINT NOT NULL DEFAULT 0, -- The executor-processor id that took over the task
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Failover time
+);
+CREATE INDEX task_failover_task_id_idx ON task_failover (task_id);
+CREATE INDEX task_failover_origin_executor_id_idx ON task_failover (origin_executor_id);
+CREATE INDEX task_failover_target_executor_id_idx ON task_failover (target_executor_id);
+
+CREATE TABLE task_dependency (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  task_id BIGINT NOT NULL DEFAULT 0, -- The downstream task-id
+  upstream_task_id BIGINT NOT NULL DEFAULT 0, -- The upstream task-id
+  trigger_condition SMALLINT NOT NULL DEFAULT 1, -- When the downstream task is triggered, 1: upstream succeeded, 2: upstream failed
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Creation time
+);
+CREATE UNIQUE INDEX task_dependency_task_upstream_idx ON task_dependency (task_id, upstream_task_id);
+CREATE INDEX task_dependency_upstream_task_id_idx ON task_dependency (upstream_task_id);
+
+CREATE TABLE task_dependency_trigger (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  run_id BIGINT NOT NULL DEFAULT 0, -- The run shared by the tasks of a dependency chain
+  task_id BIGINT NOT NULL DEFAULT 0, -- The triggered task-id
+  upstream_task_id BIGINT NOT NULL DEFAULT 0, -- The upstream task-id
+  upstream_record_id BIGINT NOT NULL DEFAULT 0, -- The upstream task-log id whose end triggered the task
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Trigger time
+);
+CREATE UNIQUE INDEX task_dependency_trigger_run_task_idx ON task_dependency_trigger (run_id, task_id);
+
+CREATE TABLE calendar (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  name VARCHAR(128) NOT NULL, -- Calendar name
+  description VARCHAR(128) NOT NULL DEFAULT '', -- Calendar description
+  time_zone SMALLINT NOT NULL DEFAULT 2, -- The time zone of the excluded windows 1:Utc 2:Local
+  business_days_only SMALLINT NOT NULL DEFAULT 0, -- Whether Saturdays and Sundays are excluded 0:No 1:Yes
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Creation time
+  updated_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Update time
+);
+CREATE UNIQUE INDEX calendar_name_idx ON calendar (name);
+
+CREATE TABLE calendar_window (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  calendar_id BIGINT NOT NULL DEFAULT 0, -- Calendar id
+  start_time TIMESTAMP NOT NULL, -- The start of the window (inclusive), in the time zone of the calendar
+  end_time TIMESTAMP NOT NULL, -- The end of the window (exclusive), in the time zone of the calendar
+  description VARCHAR(128) NOT NULL DEFAULT '' -- The holiday or the blackout
+);
+CREATE INDEX calendar_window_calendar_id_idx ON calendar_window (calendar_id);
+
+CREATE TABLE user_auth (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id BIGINT NOT NULL DEFAULT 0, -- user-id
+  identity_type SMALLINT NOT NULL DEFAULT 1, -- 1:Mobie-number 2:Email 3:Username 4:LDAP 5:Other-OAuth
+  identifier VARCHAR(32) NOT NULL DEFAULT '', -- Mobie-number Email Username or unique identifier for third party applications
+  certificate VARCHAR(128) NOT NULL DEFAULT '', -- Password credentials (the station saves the password, the station does not save or save the token)
+  status SMALLINT NOT NULL DEFAULT 1, -- Authentication status: 1:active, 2:disabled
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Binding time
+  updated_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Update binding time
+);
+CREATE UNIQUE INDEX user_auth_only ON user_auth (user_id, identity_type);
+CREATE UNIQUE INDEX user_auth_identifier_idx ON user_auth (identifier);
+CREATE INDEX user_auth_user_id_idx ON user_auth (user_id);
+
+CREATE TABLE user (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- user-id
+  user_name VARCHAR(32) NOT NULL DEFAULT '', -- User account, must be unique
+  nick_name VARCHAR(32) NOT NULL DEFAULT '', -- User nickname
+  mobile VARCHAR(16) NOT NULL DEFAULT '', -- Mobie-number(unique)
+  email VARCHAR(100) NOT NULL DEFAULT '', -- Email(unique)
+  face VARCHAR(255) NOT NULL DEFAULT '', -- Avatar
+  status SMALLINT NOT NULL DEFAULT 1, -- User Status: 2:Deleted
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Creation time
+  updated_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Modify time
+);
+CREATE UNIQUE INDEX user_name_idx ON user (user_name);
+CREATE UNIQUE INDEX user_mobile_idx ON user (mobile);
+CREATE UNIQUE INDEX user_email_idx ON user (email);
+
+CREATE TABLE user_login_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id BIGINT NOT NULL DEFAULT 0, -- user-id
+  login_type SMALLINT NOT NULL DEFAULT 3, -- Login method 1:Mobie-number 2:Email 3:Username 4:LDAP 5:Other-OAuth etc. 81:Logout
+  command SMALLINT NOT NULL DEFAULT 1, -- Operation type 1:Login success 2:Logout success 3:Login failure 4:Logout failure
+  lastip VARCHAR(32) NOT NULL DEFAULT '', -- Login-ip
+  created_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Creation time
+  user_name VARCHAR(64) NOT NULL DEFAULT '' -- Login user name
+);
+CREATE INDEX user_login_log_user_id_type_time_idx ON user_login_log (user_id, login_type, created_time);
+CREATE INDEX user_login_log_create_time_idx ON user_login_log (created_time);
+
+CREATE TABLE user_register_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  user_id BIGINT NOT NULL, -- user-id
+  register_method SMALLINT NOT NULL, -- Registration method 1:Mobie-number 2:Email 3:Username
+  register_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')), -- Registration Time
+  register_ip VARCHAR(32) NOT NULL DEFAULT '' -- Registered IP
+);
+
+CREATE TABLE user_info_update (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  user_id BIGINT NOT NULL, -- user-id
+  attribute_name VARCHAR(30) NOT NULL, -- Attribute Name
+  attribute_old_val VARCHAR(30) NOT NULL DEFAULT '', -- attribute corresponds to the old value
+  attribute_new_val VARCHAR(30) NOT NULL DEFAULT '', -- attribute corresponds to the new value
+  updated_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Modify time
+);
+
+CREATE TABLE operation_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  name VARCHAR(64) NOT NULL DEFAULT '', -- Operation module name
+  table_id BIGINT NOT NULL DEFAULT 0, -- Operation table id
+  operation_type SMALLINT NOT NULL DEFAULT 1, -- Operation type: 1 add 2 modify 3 delete
+  user_id BIGINT NOT NULL DEFAULT 0, -- Operation user id
+  user_name VARCHAR(64) NOT NULL DEFAULT '', -- Operation user name
+  operation_time TIMESTAMP NOT NULL DEFAULT (datetime('now', 'localtime')) -- Operation time
+);
+CREATE INDEX operation_log_table_id_idx ON operation_log (table_id);
+CREATE INDEX operation_log_operation_time_idx ON operation_log (operation_time);
+CREATE INDEX operation_log_user_id_type_time_idx ON operation_log (user_id, operation_type, operation_time);
+
+CREATE TABLE operation_log_detail (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  operation_log_id BIGINT NOT NULL DEFAULT 0, -- Operation log id
+  column_comment TEXT NOT NULL, -- Field Description
+  "values" TEXT NOT NULL -- Values
+);
+CREATE INDEX operation_log_detail_operation_log_id_idx ON operation_log_detail (operation_log_id);
+
+CREATE TABLE casbin_rule (
+  id INTEGER PRIMARY KEY AUTOINCREMENT, -- Self-incrementing id
+  ptype VARCHAR(64) NOT NULL DEFAULT '', -- ptype for casbin
+  v0 VARCHAR(64) NOT NULL DEFAULT '', -- Dynamic fields for casbin, adapted to the model
+  v1 VARCHAR(64) NOT NULL DEFAULT '', -- Dynamic fields for casbin, adapted to the model
+  v2 VARCHAR(64) NOT NULL DEFAULT '', -- Dynamic fields for casbin, adapted to the model
+  v3 VARCHAR(64) NOT NULL DEFAULT '', -- Dynamic fields for casbin, adapted to the model
+  v4 VARCHAR(64) NOT NULL DEFAULT '', -- Dynamic fields for casbin, adapted to the model
+  v5 VARCHAR(64) NOT NULL DEFAULT '' -- Dynamic fields for casbin, adapted to the model
+);
+CREATE UNIQUE INDEX casbin_unique_key_idx ON casbin_rule (ptype, v0, v1, v2, v3, v4, v5);
+
+-- Keeps the `updated_time` of the rows current, as `ON UPDATE CURRENT_TIMESTAMP` does on mysql.
+CREATE TRIGGER task_log_updated_time AFTER UPDATE ON task_log FOR EACH ROW WHEN NEW.updated_time = OLD.updated_time
+BEGIN
+  UPDATE task_log SET updated_time = datetime('now', 'localtime') WHERE id = NEW.id;
+END;
+CREATE TRIGGER calendar_updated_time AFTER UPDATE ON calendar FOR EACH ROW WHEN NEW.updated_time = OLD.updated_time
+BEGIN
+  UPDATE calendar SET updated_time = datetime('now', 'localtime') WHERE id = NEW.id;
+END;
+CREATE TRIGGER user_auth_updated_time AFTER UPDATE ON user_auth FOR EACH ROW WHEN NEW.updated_time = OLD.updated_time
+BEGIN
+  UPDATE user_auth SET updated_time = datetime('now', 'localtime') WHERE id = NEW.id;
+END;
+CREATE TRIGGER user_updated_time AFTER UPDATE ON user FOR EACH ROW WHEN NEW.updated_time = OLD.updated_time
+BEGIN
+  UPDATE user SET updated_time = datetime('now', 'localtime') WHERE id = NEW.id;
+END;
+CREATE TRIGGER user_info_update_updated_time AFTER UPDATE ON user_info_update FOR EACH ROW WHEN NEW.updated_time = OLD.updated_time
+BEGIN
+  UPDATE user_info_update SET updated_time = datetime('now', 'localtime') WHERE id = NEW.id;
+END;
+
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'task', 'create');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'task', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'task', 'update');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'task', 'run');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task', 'advance');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task', 'update');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task', 'delete');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task', 'run');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task', 'create');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task', 'suspend');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task_instance', 'kill');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task_log', 'delete');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task_log', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task_log', 'detail');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'processor_admin', 'executor_processor', 'activate');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'processor_admin', 'executor_processor', 'update');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'processor_admin', 'executor_processor', 'delete');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'processor_admin', 'executor_processor', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'processor_admin', 'executor_processor', 'create');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_group', 'update');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_group', 'delete');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_group', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_group', 'create');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_group', 'detail');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_processor_bind', 'create');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_processor_bind', 'update');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_processor_bind', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'group_admin', 'executor_processor_bind', 'delete');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'delete');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'update');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'create');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'append_role');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'delete_role');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'append_permission');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'delete_permission');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'roles');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'user', 'permissions');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'permission', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'role', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'role', 'permission_detail');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'user_admin', 'role', 'users');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'log_admin', 'user_login_log', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'log_admin', 'operation_log', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'log_admin', 'operation_log', 'detail');
+INSERT INTO casbin_rule (ptype, v0, v1) VALUES ('g', 'team_leader', 'task_admin');
+INSERT INTO casbin_rule (ptype, v0, v1) VALUES ('g', 'team_leader', 'processor_admin');
+INSERT INTO casbin_rule (ptype, v0, v1) VALUES ('g', 'team_leader', 'group_admin');
+INSERT INTO casbin_rule (ptype, v0, v1) VALUES ('g', 'team_leader', 'user_admin');
+INSERT INTO casbin_rule (ptype, v0, v1) VALUES ('g', 'team_leader', 'log_admin');
+INSERT INTO casbin_rule (ptype, v0, v1) VALUES ('g', 'developer', 'processor_admin');
+INSERT INTO casbin_rule (ptype, v0, v1) VALUES ('g', 'developer', 'log_admin');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'calendar', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'calendar', 'detail');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'list');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'detail');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'create');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'update');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'calendar', 'delete');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'developer', 'task', 'preview');
+INSERT INTO casbin_rule (ptype, v0, v1, v2) VALUES ('p', 'task_admin', 'task', 'preview');
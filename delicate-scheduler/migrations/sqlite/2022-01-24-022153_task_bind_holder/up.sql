@@ -0,0 +1 @@
+ALTER TABLE task_bind ADD holder SMALLINT NOT NULL DEFAULT 0; -- Whether the member holds the routed task 0:No 1:Yes
@@ -0,0 +1,653 @@
+use super::*;
+use crate::scheduler::db::ExecutorRepository;
+use model::{
+    BindProcessor, BindingSelection, ExecutorGroup, ExecutorGroupBinding, ExecutorGroupDetail,
+    ExecutorProcessor, ExecutorProcessorBind, ExecutorProcessorHealth, ExecutorSelection,
+    FrontEndExecutorProcessorHealth, NewExecutorGroup, NewExecutorProcessor,
+    NewExecutorProcessorBind, NewExecutorProcessorHealth, QueryParamsExecutorGroup,
+    QueryParamsExecutorProcessor, QueryParamsExecutorProcessorBind,
+    QueryParamsExecutorProcessorHealth, UpdateExecutorGroup, UpdateExecutorProcessor,
+    UpdateExecutorProcessorBind,
+};
+use schema::{
+    executor_group, executor_processor, executor_processor_bind, executor_processor_health,
+};
+
+struct ExecutorGroupQueryBuilder;
+
+impl ExecutorGroupQueryBuilder {
+    fn query_all_columns() -> executor_group::BoxedQuery<'static, DbBackend> {
+        executor_group::table
+            .into_boxed()
+            .select(executor_group::all_columns)
+    }
+
+    fn query_count() -> executor_group::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint> {
+        executor_group::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<executor_group::BoxedQuery<'static, DbBackend, ST>>
+    for QueryParamsExecutorGroup
+{
+    fn query_filter(
+        self,
+        mut statement_builder: executor_group::BoxedQuery<'static, DbBackend, ST>,
+    ) -> executor_group::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(executor_group_id) = self.id {
+            statement_builder = statement_builder.filter(executor_group::id.eq(executor_group_id));
+        }
+
+        if let Some(executor_group_name) = self.name {
+            statement_builder =
+                statement_builder.filter(executor_group::name.like(executor_group_name));
+        }
+
+        if let Some(executor_group_description) = self.description {
+            statement_builder = statement_builder
+                .filter(executor_group::description.like(executor_group_description));
+        }
+
+        if let Some(executor_group_tag) = self.tag {
+            statement_builder =
+                statement_builder.filter(executor_group::tag.like(executor_group_tag));
+        }
+
+        statement_builder.order(executor_group::id.desc())
+    }
+}
+
+struct ExecutorProcessorQueryBuilder;
+
+impl ExecutorProcessorQueryBuilder {
+    fn query_all_columns() -> executor_processor::BoxedQuery<'static, DbBackend> {
+        executor_processor::table
+            .into_boxed()
+            .select(executor_processor::all_columns)
+    }
+
+    fn query_selection_columns(
+    ) -> executor_processor::BoxedQuery<'static, DbBackend, (sql_types::Bigint, sql_types::VarChar)>
+    {
+        executor_processor::table
+            .into_boxed()
+            .select((executor_processor::id, executor_processor::name))
+    }
+    fn query_count() -> executor_processor::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint>
+    {
+        executor_processor::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<executor_processor::BoxedQuery<'static, DbBackend, ST>>
+    for QueryParamsExecutorProcessor
+{
+    fn query_filter(
+        self,
+        mut statement_builder: executor_processor::BoxedQuery<'static, DbBackend, ST>,
+    ) -> executor_processor::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(executor_processor_id) = self.id {
+            statement_builder =
+                statement_builder.filter(executor_processor::id.eq(executor_processor_id));
+        }
+
+        if let Some(machine_id) = self.machine_id {
+            statement_builder =
+                statement_builder.filter(executor_processor::machine_id.eq(machine_id));
+        }
+
+        if let Some(executor_processor_name) = self.name {
+            statement_builder =
+                statement_builder.filter(executor_processor::name.like(executor_processor_name));
+        }
+
+        if let Some(executor_processor_description) = self.description {
+            statement_builder = statement_builder
+                .filter(executor_processor::description.like(executor_processor_description));
+        }
+
+        if let Some(executor_processor_tag) = self.tag {
+            statement_builder =
+                statement_builder.filter(executor_processor::tag.like(executor_processor_tag));
+        }
+
+        statement_builder.order(executor_processor::id.desc())
+    }
+}
+
+struct ExecutorProcessorBindQueryBuilder;
+
+impl ExecutorProcessorBindQueryBuilder {
+    fn query_all_columns() -> executor_processor_bind::BoxedQuery<'static, DbBackend> {
+        executor_processor_bind::table
+            .into_boxed()
+            .select(executor_processor_bind::all_columns)
+    }
+
+    fn query_binding_columns() -> executor_processor_bind::BoxedQuery<
+        'static,
+        DbBackend,
+        (sql_types::Bigint, sql_types::VarChar),
+    > {
+        executor_processor_bind::table
+            .into_boxed()
+            .select((executor_processor_bind::id, executor_processor_bind::name))
+    }
+
+    fn query_count(
+    ) -> executor_processor_bind::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint> {
+        executor_processor_bind::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<executor_processor_bind::BoxedQuery<'static, DbBackend, ST>>
+    for QueryParamsExecutorProcessorBind
+{
+    fn query_filter(
+        self,
+        mut statement_builder: executor_processor_bind::BoxedQuery<'static, DbBackend, ST>,
+    ) -> executor_processor_bind::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(executor_processor_bind_id) = self.id {
+            statement_builder = statement_builder
+                .filter(executor_processor_bind::id.eq(executor_processor_bind_id));
+        }
+
+        if let Some(executor_processor_bind_group_id) = self.group_id {
+            statement_builder = statement_builder
+                .filter(executor_processor_bind::group_id.eq(executor_processor_bind_group_id));
+        }
+
+        if let Some(executor_processor_bind_executor_id) = self.executor_id {
+            statement_builder = statement_builder.filter(
+                executor_processor_bind::executor_id.eq(executor_processor_bind_executor_id),
+            );
+        }
+
+        if let Some(executor_processor_bind_name) = self.name {
+            statement_builder = statement_builder
+                .filter(executor_processor_bind::name.like(executor_processor_bind_name));
+        }
+
+        statement_builder.order(executor_processor_bind::id.desc())
+    }
+}
+
+struct ExecutorProcessorHealthQueryBuilder;
+
+impl ExecutorProcessorHealthQueryBuilder {
+    fn query_all_columns() -> executor_processor_health::BoxedQuery<'static, DbBackend> {
+        executor_processor_health::table
+            .into_boxed()
+            .select(executor_processor_health::all_columns)
+    }
+
+    fn query_count(
+    ) -> executor_processor_health::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint> {
+        executor_processor_health::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<executor_processor_health::BoxedQuery<'static, DbBackend, ST>>
+    for QueryParamsExecutorProcessorHealth
+{
+    fn query_filter(
+        self,
+        mut statement_builder: executor_processor_health::BoxedQuery<'static, DbBackend, ST>,
+    ) -> executor_processor_health::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(executor_processor_id) = self.executor_processor_id {
+            statement_builder = statement_builder
+                .filter(executor_processor_health::executor_processor_id.eq(executor_processor_id));
+        }
+
+        if let Some(result) = self.result {
+            statement_builder =
+                statement_builder.filter(executor_processor_health::result.eq(result));
+        }
+
+        if let Some(Ok(start_time)) = self
+            .start_time
+            .map(|s| NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S"))
+        {
+            let end_time = self
+                .end_time
+                .map(|s| {
+                    NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                        .unwrap_or_else(|_| start_time + ChronoDuration::days(3))
+                })
+                .unwrap_or_else(|| start_time + ChronoDuration::days(3));
+
+            statement_builder = statement_builder
+                .filter(executor_processor_health::created_time.between(start_time, end_time));
+        }
+
+        statement_builder.order(executor_processor_health::id.desc())
+    }
+}
+
+impl ExecutorRepository for DieselStorage {
+    fn create_executor_group(&self, executor_group: NewExecutorGroup) -> Result<i64, CommonError> {
+        let conn = self.conn()?;
+        Ok(insert_returning_id!(
+            &conn,
+            executor_group,
+            &executor_group
+        )?)
+    }
+
+    fn list_executor_groups(
+        &self,
+        query_params: QueryParamsExecutorGroup,
+    ) -> Result<PaginateData<ExecutorGroup>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = ExecutorGroupQueryBuilder::query_all_columns();
+
+        let executor_groups = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<ExecutorGroup>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = ExecutorGroupQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        Ok(PaginateData::<ExecutorGroup>::default()
+            .set_data_source(executor_groups)
+            .set_page_size(per_page)
+            .set_total(count))
+    }
+
+    fn executor_group_detail(
+        &self,
+        executor_group_id: i64,
+    ) -> Result<ExecutorGroupDetail, CommonError> {
+        let conn = self.conn()?;
+        let executor_group_detail_inner = executor_group::table
+            .select(executor_group::all_columns)
+            .find(executor_group_id)
+            .first::<ExecutorGroup>(&conn)?;
+
+        let bindings = executor_processor_bind::table
+            .inner_join(executor_processor::table)
+            .filter(executor_processor_bind::group_id.eq(executor_group_id))
+            .select((
+                executor_processor_bind::id,
+                executor_processor_bind::name,
+                executor_processor_bind::executor_id,
+                executor_processor_bind::weight,
+                executor_processor::name,
+                executor_processor::host,
+                executor_processor::machine_id,
+            ))
+            .load::<ExecutorGroupBinding>(&conn)?;
+
+        Ok(ExecutorGroupDetail {
+            inner: executor_group_detail_inner,
+            bindings,
+        })
+    }
+
+    fn update_executor_group(
+        &self,
+        executor_group: UpdateExecutorGroup,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(diesel::update(&executor_group)
+            .set(&executor_group)
+            .execute(&conn)?)
+    }
+
+    fn delete_executor_group(&self, executor_group_id: i64) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        // Cannot link to delete internal bindings, otherwise it will cause data misalignment.
+        Ok(diesel::delete(executor_group::table.find(executor_group_id)).execute(&conn)?)
+    }
+
+    fn create_executor_processor(
+        &self,
+        executor_processor: NewExecutorProcessor,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(diesel::insert_into(executor_processor::table)
+            .values(&executor_processor)
+            .execute(&conn)?)
+    }
+
+    fn list_executor_processors(
+        &self,
+        query_params: QueryParamsExecutorProcessor,
+    ) -> Result<PaginateData<ExecutorProcessor>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = ExecutorProcessorQueryBuilder::query_all_columns();
+
+        let executor_processors = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<ExecutorProcessor>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = ExecutorProcessorQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        Ok(PaginateData::<ExecutorProcessor>::default()
+            .set_data_source(executor_processors)
+            .set_page_size(per_page)
+            .set_total(count)
+            .set_state_desc::<state::executor_processor::State>())
+    }
+
+    fn list_executor_processor_health(
+        &self,
+        query_params: QueryParamsExecutorProcessorHealth,
+    ) -> Result<PaginateData<FrontEndExecutorProcessorHealth>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = ExecutorProcessorHealthQueryBuilder::query_all_columns();
+
+        let health_history = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<ExecutorProcessorHealth>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = ExecutorProcessorHealthQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        let front_end_health_history: Vec<FrontEndExecutorProcessorHealth> = health_history
+            .into_iter()
+            .map(|health| health.into())
+            .collect();
+
+        Ok(PaginateData::<FrontEndExecutorProcessorHealth>::default()
+            .set_data_source(front_end_health_history)
+            .set_page_size(per_page)
+            .set_total(count)
+            .set_state_desc::<state::executor_processor::State>()
+            .set_state_desc::<state::executor_processor_health::ProbeResult>())
+    }
+
+    fn find_executor_processor(
+        &self,
+        executor_processor_id: i64,
+    ) -> Result<UpdateExecutorProcessor, CommonError> {
+        let conn = self.conn()?;
+        Ok(executor_processor::table
+            .find(executor_processor_id)
+            .select((
+                executor_processor::id,
+                executor_processor::name,
+                executor_processor::description,
+                executor_processor::host,
+                executor_processor::machine_id,
+                executor_processor::tag,
+            ))
+            .first(&conn)?)
+    }
+
+    fn update_executor_processor(
+        &self,
+        executor_processor: UpdateExecutorProcessor,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(diesel::update(&executor_processor)
+            .set(&executor_processor)
+            .execute(&conn)?)
+    }
+
+    fn delete_executor_processor(&self, executor_processor_id: i64) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(diesel::delete(executor_processor::table.find(executor_processor_id)).execute(&conn)?)
+    }
+
+    fn activate_executor_processor(
+        &self,
+        executor_processor_id: i64,
+        token: String,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(
+            diesel::update(executor_processor::table.find(executor_processor_id))
+                .set((
+                    executor_processor::token.eq(&token),
+                    executor_processor::status.eq(state::executor_processor::State::Enabled as i16),
+                ))
+                .execute(&conn)?,
+        )
+    }
+
+    fn rebind_executor_processor(
+        &self,
+        executor_processor_id: i64,
+        token: String,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(
+            diesel::update(executor_processor::table.find(executor_processor_id))
+                .set(executor_processor::token.eq(&token))
+                .execute(&conn)?,
+        )
+    }
+
+    fn executor_token(&self, executor_processor_id: i64) -> Result<Option<String>, CommonError> {
+        let conn = self.conn()?;
+        Ok(executor_processor::table
+            .find(executor_processor_id)
+            .select(executor_processor::token)
+            .first::<String>(&conn)
+            .optional()?)
+    }
+
+    fn executor_selections(&self) -> Result<Vec<ExecutorSelection>, CommonError> {
+        let conn = self.conn()?;
+        Ok(ExecutorProcessorQueryBuilder::query_selection_columns()
+            .load::<ExecutorSelection>(&conn)?)
+    }
+
+    fn create_executor_processor_binds(
+        &self,
+        executor_processor_binds: Vec<NewExecutorProcessorBind>,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(insert_rows!(
+            &conn,
+            executor_processor_bind,
+            &executor_processor_binds[..]
+        )?)
+    }
+
+    fn list_executor_processor_binds(
+        &self,
+        query_params: QueryParamsExecutorProcessorBind,
+    ) -> Result<PaginateData<ExecutorProcessorBind>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = ExecutorProcessorBindQueryBuilder::query_all_columns();
+
+        let executor_processor_binds = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<ExecutorProcessorBind>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = ExecutorProcessorBindQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        Ok(PaginateData::<ExecutorProcessorBind>::default()
+            .set_data_source(executor_processor_binds)
+            .set_page_size(per_page)
+            .set_total(count))
+    }
+
+    fn update_executor_processor_bind(
+        &self,
+        executor_processor_bind: UpdateExecutorProcessorBind,
+    ) -> Result<(i64, String, String), CommonError> {
+        let conn = self.conn()?;
+        let older_executor = executor_processor_bind::table
+            .inner_join(executor_processor::table)
+            .filter(executor_processor_bind::id.eq(executor_processor_bind.id))
+            .select((
+                executor_processor_bind::executor_id,
+                executor_processor::host,
+                executor_processor::token,
+            ))
+            .first::<(i64, String, String)>(&conn)?;
+
+        diesel::update(&executor_processor_bind)
+            .set(&executor_processor_bind)
+            .execute(&conn)?;
+        Ok(older_executor)
+    }
+
+    fn delete_executor_processor_bind(
+        &self,
+        executor_processor_bind_id: i64,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(
+            diesel::delete(executor_processor_bind::table.find(executor_processor_bind_id))
+                .execute(&conn)?,
+        )
+    }
+
+    fn binding_selections(&self) -> Result<Vec<BindingSelection>, CommonError> {
+        let conn = self.conn()?;
+        Ok(ExecutorProcessorBindQueryBuilder::query_binding_columns()
+            .load::<BindingSelection>(&conn)?)
+    }
+
+    fn executors_in_status(
+        &self,
+        statuses: Vec<i16>,
+    ) -> Result<Vec<(i64, String, String, i16)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(executor_processor::table
+            .select((
+                executor_processor::id,
+                executor_processor::host,
+                executor_processor::token,
+                executor_processor::status,
+            ))
+            .filter(executor_processor::status.eq_any(statuses))
+            .load::<(i64, String, String, i16)>(&conn)?)
+    }
+
+    fn executors_by_ids(
+        &self,
+        executor_processor_ids: Vec<i64>,
+    ) -> Result<Vec<(i64, String, String, i16)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(executor_processor::table
+            .select((
+                executor_processor::id,
+                executor_processor::host,
+                executor_processor::token,
+                executor_processor::status,
+            ))
+            .filter(executor_processor::id.eq_any(executor_processor_ids))
+            .load::<(i64, String, String, i16)>(&conn)?)
+    }
+
+    fn record_health_check(
+        &self,
+        abnormal_processors: Vec<i64>,
+        recovered_processors: Vec<i64>,
+        health_history: Vec<NewExecutorProcessorHealth>,
+    ) -> Result<(), CommonError> {
+        use state::executor_processor::State;
+
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            if !abnormal_processors.is_empty() {
+                diesel::update(
+                    executor_processor::table
+                        .filter(executor_processor::id.eq_any(&abnormal_processors[..])),
+                )
+                .set(executor_processor::status.eq(State::Abnormal as i16))
+                .execute(&conn)?;
+            }
+
+            if !recovered_processors.is_empty() {
+                diesel::update(
+                    executor_processor::table
+                        .filter(executor_processor::id.eq_any(&recovered_processors[..])),
+                )
+                .set(executor_processor::status.eq(State::Enabled as i16))
+                .execute(&conn)?;
+            }
+
+            if !health_history.is_empty() {
+                insert_rows!(&conn, executor_processor_health, &health_history[..])?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn prune_health_history(&self, deadline: DateTime<Utc>) -> Result<usize, CommonError> {
+        let deadline = self.record_time_zone.record_time_of(deadline);
+        let conn = self.conn()?;
+        Ok(diesel::delete(
+            executor_processor_health::table
+                .filter(executor_processor_health::created_time.lt(deadline)),
+        )
+        .execute(&conn)?)
+    }
+
+    fn recent_health_history(
+        &self,
+        executor_processor_ids: Vec<i64>,
+        limit: i64,
+    ) -> Result<HashMap<i64, Vec<i16>>, CommonError> {
+        let conn = self.conn()?;
+        let mut recent_health_history: HashMap<i64, Vec<i16>> = HashMap::new();
+        for executor_processor_id in executor_processor_ids {
+            let results = executor_processor_health::table
+                .filter(executor_processor_health::executor_processor_id.eq(executor_processor_id))
+                .order(executor_processor_health::id.desc())
+                .limit(limit)
+                .select(executor_processor_health::result)
+                .load::<i16>(&conn)?;
+            recent_health_history.insert(executor_processor_id, results);
+        }
+
+        Ok(recent_health_history)
+    }
+
+    fn enabled_group_members(
+        &self,
+        group_ids: Vec<i64>,
+    ) -> Result<Vec<(i64, BindProcessor)>, CommonError> {
+        use state::executor_processor::State;
+
+        let conn = self.conn()?;
+        Ok(executor_processor_bind::table
+            .inner_join(executor_processor::table)
+            .filter(executor_processor_bind::group_id.eq_any(group_ids))
+            .filter(executor_processor::status.eq(State::Enabled as i16))
+            .select((
+                executor_processor_bind::group_id,
+                (
+                    executor_processor_bind::id,
+                    executor_processor_bind::executor_id,
+                    executor_processor_bind::weight,
+                    executor_processor::host,
+                    executor_processor::token,
+                ),
+            ))
+            .load::<(i64, BindProcessor)>(&conn)?)
+    }
+}
@@ -1,10 +1,10 @@
-use super::prelude::*;
+use super::*;
 
-impl<T> QueryFragment<Mysql> for Paginated<T>
+impl<T> QueryFragment<DbBackend> for Paginated<T>
 where
-    T: QueryFragment<Mysql>,
+    T: QueryFragment<DbBackend>,
 {
-    fn walk_ast(&self, mut out: AstPass<'_, Mysql>) -> QueryResult<()> {
+    fn walk_ast(&self, mut out: AstPass<'_, DbBackend>) -> QueryResult<()> {
         self.query.walk_ast(out.reborrow())?;
         out.push_sql(" LIMIT ");
         out.push_bind_param::<sql_types::BigInt, _>(&self.per_page)?;
@@ -19,7 +19,7 @@ impl<T: Query> Query for Paginated<T> {
     type SqlType = T::SqlType;
 }
 
-impl<T> RunQueryDsl<MysqlConnection> for Paginated<T> {}
+impl<T> RunQueryDsl<DbConnection> for Paginated<T> {}
 
 pub(crate) trait Paginate: AsQuery + Sized {
     fn paginate(self, page: i64) -> Paginated<Self::Query> {
@@ -50,9 +50,9 @@ impl<T> Paginated<T> {
 
 impl<T> Paginated<T> {
     #[allow(dead_code)]
-    fn load_pages<U>(self, conn: &MysqlConnection) -> QueryResult<Vec<U>>
+    fn load_pages<U>(self, conn: &DbConnection) -> QueryResult<Vec<U>>
     where
-        Self: LoadQuery<MysqlConnection, U>,
+        Self: LoadQuery<DbConnection, U>,
     {
         self.load::<U>(conn)
     }
@@ -0,0 +1,49 @@
+//! The repositories of the scheduler on diesel.
+//!
+//! This module is compiled once for each backend through `#[path]`, on the connections of the backend module above,
+//! So the queries are written once while diesel checks them against each backend.
+
+pub(crate) use crate::scheduler::db::{model, schema, Storage};
+pub(crate) use crate::scheduler::prelude::*;
+
+use super::{
+    embedded_migrations, hour_of, ConnectionPool, DbBackend, DbConnection, PoolConnection,
+};
+
+mod executor;
+mod extension;
+mod task;
+mod task_log;
+mod user;
+
+use extension::*;
+
+/// The storage of the scheduler on one of the diesel backends.
+pub(crate) struct DieselStorage {
+    pool: ConnectionPool,
+    record_time_zone: RecordTimeZone,
+}
+
+impl DieselStorage {
+    pub(crate) fn new(pool: ConnectionPool, record_time_zone: RecordTimeZone) -> Self {
+        DieselStorage {
+            pool,
+            record_time_zone,
+        }
+    }
+
+    fn conn(&self) -> Result<PoolConnection, CommonError> {
+        Ok(self.pool.get()?)
+    }
+}
+
+impl Storage for DieselStorage {
+    fn run_migrations(&self) -> Result<(), CommonError> {
+        embedded_migrations::run(&*self.conn()?).map_err(|e| CommonError::DisPass(e.to_string()))
+    }
+}
+
+// Applies the query parameters of a list to its boxed statement.
+trait QueryFilter<Q> {
+    fn query_filter(self, statement_builder: Q) -> Q;
+}
@@ -0,0 +1,899 @@
+use super::*;
+use crate::scheduler::components::dependency::find_cycle;
+use crate::scheduler::db::{TaskBindChanges, TaskRepository};
+use delicate_utils::consensus_message::calendar::{ExcludedWindow, TaskCalendar};
+use delicate_utils_task::TaskPackage;
+use diesel::result::DatabaseErrorKind;
+use diesel::sql_types::{Bigint, SmallInt, VarChar};
+use model::{
+    BindProcessor, Calendar, CalendarDetail, CalendarWindow, CalendarWindowItem, FrontEndTask,
+    NewCalendar, NewCalendarWindow, NewTask, NewTaskBind, NewTaskDependency,
+    NewTaskDependencyTrigger, NewTaskFailover, QueryParamsCalendar, QueryParamsTask, Task,
+    TaskDependency, TaskDependencyItem, TaskFailover, UpdateCalendar, UpdateTask,
+};
+use schema::{
+    calendar, calendar_window, executor_processor, executor_processor_bind, task, task_bind,
+    task_dependency, task_dependency_trigger, task_failover,
+};
+use state::task::{State, TriggerMode};
+use state::task_dependency::TriggerCondition;
+
+pub(super) type SupplyTaskType = (
+    Bigint,
+    VarChar,
+    VarChar,
+    VarChar,
+    VarChar,
+    VarChar,
+    VarChar,
+    SmallInt,
+);
+
+pub(super) struct TaskQueryBuilder;
+
+impl TaskQueryBuilder {
+    fn query_all_columns() -> task::BoxedQuery<'static, DbBackend> {
+        task::table.into_boxed().select(task::all_columns)
+    }
+
+    pub(super) fn query_supply_task_log() -> task::BoxedQuery<'static, DbBackend, SupplyTaskType> {
+        task::table.into_boxed().select((
+            task::id,
+            task::name,
+            task::description,
+            task::command,
+            task::frequency,
+            task::cron_expression,
+            task::tag,
+            task::maximum_parallel_runnable_num,
+        ))
+    }
+
+    fn query_count() -> task::BoxedQuery<'static, DbBackend, Bigint> {
+        task::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<task::BoxedQuery<'static, DbBackend, ST>> for QueryParamsTask {
+    fn query_filter(
+        self,
+        mut statement_builder: task::BoxedQuery<'static, DbBackend, ST>,
+    ) -> task::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(task_id) = self.id {
+            statement_builder = statement_builder.filter(task::id.eq(task_id));
+        }
+
+        if let Some(task_status) = self.status {
+            statement_builder = statement_builder.filter(task::status.eq(task_status));
+        } else {
+            statement_builder = statement_builder.filter(task::status.ne(State::Deleted as i16));
+        }
+
+        if let Some(task_name) = self.name {
+            statement_builder = statement_builder.filter(task::name.like(task_name));
+        }
+
+        if let Some(task_description) = self.description {
+            statement_builder = statement_builder.filter(task::description.like(task_description));
+        }
+
+        if let Some(task_command) = self.command {
+            statement_builder = statement_builder.filter(task::command.like(task_command));
+        }
+
+        if let Some(task_cron_expression) = self.cron_expression {
+            statement_builder =
+                statement_builder.filter(task::cron_expression.like(task_cron_expression));
+        }
+
+        if let Some(task_tag) = self.tag {
+            statement_builder = statement_builder.filter(task::tag.like(task_tag));
+        }
+
+        statement_builder.order(task::id.desc())
+    }
+}
+
+struct CalendarQueryBuilder;
+
+impl CalendarQueryBuilder {
+    fn query_all_columns() -> calendar::BoxedQuery<'static, DbBackend> {
+        calendar::table.into_boxed().select(calendar::all_columns)
+    }
+
+    fn query_count() -> calendar::BoxedQuery<'static, DbBackend, Bigint> {
+        calendar::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<calendar::BoxedQuery<'static, DbBackend, ST>> for QueryParamsCalendar {
+    fn query_filter(
+        self,
+        mut statement_builder: calendar::BoxedQuery<'static, DbBackend, ST>,
+    ) -> calendar::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(calendar_id) = self.id {
+            statement_builder = statement_builder.filter(calendar::id.eq(calendar_id));
+        }
+
+        if let Some(calendar_name) = self.name {
+            statement_builder = statement_builder.filter(calendar::name.like(calendar_name));
+        }
+
+        if let Some(calendar_description) = self.description {
+            statement_builder =
+                statement_builder.filter(calendar::description.like(calendar_description));
+        }
+
+        statement_builder.order(calendar::id.desc())
+    }
+}
+
+impl TaskRepository for DieselStorage {
+    fn create_task(
+        &self,
+        task: NewTask,
+        binding_ids: Vec<i64>,
+        dependencies: Vec<TaskDependencyItem>,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            check_task_calendar(&conn, task.calendar_id)?;
+            let task_id = insert_returning_id!(&conn, task, &task)?;
+
+            let new_task_binds: Vec<NewTaskBind> = binding_ids
+                .into_iter()
+                .map(|bind_id| NewTaskBind { task_id, bind_id })
+                .collect();
+
+            let count = insert_rows!(&conn, task_bind, &new_task_binds)?;
+
+            save_task_dependencies(&conn, task_id, dependencies)?;
+            Ok(count)
+        })
+    }
+
+    fn list_tasks(
+        &self,
+        query_params: QueryParamsTask,
+    ) -> Result<PaginateData<FrontEndTask>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = TaskQueryBuilder::query_all_columns();
+
+        let mut tasks: HashMap<i64, FrontEndTask> = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<Task>(&conn)?
+            .into_iter()
+            .map(|t| (t.id, t.into()))
+            .collect();
+
+        let tasks_ids: Vec<i64> = tasks.iter().map(|(id, _)| *id).collect();
+
+        let tasks_bind_pairs = task_bind::table
+            .select((task_bind::task_id, task_bind::bind_id))
+            .filter(task_bind::task_id.eq_any(&tasks_ids[..]))
+            .load::<(i64, i64)>(&conn)?;
+
+        tasks_bind_pairs.into_iter().for_each(|(task_id, bind_id)| {
+            if let Some(task) = tasks.get_mut(&task_id) {
+                task.binding_ids.push(bind_id);
+            }
+        });
+
+        let tasks_dependency_pairs = task_dependency::table
+            .select((
+                task_dependency::task_id,
+                (
+                    task_dependency::upstream_task_id,
+                    task_dependency::trigger_condition,
+                ),
+            ))
+            .filter(task_dependency::task_id.eq_any(&tasks_ids[..]))
+            .load::<(i64, TaskDependencyItem)>(&conn)?;
+
+        tasks_dependency_pairs
+            .into_iter()
+            .for_each(|(task_id, dependency)| {
+                if let Some(task) = tasks.get_mut(&task_id) {
+                    task.dependencies.push(dependency);
+                }
+            });
+
+        let per_page = query_params.per_page;
+        let count_builder = TaskQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        let mut front_end_task = tasks
+            .into_iter()
+            .map(|(_, t)| t)
+            .collect::<Vec<FrontEndTask>>();
+
+        front_end_task.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(PaginateData::<FrontEndTask>::default()
+            .set_data_source(front_end_task)
+            .set_page_size(per_page)
+            .set_total(count)
+            .set_state_desc::<state::task::State>()
+            .set_state_desc::<state::task::RoutingStrategy>()
+            .set_state_desc::<state::task::Kind>()
+            .set_state_desc::<state::task_dependency::TriggerCondition>())
+    }
+
+    fn update_task(
+        &self,
+        task: UpdateTask,
+        binding_ids: Vec<i64>,
+        dependencies: Option<Vec<TaskDependencyItem>>,
+    ) -> Result<TaskBindChanges, CommonError> {
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            let task_id = task.id;
+            check_task_calendar(&conn, task.calendar_id)?;
+            let update_effect_row = diesel::update(&task).set(&task).execute(&conn)?;
+
+            if let Some(dependencies) = dependencies {
+                save_task_dependencies(&conn, task_id, dependencies)?;
+            }
+
+            let original_bind_processors: Vec<BindProcessor> = task_bind::table
+                .inner_join(executor_processor_bind::table.inner_join(executor_processor::table))
+                .select((
+                    task_bind::bind_id,
+                    executor_processor_bind::executor_id,
+                    executor_processor_bind::weight,
+                    executor_processor::host,
+                    executor_processor::token,
+                ))
+                .filter(task_bind::task_id.eq(task_id))
+                .load(&conn)?;
+
+            // Contrast with binding updates.
+            let original_task_binds: HashSet<i64> =
+                original_bind_processors.iter().map(|b| b.bind_id).collect();
+
+            let current_task_binds: HashSet<i64> = binding_ids.into_iter().collect();
+
+            let removed_task_binds: Vec<i64> = original_task_binds
+                .difference(&current_task_binds)
+                .copied()
+                .collect();
+
+            diesel::delete(
+                task_bind::table
+                    .filter(task_bind::task_id.eq(task_id))
+                    .filter(task_bind::bind_id.eq_any(&removed_task_binds[..])),
+            )
+            .execute(&conn)?;
+
+            let append_task_binds: Vec<NewTaskBind> = current_task_binds
+                .difference(&original_task_binds)
+                .copied()
+                .map(|bind_id| NewTaskBind { task_id, bind_id })
+                .collect();
+
+            insert_rows!(&conn, task_bind, &append_task_binds[..])?;
+
+            let removed_bind_processors: Vec<BindProcessor> = original_bind_processors
+                .iter()
+                .filter(|b| removed_task_binds.contains(&b.bind_id))
+                .cloned()
+                .collect();
+
+            let append_binds: Vec<i64> = append_task_binds.iter().map(|b| b.bind_id).collect();
+
+            let append_bind_processors: Vec<BindProcessor> = executor_processor_bind::table
+                .inner_join(executor_processor::table)
+                .select((
+                    executor_processor_bind::id,
+                    executor_processor_bind::executor_id,
+                    executor_processor_bind::weight,
+                    executor_processor::host,
+                    executor_processor::token,
+                ))
+                .filter(executor_processor_bind::id.eq_any(&append_binds))
+                .load(&conn)?;
+
+            let reserved_bind_processors: Vec<BindProcessor> = if update_effect_row != 0 {
+                original_bind_processors
+                    .into_iter()
+                    .filter(|b| current_task_binds.contains(&b.bind_id))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            Ok((
+                removed_bind_processors,
+                append_bind_processors,
+                reserved_bind_processors,
+            ))
+        })
+    }
+
+    fn delete_task(&self, task_id: i64) -> Result<(), CommonError> {
+        let conn = self.conn()?;
+        diesel::delete(task::table.find(task_id)).execute(&conn)?;
+        // The holder of a routed task is kept with its bindings, it goes along with them.
+        diesel::delete(task_bind::table.filter(task_bind::task_id.eq(task_id))).execute(&conn)?;
+        diesel::delete(task_failover::table.filter(task_failover::task_id.eq(task_id)))
+            .execute(&conn)?;
+        diesel::delete(
+            task_dependency::table.filter(
+                task_dependency::task_id
+                    .eq(task_id)
+                    .or(task_dependency::upstream_task_id.eq(task_id)),
+            ),
+        )
+        .execute(&conn)?;
+        Ok(())
+    }
+
+    fn set_task_status(&self, task_id: i64, status: i16) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(diesel::update(task::table.find(task_id))
+            .set(task::status.eq(status))
+            .execute(&conn)?)
+    }
+
+    fn task_packages(
+        &self,
+        task_ids: Vec<i64>,
+    ) -> Result<Vec<(TaskPackage, i16, i16, i16)>, CommonError> {
+        let conn = self.conn()?;
+        let mut task_packages: Vec<(TaskPackage, i16, i16, i16)> = task::table
+            .filter(task::id.eq_any(&task_ids[..]))
+            .select((
+                model::task_package_columns(),
+                task::status,
+                task::routing_strategy,
+                task::trigger_mode,
+            ))
+            .load(&conn)?;
+        attach_calendars(&conn, task_packages.iter_mut().map(|t| &mut t.0))?;
+
+        Ok(task_packages)
+    }
+
+    fn tasks_bound_to_executors(
+        &self,
+        executor_processor_ids: Vec<i64>,
+    ) -> Result<Vec<(TaskPackage, i16, i64, i64, i64)>, CommonError> {
+        let conn = self.conn()?;
+        let mut task_packages: Vec<(TaskPackage, i16, i64, i64, i64)> = task_bind::table
+            .inner_join(executor_processor_bind::table)
+            .inner_join(task::table)
+            .filter(task::status.eq(State::Enabled as i16))
+            // The scheduler triggers the other tasks, the executors do not hold them.
+            .filter(task::trigger_mode.eq(TriggerMode::Executor as i16))
+            .filter(executor_processor_bind::executor_id.eq_any(&executor_processor_ids[..]))
+            .select((
+                model::task_package_columns(),
+                task::routing_strategy,
+                task_bind::bind_id,
+                executor_processor_bind::executor_id,
+                executor_processor_bind::group_id,
+            ))
+            .load(&conn)?;
+        attach_calendars(&conn, task_packages.iter_mut().map(|t| &mut t.0))?;
+
+        Ok(task_packages)
+    }
+
+    fn tasks_bound_to_binding(
+        &self,
+        executor_processor_bind_id: i64,
+    ) -> Result<Vec<(TaskPackage, i16, (String, String))>, CommonError> {
+        let conn = self.conn()?;
+        let mut task_packages: Vec<(TaskPackage, i16, (String, String))> = task_bind::table
+            .inner_join(executor_processor_bind::table.inner_join(executor_processor::table))
+            .inner_join(task::table)
+            .filter(task::status.eq(State::Enabled as i16))
+            // The scheduler triggers the other tasks, the executors do not hold them.
+            .filter(task::trigger_mode.eq(TriggerMode::Executor as i16))
+            .filter(executor_processor_bind::id.eq(executor_processor_bind_id))
+            .select((
+                model::task_package_columns(),
+                task::routing_strategy,
+                (executor_processor::host, executor_processor::token),
+            ))
+            .load(&conn)?;
+        attach_calendars(&conn, task_packages.iter_mut().map(|t| &mut t.0))?;
+
+        Ok(task_packages)
+    }
+
+    fn task_members(
+        &self,
+        task_ids: Vec<i64>,
+        enabled_only: bool,
+    ) -> Result<Vec<(i64, BindProcessor)>, CommonError> {
+        let conn = self.conn()?;
+        let mut query = task_bind::table
+            .inner_join(executor_processor_bind::table.inner_join(executor_processor::table))
+            .filter(task_bind::task_id.eq_any(task_ids))
+            .select((
+                task_bind::task_id,
+                (
+                    task_bind::bind_id,
+                    executor_processor_bind::executor_id,
+                    executor_processor_bind::weight,
+                    executor_processor::host,
+                    executor_processor::token,
+                ),
+            ))
+            .into_boxed();
+
+        if enabled_only {
+            query = query.filter(
+                executor_processor::status.eq(state::executor_processor::State::Enabled as i16),
+            );
+        }
+
+        Ok(query.load::<(i64, BindProcessor)>(&conn)?)
+    }
+
+    fn task_executors(&self, task_ids: Vec<i64>) -> Result<Vec<(i64, i64)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_bind::table
+            .inner_join(executor_processor_bind::table)
+            .filter(task_bind::task_id.eq_any(task_ids))
+            .select((task_bind::task_id, executor_processor_bind::executor_id))
+            .load::<(i64, i64)>(&conn)?)
+    }
+
+    fn central_task_schedules(&self) -> Result<Vec<(i64, String, String)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task::table
+            .filter(task::status.eq(State::Enabled as i16))
+            .filter(task::trigger_mode.eq(TriggerMode::Scheduler as i16))
+            .select((task::id, task::frequency, task::cron_expression))
+            .load::<(i64, String, String)>(&conn)?)
+    }
+
+    fn task_timeouts(&self, task_ids: Vec<i64>) -> Result<Vec<(i64, i16, i32)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task::table
+            .filter(task::id.eq_any(task_ids))
+            .select((task::id, task::timeout, task::wall_clock_limit))
+            .load::<(i64, i16, i32)>(&conn)?)
+    }
+
+    fn task_retry_times(&self, task_ids: Vec<i64>) -> Result<Vec<(i64, i16)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task::table
+            .filter(task::id.eq_any(task_ids))
+            .select((task::id, task::retry_times))
+            .load::<(i64, i16)>(&conn)?)
+    }
+
+    fn misfire_settings(&self, task_id: i64) -> Result<(i16, i16), CommonError> {
+        let conn = self.conn()?;
+        Ok(task::table
+            .find(task_id)
+            .select((task::misfire_policy, task::misfire_limit))
+            .first::<(i16, i16)>(&conn)?)
+    }
+
+    fn task_holder(&self, task_id: i64) -> Result<Option<i64>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_bind::table
+            .filter(task_bind::task_id.eq(task_id))
+            .filter(task_bind::holder.eq(state::task_bind::Holder::Yes as i16))
+            .select(task_bind::bind_id)
+            .first::<i64>(&conn)
+            .optional()?)
+    }
+
+    fn set_task_holder(&self, task_id: i64, bind_id: Option<i64>) -> Result<(), CommonError> {
+        use state::task_bind::Holder;
+
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            diesel::update(
+                task_bind::table
+                    .filter(task_bind::task_id.eq(task_id))
+                    .filter(task_bind::holder.eq(Holder::Yes as i16)),
+            )
+            .set(task_bind::holder.eq(Holder::No as i16))
+            .execute(&conn)?;
+
+            if let Some(bind_id) = bind_id {
+                diesel::update(
+                    task_bind::table
+                        .filter(task_bind::task_id.eq(task_id))
+                        .filter(task_bind::bind_id.eq(bind_id)),
+                )
+                .set(task_bind::holder.eq(Holder::Yes as i16))
+                .execute(&conn)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn failovers_of_tasks(&self, task_ids: Vec<i64>) -> Result<Vec<TaskFailover>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_failover::table
+            .filter(task_failover::task_id.eq_any(task_ids))
+            .load::<TaskFailover>(&conn)?)
+    }
+
+    fn failovers_of_executors(
+        &self,
+        executor_processor_ids: Vec<i64>,
+    ) -> Result<Vec<TaskFailover>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_failover::table
+            .filter(
+                task_failover::origin_executor_id
+                    .eq_any(&executor_processor_ids[..])
+                    .or(task_failover::target_executor_id.eq_any(&executor_processor_ids[..])),
+            )
+            .load::<TaskFailover>(&conn)?)
+    }
+
+    fn failovers_from_executors(
+        &self,
+        origin_executor_ids: Vec<i64>,
+    ) -> Result<Vec<(TaskFailover, BindProcessor)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_failover::table
+            .inner_join(executor_processor_bind::table.inner_join(executor_processor::table))
+            .filter(task_failover::origin_executor_id.eq_any(origin_executor_ids))
+            .select((
+                task_failover::all_columns,
+                (
+                    executor_processor_bind::id,
+                    executor_processor_bind::executor_id,
+                    executor_processor_bind::weight,
+                    executor_processor::host,
+                    executor_processor::token,
+                ),
+            ))
+            .load(&conn)?)
+    }
+
+    fn task_failover_processors(
+        &self,
+        task_id: i64,
+    ) -> Result<Vec<(TaskFailover, BindProcessor)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_failover::table
+            .inner_join(executor_processor_bind::table.inner_join(executor_processor::table))
+            .filter(task_failover::task_id.eq(task_id))
+            .select((
+                task_failover::all_columns,
+                (
+                    executor_processor_bind::id,
+                    executor_processor_bind::executor_id,
+                    executor_processor_bind::weight,
+                    executor_processor::host,
+                    executor_processor::token,
+                ),
+            ))
+            .load(&conn)?)
+    }
+
+    fn save_task_failovers(
+        &self,
+        failovers: Vec<(Option<i64>, NewTaskFailover)>,
+    ) -> Result<(), CommonError> {
+        let conn = self.conn()?;
+        for (failover_id, new_task_failover) in failovers.iter() {
+            match failover_id {
+                Some(failover_id) => diesel::update(task_failover::table.find(*failover_id))
+                    .set(new_task_failover)
+                    .execute(&conn)?,
+                None => diesel::insert_into(task_failover::table)
+                    .values(new_task_failover)
+                    .execute(&conn)?,
+            };
+        }
+        Ok(())
+    }
+
+    fn delete_task_failovers(&self, failover_ids: Vec<i64>) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(
+            diesel::delete(task_failover::table.filter(task_failover::id.eq_any(failover_ids)))
+                .execute(&conn)?,
+        )
+    }
+
+    fn downstream_dependencies(
+        &self,
+        upstream_task_ids: Vec<i64>,
+    ) -> Result<Vec<TaskDependency>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_dependency::table
+            .filter(task_dependency::upstream_task_id.eq_any(upstream_task_ids))
+            .load::<TaskDependency>(&conn)?)
+    }
+
+    fn insert_dependency_trigger(
+        &self,
+        trigger: NewTaskDependencyTrigger,
+    ) -> Result<bool, CommonError> {
+        let conn = self.conn()?;
+
+        // The unique key on (run-id, task-id) keeps a task from running twice in a run,
+        // Even when several scheduler instances receive the events.
+        match diesel::insert_into(task_dependency_trigger::table)
+            .values(&trigger)
+            .execute(&conn)
+        {
+            Err(DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => Ok(false),
+            Err(e) => Err(e.into()),
+            Ok(_) => Ok(true),
+        }
+    }
+
+    fn create_calendar(
+        &self,
+        calendar: NewCalendar,
+        excluded_windows: Vec<CalendarWindowItem>,
+    ) -> Result<i64, CommonError> {
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            let calendar_id = insert_returning_id!(&conn, calendar, &calendar)?;
+
+            insert_rows!(
+                &conn,
+                calendar_window,
+                &new_calendar_windows(calendar_id, excluded_windows)
+            )?;
+
+            Ok(calendar_id)
+        })
+    }
+
+    fn list_calendars(
+        &self,
+        query_params: QueryParamsCalendar,
+    ) -> Result<PaginateData<Calendar>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = CalendarQueryBuilder::query_all_columns();
+
+        let calendars = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<Calendar>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = CalendarQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        Ok(PaginateData::<Calendar>::default()
+            .set_data_source(calendars)
+            .set_page_size(per_page)
+            .set_total(count))
+    }
+
+    fn calendar_detail(&self, calendar_id: i64) -> Result<CalendarDetail, CommonError> {
+        let conn = self.conn()?;
+        let inner = calendar::table.find(calendar_id).first::<Calendar>(&conn)?;
+
+        let excluded_windows = calendar_window::table
+            .filter(calendar_window::calendar_id.eq(calendar_id))
+            .order(calendar_window::start_time.asc())
+            .load::<CalendarWindow>(&conn)?;
+
+        Ok(CalendarDetail {
+            inner,
+            excluded_windows,
+        })
+    }
+
+    fn update_calendar(
+        &self,
+        calendar: UpdateCalendar,
+        excluded_windows: Vec<CalendarWindowItem>,
+    ) -> Result<Vec<i64>, CommonError> {
+        let calendar_id = calendar.id;
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            diesel::update(&calendar).set(&calendar).execute(&conn)?;
+
+            diesel::delete(
+                calendar_window::table.filter(calendar_window::calendar_id.eq(calendar_id)),
+            )
+            .execute(&conn)?;
+            insert_rows!(
+                &conn,
+                calendar_window,
+                &new_calendar_windows(calendar_id, excluded_windows)
+            )?;
+
+            // The scheduler loads the calendar on each run of the tasks it triggers itself.
+            Ok(task::table
+                .filter(task::calendar_id.eq(calendar_id))
+                .filter(task::status.eq(State::Enabled as i16))
+                .filter(task::trigger_mode.eq(TriggerMode::Executor as i16))
+                .select(task::id)
+                .load::<i64>(&conn)?)
+        })
+    }
+
+    fn delete_calendar(&self, calendar_id: i64) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            // The tasks would silently start running in the excluded times.
+            let attached_task_count = task::table
+                .filter(task::calendar_id.eq(calendar_id))
+                .count()
+                .get_result::<i64>(&conn)?;
+            if attached_task_count != 0 {
+                return Err(CommonError::DisPass(format!(
+                    "Calendar {} is still attached to {} tasks.",
+                    calendar_id, attached_task_count
+                )));
+            }
+
+            diesel::delete(
+                calendar_window::table.filter(calendar_window::calendar_id.eq(calendar_id)),
+            )
+            .execute(&conn)?;
+            Ok(diesel::delete(calendar::table.find(calendar_id)).execute(&conn)?)
+        })
+    }
+}
+
+// Replace the upstream dependencies of a task,
+// Dependencies that would form a cycle or point to an unknown task are refused.
+fn save_task_dependencies(
+    conn: &PoolConnection,
+    task_id: i64,
+    dependencies: Vec<TaskDependencyItem>,
+) -> Result<(), CommonError> {
+    diesel::delete(task_dependency::table.filter(task_dependency::task_id.eq(task_id)))
+        .execute(conn)?;
+
+    // The last declaration wins when an upstream task is declared twice.
+    let dependencies: HashMap<i64, i16> = dependencies
+        .into_iter()
+        .map(|d| (d.upstream_task_id, d.trigger_condition))
+        .collect();
+
+    if dependencies.is_empty() {
+        return Ok(());
+    }
+
+    if dependencies
+        .values()
+        .any(|c| TriggerCondition::from(*c) == TriggerCondition::Unknown)
+    {
+        return Err(CommonError::DisPass(
+            "Ineffective trigger condition of the dependencies.".into(),
+        ));
+    }
+
+    let upstream_task_ids: Vec<i64> = dependencies.keys().copied().collect();
+    let known_task_count = task::table
+        .filter(task::id.eq_any(&upstream_task_ids[..]))
+        .count()
+        .get_result::<i64>(conn)?;
+    if known_task_count != upstream_task_ids.len() as i64 {
+        return Err(CommonError::DisPass(
+            "Some upstream tasks of the dependencies do not exist.".into(),
+        ));
+    }
+
+    let edges: Vec<(i64, i64)> = task_dependency::table
+        .select((task_dependency::upstream_task_id, task_dependency::task_id))
+        .load(conn)?;
+    if let Some(upstream_task_id) = find_cycle(&edges, task_id, &upstream_task_ids) {
+        return Err(CommonError::DisPass(format!(
+            "Task {} depending on task {} forms a cycle.",
+            task_id, upstream_task_id
+        )));
+    }
+
+    let new_dependencies: Vec<NewTaskDependency> = dependencies
+        .into_iter()
+        .map(|(upstream_task_id, trigger_condition)| NewTaskDependency {
+            task_id,
+            upstream_task_id,
+            trigger_condition,
+        })
+        .collect();
+
+    insert_rows!(conn, task_dependency, &new_dependencies[..])?;
+
+    Ok(())
+}
+
+// A task can only be attached to a calendar that exists, 0 detaches it.
+fn check_task_calendar(conn: &PoolConnection, calendar_id: i64) -> Result<(), CommonError> {
+    if calendar_id == 0 {
+        return Ok(());
+    }
+
+    let count = calendar::table
+        .find(calendar_id)
+        .count()
+        .get_result::<i64>(conn)?;
+    if count == 0 {
+        return Err(CommonError::DisPass(format!(
+            "Calendar {} does not exist.",
+            calendar_id
+        )));
+    }
+    Ok(())
+}
+
+// Fill in the calendars of the task packages, which only carry the calendar-id when loaded.
+fn attach_calendars<'a>(
+    conn: &PoolConnection,
+    task_packages: impl IntoIterator<Item = &'a mut TaskPackage>,
+) -> QueryResult<()> {
+    let task_packages: Vec<&'a mut TaskPackage> = task_packages
+        .into_iter()
+        .filter(|task_package| task_package.calendar.id != 0)
+        .collect();
+    if task_packages.is_empty() {
+        return Ok(());
+    }
+
+    let calendar_ids: Vec<i64> = task_packages.iter().map(|t| t.calendar.id).collect();
+    let mut calendars: HashMap<i64, TaskCalendar> = calendar::table
+        .filter(calendar::id.eq_any(&calendar_ids[..]))
+        .load::<Calendar>(conn)?
+        .into_iter()
+        .map(|calendar| {
+            let task_calendar = TaskCalendar {
+                id: calendar.id,
+                name: calendar.name,
+                time_zone: calendar.time_zone,
+                business_days_only: calendar.business_days_only != 0,
+                excluded_windows: Vec::new(),
+            };
+            (calendar.id, task_calendar)
+        })
+        .collect();
+
+    calendar_window::table
+        .filter(calendar_window::calendar_id.eq_any(&calendar_ids[..]))
+        .load::<CalendarWindow>(conn)?
+        .into_iter()
+        .for_each(|window| {
+            if let Some(task_calendar) = calendars.get_mut(&window.calendar_id) {
+                task_calendar.excluded_windows.push(ExcludedWindow {
+                    start_time: window.start_time,
+                    end_time: window.end_time,
+                    description: window.description,
+                });
+            }
+        });
+
+    // A calendar that no longer exists excludes nothing.
+    for task_package in task_packages {
+        task_package.calendar = calendars
+            .get(&task_package.calendar.id)
+            .cloned()
+            .unwrap_or_default();
+    }
+    Ok(())
+}
+
+fn new_calendar_windows(
+    calendar_id: i64,
+    excluded_windows: Vec<CalendarWindowItem>,
+) -> Vec<NewCalendarWindow> {
+    excluded_windows
+        .into_iter()
+        .map(|window| NewCalendarWindow {
+            calendar_id,
+            start_time: window.start_time,
+            end_time: window.end_time,
+            description: window.description,
+        })
+        .collect()
+}
@@ -0,0 +1,464 @@
+use super::task::TaskQueryBuilder;
+use super::*;
+use crate::scheduler::db::TaskLogRepository;
+use model::task::SupplyTask;
+use model::{
+    DeleteParamsTaskLog, FrontEndTaskLog, NewTaskLog, QueryParamsTaskLog, SupplyTaskLogExtend,
+    SupplyTaskLogTuple, TaskDependencyTrigger, TaskLog, TaskLogExtend, TaskState,
+};
+use schema::{task, task_dependency_trigger, task_log, task_log_extend};
+use state::task_log::State;
+
+struct TaskLogQueryBuilder;
+
+impl TaskLogQueryBuilder {
+    fn query_all_columns() -> task_log::BoxedQuery<'static, DbBackend> {
+        task_log::table.into_boxed().select(task_log::all_columns)
+    }
+
+    fn query_id_column() -> task_log::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint> {
+        task_log::table.into_boxed().select(task_log::id)
+    }
+
+    fn query_count() -> task_log::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint> {
+        task_log::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<task_log::BoxedQuery<'static, DbBackend, ST>> for QueryParamsTaskLog {
+    fn query_filter(
+        self,
+        mut statement_builder: task_log::BoxedQuery<'static, DbBackend, ST>,
+    ) -> task_log::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(task_id) = self.task_id {
+            statement_builder = statement_builder.filter(task_log::task_id.eq(task_id));
+        }
+
+        if let Some(id) = self.id {
+            statement_builder = statement_builder.filter(task_log::id.eq(id.0));
+        }
+
+        if let Some(status) = self.status {
+            statement_builder = statement_builder.filter(task_log::status.eq(status));
+        }
+
+        if let Some(run_id) = self.run_id {
+            statement_builder = statement_builder.filter(task_log::run_id.eq(run_id.0));
+        }
+
+        if let Some(task_name) = self.name {
+            statement_builder = statement_builder.filter(task_log::name.like(task_name));
+        }
+
+        if let Some(task_description) = self.description {
+            statement_builder =
+                statement_builder.filter(task_log::description.like(task_description));
+        }
+
+        if let Some(task_command) = self.command {
+            statement_builder = statement_builder.filter(task_log::command.like(task_command));
+        }
+
+        if let Some(task_tag) = self.tag {
+            statement_builder = statement_builder.filter(task_log::tag.like(task_tag));
+        }
+
+        if let Some(Ok(start_time)) = self
+            .start_time
+            .map(|s| NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S"))
+        {
+            let end_time = self
+                .end_time
+                .map(|s| {
+                    NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                        .unwrap_or_else(|_| start_time + ChronoDuration::days(3))
+                })
+                .unwrap_or_else(|| start_time + ChronoDuration::days(3));
+
+            statement_builder =
+                statement_builder.filter(task_log::created_time.between(start_time, end_time));
+        }
+
+        statement_builder.order(task_log::id.desc())
+    }
+}
+
+impl<ST> QueryFilter<task_log::BoxedQuery<'static, DbBackend, ST>> for DeleteParamsTaskLog {
+    fn query_filter(
+        self,
+        mut statement_builder: task_log::BoxedQuery<'static, DbBackend, ST>,
+    ) -> task_log::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(task_id) = self.task_id {
+            statement_builder = statement_builder.filter(task_log::task_id.eq(task_id));
+        }
+
+        if let Some(status) = self.status {
+            statement_builder = statement_builder.filter(task_log::status.eq(status));
+        }
+
+        if let Some(executor_processor_id) = self.executor_processor_id {
+            statement_builder =
+                statement_builder.filter(task_log::executor_processor_id.eq(executor_processor_id));
+        }
+
+        if let Some(Ok(start_time)) = self
+            .start_time
+            .map(|s| NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S"))
+        {
+            let end_time = self
+                .end_time
+                .map(|s| {
+                    NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                        .unwrap_or_else(|_| start_time + ChronoDuration::days(3))
+                })
+                .unwrap_or_else(|| start_time + ChronoDuration::days(3));
+
+            statement_builder =
+                statement_builder.filter(task_log::created_time.between(start_time, end_time));
+        }
+
+        let limit = self.limit.unwrap_or(524_288) as i64;
+        statement_builder.limit(limit)
+    }
+}
+
+impl TaskLogRepository for DieselStorage {
+    fn record_task_events(
+        &self,
+        new_task_logs: Vec<NewTaskLog>,
+        supply_task_logs: Vec<SupplyTaskLogTuple>,
+    ) -> Result<(usize, Vec<i64>), CommonError> {
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            let (new_task_logs, supply_task_logs) =
+                skip_recorded_events(&conn, new_task_logs, supply_task_logs)?;
+            let ended_record_ids: Vec<i64> = supply_task_logs.iter().map(|t| t.0.id).collect();
+
+            let mut effect_num = batch_insert_task_logs(&conn, new_task_logs)?;
+
+            effect_num += batch_update_task_logs(&conn, supply_task_logs)?;
+
+            Ok((effect_num, ended_record_ids))
+        })
+    }
+
+    fn list_task_logs(
+        &self,
+        query_params: QueryParamsTaskLog,
+    ) -> Result<PaginateData<FrontEndTaskLog>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = TaskLogQueryBuilder::query_all_columns();
+
+        let task_logs = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<TaskLog>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = TaskLogQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        let front_end_task_logs: Vec<FrontEndTaskLog> =
+            task_logs.into_iter().map(|t| t.into()).collect();
+        Ok(PaginateData::<FrontEndTaskLog>::default()
+            .set_data_source(front_end_task_logs)
+            .set_page_size(per_page)
+            .set_total(count)
+            .set_state_desc::<State>())
+    }
+
+    fn task_log_detail(&self, record_id: i64) -> Result<TaskLogExtend, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_log_extend::table
+            .find(record_id)
+            .first::<TaskLogExtend>(&conn)?)
+    }
+
+    fn run_records(
+        &self,
+        run_id: i64,
+    ) -> Result<(Vec<TaskLog>, Vec<TaskDependencyTrigger>), CommonError> {
+        let conn = self.conn()?;
+        let task_logs = TaskLogQueryBuilder::query_all_columns()
+            .filter(task_log::run_id.eq(run_id))
+            .order(task_log::id.asc())
+            .load::<TaskLog>(&conn)?;
+
+        let triggers = task_dependency_trigger::table
+            .filter(task_dependency_trigger::run_id.eq(run_id))
+            .order(task_dependency_trigger::id.asc())
+            .load::<TaskDependencyTrigger>(&conn)?;
+
+        Ok((task_logs, triggers))
+    }
+
+    fn cancel_running_record(&self, record_id: i64) -> Result<String, CommonError> {
+        let conn = self.conn()?;
+        let host = task_log::table
+            .find(record_id)
+            .filter(task_log::status.eq(State::Running as i16))
+            .select(task_log::executor_processor_host)
+            .first::<String>(&conn)?;
+
+        diesel::update(task_log::table.find(record_id))
+            .filter(task_log::status.eq(State::Running as i16))
+            .set(task_log::status.eq(State::TmanualCancellation as i16))
+            .execute(&conn)?;
+
+        Ok(host)
+    }
+
+    fn record_executor(&self, record_id: i64) -> Result<(String, i64), CommonError> {
+        let conn = self.conn()?;
+        Ok(task_log::table
+            .find(record_id)
+            .select((
+                task_log::executor_processor_host,
+                task_log::executor_processor_id,
+            ))
+            .first::<(String, i64)>(&conn)?)
+    }
+
+    fn delete_task_logs(&self, delete_params: DeleteParamsTaskLog) -> Result<(), CommonError> {
+        // Because `diesel` does not support join table deletion, so here is divided into two steps to delete logs.
+
+        // 1. query the primary key of task-log according to the given conditions, with a single maximum limit of 524288 items.
+
+        // 2. the primary key in batches of 2048 items and then start executing the deletion, task-log and task-log-extend.
+
+        let conn = self.conn()?;
+        let query_builder = TaskLogQueryBuilder::query_id_column();
+        let task_log_ids = delete_params
+            .query_filter(query_builder)
+            .load::<i64>(&conn)?;
+
+        let ids_chunk = task_log_ids.chunks(2048);
+        for ids in ids_chunk {
+            conn.transaction::<_, CommonError, _>(|| {
+                diesel::delete(task_log::table.filter(task_log::id.eq_any(ids))).execute(&conn)?;
+                diesel::delete(task_log_extend::table.filter(task_log_extend::id.eq_any(ids)))
+                    .execute(&conn)?;
+                Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn ended_task_logs(
+        &self,
+        record_ids: Vec<i64>,
+    ) -> Result<Vec<(i64, i64, i16, i16, i64)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_log::table
+            .filter(task_log::id.eq_any(record_ids))
+            // A run skipped by the calendar of the task neither succeeded nor failed.
+            .filter(task_log::status.eq_any(vec![
+                State::NormalEnding as i16,
+                State::AbnormalEnding as i16,
+                State::TimeoutEnding as i16,
+                State::ResourceExceeded as i16,
+                State::Lost as i16,
+            ]))
+            .select((
+                task_log::id,
+                task_log::task_id,
+                task_log::status,
+                task_log::attempt,
+                task_log::run_id,
+            ))
+            .load(&conn)?)
+    }
+
+    fn last_run_time(&self, task_id: i64) -> Result<Option<DateTime<Utc>>, CommonError> {
+        let conn = self.conn()?;
+        let last_run: Option<NaiveDateTime> = task_log::table
+            .filter(task_log::task_id.eq(task_id))
+            .select(diesel::dsl::max(task_log::created_time))
+            .first(&conn)?;
+
+        Ok(last_run.and_then(|time| self.record_time_zone.to_utc(time)))
+    }
+
+    fn running_records_before(
+        &self,
+        deadline: NaiveDateTime,
+    ) -> Result<Vec<(i64, i64, i64, NaiveDateTime)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(task_log::table
+            .filter(task_log::status.eq(State::Running as i16))
+            .filter(task_log::created_time.lt(deadline))
+            .select((
+                task_log::id,
+                task_log::task_id,
+                task_log::executor_processor_id,
+                task_log::created_time,
+            ))
+            .load(&conn)?)
+    }
+
+    fn mark_lost_records(&self, record_ids: Vec<i64>) -> Result<Vec<i64>, CommonError> {
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            let lost_record_ids: Vec<i64> = task_log::table
+                .filter(task_log::id.eq_any(&record_ids[..]))
+                .filter(task_log::status.eq(State::Running as i16))
+                .select(task_log::id)
+                .load::<i64>(&conn)?;
+
+            diesel::update(task_log::table.filter(task_log::id.eq_any(&lost_record_ids[..])))
+                .set(task_log::status.eq(State::Lost as i16))
+                .execute(&conn)?;
+
+            Ok(lost_record_ids)
+        })
+    }
+
+    fn hourly_task_states(
+        &self,
+        start_time: NaiveDateTime,
+        end_time: NaiveDateTime,
+    ) -> Result<(Vec<TaskState>, Vec<TaskState>), CommonError> {
+        let conn = self.conn()?;
+
+        // Fix-`Count` by: https://github.com/diesel-rs/diesel/issues/1781.
+        // The status is not grouped by, all of the created runs are counted as running.
+        let create_count: Vec<TaskState> = task_log::table
+            .select(&(
+                diesel::dsl::sql::<diesel::sql_types::SmallInt>(&format!(
+                    "{} as hour_num",
+                    hour_of("task_log.created_time")
+                )),
+                (State::Running as i16).into_sql::<diesel::sql_types::SmallInt>(),
+                diesel::dsl::sql::<diesel::sql_types::BigInt>("count(task_log.id) as total"),
+            ))
+            .filter(task_log::created_time.between(start_time, end_time))
+            .group_by(diesel::dsl::sql::<()>("hour_num"))
+            .load(&conn)?;
+
+        let end_states_count: Vec<TaskState> = task_log::table
+            .select(&(
+                diesel::dsl::sql::<diesel::sql_types::SmallInt>(&format!(
+                    "{} as hour_num",
+                    hour_of("task_log.updated_time")
+                )),
+                task_log::status,
+                diesel::dsl::sql::<diesel::sql_types::BigInt>("count(task_log.id) as total"),
+            ))
+            .filter(task_log::updated_time.between(start_time, end_time))
+            .filter(task_log::status.eq_any(&[
+                State::AbnormalEnding as i16,
+                State::NormalEnding as i16,
+                State::TimeoutEnding as i16,
+                State::TmanualCancellation as i16,
+                State::ResourceExceeded as i16,
+                State::Lost as i16,
+            ]))
+            .group_by((diesel::dsl::sql::<()>("hour_num"), task_log::status))
+            .load(&conn)?;
+
+        Ok((create_count, end_states_count))
+    }
+}
+
+// The executor delivers the events again when it is not sure they were accepted,
+// So a record is only started once and only ended once, by the first end event recorded.
+fn skip_recorded_events(
+    conn: &PoolConnection,
+    new_task_logs: Vec<NewTaskLog>,
+    supply_task_logs: Vec<SupplyTaskLogTuple>,
+) -> QueryResult<(Vec<NewTaskLog>, Vec<SupplyTaskLogTuple>)> {
+    let new_ids: Vec<i64> = new_task_logs.iter().map(|t| t.id).collect();
+    let mut started_ids: HashSet<i64> = task_log::table
+        .filter(task_log::id.eq_any(&new_ids[..]))
+        .select(task_log::id)
+        .load::<i64>(conn)?
+        .into_iter()
+        .collect();
+
+    let ended_ids: Vec<i64> = supply_task_logs.iter().map(|t| t.0.id).collect();
+    let mut ended_ids: HashSet<i64> = task_log_extend::table
+        .filter(task_log_extend::id.eq_any(&ended_ids[..]))
+        .select(task_log_extend::id)
+        .load::<i64>(conn)?
+        .into_iter()
+        .collect();
+
+    let new_task_logs = new_task_logs
+        .into_iter()
+        .filter(|t| started_ids.insert(t.id))
+        .collect();
+    let supply_task_logs = supply_task_logs
+        .into_iter()
+        .filter(|t| ended_ids.insert(t.0.id))
+        .collect();
+
+    Ok((new_task_logs, supply_task_logs))
+}
+
+fn batch_insert_task_logs(
+    conn: &PoolConnection,
+    mut new_task_logs: Vec<NewTaskLog>,
+) -> QueryResult<usize> {
+    if !new_task_logs.is_empty() {
+        let task_ids: Vec<i64> = new_task_logs.iter().map(|e| e.task_id).collect();
+
+        let tasks: HashMap<i64, SupplyTask> = TaskQueryBuilder::query_supply_task_log()
+            .filter(task::id.eq_any(&task_ids[..]))
+            .load::<SupplyTask>(conn)?
+            .into_iter()
+            .map(|t| (t.id, t))
+            .collect();
+
+        new_task_logs.iter_mut().for_each(|t| {
+            if t.run_id == 0 {
+                t.run_id = t.id;
+            }
+
+            if let Some(task) = tasks.get(&t.task_id) {
+                t.name.clone_from(&task.name);
+                t.description.clone_from(&task.description);
+                t.command.clone_from(&task.command);
+                t.frequency.clone_from(&task.frequency);
+                t.cron_expression.clone_from(&task.cron_expression);
+                t.tag.clone_from(&task.tag);
+                t.maximum_parallel_runnable_num
+                    .clone_from(&task.maximum_parallel_runnable_num);
+            }
+        });
+
+        return insert_rows!(conn, task_log, &new_task_logs[..]);
+    }
+
+    Ok(0)
+}
+
+fn batch_update_task_logs(
+    conn: &PoolConnection,
+    supply_task_logs: Vec<SupplyTaskLogTuple>,
+) -> QueryResult<usize> {
+    let mut effect_num = 0;
+
+    for supply_task_log in supply_task_logs.iter() {
+        effect_num += diesel::update(&supply_task_log.0)
+            .set(&supply_task_log.0)
+            .execute(conn)?;
+    }
+
+    let supply_task_logs_extend: Vec<SupplyTaskLogExtend> = supply_task_logs
+        .into_iter()
+        .map(|SupplyTaskLogTuple(_, t)| t)
+        .collect();
+
+    if supply_task_logs_extend.is_empty() {
+        return Ok(effect_num);
+    }
+
+    insert_rows!(conn, task_log_extend, &supply_task_logs_extend[..])?;
+
+    Ok(effect_num)
+}
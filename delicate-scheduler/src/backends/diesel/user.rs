@@ -0,0 +1,652 @@
+use super::*;
+use crate::scheduler::db::UserRepository;
+use model::casbin_rule::{CasbinRule, NewCasbinRule};
+use model::user::get_encrypted_certificate_by_raw_certificate;
+use model::{
+    FrontEndOperationLog, FrontEndUserLoginLog, NewUser, NewUserAuths, NewUserLoginLog,
+    OperationLog, OperationLogDetail, QueryNewUser, QueryParamsOperationLog, QueryParamsUser,
+    QueryParamsUserLoginLog, UpdateUser, User, UserAuth, UserAuthLogin, UserChangePassword,
+    UserLoginLog,
+};
+use schema::{casbin_rule, operation_log, operation_log_detail, user, user_auth, user_login_log};
+
+// Matches any value of the field when the value is empty.
+macro_rules! eq_empty {
+    ($v:expr,$field:expr) => {{
+        || {
+            use diesel::BoolExpressionMethods;
+
+            diesel::dsl::sql("")
+                .bind::<diesel::sql_types::Bool, _>($v.is_empty())
+                .or(diesel::dsl::sql("")
+                    .bind::<diesel::sql_types::Bool, _>(!$v.is_empty())
+                    .and($field.eq($v)))
+        }
+    }
+    ()};
+}
+
+struct UserQueryBuilder;
+
+impl UserQueryBuilder {
+    fn query_all_columns() -> user::BoxedQuery<'static, DbBackend> {
+        user::table.into_boxed().select(user::all_columns)
+    }
+
+    fn query_count() -> user::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint> {
+        user::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<user::BoxedQuery<'static, DbBackend, ST>> for QueryParamsUser {
+    fn query_filter(
+        self,
+        mut statement_builder: user::BoxedQuery<'static, DbBackend, ST>,
+    ) -> user::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(user_id) = self.id {
+            statement_builder = statement_builder.filter(user::id.eq(user_id));
+        }
+
+        if let Some(status) = self.status {
+            statement_builder = statement_builder.filter(user::status.eq(status));
+        } else {
+            statement_builder =
+                statement_builder.filter(user::status.ne(state::user::State::Forbidden as i16));
+        }
+
+        if let Some(user_name) = self.user_name {
+            statement_builder = statement_builder.filter(user::user_name.like(user_name));
+        }
+
+        if let Some(mobile) = self.mobile {
+            statement_builder = statement_builder.filter(user::mobile.eq(mobile));
+        }
+
+        if let Some(email) = self.email {
+            statement_builder = statement_builder.filter(user::email.eq(email));
+        }
+
+        statement_builder.order(user::id.desc())
+    }
+}
+
+struct OperationLogQueryBuilder;
+
+impl OperationLogQueryBuilder {
+    fn query_all_columns() -> operation_log::BoxedQuery<'static, DbBackend> {
+        operation_log::table
+            .into_boxed()
+            .select(operation_log::all_columns)
+    }
+
+    fn query_count() -> operation_log::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint> {
+        operation_log::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<operation_log::BoxedQuery<'static, DbBackend, ST>>
+    for QueryParamsOperationLog
+{
+    fn query_filter(
+        self,
+        mut statement_builder: operation_log::BoxedQuery<'static, DbBackend, ST>,
+    ) -> operation_log::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(operation_log_id) = self.id {
+            statement_builder = statement_builder.filter(operation_log::id.eq(operation_log_id));
+        }
+
+        if let Some(operation_log_name) = self.name {
+            statement_builder =
+                statement_builder.filter(operation_log::name.like(operation_log_name));
+        }
+
+        if let Some(table_id) = self.table_id {
+            statement_builder = statement_builder.filter(operation_log::table_id.eq(table_id));
+        }
+
+        if let Some(operation_type) = self.operation_type {
+            statement_builder =
+                statement_builder.filter(operation_log::operation_type.eq(operation_type));
+        }
+
+        if let Some(user_id) = self.user_id {
+            statement_builder = statement_builder.filter(operation_log::user_id.eq(user_id));
+        }
+
+        if let Some(user_name) = self.user_name {
+            statement_builder = statement_builder.filter(operation_log::user_name.like(user_name));
+        }
+
+        if let Some(Ok(start_time)) = self
+            .start_time
+            .map(|s| NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S"))
+        {
+            let end_time = self
+                .end_time
+                .map(|s| {
+                    NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                        .unwrap_or_else(|_| start_time + ChronoDuration::days(3))
+                })
+                .unwrap_or_else(|| start_time + ChronoDuration::days(3));
+
+            statement_builder = statement_builder
+                .filter(operation_log::operation_time.between(start_time, end_time));
+        }
+
+        statement_builder.order(operation_log::id.desc())
+    }
+}
+
+struct UserLoginLogQueryBuilder;
+
+impl UserLoginLogQueryBuilder {
+    fn query_all_columns() -> user_login_log::BoxedQuery<'static, DbBackend> {
+        user_login_log::table
+            .into_boxed()
+            .select(user_login_log::all_columns)
+    }
+
+    fn query_count() -> user_login_log::BoxedQuery<'static, DbBackend, diesel::sql_types::Bigint> {
+        user_login_log::table.into_boxed().count()
+    }
+}
+
+impl<ST> QueryFilter<user_login_log::BoxedQuery<'static, DbBackend, ST>>
+    for QueryParamsUserLoginLog
+{
+    fn query_filter(
+        self,
+        mut statement_builder: user_login_log::BoxedQuery<'static, DbBackend, ST>,
+    ) -> user_login_log::BoxedQuery<'static, DbBackend, ST> {
+        if let Some(id) = self.id {
+            statement_builder = statement_builder.filter(user_login_log::id.eq(id));
+        }
+
+        if let Some(user_id) = self.user_id {
+            statement_builder = statement_builder.filter(user_login_log::user_id.eq(user_id));
+        }
+
+        if let Some(login_type) = self.login_type {
+            statement_builder = statement_builder.filter(user_login_log::login_type.eq(login_type));
+        }
+
+        if let Some(command) = self.command {
+            statement_builder = statement_builder.filter(user_login_log::command.eq(command));
+        }
+
+        if let Some(lastip) = self.lastip {
+            statement_builder = statement_builder.filter(user_login_log::user_name.like(lastip));
+        }
+
+        if let Some(user_name) = self.user_name {
+            statement_builder = statement_builder.filter(user_login_log::user_name.like(user_name));
+        }
+
+        if let Some(Ok(start_time)) = self
+            .start_time
+            .map(|s| NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S"))
+        {
+            let end_time = self
+                .end_time
+                .map(|s| {
+                    NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                        .unwrap_or_else(|_| start_time + ChronoDuration::days(3))
+                })
+                .unwrap_or_else(|| start_time + ChronoDuration::days(3));
+
+            statement_builder = statement_builder
+                .filter(user_login_log::created_time.between(start_time, end_time));
+        }
+
+        statement_builder.order(user_login_log::id.desc())
+    }
+}
+
+impl UserRepository for DieselStorage {
+    fn create_user(&self, user: QueryNewUser) -> Result<(), CommonError> {
+        let conn = self.conn()?;
+        let new_user = Into::<NewUser>::into(&user);
+
+        conn.transaction::<_, CommonError, _>(|| {
+            let last_id = insert_returning_id!(&conn, user, &new_user)?;
+
+            let user_auths: NewUserAuths = From::<(QueryNewUser, i64)>::from((user, last_id));
+
+            insert_rows!(&conn, user_auth, &user_auths.0[..])?;
+
+            Ok(())
+        })
+    }
+
+    fn init_admin_account(&self, admin: QueryNewUser) -> Result<(), CommonError> {
+        let conn = self.conn()?;
+
+        let count: i64 = user::table
+            .filter(user::user_name.eq(&admin.user_name))
+            .count()
+            .get_result(&conn)?;
+
+        if count != 0 {
+            return Ok(());
+        }
+
+        conn.transaction::<_, CommonError, _>(|| {
+            let last_id = insert_returning_id!(&conn, user, &(Into::<NewUser>::into(&admin)))?;
+
+            let user_auths: NewUserAuths = From::<(QueryNewUser, i64)>::from((admin, last_id));
+
+            insert_rows!(&conn, user_auth, &user_auths.0[..])?;
+            Ok(())
+        })
+    }
+
+    fn list_users(&self, query_params: QueryParamsUser) -> Result<PaginateData<User>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = UserQueryBuilder::query_all_columns();
+
+        let users = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<User>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = UserQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        Ok(PaginateData::<User>::default()
+            .set_data_source(users)
+            .set_page_size(per_page)
+            .set_total(count))
+    }
+
+    fn update_user(&self, user_value: UpdateUser) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        Ok(diesel::update(&user_value)
+            .set(&user_value)
+            .execute(&conn)?)
+    }
+
+    fn change_password(
+        &self,
+        user_id: i64,
+        user_value: UserChangePassword,
+    ) -> Result<usize, CommonError> {
+        let conn = self.conn()?;
+        let user_auth_id = user_auth::table
+            .select(user_auth::id)
+            .filter(user_auth::user_id.eq(user_id))
+            .filter(user_auth::identity_type.eq(user_value.identity_type))
+            .filter(
+                user_auth::certificate.eq(get_encrypted_certificate_by_raw_certificate(
+                    &user_value.current_password,
+                )),
+            )
+            .first::<i64>(&conn)?;
+
+        Ok(diesel::update(user_auth::table.find(user_auth_id))
+            .set(
+                user_auth::certificate.eq(get_encrypted_certificate_by_raw_certificate(
+                    &user_value.modified_password,
+                )),
+            )
+            .execute(&conn)?)
+    }
+
+    fn delete_user(&self, user_id: i64) -> Result<(), CommonError> {
+        let conn = self.conn()?;
+        conn.transaction::<_, CommonError, _>(|| {
+            diesel::delete(user::table.filter(user::id.eq(user_id))).execute(&conn)?;
+            diesel::delete(user_auth::table.filter(user_auth::user_id.eq(user_id)))
+                .execute(&conn)?;
+
+            Ok(())
+        })
+    }
+
+    fn login_user(
+        &self,
+        UserAuthLogin {
+            login_type,
+            account,
+            password,
+        }: UserAuthLogin,
+        mut login_log: NewUserLoginLog,
+    ) -> Result<(UserAuth, User), CommonError> {
+        use state::user_login_log::LoginCommand;
+
+        let conn = self.conn()?;
+        let login_result = user_auth::table
+            .inner_join(user::table)
+            .select((user_auth::all_columns, user::all_columns))
+            .filter(user_auth::identity_type.eq(login_type))
+            .filter(user_auth::identifier.eq(&account))
+            .filter(
+                user_auth::certificate.eq(get_encrypted_certificate_by_raw_certificate(&password)),
+            )
+            .first::<(UserAuth, User)>(&conn);
+
+        match login_result.as_ref() {
+            Ok((_, user)) => login_log
+                .set_user_name(user.user_name.clone())
+                .set_user_id(user.id)
+                .set_command(LoginCommand::LoginSuccess as i16),
+            Err(_) => login_log
+                .set_user_name(account)
+                .set_command(LoginCommand::Logoutfailure as i16),
+        };
+
+        diesel::insert_into(user_login_log::table)
+            .values(&login_log)
+            .execute(&conn)
+            .ok();
+
+        Ok(login_result?)
+    }
+
+    fn find_user(&self, user_id: i64) -> Result<User, CommonError> {
+        let conn = self.conn()?;
+        Ok(user::table
+            .select(user::all_columns)
+            .find(user_id)
+            .first::<User>(&conn)?)
+    }
+
+    fn list_user_login_logs(
+        &self,
+        query_params: QueryParamsUserLoginLog,
+    ) -> Result<PaginateData<FrontEndUserLoginLog>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = UserLoginLogQueryBuilder::query_all_columns();
+
+        let user_login_log = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<UserLoginLog>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = UserLoginLogQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        let front_end_user_login_log: Vec<FrontEndUserLoginLog> =
+            user_login_log.into_iter().map(|log| log.into()).collect();
+
+        Ok(PaginateData::<FrontEndUserLoginLog>::default()
+            .set_data_source(front_end_user_login_log)
+            .set_page_size(per_page)
+            .set_total(count)
+            .set_state_desc::<state::user_login_log::LoginCommand>()
+            .set_state_desc::<state::user_login_log::LoginType>())
+    }
+
+    fn list_operation_logs(
+        &self,
+        query_params: QueryParamsOperationLog,
+    ) -> Result<PaginateData<FrontEndOperationLog>, CommonError> {
+        let conn = self.conn()?;
+        let query_builder = OperationLogQueryBuilder::query_all_columns();
+
+        let operation_log = query_params
+            .clone()
+            .query_filter(query_builder)
+            .paginate(query_params.page)
+            .set_per_page(query_params.per_page)
+            .load::<OperationLog>(&conn)?;
+
+        let per_page = query_params.per_page;
+        let count_builder = OperationLogQueryBuilder::query_count();
+        let count = query_params
+            .query_filter(count_builder)
+            .get_result::<i64>(&conn)?;
+
+        let front_end_operation_log: Vec<FrontEndOperationLog> =
+            operation_log.into_iter().map(|log| log.into()).collect();
+        Ok(PaginateData::<FrontEndOperationLog>::default()
+            .set_data_source(front_end_operation_log)
+            .set_page_size(per_page)
+            .set_total(count)
+            .set_state_desc::<state::operation_log::OperationType>())
+    }
+
+    fn operation_log_detail(
+        &self,
+        operation_log_id: i64,
+    ) -> Result<Vec<OperationLogDetail>, CommonError> {
+        let conn = self.conn()?;
+        Ok(operation_log_detail::table
+            .filter(operation_log_detail::operation_log_id.eq(operation_log_id))
+            .load::<OperationLogDetail>(&conn)?)
+    }
+
+    fn insert_operation_logs(&self, logs: Vec<NewOperationLogPair>) -> Result<(), CommonError> {
+        let conn = self.conn()?;
+        let (operation_logs, mut operation_log_details): (Vec<_>, Vec<_>) =
+            logs.into_iter().unzip();
+
+        // On mysql this needs `innodb_autoinc_lock_mode` to be 0 or 1, see `insert_returning_ids`:
+        // https://stackoverflow.com/questions/27225804/mysql-batch-insert-on-multiple-tables-with-last-insert-id
+        let operation_log_ids = insert_returning_ids!(&conn, operation_log, &operation_logs)?;
+
+        operation_log_details
+            .iter_mut()
+            .zip(operation_log_ids.into_iter())
+            .for_each(|(detail, operation_log_id)| detail.operation_log_id = operation_log_id);
+
+        insert_rows!(&conn, operation_log_detail, &operation_log_details)?;
+        Ok(())
+    }
+
+    fn permission_list(&self) -> Result<Vec<(String, String)>, CommonError> {
+        let conn = self.conn()?;
+        Ok(casbin_rule::table
+            .select((casbin_rule::v1, casbin_rule::v2))
+            .filter(casbin_rule::ptype.eq("p"))
+            .filter(casbin_rule::v0.eq_any(&[
+                "task_admin",
+                "processor_admin",
+                "group_admin",
+                "user_admin",
+                "log_admin",
+            ]))
+            .load::<(String, String)>(&conn)?)
+    }
+
+    fn load_casbin_rules(&self) -> AuthServiceResult<Vec<CasbinRule>> {
+        let conn = self.pool.get()?;
+
+        Ok(casbin_rule::table.load::<CasbinRule>(&conn)?)
+    }
+
+    fn clear_casbin_rules(&self) -> AuthServiceResult<()> {
+        let conn = self.pool.get()?;
+
+        Ok(diesel::delete(casbin_rule::table)
+            .execute(&conn)
+            .map(|_| ())?)
+    }
+
+    fn save_casbin_rules(&self, rules: Vec<NewCasbinRule>) -> AuthServiceResult<()> {
+        let conn = self.pool.get()?;
+
+        Ok(conn.transaction::<_, DieselError, _>(|| {
+            diesel::delete(casbin_rule::table).execute(&conn)?;
+
+            Ok(diesel::insert_into(casbin_rule::table)
+                .values(&rules)
+                .execute(&*conn)
+                .and_then(|n| {
+                    if n == rules.len() {
+                        Ok(())
+                    } else {
+                        Err(DieselError::RollbackTransaction)
+                    }
+                }))
+        })??)
+    }
+
+    fn add_casbin_rule(&self, new_rule: NewCasbinRule) -> AuthServiceResult<bool> {
+        let conn = self.pool.get()?;
+
+        Ok(diesel::insert_into(casbin_rule::table)
+            .values(&new_rule)
+            .execute(&conn)
+            .map(|n| n == 1)?)
+    }
+
+    fn add_casbin_rules(&self, new_rules: Vec<NewCasbinRule>) -> AuthServiceResult<bool> {
+        let conn = self.pool.get()?;
+
+        Ok(conn.transaction::<_, DieselError, _>(|| {
+            diesel::insert_into(casbin_rule::table)
+                .values(&new_rules)
+                .execute(&*conn)
+                .and_then(|n| {
+                    if n == new_rules.len() {
+                        Ok(true)
+                    } else {
+                        Err(DieselError::RollbackTransaction)
+                    }
+                })
+                .map_err(|_| DieselError::RollbackTransaction)
+        })?)
+    }
+
+    fn remove_casbin_rule(&self, pt: &str, rule: Vec<String>) -> AuthServiceResult<bool> {
+        use schema::casbin_rule::dsl::*;
+
+        let conn = self.pool.get()?;
+        let rule = normalize_casbin_rule(rule, 0);
+
+        let filter = ptype
+            .eq(pt)
+            .and(v0.eq(&rule[0]))
+            .and(v1.eq(&rule[1]))
+            .and(v2.eq(&rule[2]))
+            .and(v3.eq(&rule[3]))
+            .and(v4.eq(&rule[4]))
+            .and(v5.eq(&rule[5]));
+        Ok(diesel::delete(casbin_rule.filter(filter))
+            .execute(&conn)
+            .map(|n| n == 1)?)
+    }
+
+    fn remove_casbin_rules(&self, pt: &str, rules: Vec<Vec<String>>) -> AuthServiceResult<bool> {
+        use schema::casbin_rule::dsl::*;
+
+        let conn = self.pool.get()?;
+
+        Ok(conn.transaction::<_, DieselError, _>(|| {
+            for rule in rules {
+                let rule = normalize_casbin_rule(rule, 0);
+
+                let filter = ptype
+                    .eq(pt)
+                    .and(v0.eq(&rule[0]))
+                    .and(v1.eq(&rule[1]))
+                    .and(v2.eq(&rule[2]))
+                    .and(v3.eq(&rule[3]))
+                    .and(v4.eq(&rule[4]))
+                    .and(v5.eq(&rule[5]));
+
+                match diesel::delete(casbin_rule.filter(filter)).execute(&conn) {
+                    Ok(n) if n == 1 => continue,
+                    _ => return Err(DieselError::RollbackTransaction),
+                }
+            }
+
+            Ok(true)
+        })?)
+    }
+
+    fn remove_filtered_casbin_rules(
+        &self,
+        pt: &str,
+        field_index: usize,
+        field_values: Vec<String>,
+    ) -> AuthServiceResult<bool> {
+        use schema::casbin_rule::dsl::*;
+
+        let conn = self.pool.get()?;
+
+        let field_values = normalize_casbin_rule(field_values, field_index);
+
+        let boxed_query = if field_index == 5 {
+            diesel::delete(casbin_rule.filter(ptype.eq(pt).and(eq_empty!(&field_values[0], v5))))
+                .into_boxed()
+        } else if field_index == 4 {
+            diesel::delete(
+                casbin_rule.filter(
+                    ptype
+                        .eq(pt)
+                        .and(eq_empty!(&field_values[0], v4))
+                        .and(eq_empty!(&field_values[1], v5)),
+                ),
+            )
+            .into_boxed()
+        } else if field_index == 3 {
+            diesel::delete(
+                casbin_rule.filter(
+                    ptype
+                        .eq(pt)
+                        .and(eq_empty!(&field_values[0], v3))
+                        .and(eq_empty!(&field_values[1], v4))
+                        .and(eq_empty!(&field_values[2], v5)),
+                ),
+            )
+            .into_boxed()
+        } else if field_index == 2 {
+            diesel::delete(
+                casbin_rule.filter(
+                    ptype
+                        .eq(pt)
+                        .and(eq_empty!(&field_values[0], v2))
+                        .and(eq_empty!(&field_values[1], v3))
+                        .and(eq_empty!(&field_values[2], v4))
+                        .and(eq_empty!(&field_values[3], v5)),
+                ),
+            )
+            .into_boxed()
+        } else if field_index == 1 {
+            diesel::delete(
+                casbin_rule.filter(
+                    ptype
+                        .eq(pt)
+                        .and(eq_empty!(&field_values[0], v1))
+                        .and(eq_empty!(&field_values[1], v2))
+                        .and(eq_empty!(&field_values[2], v3))
+                        .and(eq_empty!(&field_values[3], v4))
+                        .and(eq_empty!(&field_values[4], v5)),
+                ),
+            )
+            .into_boxed()
+        } else {
+            diesel::delete(
+                casbin_rule.filter(
+                    ptype
+                        .eq(pt)
+                        .and(eq_empty!(&field_values[0], v0))
+                        .and(eq_empty!(&field_values[1], v1))
+                        .and(eq_empty!(&field_values[2], v2))
+                        .and(eq_empty!(&field_values[3], v3))
+                        .and(eq_empty!(&field_values[4], v4))
+                        .and(eq_empty!(&field_values[5], v5)),
+                ),
+            )
+            .into_boxed()
+        };
+
+        Ok(boxed_query.execute(&conn).map(|n| n >= 1)?)
+    }
+}
+
+fn normalize_casbin_rule(mut rule: Vec<String>, field_index: usize) -> Vec<String> {
+    rule.resize(6 - field_index, String::from(""));
+    rule
+}
@@ -0,0 +1,43 @@
+//! The database backends, each one compiled in through the cargo feature of the same name.
+//!
+//! A backend module holds its connections and migrations,
+//! And the storage the `scheduler` runs on.
+
+use crate::config::SchedulerConfig;
+use crate::scheduler::db::Storage;
+use std::sync::Arc;
+
+#[cfg(feature = "mysql")]
+pub(crate) mod mysql;
+
+#[cfg(feature = "postgres")]
+pub(crate) mod postgres;
+
+#[cfg(feature = "sqlite")]
+pub(crate) mod sqlite;
+
+pub(crate) const COMPILED_BACKENDS: &[&str] = &[
+    #[cfg(feature = "mysql")]
+    "mysql",
+    #[cfg(feature = "postgres")]
+    "postgres",
+    #[cfg(feature = "sqlite")]
+    "sqlite",
+];
+
+/// The storage on the backend in `DATABASE`, which has to be one of those compiled in.
+pub(crate) fn open_storage(conf: &SchedulerConfig) -> anyhow::Result<Arc<dyn Storage>> {
+    match conf.database.to_lowercase().as_str() {
+        #[cfg(feature = "mysql")]
+        "mysql" => Ok(mysql::open_storage(conf)),
+        #[cfg(feature = "postgres")]
+        "postgres" => Ok(postgres::open_storage(conf)),
+        #[cfg(feature = "sqlite")]
+        "sqlite" => Ok(sqlite::open_storage(conf)),
+        database => Err(anyhow::anyhow!(
+            "`database` ({}) is not one of the backends compiled in {:?}.",
+            database,
+            COMPILED_BACKENDS
+        )),
+    }
+}
@@ -0,0 +1,110 @@
+use diesel::mysql::{Mysql, MysqlConnection};
+use diesel::r2d2::{Builder, ConnectionManager, Pool, PooledConnection};
+
+use crate::scheduler::db::Storage;
+use crate::scheduler::prelude::*;
+
+// Insert the row into the table (a module of `db::schema` in scope) and get its id, through `LAST_INSERT_ID()`.
+// The insertion macros are defined ahead of `storage`, so that they are in scope there.
+macro_rules! insert_returning_id {
+    ($conn:expr, $table:ident, $values:expr) => {
+        diesel::insert_into($table::table)
+            .values($values)
+            .execute($conn)
+            .and_then(|_| {
+                diesel::select($crate::backends::mysql::last_insert_id).get_result::<u64>($conn)
+            })
+            .map(|id| id as i64)
+    };
+}
+
+// Insert the rows into the table and get their ids, in the order of the rows.
+//
+// `LAST_INSERT_ID()` is the id of the first row, the others follow it
+// As long as `innodb_autoinc_lock_mode` is not 2 ("interleaved" lock mode):
+// https://dev.mysql.com/doc/refman/8.0/en/innodb-auto-increment-handling.html
+macro_rules! insert_returning_ids {
+    ($conn:expr, $table:ident, $values:expr) => {
+        diesel::insert_into($table::table)
+            .values($values)
+            .execute($conn)
+            .and_then(|count| {
+                diesel::select($crate::backends::mysql::last_insert_id)
+                    .get_result::<u64>($conn)
+                    .map(|first_id| (first_id as i64..).take(count).collect::<Vec<i64>>())
+            })
+    };
+}
+
+// Insert the rows into the table in one statement, and get the number of rows inserted.
+macro_rules! insert_rows {
+    ($conn:expr, $table:ident, $values:expr) => {
+        diesel::insert_into($table::table)
+            .values($values)
+            .execute($conn)
+    };
+}
+
+#[path = "../diesel/mod.rs"]
+mod storage;
+
+no_arg_sql_function!(last_insert_id, sql_types::Unsigned<sql_types::Bigint>);
+
+embed_migrations!("./migrations/mysql");
+
+pub(crate) type DbBackend = Mysql;
+pub(crate) type DbConnection = MysqlConnection;
+pub(crate) type ConnectionPool = Pool<ConnectionManager<MysqlConnection>>;
+pub(crate) type PoolConnection = PooledConnection<ConnectionManager<MysqlConnection>>;
+
+/// The storage of the scheduler on this backend.
+pub(crate) fn open_storage(conf: &SchedulerConfig) -> Arc<dyn Storage> {
+    Arc::new(storage::DieselStorage::new(
+        get_connection_pool(conf),
+        conf.record_time_zone(),
+    ))
+}
+
+fn get_connection_pool(conf: &SchedulerConfig) -> Pool<ConnectionManager<MysqlConnection>> {
+    let database_url = conf.database_url.clone();
+    let manager: ConnectionManager<MysqlConnection> = ConnectionManager::new(database_url);
+
+    // Supports user configuration, through the configuration file or .env.
+    let max_size = conf.connection_pool_max_size.unwrap_or(64);
+    let min_idle = conf.connection_pool_min_idle.unwrap_or(32);
+
+    Builder::new()
+        .max_size(max_size)
+        .min_idle(Some(min_idle))
+        .connection_customizer(Box::new(Customizer {
+            time_zone: conf.customize_time_zone.clone(),
+        }))
+        .build(manager)
+        .expect("Connection pool initialization failed")
+}
+
+/// The sql of the hour (0-23) of a time column, in the session time zone.
+pub(crate) fn hour_of(column: &str) -> String {
+    format!("Hour({})", column)
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct Customizer {
+    time_zone: Option<String>,
+}
+
+// diesel The default is UTC time, here we need to configure it to customized time.
+impl CustomizeConnection<MysqlConnection, diesel::r2d2::Error> for Customizer {
+    fn on_acquire(&self, conn: &mut MysqlConnection) -> Result<(), diesel::r2d2::Error> {
+        if let Some(time_zone) = self.time_zone.as_ref() {
+            let mut query = String::from("SET time_zone = ");
+            query.push_str(time_zone);
+            query.push(';');
+
+            conn.execute(&query)
+                .map_err(diesel::r2d2::Error::QueryError)?;
+        }
+
+        Ok(())
+    }
+}
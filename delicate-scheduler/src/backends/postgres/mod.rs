@@ -0,0 +1,97 @@
+use diesel::pg::{Pg, PgConnection};
+use diesel::r2d2::{Builder, ConnectionManager, Pool, PooledConnection};
+
+use crate::scheduler::db::Storage;
+use crate::scheduler::prelude::*;
+
+// Insert the row into the table (a module of `db::schema` in scope) and get its id, through `RETURNING`.
+// The insertion macros are defined ahead of `storage`, so that they are in scope there.
+macro_rules! insert_returning_id {
+    ($conn:expr, $table:ident, $values:expr) => {
+        diesel::insert_into($table::table)
+            .values($values)
+            .returning($table::id)
+            .get_result::<i64>($conn)
+    };
+}
+
+// Insert the rows into the table and get their ids, in the order of the rows.
+macro_rules! insert_returning_ids {
+    ($conn:expr, $table:ident, $values:expr) => {
+        diesel::insert_into($table::table)
+            .values($values)
+            .returning($table::id)
+            .get_results::<i64>($conn)
+    };
+}
+
+// Insert the rows into the table in one statement, and get the number of rows inserted.
+macro_rules! insert_rows {
+    ($conn:expr, $table:ident, $values:expr) => {
+        diesel::insert_into($table::table)
+            .values($values)
+            .execute($conn)
+    };
+}
+
+#[path = "../diesel/mod.rs"]
+mod storage;
+
+embed_migrations!("./migrations/postgres");
+
+pub(crate) type DbBackend = Pg;
+pub(crate) type DbConnection = PgConnection;
+pub(crate) type ConnectionPool = Pool<ConnectionManager<PgConnection>>;
+pub(crate) type PoolConnection = PooledConnection<ConnectionManager<PgConnection>>;
+
+/// The storage of the scheduler on this backend.
+pub(crate) fn open_storage(conf: &SchedulerConfig) -> Arc<dyn Storage> {
+    Arc::new(storage::DieselStorage::new(
+        get_connection_pool(conf),
+        conf.record_time_zone(),
+    ))
+}
+
+fn get_connection_pool(conf: &SchedulerConfig) -> Pool<ConnectionManager<PgConnection>> {
+    let database_url = conf.database_url.clone();
+    let manager: ConnectionManager<PgConnection> = ConnectionManager::new(database_url);
+
+    // Supports user configuration, through the configuration file or .env.
+    let max_size = conf.connection_pool_max_size.unwrap_or(64);
+    let min_idle = conf.connection_pool_min_idle.unwrap_or(32);
+
+    Builder::new()
+        .max_size(max_size)
+        .min_idle(Some(min_idle))
+        .connection_customizer(Box::new(Customizer {
+            time_zone: conf.customize_time_zone.clone(),
+        }))
+        .build(manager)
+        .expect("Connection pool initialization failed")
+}
+
+/// The sql of the hour (0-23) of a time column, in the session time zone.
+pub(crate) fn hour_of(column: &str) -> String {
+    format!("CAST(EXTRACT(HOUR FROM {}) AS smallint)", column)
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct Customizer {
+    time_zone: Option<String>,
+}
+
+// The `timestamp` columns hold the times of the session time zone, as they do on mysql.
+impl CustomizeConnection<PgConnection, diesel::r2d2::Error> for Customizer {
+    fn on_acquire(&self, conn: &mut PgConnection) -> Result<(), diesel::r2d2::Error> {
+        if let Some(time_zone) = self.time_zone.as_ref() {
+            let mut query = String::from("SET TIME ZONE ");
+            query.push_str(time_zone);
+            query.push(';');
+
+            conn.execute(&query)
+                .map_err(diesel::r2d2::Error::QueryError)?;
+        }
+
+        Ok(())
+    }
+}
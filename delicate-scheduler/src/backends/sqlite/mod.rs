@@ -0,0 +1,103 @@
+use diesel::r2d2::{Builder, ConnectionManager, Pool, PooledConnection};
+use diesel::sqlite::{Sqlite, SqliteConnection};
+
+use crate::scheduler::db::Storage;
+use crate::scheduler::prelude::*;
+
+// Insert the row into the table (a module of `db::schema` in scope) and get its id, through `last_insert_rowid()`.
+// The insertion macros are defined ahead of `storage`, so that they are in scope there.
+macro_rules! insert_returning_id {
+    ($conn:expr, $table:ident, $values:expr) => {
+        diesel::insert_into($table::table)
+            .values($values)
+            .execute($conn)
+            .and_then(|_| {
+                diesel::select($crate::backends::sqlite::last_insert_rowid).get_result::<i64>($conn)
+            })
+    };
+}
+
+// Insert the rows into the table and get their ids, in the order of the rows.
+//
+// Diesel takes no multi-row `VALUES` on sqlite, so the rows are inserted one by one in a transaction.
+macro_rules! insert_returning_ids {
+    ($conn:expr, $table:ident, $values:expr) => {
+        $conn.transaction::<_, diesel::result::Error, _>(|| {
+            $values
+                .iter()
+                .map(|row| insert_returning_id!($conn, $table, row))
+                .collect::<Result<Vec<i64>, _>>()
+        })
+    };
+}
+
+// Insert the rows into the table one by one in a transaction, and get the number of rows inserted.
+macro_rules! insert_rows {
+    ($conn:expr, $table:ident, $values:expr) => {
+        $conn.transaction::<_, diesel::result::Error, _>(|| {
+            $values.iter().try_fold(0, |count, row| {
+                diesel::insert_into($table::table)
+                    .values(row)
+                    .execute($conn)
+                    .map(|inserted| count + inserted)
+            })
+        })
+    };
+}
+
+#[path = "../diesel/mod.rs"]
+mod storage;
+
+no_arg_sql_function!(last_insert_rowid, sql_types::BigInt);
+
+embed_migrations!("./migrations/sqlite");
+
+pub(crate) type DbBackend = Sqlite;
+pub(crate) type DbConnection = SqliteConnection;
+pub(crate) type ConnectionPool = Pool<ConnectionManager<SqliteConnection>>;
+pub(crate) type PoolConnection = PooledConnection<ConnectionManager<SqliteConnection>>;
+
+/// The storage of the scheduler on this backend.
+pub(crate) fn open_storage(conf: &SchedulerConfig) -> Arc<dyn Storage> {
+    // The times are recorded with `datetime('now', 'localtime')`.
+    Arc::new(storage::DieselStorage::new(
+        get_connection_pool(conf),
+        RecordTimeZone::Local,
+    ))
+}
+
+fn get_connection_pool(conf: &SchedulerConfig) -> Pool<ConnectionManager<SqliteConnection>> {
+    let database_url = conf.database_url.clone();
+    let manager: ConnectionManager<SqliteConnection> = ConnectionManager::new(database_url);
+
+    // Supports user configuration, through the configuration file or .env.
+    // The writes to a sqlite file are serialized anyway, so the defaults are small.
+    let max_size = conf.connection_pool_max_size.unwrap_or(8);
+    let min_idle = conf.connection_pool_min_idle.unwrap_or(1);
+
+    Builder::new()
+        .max_size(max_size)
+        .min_idle(Some(min_idle))
+        .connection_customizer(Box::new(Customizer))
+        .build(manager)
+        .expect("Connection pool initialization failed")
+}
+
+/// The sql of the hour (0-23) of a time column.
+pub(crate) fn hour_of(column: &str) -> String {
+    format!("CAST(strftime('%H', {}) AS INTEGER)", column)
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+struct Customizer;
+
+// The connections of the pool share one file, a writer waits for the others rather than failing at once.
+// The times are kept in the local time of the scheduler, `DELICATE_CUSTOMIZE_TIME_ZONE` does not apply.
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for Customizer {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        conn.execute("PRAGMA busy_timeout = 5000; PRAGMA journal_mode = WAL;")
+            .map_err(diesel::r2d2::Error::QueryError)?;
+
+        Ok(())
+    }
+}
@@ -1,6 +0,0 @@
-#[macro_use]
-pub mod macros;
-pub mod actions;
-pub mod adapter_core;
-
-pub use adapter_core::DieselAdapter;
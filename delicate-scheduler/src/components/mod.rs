@@ -1,9 +0,0 @@
-pub(crate) use super::prelude;
-
-pub(crate) mod auth;
-pub(crate) mod base;
-pub(crate) mod health_checker;
-pub(crate) mod helper;
-pub(crate) mod logger_id;
-pub(crate) mod operation_log_consumer;
-pub(crate) mod session;
@@ -0,0 +1,623 @@
+//! The configuration of the scheduler, shared by the backends.
+
+use crate::backends::COMPILED_BACKENDS;
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use delicate_utils::conf::{ConfigProblems, ValidateConfig};
+use delicate_utils::consensus_message::security::SecurityLevel;
+use delicate_utils::prelude::redis;
+use delicate_utils::prelude::tracing::Level;
+use serde::Deserialize;
+use std::convert::TryFrom;
+use std::net::ToSocketAddrs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The environment variable naming the configuration file of the scheduler, it is optional.
+pub(crate) const SCHEDULER_CONFIG_FILE: &str = "SCHEDULER_CONFIG_FILE";
+
+/// The configuration of the scheduler, the keys are the names of the environment variables
+/// Without the `DELICATE_` prefix in lower case.
+///
+/// The fields of `ReloadableConf` are applied again on `SIGHUP`, the others need a restart.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub(crate) struct SchedulerConfig {
+    pub(crate) database: String,
+    pub(crate) database_url: String,
+    /// Schedulers sharing a redis elect a leader through it, it is required.
+    pub(crate) redis_url: String,
+    pub(crate) log_level: String,
+    pub(crate) session_token: String,
+    pub(crate) scheduler_domain: String,
+    /// The origins allowed by CORS, separated by commas.
+    pub(crate) scheduler_front_end_domain: String,
+    pub(crate) scheduler_cookie_domain: String,
+    pub(crate) scheduler_name: String,
+    pub(crate) scheduler_listening_address: String,
+    pub(crate) security_level: u16,
+    pub(crate) security_private_key: Option<PathBuf>,
+    /// The default of the pool depends on the backend.
+    pub(crate) connection_pool_max_size: Option<u32>,
+    pub(crate) connection_pool_min_idle: Option<u32>,
+    pub(crate) initial_administrator_user_name: Option<String>,
+    pub(crate) initial_administrator_password: Option<String>,
+    pub(crate) initial_administrator_nick_name: String,
+    pub(crate) initial_administrator_mobile: String,
+    pub(crate) initial_administrator_email: String,
+    /// The session time zone of mysql and postgres, such as `'+08:00'` or `'Asia/Shanghai'`.
+    /// The times they record are in it, the times sqlite records are in the local time zone.
+    pub(crate) customize_time_zone: Option<String>,
+    /// Authentication-Model, currently optional value `casbin`.
+    pub(crate) auth_model: Option<String>,
+    pub(crate) casbin_model_conf: Option<PathBuf>,
+    /// Debug switch (For Developers), the session and the permissions are not checked.
+    pub(crate) app_debug: bool,
+    /// Seconds between the rounds of the health check.
+    pub(crate) health_check_interval: u64,
+    /// Seconds an executor has to answer a probe, it is shorter than the interval.
+    /// Half the interval by default, at most 10 seconds.
+    pub(crate) health_check_timeout: Option<u64>,
+    /// Consecutive missed probes that make an enabled processor abnormal.
+    pub(crate) health_check_failure_threshold: u32,
+    /// Consecutive healthy probes that enable an abnormal processor again.
+    pub(crate) health_check_success_threshold: u32,
+    /// Days the health history of the processors is kept, 0 keeps it forever.
+    pub(crate) health_check_history_days: u32,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        SchedulerConfig {
+            database: String::new(),
+            database_url: String::new(),
+            redis_url: String::new(),
+            log_level: String::from("info"),
+            session_token: String::new(),
+            scheduler_domain: String::new(),
+            scheduler_front_end_domain: String::new(),
+            scheduler_cookie_domain: String::new(),
+            scheduler_name: String::new(),
+            scheduler_listening_address: String::new(),
+            security_level: 0,
+            security_private_key: None,
+            connection_pool_max_size: None,
+            connection_pool_min_idle: None,
+            initial_administrator_user_name: None,
+            initial_administrator_password: None,
+            initial_administrator_nick_name: String::from("admin"),
+            initial_administrator_mobile: String::from("12345054321"),
+            initial_administrator_email: String::from("admin@admin.com"),
+            customize_time_zone: None,
+            auth_model: None,
+            casbin_model_conf: None,
+            app_debug: false,
+            health_check_interval: 20,
+            health_check_timeout: None,
+            health_check_failure_threshold: 3,
+            health_check_success_threshold: 2,
+            health_check_history_days: 7,
+        }
+    }
+}
+
+/// The time zone the times recorded by the database are in, they have no time zone of their own.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RecordTimeZone {
+    Local,
+    Fixed(FixedOffset),
+    Named(Tz),
+}
+
+impl RecordTimeZone {
+    /// The instant of a recorded time, `None` when it does not exist in the time zone.
+    pub(crate) fn to_utc(&self, time: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            RecordTimeZone::Local => Local
+                .from_local_datetime(&time)
+                .earliest()
+                .map(|t| t.with_timezone(&Utc)),
+            RecordTimeZone::Fixed(offset) => offset
+                .from_local_datetime(&time)
+                .earliest()
+                .map(|t| t.with_timezone(&Utc)),
+            RecordTimeZone::Named(time_zone) => time_zone
+                .from_local_datetime(&time)
+                .earliest()
+                .map(|t| t.with_timezone(&Utc)),
+        }
+    }
+
+    /// The recorded time of an instant, as the database would record it.
+    pub(crate) fn record_time_of(&self, time: DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            RecordTimeZone::Local => time.with_timezone(&Local).naive_local(),
+            RecordTimeZone::Fixed(offset) => time.with_timezone(offset).naive_local(),
+            RecordTimeZone::Named(time_zone) => time.with_timezone(time_zone).naive_local(),
+        }
+    }
+}
+
+impl FromStr for RecordTimeZone {
+    type Err = String;
+
+    // The value is the sql literal of the session, so it may be quoted.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().trim_matches(|c| c == '\'' || c == '"');
+
+        if ["SYSTEM", "LOCAL", "DEFAULT"]
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(value))
+        {
+            return Ok(RecordTimeZone::Local);
+        }
+
+        if let Some(offset) = parse_utc_offset(value) {
+            return Ok(RecordTimeZone::Fixed(offset));
+        }
+
+        Tz::from_str(value).map(RecordTimeZone::Named)
+    }
+}
+
+// An offset such as `+08:00` or `-05:30`.
+fn parse_utc_offset(value: &str) -> Option<FixedOffset> {
+    let sign = match value.get(..1)? {
+        "+" => 1,
+        "-" => -1,
+        _ => return None,
+    };
+    let (hours, minutes) = value.get(1..)?.split_once(':')?;
+    let seconds = hours.parse::<i32>().ok()? * 3600 + minutes.parse::<i32>().ok()? * 60;
+
+    FixedOffset::east_opt(sign * seconds)
+}
+
+/// The part of the configuration applied again on `SIGHUP`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReloadableConf {
+    pub(crate) log_level: Level,
+    pub(crate) health_check: HealthCheckConf,
+    pub(crate) cors_origins: Vec<String>,
+}
+
+/// How the processors are probed and when their status changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HealthCheckConf {
+    pub(crate) interval: Duration,
+    pub(crate) timeout: Duration,
+    pub(crate) failure_threshold: u32,
+    pub(crate) success_threshold: u32,
+    /// `None` keeps the history forever.
+    pub(crate) history_retention: Option<Duration>,
+}
+
+impl SchedulerConfig {
+    pub(crate) fn reloadable(&self) -> ReloadableConf {
+        ReloadableConf {
+            log_level: Level::from_str(&self.log_level).expect("The log level is validated."),
+            health_check: HealthCheckConf {
+                interval: Duration::from_secs(self.health_check_interval),
+                timeout: Duration::from_secs(self.health_check_timeout()),
+                failure_threshold: self.health_check_failure_threshold,
+                success_threshold: self.health_check_success_threshold,
+                history_retention: Some(self.health_check_history_days)
+                    .filter(|days| *days > 0)
+                    .map(|days| Duration::from_secs(u64::from(days) * 24 * 60 * 60)),
+            },
+            cors_origins: self.cors_origins(),
+        }
+    }
+
+    // The timeout follows the interval unless it is set.
+    fn health_check_timeout(&self) -> u64 {
+        self.health_check_timeout
+            .unwrap_or_else(|| (self.health_check_interval / 2).min(10))
+    }
+
+    pub(crate) fn security_level(&self) -> SecurityLevel {
+        SecurityLevel::try_from(self.security_level).expect("The security level is validated.")
+    }
+
+    /// The time zone of the times recorded by mysql and postgres.
+    #[cfg(any(feature = "mysql", feature = "postgres"))]
+    pub(crate) fn record_time_zone(&self) -> RecordTimeZone {
+        self.customize_time_zone
+            .as_deref()
+            .map(|time_zone| {
+                RecordTimeZone::from_str(time_zone).expect("The time zone is validated.")
+            })
+            .unwrap_or(RecordTimeZone::Local)
+    }
+
+    pub(crate) fn casbin_enabled(&self) -> bool {
+        matches!(self.auth_model.as_deref(), Some("casbin") | Some("CASBIN"))
+    }
+
+    fn cors_origins(&self) -> Vec<String> {
+        self.scheduler_front_end_domain
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(String::from)
+            .collect()
+    }
+}
+
+impl ValidateConfig for SchedulerConfig {
+    fn legacy_keys() -> &'static [&'static str] {
+        &[
+            "database",
+            "database_url",
+            "redis_url",
+            "log_level",
+            "session_token",
+            "scheduler_domain",
+            "scheduler_front_end_domain",
+            "scheduler_cookie_domain",
+            "scheduler_name",
+            "scheduler_listening_address",
+            "connection_pool_max_size",
+            "connection_pool_min_idle",
+            "initial_administrator_user_name",
+            "initial_administrator_password",
+            "initial_administrator_nick_name",
+            "initial_administrator_mobile",
+            "initial_administrator_email",
+            "customize_time_zone",
+            "auth_model",
+            "casbin_model_conf",
+            "app_debug",
+        ]
+    }
+
+    fn validate(&self, problems: &mut ConfigProblems) {
+        problems.require("database", &self.database);
+        if !self.database.trim().is_empty() {
+            problems.check(
+                COMPILED_BACKENDS.contains(&self.database.to_lowercase().as_str()),
+                format!(
+                    "`database` ({}) is not one of the backends compiled in {:?}.",
+                    self.database, COMPILED_BACKENDS
+                ),
+            );
+        }
+        problems.require("database_url", &self.database_url);
+        problems.require("scheduler_domain", &self.scheduler_domain);
+        problems.require(
+            "scheduler_front_end_domain",
+            &self.scheduler_front_end_domain,
+        );
+        problems.require("scheduler_cookie_domain", &self.scheduler_cookie_domain);
+        problems.require("scheduler_name", &self.scheduler_name);
+
+        problems.require(
+            "scheduler_listening_address",
+            &self.scheduler_listening_address,
+        );
+        if !self.scheduler_listening_address.trim().is_empty() {
+            problems.check(
+                self.scheduler_listening_address.to_socket_addrs().is_ok(),
+                format!(
+                    "`scheduler_listening_address` ({}) is not an address to listen on.",
+                    self.scheduler_listening_address
+                ),
+            );
+        }
+
+        if let Some(time_zone) = self.customize_time_zone.as_ref() {
+            problems.check(
+                RecordTimeZone::from_str(time_zone).is_ok(),
+                format!(
+                    "`customize_time_zone` ({}) is neither a utc offset nor a time zone name.",
+                    time_zone
+                ),
+            );
+        }
+
+        // Without it every scheduler would regard itself as the leader, so it is not optional.
+        problems.check(!self.redis_url.is_empty(), "`redis_url` is required.");
+        if !self.redis_url.is_empty() {
+            problems.check(
+                redis::Client::open(self.redis_url.as_str()).is_ok(),
+                format!("`redis_url` ({}) is not a redis url.", self.redis_url),
+            );
+        }
+
+        // The cookie key is derived from it, which panics on less than 32 bytes.
+        problems.check(
+            self.session_token.len() >= 32,
+            "`session_token` must be at least 32 bytes long.",
+        );
+        problems.check(
+            Level::from_str(&self.log_level).is_ok(),
+            format!(
+                "`log_level` ({}) is none of `error`, `warn`, `info`, `debug` and `trace`.",
+                self.log_level
+            ),
+        );
+        problems.check(
+            self.health_check_interval > 0,
+            "`health_check_interval` must be greater than 0.",
+        );
+        // The values in effect are checked, the default timeout included.
+        let health_check_timeout = self.health_check_timeout();
+        problems.check(
+            health_check_timeout > 0 && health_check_timeout < self.health_check_interval,
+            format!(
+                "`health_check_timeout` ({}) must be greater than 0 and less than `health_check_interval` ({}).",
+                health_check_timeout, self.health_check_interval
+            ),
+        );
+        problems.check(
+            self.health_check_failure_threshold > 0,
+            "`health_check_failure_threshold` must be greater than 0.",
+        );
+        problems.check(
+            self.health_check_success_threshold > 0,
+            "`health_check_success_threshold` must be greater than 0.",
+        );
+
+        if let Some(max_size) = self.connection_pool_max_size {
+            problems.check(
+                max_size > 0,
+                "`connection_pool_max_size` must be greater than 0.",
+            );
+            problems.check(
+                self.connection_pool_min_idle.unwrap_or(0) <= max_size,
+                "`connection_pool_min_idle` must not exceed `connection_pool_max_size`.",
+            );
+        }
+
+        match SecurityLevel::try_from(self.security_level) {
+            Ok(SecurityLevel::Normal) => match self.security_private_key.as_ref() {
+                Some(key) => problems.check(
+                    key.is_file(),
+                    format!("`security_private_key` ({}) is not a file.", key.display()),
+                ),
+                None => problems.check(
+                    false,
+                    "`security_private_key` is required when `security_level` is 1.",
+                ),
+            },
+            Ok(SecurityLevel::ZeroRestriction) => {}
+            Err(_) => problems.check(
+                false,
+                format!(
+                    "`security_level` ({}) is neither 0 nor 1.",
+                    self.security_level
+                ),
+            ),
+        }
+
+        match self.auth_model.as_deref() {
+            None | Some("casbin") | Some("CASBIN") => {}
+            Some(auth_model) => problems.check(
+                false,
+                format!("`auth_model` ({}) is not `casbin`.", auth_model),
+            ),
+        }
+        if self.casbin_enabled() {
+            match self.casbin_model_conf.as_ref() {
+                Some(model_conf) => problems.check(
+                    model_conf.is_file(),
+                    format!(
+                        "`casbin_model_conf` ({}) is not a file.",
+                        model_conf.display()
+                    ),
+                ),
+                None => problems.check(
+                    false,
+                    "`casbin_model_conf` is required when `auth_model` is `casbin`.",
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A configuration with every required key.
+    fn valid_conf() -> SchedulerConfig {
+        SchedulerConfig {
+            database: COMPILED_BACKENDS[0].to_string(),
+            database_url: String::from("delicate.db"),
+            redis_url: String::from("redis://127.0.0.1:6379"),
+            session_token: "s".repeat(32),
+            scheduler_domain: String::from("scheduler.delicate.local"),
+            scheduler_front_end_domain: String::from("delicate.local"),
+            scheduler_cookie_domain: String::from("delicate.local"),
+            scheduler_name: String::from("scheduler"),
+            scheduler_listening_address: String::from("127.0.0.1:8090"),
+            ..SchedulerConfig::default()
+        }
+    }
+
+    fn problems(conf: &SchedulerConfig) -> Vec<String> {
+        let mut problems = ConfigProblems::default();
+        conf.validate(&mut problems);
+        problems.problems().to_vec()
+    }
+
+    type ConfChange = Box<dyn FnOnce(&mut SchedulerConfig)>;
+
+    // The problems of the configuration changed by `change`.
+    fn problems_of(change: impl FnOnce(&mut SchedulerConfig)) -> Vec<String> {
+        let mut conf = valid_conf();
+        change(&mut conf);
+        problems(&conf)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let conf = SchedulerConfig::default();
+        let reloadable_conf = conf.reloadable();
+
+        assert_eq!(reloadable_conf.log_level, Level::INFO);
+        assert_eq!(
+            reloadable_conf.health_check,
+            HealthCheckConf {
+                interval: Duration::from_secs(20),
+                timeout: Duration::from_secs(10),
+                failure_threshold: 3,
+                success_threshold: 2,
+                history_retention: Some(Duration::from_secs(7 * 24 * 60 * 60)),
+            }
+        );
+        assert!(reloadable_conf.cors_origins.is_empty());
+        assert!(matches!(
+            conf.security_level(),
+            SecurityLevel::ZeroRestriction
+        ));
+        assert!(!conf.casbin_enabled());
+    }
+
+    #[test]
+    fn test_defaults_need_the_required_keys() {
+        let problems = problems(&SchedulerConfig::default());
+
+        for key in [
+            "database",
+            "database_url",
+            "scheduler_domain",
+            "scheduler_front_end_domain",
+            "scheduler_cookie_domain",
+            "scheduler_name",
+            "scheduler_listening_address",
+            "redis_url",
+        ] {
+            assert!(
+                problems.contains(&format!("`{}` is required.", key)),
+                "{}",
+                key
+            );
+        }
+        assert!(problems.contains(&String::from(
+            "`session_token` must be at least 32 bytes long."
+        )));
+    }
+
+    #[test]
+    fn test_valid_conf() {
+        assert!(problems(&valid_conf()).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_conf() {
+        let invalid_changes: Vec<(&str, ConfChange)> = vec![
+            (
+                "database",
+                Box::new(|c| c.database = String::from("oracle")),
+            ),
+            (
+                "scheduler_listening_address",
+                Box::new(|c| c.scheduler_listening_address = String::from("localhost")),
+            ),
+            (
+                "customize_time_zone",
+                Box::new(|c| c.customize_time_zone = Some(String::from("Mars/Olympus_Mons"))),
+            ),
+            (
+                "redis_url",
+                Box::new(|c| c.redis_url = String::from("127.0.0.1:6379")),
+            ),
+            (
+                "session_token",
+                Box::new(|c| c.session_token = "s".repeat(31)),
+            ),
+            (
+                "log_level",
+                Box::new(|c| c.log_level = String::from("verbose")),
+            ),
+            (
+                "health_check_interval",
+                Box::new(|c| c.health_check_interval = 0),
+            ),
+            (
+                "health_check_timeout",
+                Box::new(|c| c.health_check_timeout = Some(20)),
+            ),
+            (
+                "health_check_timeout",
+                Box::new(|c| c.health_check_timeout = Some(0)),
+            ),
+            (
+                "health_check_failure_threshold",
+                Box::new(|c| c.health_check_failure_threshold = 0),
+            ),
+            (
+                "health_check_success_threshold",
+                Box::new(|c| c.health_check_success_threshold = 0),
+            ),
+            (
+                "connection_pool_max_size",
+                Box::new(|c| c.connection_pool_max_size = Some(0)),
+            ),
+            (
+                "connection_pool_min_idle",
+                Box::new(|c| {
+                    c.connection_pool_max_size = Some(4);
+                    c.connection_pool_min_idle = Some(8);
+                }),
+            ),
+            ("security_level", Box::new(|c| c.security_level = 2)),
+            ("security_private_key", Box::new(|c| c.security_level = 1)),
+            (
+                "security_private_key",
+                Box::new(|c| {
+                    c.security_level = 1;
+                    c.security_private_key = Some(PathBuf::from("/nonexistent/private.pem"));
+                }),
+            ),
+            (
+                "auth_model",
+                Box::new(|c| c.auth_model = Some(String::from("acl"))),
+            ),
+            (
+                "casbin_model_conf",
+                Box::new(|c| c.auth_model = Some(String::from("casbin"))),
+            ),
+        ];
+
+        for (key, change) in invalid_changes {
+            let problems = problems_of(change);
+            assert!(!problems.is_empty(), "{}", key);
+            assert!(
+                problems[0].contains(&format!("`{}`", key)),
+                "{}: {:?}",
+                key,
+                problems
+            );
+        }
+    }
+
+    #[test]
+    fn test_derived_health_check_timeout() {
+        let timeout_of = |interval: u64| {
+            let mut conf = valid_conf();
+            conf.health_check_interval = interval;
+            conf.health_check_timeout()
+        };
+
+        // Half the interval, at most 10 seconds.
+        assert_eq!(timeout_of(6), 3);
+        assert_eq!(timeout_of(7), 3);
+        assert_eq!(timeout_of(20), 10);
+        assert_eq!(timeout_of(60), 10);
+
+        // An interval of 1 second leaves no time to answer.
+        let problems = problems_of(|c| c.health_check_interval = 1);
+        assert!(problems[0].contains("`health_check_timeout` (0)"));
+
+        // A timeout that is set is kept.
+        let problems = problems_of(|c| {
+            c.health_check_interval = 60;
+            c.health_check_timeout = Some(30);
+        });
+        assert!(problems.is_empty());
+    }
+}
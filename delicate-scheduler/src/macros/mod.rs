@@ -1,2 +0,0 @@
-#[macro_use]
-pub(crate) mod feature_cfg;
@@ -0,0 +1,170 @@
+use super::prelude::*;
+use super::task::pre_update_task_sevice;
+
+pub(crate) fn route_config() -> Route {
+    Route::new()
+        .at("/api/calendar/list", post(show_calendars))
+        .at("/api/calendar/detail", post(show_calendar_detail))
+        .at("/api/calendar/create", post(create_calendar))
+        .at("/api/calendar/update", post(update_calendar))
+        .at("/api/calendar/delete", post(delete_calendar))
+}
+
+#[handler]
+async fn create_calendar(
+    req: &Request,
+    Json(calendar_body): Json<model::NewCalendarBody>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let response: UnifiedResponseMessages<i64> = pre_create_calendar(req, calendar_body, &storage)
+        .await
+        .into();
+    Json(response)
+}
+
+async fn pre_create_calendar(
+    req: &Request,
+    model::NewCalendarBody {
+        calendar,
+        excluded_windows,
+    }: model::NewCalendarBody,
+    storage: &Arc<dyn db::Storage>,
+) -> Result<i64, CommonError> {
+    model::check_calendar(calendar.time_zone, &excluded_windows)?;
+
+    let operation_log_pair_option =
+        generate_operation_calendar_addtion_log(req.get_session(), &calendar).ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    db::blocking(storage, move |storage| {
+        storage.create_calendar(calendar, excluded_windows)
+    })
+    .await
+}
+
+#[handler]
+async fn show_calendars(
+    Json(query_params): Json<model::QueryParamsCalendar>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let page: UnifiedResponseMessages<PaginateData<model::Calendar>> =
+        db::blocking(&storage, move |storage| {
+            storage.list_calendars(query_params)
+        })
+        .await
+        .into();
+    Json(page)
+}
+
+#[handler]
+async fn show_calendar_detail(
+    Json(model::CalendarId { calendar_id }): Json<model::CalendarId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let calendar_detail_result = db::blocking(&storage, move |storage| {
+        storage.calendar_detail(calendar_id)
+    })
+    .await;
+    if let Ok(calendar_detail) = calendar_detail_result {
+        return Json(
+            UnifiedResponseMessages::<model::CalendarDetail>::success_with_data(calendar_detail),
+        )
+        .into_response();
+    };
+
+    Json(
+        UnifiedResponseMessages::<()>::error()
+            .customized_error_msg(calendar_detail_result.expect_err("").to_string()),
+    )
+    .into_response()
+}
+
+#[handler]
+async fn update_calendar(
+    req: &Request,
+    Json(calendar_body): Json<model::UpdateCalendarBody>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let response: UnifiedResponseMessages<()> = pre_update_calendar(req, calendar_body, &storage)
+        .instrument(span!(Level::INFO, "update-calendar"))
+        .await
+        .into();
+    Json(response)
+}
+
+async fn pre_update_calendar(
+    req: &Request,
+    model::UpdateCalendarBody {
+        calendar,
+        excluded_windows,
+    }: model::UpdateCalendarBody,
+    storage: &Arc<dyn db::Storage>,
+) -> Result<(), CommonError> {
+    model::check_calendar(calendar.time_zone, &excluded_windows)?;
+
+    let operation_log_pair_option =
+        generate_operation_calendar_modify_log(req.get_session(), &calendar).ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let task_ids: Vec<i64> = db::blocking(storage, move |storage| {
+        storage.update_calendar(calendar, excluded_windows)
+    })
+    .await?;
+
+    // The executors holding the tasks get them again along with the new calendar.
+    for task_id in task_ids {
+        let bind_processors = load_bind_processors(storage, task_id).await?;
+        pre_update_task_sevice(
+            req,
+            storage,
+            task_id,
+            (Vec::new(), Vec::new(), bind_processors),
+        )
+        .await
+        .map_err(|e| error!("Failed to refresh the calendar of task {}: {}", task_id, e))
+        .ok();
+    }
+
+    Ok(())
+}
+
+#[handler]
+async fn delete_calendar(
+    req: &Request,
+    Json(model::CalendarId { calendar_id }): Json<model::CalendarId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let response: UnifiedResponseMessages<usize> =
+        pre_delete_calendar(req, calendar_id, &storage).await.into();
+    Json(response)
+}
+
+async fn pre_delete_calendar(
+    req: &Request,
+    calendar_id: i64,
+    storage: &Arc<dyn db::Storage>,
+) -> Result<usize, CommonError> {
+    let operation_log_pair_option = generate_operation_calendar_delete_log(
+        req.get_session(),
+        &CommonTableRecord::default().set_id(calendar_id),
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    db::blocking(storage, move |storage| storage.delete_calendar(calendar_id)).await
+}
+
+async fn load_bind_processors(
+    storage: &Arc<dyn db::Storage>,
+    task_id: i64,
+) -> Result<Vec<model::BindProcessor>, CommonError> {
+    let bind_processors = db::blocking(storage, move |storage| {
+        storage.task_members(vec![task_id], false)
+    })
+    .await?
+    .into_iter()
+    .map(|(_, member)| member)
+    .collect();
+
+    Ok(bind_processors)
+}
@@ -0,0 +1,16 @@
+// Front-end components api.
+
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new().at("/api/binding/list", get(binding_list))
+}
+
+#[handler]
+async fn binding_list(storage: Data<&Arc<dyn db::Storage>>) -> impl IntoResponse {
+    let response: UnifiedResponseMessages<Vec<model::BindingSelection>> =
+        db::blocking(&storage, |storage| storage.binding_selections())
+            .await
+            .into();
+    Json(response)
+}
@@ -0,0 +1,16 @@
+// Front-end components api.
+
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new().at("/api/executor/list", get(executor_list))
+}
+
+#[handler]
+async fn executor_list(storage: Data<&Arc<dyn db::Storage>>) -> impl IntoResponse {
+    let response: UnifiedResponseMessages<Vec<model::ExecutorSelection>> =
+        db::blocking(&storage, |storage| storage.executor_selections())
+            .await
+            .into();
+    Json(response)
+}
@@ -1,3 +1,5 @@
+pub(crate) use super::prelude;
+
 pub(crate) mod binding;
 pub(crate) mod executor;
 pub(crate) mod permission;
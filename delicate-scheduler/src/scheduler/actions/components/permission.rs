@@ -0,0 +1,16 @@
+// Front-end components api.
+
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new().at("/api/permission/list", get(permission_list))
+}
+
+#[handler]
+async fn permission_list(storage: Data<&Arc<dyn db::Storage>>) -> impl IntoResponse {
+    let response: UnifiedResponseMessages<Vec<(String, String)>> =
+        db::blocking(&storage, |storage| storage.permission_list())
+            .await
+            .into();
+    Json(response)
+}
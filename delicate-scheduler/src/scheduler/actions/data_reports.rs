@@ -5,12 +5,9 @@ pub(crate) fn route_config() -> Route {
 }
 
 #[handler]
-async fn show_one_day_tasks_state(pool: Data<&Arc<db::ConnectionPool>>) -> impl IntoResponse {
-    use db::schema::task_log;
-    use state::task_log::State;
-
-    if let Ok(conn) = pool.get() {
-        let daily_state_result = spawn_blocking::<_, Result<_, diesel::result::Error>>(move || {
+async fn show_one_day_tasks_state(storage: Data<&Arc<dyn db::Storage>>) -> impl IntoResponse {
+    let daily_state: UnifiedResponseMessages<model::DailyState> =
+        db::blocking(&storage, move |storage| {
             let datetime: DateTime<Local> = SystemTime::now().into();
             let now = datetime.naive_local();
             let raw_past_day = now - ChronoDuration::days(1);
@@ -31,41 +28,7 @@ async fn show_one_day_tasks_state(pool: Data<&Arc<db::ConnectionPool>>) -> impl
             // the number of tasks that timed out at a certain time
             // Number of manually cancelled tasks at a certain time
             // Update to add a limit so that only tasks with Running status can be modified.
-
-            // Fix-`Count` by: https://github.com/diesel-rs/diesel/issues/1781.
-            let mut create_count: Vec<model::TaskState> = task_log::table
-                .select(&(
-                    diesel::dsl::sql::<diesel::sql_types::SmallInt>(
-                        "Hour(task_log.created_time) as hour_num",
-                    ),
-                    task_log::status,
-                    diesel::dsl::sql::<diesel::sql_types::BigInt>("count(task_log.id) as total"),
-                ))
-                .filter(task_log::created_time.between(past_day, now))
-                .group_by(diesel::dsl::sql::<()>("hour_num"))
-                .load(&conn)?;
-
-            create_count
-                .iter_mut()
-                .for_each(|s| s.status = State::Running as i16);
-
-            let end_states_count: Vec<model::TaskState> = task_log::table
-                .select(&(
-                    diesel::dsl::sql::<diesel::sql_types::SmallInt>(
-                        "Hour(task_log.updated_time) as hour_num",
-                    ),
-                    task_log::status,
-                    diesel::dsl::sql::<diesel::sql_types::BigInt>("count(task_log.id) as total"),
-                ))
-                .filter(task_log::updated_time.between(past_day, now))
-                .filter(task_log::status.eq_any(&[
-                    State::AbnormalEnding as i16,
-                    State::NormalEnding as i16,
-                    State::TimeoutEnding as i16,
-                    State::TmanualCancellation as i16,
-                ]))
-                .group_by((diesel::dsl::sql::<()>("hour_num"), task_log::status))
-                .load(&conn)?;
+            let (create_count, end_states_count) = storage.hourly_task_states(past_day, now)?;
 
             Ok(pre_show_one_day_tasks_state(
                 hours_range,
@@ -73,19 +36,9 @@ async fn show_one_day_tasks_state(pool: Data<&Arc<db::ConnectionPool>>) -> impl
                 end_states_count,
             ))
         })
-        .await;
-        let daily_state = daily_state_result
-            .map(|daily_state_result| {
-                Into::<UnifiedResponseMessages<model::DailyState>>::into(daily_state_result)
-            })
-            .unwrap_or_else(|e| {
-                UnifiedResponseMessages::<model::DailyState>::error()
-                    .customized_error_msg(e.to_string())
-            });
-        return Json(daily_state);
-    }
-
-    Json(UnifiedResponseMessages::<model::DailyState>::error())
+        .await
+        .into();
+    Json(daily_state)
 }
 
 pub(crate) fn pre_show_one_day_tasks_state(
@@ -115,11 +68,15 @@ pub(crate) fn pre_show_one_day_tasks_state(
                 finished_map.insert(t.hour_num as u32, t.total);
             }
             State::AbnormalEnding => {
-                abnormal_map.insert(t.hour_num as u32, t.total);
+                *abnormal_map.entry(t.hour_num as u32).or_default() += t.total;
             }
             State::TmanualCancellation => {
                 canceled_map.insert(t.hour_num as u32, t.total);
             }
+            // Runs stopped by a resource limit or lost are counted as abnormal endings.
+            State::ResourceExceeded | State::Lost => {
+                *abnormal_map.entry(t.hour_num as u32).or_default() += t.total;
+            }
             _ => {}
         })
         .for_each(drop);
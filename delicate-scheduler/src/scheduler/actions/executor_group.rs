@@ -0,0 +1,109 @@
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new()
+        .at("/api/executor_group/list", post(show_executor_groups))
+        .at(
+            "/api/executor_group/detail",
+            post(show_executor_group_detail),
+        )
+        .at("/api/executor_group/create", post(create_executor_group))
+        .at("/api/executor_group/update", post(update_executor_group))
+        .at("/api/executor_group/delete", post(delete_executor_group))
+}
+
+#[handler]
+async fn create_executor_group(
+    req: &Request,
+    Json(executor_group): Json<model::NewExecutorGroup>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option =
+        generate_operation_executor_group_addtion_log(req.get_session(), &executor_group).ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let id: UnifiedResponseMessages<i64> = db::blocking(&storage, move |storage| {
+        storage.create_executor_group(executor_group)
+    })
+    .await
+    .into();
+    Json(id)
+}
+
+#[handler]
+async fn show_executor_groups(
+    Json(query_params): Json<model::QueryParamsExecutorGroup>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let page: UnifiedResponseMessages<PaginateData<model::ExecutorGroup>> =
+        db::blocking(&storage, move |storage| {
+            storage.list_executor_groups(query_params)
+        })
+        .await
+        .into();
+    Json(page)
+}
+
+#[handler]
+async fn show_executor_group_detail(
+    Json(model::ExecutorGroupId { executor_group_id }): Json<model::ExecutorGroupId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let executor_group_detail_result = db::blocking(&storage, move |storage| {
+        storage.executor_group_detail(executor_group_id)
+    })
+    .await;
+    if let Ok(executor_group_detail) = executor_group_detail_result {
+        return Json(
+            UnifiedResponseMessages::<model::ExecutorGroupDetail>::success_with_data(
+                executor_group_detail,
+            ),
+        )
+        .into_response();
+    };
+
+    Json(
+        UnifiedResponseMessages::<()>::error()
+            .customized_error_msg(executor_group_detail_result.expect_err("").to_string()),
+    )
+    .into_response()
+}
+
+#[handler]
+async fn update_executor_group(
+    req: &Request,
+    Json(executor_group): Json<model::UpdateExecutorGroup>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option =
+        generate_operation_executor_group_modify_log(req.get_session(), &executor_group).ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.update_executor_group(executor_group)
+    })
+    .await
+    .into();
+    Json(count)
+}
+
+#[handler]
+async fn delete_executor_group(
+    req: &Request,
+    Json(model::ExecutorGroupId { executor_group_id }): Json<model::ExecutorGroupId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option = generate_operation_executor_group_delete_log(
+        req.get_session(),
+        &CommonTableRecord::default().set_id(executor_group_id),
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.delete_executor_group(executor_group_id)
+    })
+    .await
+    .into();
+    Json(count)
+}
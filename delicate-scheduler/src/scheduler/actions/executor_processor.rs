@@ -0,0 +1,176 @@
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new()
+        .at(
+            "/api/executor_processor/list",
+            post(show_executor_processors),
+        )
+        .at(
+            "/api/executor_processor/create",
+            post(create_executor_processor),
+        )
+        .at(
+            "/api/executor_processor/update",
+            post(update_executor_processor),
+        )
+        .at(
+            "/api/executor_processor/delete",
+            post(delete_executor_processor),
+        )
+        .at(
+            "/api/executor_processor/activate",
+            post(activate_executor_processor),
+        )
+        .at(
+            "/api/executor_processor/health_history",
+            post(show_executor_processor_health_history),
+        )
+}
+
+#[handler]
+async fn create_executor_processor(
+    req: &Request,
+    Json(executor_processor): Json<model::NewExecutorProcessor>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option =
+        generate_operation_executor_processor_addtion_log(req.get_session(), &executor_processor)
+            .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.create_executor_processor(executor_processor)
+    })
+    .await
+    .into();
+    Json(count)
+}
+
+#[handler]
+async fn show_executor_processors(
+    Json(query_params): Json<model::QueryParamsExecutorProcessor>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let processor: UnifiedResponseMessages<PaginateData<model::ExecutorProcessor>> =
+        db::blocking(&storage, move |storage| {
+            storage.list_executor_processors(query_params)
+        })
+        .await
+        .into();
+    Json(processor)
+}
+
+#[handler]
+async fn show_executor_processor_health_history(
+    Json(query_params): Json<model::QueryParamsExecutorProcessorHealth>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let page: UnifiedResponseMessages<PaginateData<model::FrontEndExecutorProcessorHealth>> =
+        db::blocking(&storage, move |storage| {
+            storage.list_executor_processor_health(query_params)
+        })
+        .await
+        .into();
+    Json(page)
+}
+
+#[handler]
+async fn update_executor_processor(
+    req: &Request,
+    Json(executor_processor): Json<model::UpdateExecutorProcessor>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option =
+        generate_operation_executor_processor_modify_log(req.get_session(), &executor_processor)
+            .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.update_executor_processor(executor_processor)
+    })
+    .await
+    .into();
+    Json(count)
+}
+
+#[handler]
+async fn delete_executor_processor(
+    req: &Request,
+    Json(model::ExecutorProcessorId {
+        executor_processor_id,
+    }): Json<model::ExecutorProcessorId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option = generate_operation_executor_processor_delete_log(
+        req.get_session(),
+        &CommonTableRecord::default().set_id(executor_processor_id),
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.delete_executor_processor(executor_processor_id)
+    })
+    .await
+    .into();
+    Json(count)
+}
+
+#[handler]
+async fn activate_executor_processor(
+    req: &Request,
+    Json(model::ExecutorProcessorId {
+        executor_processor_id,
+    }): Json<model::ExecutorProcessorId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+    scheduler: Data<&Arc<SchedulerMetaInfo>>,
+    router: Data<&Arc<ExecutorRouter>>,
+) -> impl IntoResponse {
+    let uniform_data: UnifiedResponseMessages<()> =
+        do_activate(req, &storage, executor_processor_id, scheduler, router)
+            .await
+            .into();
+    Json(uniform_data)
+}
+async fn do_activate(
+    req: &Request,
+    storage: &Arc<dyn db::Storage>,
+    executor_processor_id: i64,
+    scheduler: Data<&Arc<SchedulerMetaInfo>>,
+    router: Data<&Arc<ExecutorRouter>>,
+) -> Result<(), CommonError> {
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`");
+    let token = bind_executor(storage, request_client, &scheduler, executor_processor_id).await?;
+    activate_executor_row(storage, executor_processor_id, token).await?;
+
+    // The executor may have been restarted and lost its tasks,
+    // Push every task bound to it again, the health check repairs whatever fails here.
+    sync_executor_tasks(storage, request_client, &router, executor_processor_id)
+        .await
+        .map_err(|e| error!(target:"activate-executor", "{}", e))
+        .ok();
+
+    Ok(())
+}
+
+async fn activate_executor_row(
+    storage: &Arc<dyn db::Storage>,
+    executor_processor_id: i64,
+    token: String,
+) -> Result<(), CommonError> {
+    // TODO:
+    // Consider caching tokens to be used when collecting executor-events, and health checks.
+    // This will avoid querying the database.
+    // However, cached record operations cannot be placed in the context of the operation db update token.
+
+    db::blocking(storage, move |storage| {
+        storage.activate_executor_processor(executor_processor_id, token)
+    })
+    .await?;
+
+    Ok(())
+}
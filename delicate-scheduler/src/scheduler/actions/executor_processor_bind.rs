@@ -0,0 +1,230 @@
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new()
+        .at(
+            "/api/executor_processor_bind/list",
+            post(show_executor_processor_binds),
+        )
+        .at(
+            "/api/executor_processor_bind/create",
+            post(create_executor_processor_bind),
+        )
+        .at(
+            "/api/executor_processor_bind/update",
+            post(update_executor_processor_bind),
+        )
+        .at(
+            "/api/executor_processor_bind/delete",
+            post(delete_executor_processor_bind),
+        )
+}
+
+#[handler]
+
+async fn create_executor_processor_bind(
+    req: &Request,
+    Json(executor_processor_binds): Json<model::NewExecutorProcessorBinds>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option = generate_operation_executor_processor_bind_addtion_log(
+        req.get_session(),
+        &executor_processor_binds,
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let new_binds: Vec<model::NewExecutorProcessorBind> = executor_processor_binds
+        .executor_ids
+        .iter()
+        .map(|executor_id| model::NewExecutorProcessorBind {
+            name: executor_processor_binds.name.clone(),
+            group_id: executor_processor_binds.group_id,
+            executor_id: *executor_id,
+            weight: executor_processor_binds.weight,
+        })
+        .collect();
+
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.create_executor_processor_binds(new_binds)
+    })
+    .await
+    .into();
+    Json(count)
+}
+
+#[handler]
+
+async fn show_executor_processor_binds(
+    Json(query_params): Json<model::QueryParamsExecutorProcessorBind>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let binds: UnifiedResponseMessages<PaginateData<model::ExecutorProcessorBind>> =
+        db::blocking(&storage, move |storage| {
+            storage.list_executor_processor_binds(query_params)
+        })
+        .await
+        .into();
+    Json(binds)
+}
+
+#[handler]
+
+async fn update_executor_processor_bind(
+    req: &Request,
+    Json(executor_processor_bind): Json<model::UpdateExecutorProcessorBind>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    return Json(Into::<UnifiedResponseMessages<()>>::into(
+        pre_update_executor_processor_bind(req, executor_processor_bind, &storage).await,
+    ));
+}
+
+async fn pre_update_executor_processor_bind(
+    req: &Request,
+    executor_processor_bind: model::UpdateExecutorProcessorBind,
+    storage: &Arc<dyn db::Storage>,
+) -> Result<(), CommonError> {
+    use delicate_utils_task::{TaskPackage, TaskUnit};
+    use state::task::RoutingStrategy;
+
+    let executor_processor_bind_id = executor_processor_bind.id;
+    let executor_processor_bind_executor_id = executor_processor_bind.executor_id;
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`");
+    let router = req
+        .extensions()
+        .get::<Arc<ExecutorRouter>>()
+        .expect("Missing Components `ExecutorRouter`");
+
+    let operation_log_pair_option = generate_operation_executor_processor_bind_modify_log(
+        req.get_session(),
+        &executor_processor_bind,
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let (older_executor_id, older_executor_host, older_executor_token) =
+        db::blocking(storage, move |storage| {
+            storage.update_executor_processor_bind(executor_processor_bind)
+        })
+        .await?;
+
+    if older_executor_id == executor_processor_bind_executor_id {
+        return Ok(());
+    }
+
+    // Task migration needs to be performed only when `executor_id` is modified.
+    let bind_task_packages: Vec<(TaskPackage, i16, (String, String))> =
+        db::blocking(storage, move |storage| {
+            storage.tasks_bound_to_binding(executor_processor_bind_id)
+        })
+        .await?;
+
+    // A routed task only moves with the binding when it is held by this binding,
+    // If the holder is unknown the health check dispatches it once every member answers.
+    let mut task_packages: Vec<(TaskPackage, (String, String))> =
+        Vec::with_capacity(bind_task_packages.len());
+    for (task_package, routing_strategy, executor) in bind_task_packages {
+        let is_broadcast = matches!(
+            RoutingStrategy::from(routing_strategy),
+            RoutingStrategy::Broadcast | RoutingStrategy::Unknown
+        );
+
+        if is_broadcast
+            || router
+                .get_assignment(task_package.id)
+                .await?
+                .map_or(false, |bind_id| bind_id == executor_processor_bind_id)
+        {
+            task_packages.push((task_package, executor));
+        }
+    }
+
+    // The moved tasks may have missed runs while the previous executor held them, e.g. while it was down.
+    let moved_task_packages = task_packages.clone();
+    let misfired_tasks: Vec<(TaskPackage, usize, (String, String))> =
+        db::blocking(storage, move |storage| {
+            Ok(moved_task_packages
+                .into_iter()
+                .map(|(task_package, executor)| {
+                    let missed_runs = count_missed_runs(storage, &task_package);
+                    (task_package, missed_runs, executor)
+                })
+                .filter(|(_, missed_runs, _)| *missed_runs > 0)
+                .collect())
+        })
+        .await?;
+
+    let task_ids = task_packages.iter().map(|&(ref t, _)| t.id);
+
+    let remove_task_units: JoinAll<_> = task_ids
+        .filter_map(|task_id| {
+            let executor_host =
+                "http://".to_string() + (older_executor_host.deref()) + "/api/task/remove";
+            TaskUnit::default()
+                .set_task_id(task_id)
+                .set_time(timestamp())
+                .sign(Some(older_executor_token.deref()))
+                .map(|t| (t, executor_host))
+                .ok()
+        })
+        .map(|(signed_task_unit, executor_host)| {
+            request_client
+                .post(executor_host)
+                .json(&signed_task_unit)
+                .send()
+        })
+        .collect();
+
+    let create_task_packages: JoinAll<_> = task_packages
+        .into_iter()
+        .filter_map(|(t, (host, token))| {
+            let executor_host = "http://".to_string() + (host.deref()) + "/api/task/create";
+            t.sign(Some(&token)).map(|t| (t, executor_host)).ok()
+        })
+        .map(|(signed_task_package, executor_host)| {
+            request_client
+                .post(executor_host)
+                .json(&signed_task_package)
+                .send()
+        })
+        .collect();
+
+    join(
+        handle_response::<_, UnifiedResponseMessages<()>>(remove_task_units),
+        handle_response::<_, UnifiedResponseMessages<()>>(create_task_packages),
+    )
+    .await;
+
+    for (task_package, missed_runs, executor) in misfired_tasks {
+        fire_missed_runs(request_client, &task_package, missed_runs, &[executor]).await;
+    }
+    Ok(())
+}
+
+#[handler]
+async fn delete_executor_processor_bind(
+    req: &Request,
+    Json(model::ExecutorProcessorBindId {
+        executor_processor_bind_id,
+    }): Json<model::ExecutorProcessorBindId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option = generate_operation_executor_processor_bind_delete_log(
+        req.get_session(),
+        &CommonTableRecord::default().set_id(executor_processor_bind_id),
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    // TODO: Check if there are associated tasks on the binding.
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.delete_executor_processor_bind(executor_processor_bind_id)
+    })
+    .await
+    .into();
+    Json(count)
+}
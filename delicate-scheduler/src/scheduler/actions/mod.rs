@@ -1,5 +1,6 @@
 pub(crate) use super::prelude;
 
+pub(crate) mod calendar;
 pub(crate) mod components;
 pub(crate) mod data_reports;
 pub(crate) mod executor_group;
@@ -7,6 +8,7 @@ pub(crate) mod executor_processor;
 pub(crate) mod executor_processor_bind;
 pub(crate) mod operation_log;
 pub(crate) mod role;
+pub(crate) mod scheduler;
 pub(crate) mod task;
 pub(crate) mod task_instance;
 pub(crate) mod task_log;
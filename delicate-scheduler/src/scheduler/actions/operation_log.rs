@@ -0,0 +1,37 @@
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new()
+        .at("/api/operation_log/list", post(show_operation_log))
+        .at("/api/operation_log/detail", post(show_operation_log_detail))
+}
+
+#[handler]
+
+async fn show_operation_log(
+    Json(query_params): Json<model::QueryParamsOperationLog>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let page: UnifiedResponseMessages<PaginateData<model::FrontEndOperationLog>> =
+        db::blocking(&storage, move |storage| {
+            storage.list_operation_logs(query_params)
+        })
+        .await
+        .into();
+    Json(page)
+}
+#[handler]
+
+async fn show_operation_log_detail(
+    Json(query_params): Json<model::OperationLogId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_id = query_params.operation_log_id;
+    let log_detail: UnifiedResponseMessages<Vec<model::OperationLogDetail>> =
+        db::blocking(&storage, move |storage| {
+            storage.operation_log_detail(operation_log_id)
+        })
+        .await
+        .into();
+    Json(log_detail)
+}
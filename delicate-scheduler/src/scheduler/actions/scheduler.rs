@@ -0,0 +1,31 @@
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new().at("/api/scheduler/leader", get(show_scheduler_leader))
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct LeaderView {
+    // The scheduler running the background jobs, `None` while the lock is free.
+    leader: Option<SchedulerInstance>,
+    // The scheduler answering the request.
+    current: SchedulerInstance,
+    is_leader: bool,
+}
+
+#[handler]
+async fn show_scheduler_leader(election: Data<&Arc<LeaderElection>>) -> impl IntoResponse {
+    let leader_view = election.current_leader().await.map(|leader| LeaderView {
+        leader,
+        current: election.instance().clone(),
+        is_leader: election.is_leader(),
+    });
+
+    Json(
+        leader_view
+            .map(UnifiedResponseMessages::<LeaderView>::success_with_data)
+            .unwrap_or_else(|e| {
+                UnifiedResponseMessages::<LeaderView>::error().customized_error_msg(e.to_string())
+            }),
+    )
+}
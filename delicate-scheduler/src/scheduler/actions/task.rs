@@ -0,0 +1,680 @@
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new()
+        .at("/api/task/run", post(run_task))
+        .at("/api/task/list", post(show_tasks))
+        .at("/api/task/delete", post(delete_task))
+        .at("/api/task/create", post(create_task))
+        .at("/api/task/update", post(update_task))
+        .at("/api/task/suspend", post(suspend_task))
+        .at("/api/task/advance", post(advance_task))
+        .at("/api/task/preview", post(preview_task))
+}
+
+#[handler]
+
+async fn preview_task(
+    Json(model::TaskSchedulePreview {
+        frequency,
+        cron_expression,
+        count,
+    }): Json<model::TaskSchedulePreview>,
+) -> impl IntoResponse {
+    // The schedule is evaluated here exactly as the executors would, before the task is saved.
+    let response: UnifiedResponseMessages<Vec<DateTime<FixedOffset>>> =
+        delicate_utils_task::preview_fire_times(&frequency, &cron_expression, count.min(100))
+            .into();
+    Json(response)
+}
+
+#[handler]
+
+async fn create_task(
+    req: &Request,
+    Json(model::NewTaskBody {
+        task,
+        binding_ids,
+        dependencies,
+    }): Json<model::NewTaskBody>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    if let Err(e) = model::check_task_command(task.kind, &task.command, &task.process_options())
+        .and_then(|_| model::check_task_frequency(&task.frequency, &task.cron_expression))
+        .and_then(|_| model::check_trigger_mode(task.trigger_mode))
+        .and_then(|_| model::check_misfire_policy(task.misfire_policy, task.misfire_limit))
+    {
+        return Json(UnifiedResponseMessages::<usize>::error().customized_error_msg(e.to_string()));
+    }
+
+    let operation_log_pair_option =
+        generate_operation_task_addtion_log(req.get_session(), &task).ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.create_task(task, binding_ids, dependencies)
+    })
+    .await
+    .into();
+    Json(count)
+}
+
+#[handler]
+
+async fn show_tasks(
+    Json(query_params): Json<model::QueryParamsTask>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let page: UnifiedResponseMessages<PaginateData<model::FrontEndTask>> =
+        db::blocking(&storage, move |storage| storage.list_tasks(query_params))
+            .await
+            .into();
+    Json(page)
+}
+
+#[handler]
+async fn update_task(
+    req: &Request,
+    Json(update_task_body): Json<model::UpdateTaskBody>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let respose = dispatch_response(
+        pre_update_task(req, update_task_body, &storage)
+            .instrument(span!(Level::INFO, "update-task"))
+            .await,
+    );
+    Json(respose)
+}
+
+pub async fn pre_update_task(
+    req: &Request,
+    model::UpdateTaskBody {
+        task,
+        binding_ids,
+        dependencies,
+    }: model::UpdateTaskBody,
+    storage: &Arc<dyn db::Storage>,
+) -> Result<Vec<DispatchResult>, CommonError> {
+    let task_id = task.id;
+    model::check_task_command(task.kind, &task.command, &task.process_options())?;
+    model::check_task_frequency(&task.frequency, &task.cron_expression)?;
+    model::check_trigger_mode(task.trigger_mode)?;
+    model::check_misfire_policy(task.misfire_policy, task.misfire_limit)?;
+    let operation_log_pair_option =
+        generate_operation_task_modify_log(req.get_session(), &task).ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let task_binds_pair = db::blocking(storage, move |storage| {
+        storage.update_task(task, binding_ids, dependencies)
+    })
+    .await?;
+
+    pre_update_task_sevice(req, storage, task_id, task_binds_pair).await
+}
+
+pub async fn pre_update_task_sevice(
+    req: &Request,
+    storage: &Arc<dyn db::Storage>,
+    task_id: i64,
+    (mut removed_bind_processors, append_bind_processors, mut reserved_bind_processors): db::TaskBindChanges,
+) -> Result<Vec<DispatchResult>, CommonError> {
+    use state::task::{RoutingStrategy, TriggerMode};
+
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`");
+
+    let router = req
+        .extensions()
+        .get::<Arc<ExecutorRouter>>()
+        .expect("Missing Components `ExecutorRouter`");
+
+    let central_trigger = req
+        .extensions()
+        .get::<Arc<CentralTrigger>>()
+        .expect("Missing Components `CentralTrigger`");
+
+    let (task_package, status, routing_strategy, trigger_mode) =
+        db::blocking(storage, move |storage| storage.task_packages(vec![task_id]))
+            .await?
+            .pop()
+            .ok_or_else(|| CommonError::DisPass(format!("Task {} does not exist.", task_id)))?;
+
+    // The task may have moved from one mode to the other.
+    central_trigger.sync().await?;
+
+    // The executors do not hold a task triggered by the scheduler,
+    // Though they may have held it before the update.
+    if TriggerMode::from(trigger_mode) == TriggerMode::Scheduler {
+        let failover_processors = release_failovers(storage, task_id, |_| true).await?;
+        let holder_bind_ids = former_holder_bind_ids(router, task_id, &failover_processors).await?;
+        router.forget(task_id).await?;
+
+        let all_processors: Vec<model::BindProcessor> = removed_bind_processors
+            .into_iter()
+            .chain(append_bind_processors.into_iter())
+            .chain(reserved_bind_processors.into_iter())
+            .chain(failover_processors.into_iter().map(|(_, p)| p))
+            .collect();
+        let dispatch_results = dispatch_routed_task(
+            request_client,
+            &task_package,
+            all_processors,
+            Vec::new(),
+            &holder_bind_ids,
+        )
+        .await;
+
+        return Ok(dispatch_results);
+    }
+
+    let routing_strategy: RoutingStrategy = routing_strategy.into();
+    let is_routed = !matches!(
+        routing_strategy,
+        RoutingStrategy::Broadcast | RoutingStrategy::Unknown
+    );
+
+    // Members that took over the task from abnormal executors follow the update,
+    // Unless the task is re-dispatched or the abnormal member is no longer bound.
+    let mut holder_bind_ids = HashSet::new();
+    if status == state::task::State::Enabled as i16 {
+        let removed_bind_ids: HashSet<i64> =
+            removed_bind_processors.iter().map(|b| b.bind_id).collect();
+
+        let released_bind_ids = removed_bind_ids.clone();
+        let failover_processors = release_failovers(storage, task_id, move |failover| {
+            is_routed || released_bind_ids.contains(&failover.origin_bind_id)
+        })
+        .await?;
+        holder_bind_ids = former_holder_bind_ids(router, task_id, &failover_processors).await?;
+
+        for (failover, processor) in failover_processors {
+            if is_routed || removed_bind_ids.contains(&failover.origin_bind_id) {
+                removed_bind_processors.push(processor);
+            } else {
+                reserved_bind_processors.push(processor);
+            }
+        }
+    }
+
+    if status == state::task::State::Enabled as i16 && is_routed {
+        // A routed task is held by one member, so the update is a re-dispatch.
+        let bind_processors: Vec<model::BindProcessor> = append_bind_processors
+            .into_iter()
+            .chain(reserved_bind_processors.into_iter())
+            .collect();
+        let chosen_processors = router
+            .route(task_id, routing_strategy, bind_processors.clone())
+            .await?;
+
+        let all_processors: Vec<model::BindProcessor> = removed_bind_processors
+            .into_iter()
+            .chain(bind_processors.into_iter())
+            .collect();
+
+        let dispatch_results = dispatch_routed_task(
+            request_client,
+            &task_package,
+            all_processors,
+            chosen_processors,
+            &holder_bind_ids,
+        )
+        .await;
+
+        return Ok(dispatch_results);
+    }
+
+    if status != state::task::State::Enabled as i16 {
+        return Ok(Vec::new());
+    }
+
+    let remove_task_units = removed_bind_processors
+        .into_iter()
+        .map(|processor| {
+            info!("Remove task{} at:{}", &task_package, &processor.host);
+            let signed_task_unit = delicate_utils_task::TaskUnit::default()
+                .set_task_id(task_id)
+                .set_time(timestamp())
+                .sign(Some(&processor.token));
+            (processor.host, signed_task_unit)
+        })
+        .collect();
+
+    let append_task_packages = append_bind_processors
+        .into_iter()
+        .map(|processor| {
+            info!("Create task{} at:{}", &task_package, &processor.host);
+            let signed_task_package = task_package.clone().sign(Some(&processor.token));
+            (processor.host, signed_task_package)
+        })
+        .collect();
+
+    let update_task_packages = reserved_bind_processors
+        .into_iter()
+        .map(|processor| {
+            info!("Update task {} at:{}", &task_package, &processor.host);
+            let signed_task_package = task_package.clone().sign(Some(&processor.token));
+            (processor.host, signed_task_package)
+        })
+        .collect();
+
+    let (removed_results, appended_results, updated_results) = join3(
+        dispatch_to_executors(request_client, "/api/task/remove", remove_task_units),
+        dispatch_to_executors(request_client, "/api/task/create", append_task_packages),
+        dispatch_to_executors(request_client, "/api/task/update", update_task_packages),
+    )
+    .await;
+
+    Ok(removed_results
+        .into_iter()
+        .chain(appended_results.into_iter())
+        .chain(updated_results.into_iter())
+        .collect())
+}
+
+#[handler]
+
+async fn delete_task(
+    req: &Request,
+    Json(model::TaskId { task_id }): Json<model::TaskId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let central_trigger = req
+        .extensions()
+        .get::<Arc<CentralTrigger>>()
+        .expect("Missing Components `CentralTrigger`");
+
+    let operation_log_pair_option = generate_operation_task_delete_log(
+        req.get_session(),
+        &CommonTableRecord::default().set_id(task_id),
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    // delete
+    let f_result = db::blocking(&storage, move |storage| storage.delete_task(task_id)).await;
+
+    if f_result.is_ok() {
+        central_trigger
+            .sync()
+            .await
+            .map_err(|e| error!("Failed to sync the central tasks: {}", e))
+            .ok();
+    }
+
+    Json(Into::<UnifiedResponseMessages<()>>::into(f_result))
+}
+
+#[handler]
+async fn run_task(
+    req: &Request,
+    Json(model::TaskId { task_id }): Json<model::TaskId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let result = dispatch_response(
+        pre_run_task(req, task_id, &storage)
+            .instrument(span!(Level::INFO, "run-task"))
+            .await,
+    );
+
+    Json(result)
+}
+
+#[handler]
+
+async fn suspend_task(
+    req: &Request,
+    Json(model::TaskId { task_id }): Json<model::TaskId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let result = dispatch_response(
+        pre_operate_task(req, &storage, (task_id, "/api/task/remove", "Suspend"))
+            .instrument(span!(Level::INFO, "Suspend", task_id))
+            .await,
+    );
+
+    Json(result)
+}
+
+#[handler]
+async fn advance_task(
+    req: &Request,
+    Json(model::TaskId { task_id }): Json<model::TaskId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let result = dispatch_response(
+        pre_operate_task(req, &storage, (task_id, "/api/task/advance", "Advance"))
+            .instrument(span!(Level::INFO, "Advance", task_id))
+            .await,
+    );
+
+    Json(result)
+}
+
+async fn pre_run_task(
+    req: &Request,
+    task_id: i64,
+    storage: &Arc<dyn db::Storage>,
+) -> Result<Vec<DispatchResult>, CommonError> {
+    use state::task::{RoutingStrategy, State, TriggerMode};
+
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`");
+
+    let router = req
+        .extensions()
+        .get::<Arc<ExecutorRouter>>()
+        .expect("Missing Components `ExecutorRouter`");
+
+    let central_trigger = req
+        .extensions()
+        .get::<Arc<CentralTrigger>>()
+        .expect("Missing Components `CentralTrigger`");
+
+    let operation_log_pair_option = generate_operation_task_modify_log(
+        req.get_session(),
+        &CommonTableRecord::default()
+            .set_id(task_id)
+            .set_description("Run task"),
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    // Many machine.
+    let (
+        (task_package, task_routing_strategy, task_trigger_mode),
+        mut bind_processors,
+        missed_runs,
+    ): (
+        (delicate_utils_task::TaskPackage, i16, i16),
+        Vec<model::BindProcessor>,
+        usize,
+    ) = db::blocking(storage, move |storage| {
+        storage.set_task_status(task_id, State::Enabled as i16)?;
+
+        let (task_package, _, routing_strategy, trigger_mode) = storage
+            .task_packages(vec![task_id])?
+            .pop()
+            .ok_or_else(|| CommonError::DisPass(format!("Task {} does not exist.", task_id)))?;
+
+        // The schedules missed while the task was suspended or not held by any executor.
+        let missed_runs = count_missed_runs(storage, &task_package);
+
+        let bind_processors = storage
+            .task_members(vec![task_id], false)?
+            .into_iter()
+            .map(|(_, member)| member)
+            .collect();
+
+        Ok((
+            (task_package, routing_strategy, trigger_mode),
+            bind_processors,
+            missed_runs,
+        ))
+    })
+    .await?;
+
+    // A routed task is re-dispatched, so the members that took it over release it.
+    let task_routing_strategy: RoutingStrategy = task_routing_strategy.into();
+    let is_routed = !matches!(
+        task_routing_strategy,
+        RoutingStrategy::Broadcast | RoutingStrategy::Unknown
+    );
+    let failover_processors = release_failovers(storage, task_id, move |_| is_routed).await?;
+
+    // The scheduler keeps the schedule of the task itself.
+    if TriggerMode::from(task_trigger_mode) == TriggerMode::Scheduler {
+        central_trigger.sync().await?;
+        for _ in 0..missed_runs {
+            central_trigger.trigger_now(task_id).await?;
+        }
+        return Ok(Vec::new());
+    }
+
+    let holder_bind_ids = former_holder_bind_ids(router, task_id, &failover_processors).await?;
+    let mut chosen_processors = router
+        .route(task_id, task_routing_strategy, bind_processors.clone())
+        .await?;
+
+    if is_routed {
+        bind_processors.extend(failover_processors.into_iter().map(|(_, p)| p));
+        let chosen_members = executor_members(&chosen_processors);
+        let dispatch_results = dispatch_routed_task(
+            request_client,
+            &task_package,
+            bind_processors,
+            chosen_processors,
+            &holder_bind_ids,
+        )
+        .await;
+        fire_missed_runs(request_client, &task_package, missed_runs, &chosen_members).await;
+        return Ok(dispatch_results);
+    }
+
+    // Members that took over the task from abnormal executors run it as well.
+    chosen_processors.extend(failover_processors.into_iter().map(|(_, p)| p));
+    let chosen_members = executor_members(&chosen_processors);
+
+    let task_packages = chosen_processors
+        .into_iter()
+        .map(|processor| {
+            info!("Run task{} at:{}", &task_package, &processor.host);
+            let signed_task_package = task_package.clone().sign(Some(&processor.token));
+            (processor.host, signed_task_package)
+        })
+        .collect();
+
+    let dispatch_results =
+        dispatch_to_executors(request_client, "/api/task/create", task_packages).await;
+    fire_missed_runs(request_client, &task_package, missed_runs, &chosen_members).await;
+
+    Ok(dispatch_results)
+}
+
+// The members that took over the task from abnormal executors,
+// The failovers `release` picks are over and forgotten.
+async fn release_failovers(
+    storage: &Arc<dyn db::Storage>,
+    task_id: i64,
+    release: impl Fn(&model::TaskFailover) -> bool + Send + 'static,
+) -> Result<Vec<(model::TaskFailover, model::BindProcessor)>, CommonError> {
+    db::blocking(storage, move |storage| {
+        let failover_processors = storage.task_failover_processors(task_id)?;
+        let failover_ids: Vec<i64> = failover_processors
+            .iter()
+            .filter(|(f, _)| release(f))
+            .map(|(f, _)| f.id)
+            .collect();
+        storage.delete_task_failovers(failover_ids)?;
+        Ok(failover_processors)
+    })
+    .await
+}
+
+// The (host, token) pairs of the members.
+fn executor_members(processors: &[model::BindProcessor]) -> Vec<(String, String)> {
+    processors
+        .iter()
+        .map(|p| (p.host.clone(), p.token.clone()))
+        .collect()
+}
+
+// The members known to hold the task before a re-dispatch,
+// The recorded holder and the members that took the task over from abnormal executors.
+async fn former_holder_bind_ids(
+    router: &ExecutorRouter,
+    task_id: i64,
+    failover_processors: &[(model::TaskFailover, model::BindProcessor)],
+) -> Result<HashSet<i64>, CommonError> {
+    let mut holder_bind_ids: HashSet<i64> =
+        failover_processors.iter().map(|(_, p)| p.bind_id).collect();
+    holder_bind_ids.extend(router.get_assignment(task_id).await?);
+    Ok(holder_bind_ids)
+}
+
+// Only the chosen member keeps a routed task, it is removed from every member first,
+// So that a re-dispatch never leaves a second copy behind on the previous member.
+//
+// Only the removes from the former holders and the dispatch to the chosen member make the result,
+// The other members merely should not hold the task, So an unreachable one is just a warning.
+async fn dispatch_routed_task(
+    request_client: &RequestClient,
+    task_package: &delicate_utils_task::TaskPackage,
+    bind_processors: Vec<model::BindProcessor>,
+    chosen_processors: Vec<model::BindProcessor>,
+    holder_bind_ids: &HashSet<i64>,
+) -> Vec<DispatchResult> {
+    let task_id = task_package.id;
+
+    let sign_task_units = |processors: Vec<model::BindProcessor>| -> Vec<(String, _)> {
+        processors
+            .into_iter()
+            .map(|processor| {
+                let signed_task_unit = delicate_utils_task::TaskUnit::default()
+                    .set_task_id(task_id)
+                    .set_time(timestamp())
+                    .sign(Some(&processor.token));
+                (processor.host, signed_task_unit)
+            })
+            .collect()
+    };
+
+    let (holder_processors, other_processors): (Vec<_>, Vec<_>) = bind_processors
+        .into_iter()
+        .partition(|p| holder_bind_ids.contains(&p.bind_id));
+
+    let (mut dispatch_results, other_results) = join(
+        dispatch_to_executors(
+            request_client,
+            "/api/task/remove",
+            sign_task_units(holder_processors),
+        ),
+        dispatch_to_executors(
+            request_client,
+            "/api/task/remove",
+            sign_task_units(other_processors),
+        ),
+    )
+    .await;
+
+    for result in other_results.into_iter().filter(|r| !r.ok) {
+        warn!(
+            "Task {} is not removed from {}, which does not hold it: {}",
+            task_id, result.host, result.message
+        );
+    }
+
+    let task_packages = chosen_processors
+        .into_iter()
+        .map(|processor| {
+            info!("Dispatch task{} at:{}", task_package, &processor.host);
+            let signed_task_package = task_package.clone().sign(Some(&processor.token));
+            (processor.host, signed_task_package)
+        })
+        .collect();
+
+    dispatch_results
+        .extend(dispatch_to_executors(request_client, "/api/task/create", task_packages).await);
+    dispatch_results
+}
+
+async fn pre_operate_task(
+    req: &Request,
+    storage: &Arc<dyn db::Storage>,
+    (task_id, url, action): (i64, &'static str, &'static str),
+) -> Result<Vec<DispatchResult>, CommonError> {
+    use state::task::{State, TriggerMode};
+
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`");
+
+    let router = req
+        .extensions()
+        .get::<Arc<ExecutorRouter>>()
+        .expect("Missing Components `ExecutorRouter`");
+
+    let central_trigger = req
+        .extensions()
+        .get::<Arc<CentralTrigger>>()
+        .expect("Missing Components `CentralTrigger`");
+
+    let operation_log_pair_option = generate_operation_task_modify_log(
+        req.get_session(),
+        &CommonTableRecord::default()
+            .set_id(task_id)
+            .set_description(action),
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    // Removal goes to every member, other operations only to the member holding a routed task.
+    let assignment = if url == "/api/task/remove" {
+        router.forget(task_id).await?;
+        None
+    } else {
+        router.get_assignment(task_id).await?
+    };
+
+    let (bind_packages, trigger_mode): (Vec<(i64, String, String)>, i16) =
+        db::blocking(storage, move |storage| {
+            // TODO: Optimize.
+            if action.eq("Suspend") {
+                storage.set_task_status(task_id, State::NotEnabled as i16)?;
+            }
+
+            let (_, _, _, trigger_mode) = storage
+                .task_packages(vec![task_id])?
+                .pop()
+                .ok_or_else(|| CommonError::DisPass(format!("Task {} does not exist.", task_id)))?;
+
+            let bind_packages = storage
+                .task_members(vec![task_id], false)?
+                .into_iter()
+                .map(|(_, member)| (member.bind_id, member.host, member.token))
+                .collect();
+
+            Ok((bind_packages, trigger_mode))
+        })
+        .await?;
+
+    // Members that took over the task from abnormal executors are operated as well,
+    // And once they remove the task, the failover is over.
+    let failover_packages: Vec<(String, String)> =
+        release_failovers(storage, task_id, move |_| url == "/api/task/remove")
+            .await?
+            .into_iter()
+            .map(|(_, p)| (p.host, p.token))
+            .collect();
+
+    // The executors do not hold a task triggered by the scheduler.
+    if TriggerMode::from(trigger_mode) == TriggerMode::Scheduler {
+        if url == "/api/task/advance" {
+            central_trigger.trigger_now(task_id).await?;
+        } else {
+            central_trigger.sync().await?;
+        }
+        return Ok(Vec::new());
+    }
+
+    let task_units = bind_packages
+        .into_iter()
+        .filter(|(bind_id, _, _)| assignment.map_or(true, |a| a == *bind_id))
+        .map(|(_, executor_host, executor_token)| (executor_host, executor_token))
+        .chain(failover_packages.into_iter())
+        .map(|(executor_host, executor_token)| {
+            let message = delicate_utils_task::TaskUnit::default()
+                .set_task_id(task_id)
+                .set_time(timestamp());
+
+            info!("{} task{} at:{}", action, message, &executor_host);
+            (executor_host, message.sign(Some(&executor_token)))
+        })
+        .collect();
+
+    Ok(dispatch_to_executors(request_client, url, task_units).await)
+}
@@ -0,0 +1,209 @@
+use super::prelude::*;
+use poem::web::Query;
+
+pub(crate) fn route_config() -> Route {
+    Route::new()
+        .at("/api/task_instance/kill", post(kill_task_instance))
+        .at("/api/task_instance/output", post(show_task_instance_output))
+        .at(
+            "/api/task_instance/output_stream",
+            get(stream_task_instance_output),
+        )
+}
+
+// How often the executor is asked for new output while a record is followed.
+const OUTPUT_POLLING_INTERVAL: Duration = Duration::from_millis(500);
+
+#[handler]
+
+async fn kill_task_instance(
+    req: &Request,
+    Json(task_record): Json<model::TaskRecord>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let response_result = kill_one_task_instance(req, &storage, task_record).await;
+
+    let response = Into::<UnifiedResponseMessages<()>>::into(response_result);
+    Json(response)
+}
+
+async fn kill_one_task_instance(
+    req: &Request,
+    storage: &Arc<dyn db::Storage>,
+    model::TaskRecord {
+        task_id,
+        record_id,
+        executor_processor_id,
+    }: model::TaskRecord,
+) -> Result<(), CommonError> {
+    let operation_log_pair_option = generate_operation_task_log_modify_log(
+        req.get_session(),
+        &CommonTableRecord::default()
+            .set_id(record_id.0)
+            .set_description("kill task instance."),
+    )
+    .ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`");
+
+    let token = model::get_executor_token_by_id(executor_processor_id, storage.clone()).await;
+
+    let host = db::blocking(storage, move |storage| {
+        storage.cancel_running_record(record_id.0)
+    })
+    .await?;
+
+    let url = "http://".to_string() + (host.deref()) + "/api/task_instance/kill";
+
+    let record = delicate_utils_task_log::CancelTaskRecord::default()
+        .set_task_id(task_id)
+        .set_record_id(record_id.0)
+        .set_time(timestamp())
+        .sign(token.as_deref())?;
+
+    request_client
+        .post(url)
+        .json(&record)
+        .send()
+        .await?
+        .json::<UnifiedResponseMessages<()>>()
+        .await?
+        .into()
+}
+
+// The output a running record has produced since the given offset.
+#[handler]
+
+async fn show_task_instance_output(
+    req: &Request,
+    Json(record_output_offset): Json<model::RecordOutputOffset>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let response_result = pre_show_task_instance_output(req, &storage, record_output_offset).await;
+
+    let response = Into::<UnifiedResponseMessages<delicate_utils_task_log::TaskRecordOutput>>::into(
+        response_result,
+    );
+    Json(response)
+}
+
+async fn pre_show_task_instance_output(
+    req: &Request,
+    storage: &Arc<dyn db::Storage>,
+    model::RecordOutputOffset { record_id, offset }: model::RecordOutputOffset,
+) -> Result<delicate_utils_task_log::TaskRecordOutput, CommonError> {
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`");
+
+    let (host, token) = get_record_executor(storage, record_id.0).await?;
+    tail_task_instance_output(request_client, &host, token.as_deref(), record_id.0, offset).await
+}
+
+// Follow the output of a running record as server-sent events,
+// Each `output` event carries the new chunks and the stream ends when the run does.
+#[handler]
+async fn stream_task_instance_output(
+    req: &Request,
+    Query(record_output_offset): Query<model::RecordOutputOffset>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> SSE {
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`")
+        .clone();
+    let model::RecordOutputOffset { record_id, offset } = record_output_offset;
+    let record_executor = get_record_executor(&storage, record_id.0).await;
+
+    let stream = stream_unfold(Some((record_executor, offset, false)), move |state| {
+        let request_client = request_client.clone();
+        async move {
+            let (record_executor, mut offset, mut wait) = state?;
+            let (host, token) = match record_executor {
+                Ok(record_executor) => record_executor,
+                Err(e) => {
+                    return Some((SseEvent::message(e.to_string()).event_type("error"), None))
+                }
+            };
+
+            loop {
+                if wait {
+                    sleep(OUTPUT_POLLING_INTERVAL).await;
+                }
+                wait = true;
+
+                let output = match tail_task_instance_output(
+                    &request_client,
+                    &host,
+                    token.as_deref(),
+                    record_id.0,
+                    offset,
+                )
+                .await
+                {
+                    Ok(output) => output,
+                    Err(e) => {
+                        return Some((SseEvent::message(e.to_string()).event_type("error"), None))
+                    }
+                };
+
+                if output.chunks.is_empty() && !output.finished {
+                    continue;
+                }
+
+                offset = output.next_offset;
+                let finished = output.finished;
+                let event = SseEvent::message(to_json_string(&output).unwrap_or_default())
+                    .event_type("output");
+                let next_state = (!finished).then(|| (Ok((host, token)), offset, true));
+
+                return Some((event, next_state));
+            }
+        }
+    });
+
+    SSE::new(stream)
+}
+
+// The host of the executor that runs the record and the token to sign with.
+async fn get_record_executor(
+    storage: &Arc<dyn db::Storage>,
+    record_id: i64,
+) -> Result<(String, Option<String>), CommonError> {
+    let (host, executor_processor_id) =
+        db::blocking(storage, move |storage| storage.record_executor(record_id)).await?;
+
+    let token = model::get_executor_token_by_id(executor_processor_id, storage.clone()).await;
+    Ok((host, token))
+}
+
+async fn tail_task_instance_output(
+    request_client: &RequestClient,
+    host: &str,
+    token: Option<&str>,
+    record_id: i64,
+    offset: u64,
+) -> Result<delicate_utils_task_log::TaskRecordOutput, CommonError> {
+    let url = "http://".to_string() + host + "/api/task_instance/output";
+
+    let record = delicate_utils_task_log::TailTaskRecord::default()
+        .set_record_id(record_id)
+        .set_offset(offset)
+        .set_time(timestamp())
+        .sign(token)?;
+
+    request_client
+        .post(url)
+        .json(&record)
+        .send()
+        .await?
+        .json::<UnifiedResponseMessages<delicate_utils_task_log::TaskRecordOutput>>()
+        .await?
+        .into()
+}
@@ -0,0 +1,169 @@
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new()
+        .at("/api/task_log/event_trigger", post(create_task_logs))
+        .at("/api/task_log/list", post(show_task_logs))
+        .at("/api/task_log/detail", post(show_task_log_detail))
+        .at("/api/task_log/dag_run", post(show_dag_run))
+        .at("/api/task_log/delete", post(delete_task_log))
+}
+
+// Depending on the event, scheduler records/updates different logs.
+// Bulk operations are supported for log messages passed from delicate-executor.
+#[handler]
+
+async fn create_task_logs(
+    req: &Request,
+    Json(events_collection): Json<delicate_utils_task_log::SignedExecutorEventCollection>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let r = async {
+        debug!(
+            "Event collection - {:?}",
+            &events_collection.event_collection
+        );
+
+        pre_create_task_logs(req, events_collection, &storage).await
+    }
+    .instrument(span!(
+        Level::INFO,
+        "status-reporter",
+        log_id = get_unique_id_string().deref()
+    ))
+    .await;
+
+    let response = Into::<UnifiedResponseMessages<usize>>::into(r);
+    Json(response)
+}
+
+async fn pre_create_task_logs(
+    req: &Request,
+    events_collection: delicate_utils_task_log::SignedExecutorEventCollection,
+    storage: &Arc<dyn db::Storage>,
+) -> Result<usize, CommonError> {
+    use delicate_utils_task_log::EventType;
+
+    let executor_processor_id = (events_collection)
+        .event_collection
+        .events
+        .get(0)
+        .map(|e| e.executor_processor_id)
+        .ok_or_else(|| CommonError::DisPass(" `event_collection` is empty . ".into()))?;
+
+    let token = model::get_executor_token_by_id(executor_processor_id, storage.clone()).await;
+
+    let delicate_utils_task_log::ExecutorEventCollection { events, .. } =
+        events_collection.get_executor_event_collection_after_verify(token.as_deref())?;
+
+    let mut new_task_logs: Vec<model::NewTaskLog> = Vec::new();
+    let mut supply_task_logs: Vec<model::SupplyTaskLogTuple> = Vec::new();
+
+    events
+        .into_iter()
+        .for_each(|e| match Into::<EventType>::into(e.event_type) {
+            EventType::TaskPerform => new_task_logs.push(e.into()),
+            EventType::Unknown => {}
+            _ => supply_task_logs.push(e.into()),
+        });
+
+    debug!("{:?}, {:?}", &new_task_logs, &supply_task_logs);
+
+    let (num, ended_record_ids) = db::blocking(storage, move |storage| {
+        storage.record_task_events(new_task_logs, supply_task_logs)
+    })
+    .await?;
+
+    // The logs are already recorded, a failed trigger does not fail the event collection.
+    let request_client = req
+        .extensions()
+        .get::<RequestClient>()
+        .expect("Missing Components `RequestClient`");
+    let router = req
+        .extensions()
+        .get::<Arc<ExecutorRouter>>()
+        .expect("Missing Components `ExecutorRouter`");
+    trigger_downstream_tasks(storage, request_client, router, ended_record_ids)
+        .await
+        .map_err(|e| error!(target:"dependency", "{}", e))
+        .ok();
+
+    Ok(num)
+}
+
+#[handler]
+
+async fn show_task_logs(
+    Json(query_params): Json<model::QueryParamsTaskLog>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let page: UnifiedResponseMessages<PaginateData<model::FrontEndTaskLog>> =
+        db::blocking(&storage, move |storage| {
+            storage.list_task_logs(query_params)
+        })
+        .await
+        .into();
+    Json(page)
+}
+
+#[handler]
+
+async fn show_task_log_detail(
+    Json(query_params): Json<model::RecordId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let record_id = query_params.record_id.0;
+    let log_extend: UnifiedResponseMessages<model::TaskLogExtend> =
+        db::blocking(&storage, move |storage| storage.task_log_detail(record_id))
+            .await
+            .into();
+    Json(log_extend)
+}
+
+#[handler]
+
+async fn show_dag_run(
+    Json(model::RunId { run_id }): Json<model::RunId>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let dag_run: UnifiedResponseMessages<model::DagRun> = db::blocking(&storage, move |storage| {
+        let (task_logs, triggers) = storage.run_records(run_id.0)?;
+
+        let task_logs: Vec<model::FrontEndTaskLog> =
+            task_logs.into_iter().map(|t| t.into()).collect();
+        let task_ids: Vec<i64> = task_logs
+            .iter()
+            .map(|t| t.task_id)
+            .collect::<HashSet<i64>>()
+            .into_iter()
+            .collect();
+        let dependencies = load_reachable_dependencies(storage, task_ids)?;
+
+        Ok(model::DagRun {
+            task_logs,
+            dependencies,
+            triggers: triggers.into_iter().map(|t| t.into()).collect(),
+        })
+    })
+    .await
+    .into();
+    Json(dag_run)
+}
+
+#[handler]
+async fn delete_task_log(
+    req: &Request,
+    Json(delete_params): Json<model::DeleteParamsTaskLog>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let operation_log_pair_option =
+        generate_operation_task_delete_log(req.get_session(), &delete_params).ok();
+    send_option_operation_log_pair(operation_log_pair_option).await;
+
+    Json(Into::<UnifiedResponseMessages<()>>::into(
+        db::blocking(&storage, move |storage| {
+            storage.delete_task_logs(delete_params)
+        })
+        .await,
+    ))
+}
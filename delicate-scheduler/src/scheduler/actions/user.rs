@@ -1,8 +1,5 @@
 use super::prelude::*;
-use model::schema::{user, user_auth, user_login_log};
-use model::user::{
-    get_encrypted_certificate_by_raw_certificate, UserAndPermissions, UserAndRoles, UserName,
-};
+use model::user::{UserAndPermissions, UserAndRoles, UserName};
 use model::user_login_log::NewUserLoginLog;
 
 pub(crate) fn route_config() -> Route {
@@ -28,7 +25,7 @@ pub(crate) fn route_config() -> Route {
 async fn create_user(
     req: &Request,
     Json(user): Json<model::QueryNewUser>,
-    pool: Data<&Arc<db::ConnectionPool>>,
+    storage: Data<&Arc<dyn db::Storage>>,
 ) -> impl IntoResponse {
     let validate_result: Result<(), ValidationErrors> = user.validate();
     if validate_result.is_err() {
@@ -41,80 +38,22 @@ async fn create_user(
         generate_operation_user_addtion_log(req.get_session(), &new_user).ok();
     send_option_operation_log_pair(operation_log_pair_option).await;
 
-    if let Ok(conn) = pool.get() {
-        let f_result = spawn_blocking::<_, Result<_, diesel::result::Error>>(move || {
-            conn.transaction(|| {
-                diesel::insert_into(user::table)
-                    .values(&new_user)
-                    .execute(&conn)?;
-
-                let last_id = diesel::select(db::last_insert_id).get_result::<u64>(&conn)?;
-
-                let user_auths: model::NewUserAuths =
-                    From::<(model::QueryNewUser, u64)>::from((user, last_id));
-
-                diesel::insert_into(user_auth::table)
-                    .values(&user_auths.0[..])
-                    .execute(&conn)?;
-
-                Ok(())
-            })
-        })
-        .await;
-
-        let resp = f_result
-            .map(Into::<UnifiedResponseMessages<()>>::into)
-            .unwrap_or_else(|e| {
-                UnifiedResponseMessages::<()>::error().customized_error_msg(e.to_string())
-            });
-        return Json(resp);
-    }
-
-    Json(UnifiedResponseMessages::<()>::error())
+    Json(Into::<UnifiedResponseMessages<()>>::into(
+        db::blocking(&storage, move |storage| storage.create_user(user)).await,
+    ))
 }
 
 #[handler]
 
 async fn show_users(
     Json(query_params): Json<model::QueryParamsUser>,
-    pool: Data<&Arc<db::ConnectionPool>>,
+    storage: Data<&Arc<dyn db::Storage>>,
 ) -> impl IntoResponse {
-    if let Ok(conn) = pool.get() {
-        let f_result = spawn_blocking::<_, Result<_, diesel::result::Error>>(move || {
-            let query_builder = model::UserQueryBuilder::query_all_columns();
-
-            let users = query_params
-                .clone()
-                .query_filter(query_builder)
-                .paginate(query_params.page)
-                .set_per_page(query_params.per_page)
-                .load::<model::User>(&conn)?;
-
-            let per_page = query_params.per_page;
-            let count_builder = model::UserQueryBuilder::query_count();
-            let count = query_params
-                .query_filter(count_builder)
-                .get_result::<i64>(&conn)?;
-
-            Ok(PaginateData::<model::User>::default()
-                .set_data_source(users)
-                .set_page_size(per_page)
-                .set_total(count))
-        })
-        .await;
-
-        let page = f_result
-            .map(|page_result| {
-                Into::<UnifiedResponseMessages<PaginateData<model::User>>>::into(page_result)
-            })
-            .unwrap_or_else(|e| {
-                UnifiedResponseMessages::<PaginateData<model::User>>::error()
-                    .customized_error_msg(e.to_string())
-            });
-        return Json(page);
-    }
-
-    Json(UnifiedResponseMessages::<PaginateData<model::User>>::error())
+    let page: UnifiedResponseMessages<PaginateData<model::User>> =
+        db::blocking(&storage, move |storage| storage.list_users(query_params))
+            .await
+            .into();
+    Json(page)
 }
 
 #[handler]
@@ -122,27 +61,17 @@ async fn show_users(
 async fn update_user(
     req: &Request,
     Json(user_value): Json<model::UpdateUser>,
-    pool: Data<&Arc<db::ConnectionPool>>,
+    storage: Data<&Arc<dyn db::Storage>>,
 ) -> impl IntoResponse {
     let operation_log_pair_option =
         generate_operation_user_modify_log(req.get_session(), &user_value).ok();
     send_option_operation_log_pair(operation_log_pair_option).await;
 
-    if let Ok(conn) = pool.get() {
-        let f_result = spawn_blocking::<_, Result<_, diesel::result::Error>>(move || {
-            diesel::update(&user_value).set(&user_value).execute(&conn)
-        })
-        .await;
-
-        let count = f_result
-            .map(Into::<UnifiedResponseMessages<usize>>::into)
-            .unwrap_or_else(|e| {
-                UnifiedResponseMessages::<usize>::error().customized_error_msg(e.to_string())
-            });
-        return Json(count);
-    }
-
-    Json(UnifiedResponseMessages::<usize>::error())
+    let count: UnifiedResponseMessages<usize> =
+        db::blocking(&storage, move |storage| storage.update_user(user_value))
+            .await
+            .into();
+    Json(count)
 }
 
 #[handler]
@@ -150,83 +79,38 @@ async fn update_user(
 async fn change_password(
     req: &Request,
     Json(user_value): Json<model::UserChangePassword>,
-    pool: Data<&Arc<db::ConnectionPool>>,
+    storage: Data<&Arc<dyn db::Storage>>,
 ) -> impl IntoResponse {
     let session = req.get_session();
-    let user_id = session.get::<u64>("user_id");
-
-    if user_id.is_none() {
-        return Json(UnifiedResponseMessages::<usize>::error());
-    }
-
-    if let Ok(conn) = pool.get() {
-        let f_result = spawn_blocking::<_, Result<_, diesel::result::Error>>(move || {
-            let user_auth_id =
-                user_auth::table
-                    .select(user_auth::id)
-                    .filter(user_auth::user_id.eq(&user_id.expect("")))
-                    .filter(user_auth::identity_type.eq(user_value.identity_type))
-                    .filter(user_auth::certificate.eq(
-                        get_encrypted_certificate_by_raw_certificate(&user_value.current_password),
-                    ))
-                    .first::<i64>(&conn)?;
-
-            diesel::update(user_auth::table.find(user_auth_id))
-                .set(
-                    user_auth::certificate.eq(get_encrypted_certificate_by_raw_certificate(
-                        &user_value.modified_password,
-                    )),
-                )
-                .execute(&conn)
-        })
-        .await;
-
-        let count = f_result
-            .map(Into::<UnifiedResponseMessages<usize>>::into)
-            .unwrap_or_else(|e| {
-                UnifiedResponseMessages::<usize>::error().customized_error_msg(e.to_string())
-            });
-
-        return Json(count);
-    }
+    let user_id = match session.get::<i64>("user_id") {
+        Some(user_id) => user_id,
+        None => return Json(UnifiedResponseMessages::<usize>::error()),
+    };
 
-    Json(UnifiedResponseMessages::<usize>::error())
+    let count: UnifiedResponseMessages<usize> = db::blocking(&storage, move |storage| {
+        storage.change_password(user_id, user_value)
+    })
+    .await
+    .into();
+    Json(count)
 }
 #[handler]
 
 async fn delete_user(
     req: &Request,
     Json(model::UserId { user_id }): Json<model::UserId>,
-    pool: Data<&Arc<db::ConnectionPool>>,
+    storage: Data<&Arc<dyn db::Storage>>,
 ) -> impl IntoResponse {
     let operation_log_pair_option = generate_operation_user_delete_log(
         req.get_session(),
-        &CommonTableRecord::default().set_id(user_id as i64),
+        &CommonTableRecord::default().set_id(user_id),
     )
     .ok();
     send_option_operation_log_pair(operation_log_pair_option).await;
 
-    if let Ok(conn) = pool.get() {
-        let f_result = spawn_blocking::<_, Result<_, diesel::result::Error>>(move || {
-            conn.transaction(|| {
-                diesel::delete(user::table.filter(user::id.eq(user_id))).execute(&conn)?;
-                diesel::delete(user_auth::table.filter(user_auth::user_id.eq(user_id)))
-                    .execute(&conn)?;
-
-                Ok(())
-            })
-        })
-        .await;
-
-        let resp = f_result
-            .map(Into::<UnifiedResponseMessages<()>>::into)
-            .unwrap_or_else(|e| {
-                UnifiedResponseMessages::<()>::error().customized_error_msg(e.to_string())
-            });
-        return Json(resp);
-    }
-
-    Json(UnifiedResponseMessages::<()>::error())
+    Json(Into::<UnifiedResponseMessages<()>>::into(
+        db::blocking(&storage, move |storage| storage.delete_user(user_id)).await,
+    ))
 }
 
 #[handler]
@@ -234,22 +118,18 @@ async fn delete_user(
 async fn login_user(
     req: &Request,
     Json(user_login): Json<model::UserAuthLogin>,
-    pool: Data<&Arc<db::ConnectionPool>>,
+    storage: Data<&Arc<dyn db::Storage>>,
 ) -> impl IntoResponse {
     let login_result: UnifiedResponseMessages<()> =
-        pre_login_user(req, user_login, pool).await.into();
+        pre_login_user(req, user_login, &storage).await.into();
 
     Json(login_result)
 }
 
 async fn pre_login_user(
     req: &Request,
-    model::UserAuthLogin {
-        login_type,
-        account,
-        password,
-    }: model::UserAuthLogin,
-    pool: Data<&Arc<db::ConnectionPool>>,
+    user_login: model::UserAuthLogin,
+    storage: &Arc<dyn db::Storage>,
 ) -> Result<(), CommonError> {
     let client_ip = req
         .remote_addr()
@@ -258,45 +138,12 @@ async fn pre_login_user(
     let mut new_user_login_log = NewUserLoginLog::default();
     new_user_login_log
         .set_lastip(client_ip)
-        .set_login_type(login_type);
-
-    let conn = pool.get()?;
-    let user_package: (model::UserAuth, model::User) =
-        spawn_blocking::<_, Result<_, diesel::result::Error>>(move || {
-            let login_result = user_auth::table
-                .inner_join(user::table)
-                .select((user_auth::all_columns, user::all_columns))
-                .filter(user_auth::identity_type.eq(login_type))
-                .filter(user_auth::identifier.eq(&account))
-                .filter(
-                    user_auth::certificate
-                        .eq(get_encrypted_certificate_by_raw_certificate(&password)),
-                )
-                .first::<(model::UserAuth, model::User)>(&conn);
-
-            login_result
-                .as_ref()
-                .map(|(_, user)| {
-                    new_user_login_log
-                        .set_user_name(user.user_name.clone())
-                        .set_user_id(user.id)
-                        .set_command(state::user_login_log::LoginCommand::LoginSuccess as u8);
-                })
-                .map_err(|_| {
-                    new_user_login_log
-                        .set_user_name(account)
-                        .set_command(state::user_login_log::LoginCommand::Logoutfailure as u8);
-                })
-                .ok();
-
-            diesel::insert_into(user_login_log::table)
-                .values(&new_user_login_log)
-                .execute(&conn)
-                .ok();
-
-            login_result
-        })
-        .await??;
+        .set_login_type(user_login.login_type);
+
+    let user_package: (model::UserAuth, model::User) = db::blocking(storage, move |storage| {
+        storage.login_user(user_login, new_user_login_log)
+    })
+    .await?;
     save_session(req, user_package)
 }
 
@@ -316,8 +163,8 @@ fn save_session(
 
 #[handler]
 
-async fn check_user(req: &Request, pool: Data<&Arc<db::ConnectionPool>>) -> impl IntoResponse {
-    let check_result = pre_check_user(req, pool).await;
+async fn check_user(req: &Request, storage: Data<&Arc<dyn db::Storage>>) -> impl IntoResponse {
+    let check_result = pre_check_user(req, &storage).await;
     if let Ok(user) = check_result {
         return Json(UnifiedResponseMessages::<model::User>::success_with_data(
             user,
@@ -334,25 +181,14 @@ async fn check_user(req: &Request, pool: Data<&Arc<db::ConnectionPool>>) -> impl
 
 async fn pre_check_user(
     req: &Request,
-    pool: Data<&Arc<db::ConnectionPool>>,
+    storage: &Arc<dyn db::Storage>,
 ) -> Result<model::User, CommonError> {
     let session = req.get_session();
-    let conn = pool.get()?;
     let user_id = session
-        .get::<u64>("user_id")
+        .get::<i64>("user_id")
         .ok_or_else(|| CommonError::DisPass("Without set `user_id` .".into()))?;
 
-    let user = spawn_blocking::<_, Result<_, diesel::result::Error>>(move || {
-        let user = user::table
-            .select(user::all_columns)
-            .find(user_id)
-            .first::<model::User>(&conn)?;
-
-        Ok(user)
-    })
-    .await??;
-
-    Ok(user)
+    db::blocking(storage, move |storage| storage.find_user(user_id)).await
 }
 
 #[handler]
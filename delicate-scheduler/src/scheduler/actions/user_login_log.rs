@@ -0,0 +1,19 @@
+use super::prelude::*;
+
+pub(crate) fn route_config() -> Route {
+    Route::new().at("/api/user_login_log/list", post(show_user_login_log))
+}
+
+#[handler]
+async fn show_user_login_log(
+    Json(query_params): Json<model::QueryParamsUserLoginLog>,
+    storage: Data<&Arc<dyn db::Storage>>,
+) -> impl IntoResponse {
+    let page: UnifiedResponseMessages<PaginateData<model::FrontEndUserLoginLog>> =
+        db::blocking(&storage, move |storage| {
+            storage.list_user_login_logs(query_params)
+        })
+        .await
+        .into();
+    Json(page)
+}
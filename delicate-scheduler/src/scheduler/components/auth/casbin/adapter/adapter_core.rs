@@ -1,23 +1,36 @@
-use super::actions as adapter;
-use crate::db::model::casbin_rule::*;
-use crate::prelude::*;
+use super::prelude::*;
+use db::model::casbin_rule::*;
 
 use async_trait::async_trait;
 use casbin::{error::AdapterError, Adapter, Error as CasbinError, Filter, Model};
 
-pub struct DieselAdapter {
-    pool: Arc<db::ConnectionPool>,
+/// The casbin adapter on the storage of the scheduler.
+pub struct StorageAdapter {
+    storage: Arc<dyn db::Storage>,
     is_filtered: bool,
 }
 
-impl DieselAdapter {
+impl StorageAdapter {
     #[allow(dead_code)]
-    pub fn new(pool: Arc<db::ConnectionPool>) -> Self {
+    pub fn new(storage: Arc<dyn db::Storage>) -> Self {
         Self {
-            pool,
+            storage,
             is_filtered: false,
         }
     }
+
+    // Run the calls on the storage in the blocking thread pool.
+    async fn blocking<T, F>(&self, f: F) -> Result<T, CasbinError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&dyn db::Storage) -> AuthServiceResult<T> + Send + 'static,
+    {
+        let storage = self.storage.clone();
+        Ok(spawn_blocking(move || f(storage.as_ref()))
+            .await
+            .map_err(|e| AdapterError(Box::new(e)))?
+            .map_err(|e| AdapterError(Box::new(e)))?)
+    }
 }
 
 pub(crate) fn save_policy_line(ptype: &str, rule: &[String]) -> Option<NewCasbinRule> {
@@ -113,18 +126,11 @@ fn normalize_policy(casbin_rule: &CasbinRule) -> Option<Vec<String>> {
 }
 
 #[async_trait]
-impl Adapter for DieselAdapter {
-    async fn load_policy(&self, m: &mut dyn Model) -> Result<(), CasbinError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
-
-        let rules =
-            spawn_blocking::<_, Result<_, AuthServiceError>>(move || adapter::load_policy(conn))
-                .await
-                .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-                .map_err(|e| AdapterError(Box::new(e)))?;
+impl Adapter for StorageAdapter {
+    async fn load_policy(&mut self, m: &mut dyn Model) -> Result<(), CasbinError> {
+        let rules = self
+            .blocking(move |storage| storage.load_casbin_rules())
+            .await?;
 
         for casbin_rule in &rules {
             let rule = load_policy_line(casbin_rule);
@@ -144,17 +150,8 @@ impl Adapter for DieselAdapter {
     }
 
     async fn clear_policy(&mut self) -> Result<(), CasbinError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
-
-        Ok(
-            spawn_blocking::<_, Result<_, AuthServiceError>>(move || adapter::clear_policy(conn))
-                .await
-                .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-                .map_err(|e| AdapterError(Box::new(e)))?,
-        )
+        self.blocking(move |storage| storage.clear_casbin_rules())
+            .await
     }
 
     async fn load_filtered_policy<'a>(
@@ -162,16 +159,9 @@ impl Adapter for DieselAdapter {
         m: &mut dyn Model,
         f: Filter<'a>,
     ) -> Result<(), CasbinError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
-
-        let rules =
-            spawn_blocking::<_, Result<_, AuthServiceError>>(move || adapter::load_policy(conn))
-                .await
-                .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-                .map_err(|e| AdapterError(Box::new(e)))?;
+        let rules = self
+            .blocking(move |storage| storage.load_casbin_rules())
+            .await?;
 
         for casbin_rule in &rules {
             let rule = load_filtered_policy_line(casbin_rule, &f);
@@ -194,11 +184,6 @@ impl Adapter for DieselAdapter {
     }
 
     async fn save_policy(&mut self, m: &mut dyn Model) -> Result<(), CasbinError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
-
         let mut rules = vec![];
 
         if let Some(ast_map) = m.get_model().get("p") {
@@ -223,12 +208,8 @@ impl Adapter for DieselAdapter {
             }
         }
 
-        Ok(spawn_blocking::<_, Result<_, AuthServiceError>>(move || {
-            adapter::save_policy(conn, rules)
-        })
-        .await
-        .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-        .map_err(|e| AdapterError(Box::new(e)))?)
+        self.blocking(move |storage| storage.save_casbin_rules(rules))
+            .await
     }
 
     async fn add_policy(
@@ -237,21 +218,15 @@ impl Adapter for DieselAdapter {
         ptype: &str,
         rule: Vec<String>,
     ) -> Result<bool, CasbinError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
         let ptype_c = ptype.to_string();
 
-        Ok(spawn_blocking::<_, Result<_, AuthServiceError>>(move || {
+        self.blocking(move |storage| {
             if let Some(new_rule) = save_policy_line(&ptype_c, &rule) {
-                return adapter::add_policy(conn, new_rule);
+                return storage.add_casbin_rule(new_rule);
             }
             Ok(false)
         })
         .await
-        .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-        .map_err(|e| AdapterError(Box::new(e)))?)
     }
 
     async fn add_policies(
@@ -260,22 +235,16 @@ impl Adapter for DieselAdapter {
         ptype: &str,
         rules: Vec<Vec<String>>,
     ) -> Result<bool, CasbinError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
         let ptype_c = ptype.to_string();
 
-        Ok(spawn_blocking::<_, Result<_, AuthServiceError>>(move || {
+        self.blocking(move |storage| {
             let new_rules = rules
                 .iter()
                 .filter_map(|x: &Vec<String>| save_policy_line(&ptype_c, x))
                 .collect::<Vec<NewCasbinRule>>();
-            adapter::add_policies(conn, new_rules)
+            storage.add_casbin_rules(new_rules)
         })
         .await
-        .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-        .map_err(|e| AdapterError(Box::new(e)))?)
     }
 
     async fn remove_policy(
@@ -284,18 +253,10 @@ impl Adapter for DieselAdapter {
         pt: &str,
         rule: Vec<String>,
     ) -> Result<bool, CasbinError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
         let ptype_c = pt.to_string();
 
-        Ok(spawn_blocking::<_, Result<_, AuthServiceError>>(move || {
-            adapter::remove_policy(conn, &ptype_c, rule)
-        })
-        .await
-        .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-        .map_err(|e| AdapterError(Box::new(e)))?)
+        self.blocking(move |storage| storage.remove_casbin_rule(&ptype_c, rule))
+            .await
     }
 
     async fn remove_policies(
@@ -304,18 +265,10 @@ impl Adapter for DieselAdapter {
         pt: &str,
         rules: Vec<Vec<String>>,
     ) -> Result<bool, CasbinError> {
-        let conn = self
-            .pool
-            .get()
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
         let ptype_c = pt.to_string();
 
-        Ok(spawn_blocking::<_, Result<_, AuthServiceError>>(move || {
-            adapter::remove_policies(conn, &ptype_c, rules)
-        })
-        .await
-        .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-        .map_err(|e| AdapterError(Box::new(e)))?)
+        self.blocking(move |storage| storage.remove_casbin_rules(&ptype_c, rules))
+            .await
     }
 
     async fn remove_filtered_policy(
@@ -326,18 +279,12 @@ impl Adapter for DieselAdapter {
         field_values: Vec<String>,
     ) -> Result<bool, CasbinError> {
         if field_index <= 5 && !field_values.is_empty() {
-            let conn = self
-                .pool
-                .get()
-                .map_err(|e| casbin::error::AdapterError(Box::new(e)))?;
             let ptype_c = pt.to_string();
 
-            Ok(spawn_blocking::<_, Result<_, AuthServiceError>>(move || {
-                adapter::remove_filtered_policy(conn, &ptype_c, field_index, field_values)
+            self.blocking(move |storage| {
+                storage.remove_filtered_casbin_rules(&ptype_c, field_index, field_values)
             })
             .await
-            .map_err(|e| casbin::error::AdapterError(Box::new(e)))?
-            .map_err(|e| AdapterError(Box::new(e)))?)
         } else {
             Ok(false)
         }
@@ -359,8 +306,9 @@ mod tests {
 
     #[allow(dead_code)]
     async fn test_adapter() {
-        use crate::prelude::*;
+        use super::prelude::*;
         use casbin::prelude::*;
+        use delicate_utils::conf::load_config;
 
         let file_adapter = FileAdapter::new("examples/rbac_policy.csv");
 
@@ -369,9 +317,10 @@ mod tests {
             .unwrap();
 
         let mut e = Enforcer::new(m, file_adapter).await.unwrap();
-        let mut adapter = DieselAdapter::new({
-            let connection_pool = db::get_connection_pool();
-            Arc::new(connection_pool)
+        let mut adapter = StorageAdapter::new({
+            let conf = load_config::<SchedulerConfig>(SCHEDULER_CONFIG_FILE)
+                .expect("The configuration is invalid.");
+            crate::backends::open_storage(&conf).expect("The database is not supported.")
         });
 
         assert!(adapter.save_policy(e.get_mut_model()).await.is_ok());
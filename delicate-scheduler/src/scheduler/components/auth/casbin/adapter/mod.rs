@@ -0,0 +1,3 @@
+pub(crate) use super::prelude;
+
+pub mod adapter_core;
@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use super::prelude::*;
 use futures::StreamExt as _;
 use redis::aio::Connection;
 use redis::{AsyncCommands, RedisResult};
@@ -210,7 +210,7 @@ pub(crate) async fn publish_casbin_rule_events(mut publish_conn: Connection) ->
         // Serialize the _casbin_event ,then published it.
         if let Ok(msg) = to_json_string(&casbin_event) {
             publish_conn
-                .publish("delicate:auth:casbin:rules:sync", &msg)
+                .publish::<_, _, ()>("delicate:auth:casbin:rules:sync", &msg)
                 .await?;
         }
     }
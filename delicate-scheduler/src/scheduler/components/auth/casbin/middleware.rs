@@ -1,41 +1,21 @@
 #![allow(unused_imports)]
-use crate::prelude::*;
-use adapter::adapter_core::DieselAdapter;
+use super::prelude::*;
+use adapter::adapter_core::StorageAdapter;
+use casbin::DefaultModel;
+use std::path::Path;
 
-lazy_static! {
+// TODO: `redis` based publish-subscribe, do real-time permission information synchronization.
 
-      // Because casbin(`Enforcer::new`) requires that the `&str` type must satisfy static.
-      // And the String read by environment variable does not satisfy this condition after passing deref.
-      // Two ways to solve it.
-      // 1. Active memory leak.
-      // 2. Assign the value read by environment variable to static variable,
-      // Then the reference of static variable can satisfy the static restriction.
+// TODO: Adjustments for permissions, like the operation log consumer, have a unique channel that
+// TODO: holds information about the operation and controls the flow of consumption.
 
-    pub static ref CASBIN_MODEL_CONF_PATH: String = {
-            env::var("CASBIN_MODEL_CONF").expect("CASBIN_MODEL_CONF must be set")
-    };
-
-
-    // TODO: Can be listened to by `hotwatch` for changes.
-    // TODO: `redis` based publish-subscribe, do real-time permission information synchronization.
-
-    // TODO: Adjustments for permissions, like the operation log consumer, have a unique channel that
-    // TODO: holds information about the operation and controls the flow of consumption.
-
-    // TODO: Permissions editing is optional and
-    // TODO: By default only supports initialization from the file once and does not support editing if it is not selected.
-
-    pub static ref CASBIN_POLICY_CONF_PATH: String = {
-            env::var("CASBIN_POLICY_CONF").expect("CASBIN_POLICY_CONF must be set")
-    };
-
-
-}
+// TODO: Permissions editing is optional and
+// TODO: By default only supports initialization from the file once and does not support editing if it is not selected.
 
 pub(crate) struct CasbinGuard;
 
 impl CasbinWatcher for CasbinGuard {
-    fn set_update_callback(&mut self, _cb: Box<dyn FnMut() + Send + Sync>) {
+    fn set_update_callback(&mut self, _cb: Box<dyn FnMut(String) + Send + Sync>) {
         error!(target:"set_update_callback", "unreachable.");
     }
 
@@ -46,9 +26,15 @@ impl CasbinWatcher for CasbinGuard {
 }
 
 #[allow(dead_code)]
-pub(crate) async fn get_casbin_enforcer(pool: Arc<db::ConnectionPool>) -> Enforcer {
-    let adapter = DieselAdapter::new(pool);
-    let mut enforcer = Enforcer::new(get_casbin_model_conf_path(), adapter)
+pub(crate) async fn get_casbin_enforcer(
+    storage: Arc<dyn db::Storage>,
+    model_conf_path: &Path,
+) -> Enforcer {
+    let adapter = StorageAdapter::new(storage);
+    let model = DefaultModel::from_file(model_conf_path)
+        .await
+        .expect("Casbin's model initialization error.");
+    let mut enforcer = Enforcer::new(model, adapter)
         .await
         .expect("Casbin's enforcer initialization error.");
 
@@ -57,19 +43,20 @@ pub(crate) async fn get_casbin_enforcer(pool: Arc<db::ConnectionPool>) -> Enforc
     enforcer
 }
 
-#[allow(dead_code)]
-pub(crate) fn get_casbin_model_conf_path() -> &'static str {
-    CASBIN_MODEL_CONF_PATH.deref().deref()
-}
-
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
-pub(crate) struct CasbinService;
+pub(crate) struct CasbinService {
+    // Debug switch (For Developers), the permissions are not checked.
+    pub(crate) debug: bool,
+}
 
 impl<E: Endpoint> Middleware<E> for CasbinService {
     type Output = CasbinAuthMiddleware<E>;
 
     fn transform(&self, ep: E) -> Self::Output {
-        CasbinAuthMiddleware { ep }
+        CasbinAuthMiddleware {
+            ep,
+            debug: self.debug,
+        }
     }
 }
 
@@ -77,6 +64,7 @@ impl<E: Endpoint> Middleware<E> for CasbinService {
 
 pub struct CasbinAuthMiddleware<E> {
     ep: E,
+    debug: bool,
 }
 
 const WHITE_LIST: [&str; 9] = [
@@ -114,8 +102,7 @@ impl<E: Endpoint> Endpoint for CasbinAuthMiddleware<E> {
             return Ok(self.ep.call(req).await?.into_response());
         }
 
-        #[cfg(APP_DEBUG_MODE)]
-        {
+        if self.debug {
             return Ok(self.ep.call(req).await?.into_response());
         }
 
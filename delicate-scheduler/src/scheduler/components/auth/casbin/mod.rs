@@ -1,3 +1,5 @@
+pub(crate) use super::prelude;
+
 pub(crate) mod adapter;
 pub(crate) mod casbin_event_consumer;
 pub(crate) mod middleware;
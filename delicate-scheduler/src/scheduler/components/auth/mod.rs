@@ -0,0 +1,3 @@
+pub(crate) use super::prelude;
+
+pub(crate) mod casbin;
@@ -0,0 +1,52 @@
+use super::prelude::*;
+use delicate_utils::error::InitSchedulerError;
+use security::SchedulerSecurityConf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SchedulerMetaInfo {
+    name: String,
+    domain: String,
+    listening_address: String,
+    security_conf: SchedulerSecurityConf,
+}
+
+impl SchedulerMetaInfo {
+    pub(crate) fn get_app_name(&self) -> &String {
+        &self.name
+    }
+
+    pub(crate) fn get_app_host_name(&self) -> &String {
+        &self.domain
+    }
+
+    #[allow(dead_code)]
+    pub(crate) fn get_app_security_level(&self) -> SecurityLevel {
+        self.security_conf.security_level
+    }
+
+    #[allow(dead_code)]
+    pub(crate) fn get_app_security_conf(&self) -> &SchedulerSecurityConf {
+        &self.security_conf
+    }
+
+    pub(crate) fn get_app_security_key(&self) -> Option<&RSAPrivateKey> {
+        self.security_conf.rsa_private_key.as_ref().map(|k| &k.0)
+    }
+}
+
+impl SchedulerMetaInfo {
+    pub(crate) fn new(conf: &SchedulerConfig) -> Result<Self, InitSchedulerError> {
+        let security_conf = SchedulerSecurityConf::new(
+            conf.scheduler_cookie_domain.clone(),
+            conf.security_level(),
+            conf.security_private_key.as_deref(),
+        )?;
+
+        Ok(SchedulerMetaInfo {
+            name: conf.scheduler_name.clone(),
+            domain: conf.scheduler_domain.clone(),
+            listening_address: conf.scheduler_listening_address.clone(),
+            security_conf,
+        })
+    }
+}
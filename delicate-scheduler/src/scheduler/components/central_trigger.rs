@@ -0,0 +1,202 @@
+use super::prelude::*;
+use delicate_utils_task::{TaskPackage, TaskTrigger};
+use model::BindProcessor;
+use state::task::{RoutingStrategy, State as TaskState, TriggerMode};
+
+/// The tasks whose schedule is evaluated by the scheduler itself, in its own `DelayTimer`.
+///
+/// On each trigger the members are routed once, and the chosen ones get a one-shot run,
+/// So a run follows one clock and is decided in one place whatever the number of executors.
+/// Every scheduler keeps the schedules so that a new leader takes over at once,
+/// But only the leader sends the runs.
+pub(crate) struct CentralTrigger {
+    delay_timer: Arc<DelayTimer>,
+    context: Arc<TriggerContext>,
+    // The (frequency, cron-expression, utc-offset) each task was added with, it is rebuilt when they change.
+    // The offset only moves for the tasks in named time zones, across daylight saving transitions.
+    schedules: RwLock<HashMap<i64, (String, String, Option<i32>)>>,
+}
+
+struct TriggerContext {
+    storage: Arc<dyn db::Storage>,
+    request_client: RequestClient,
+    router: Arc<ExecutorRouter>,
+    election: Arc<LeaderElection>,
+}
+
+impl CentralTrigger {
+    pub(crate) fn new(
+        delay_timer: Arc<DelayTimer>,
+        storage: Arc<dyn db::Storage>,
+        request_client: RequestClient,
+        router: Arc<ExecutorRouter>,
+        election: Arc<LeaderElection>,
+    ) -> CentralTrigger {
+        CentralTrigger {
+            delay_timer,
+            context: Arc::new(TriggerContext {
+                storage,
+                request_client,
+                router,
+                election,
+            }),
+            schedules: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Make the schedules match the enabled tasks triggered by the scheduler.
+    pub(crate) async fn sync(&self) -> Result<(), CommonError> {
+        let task_packages: Vec<TaskPackage> = db::blocking(&self.context.storage, move |storage| {
+            let schedules = storage.central_task_schedules()?;
+
+            Ok(schedules
+                .into_iter()
+                .map(|(id, frequency, cron_expression)| TaskPackage {
+                    id,
+                    frequency,
+                    cron_expression,
+                    ..Default::default()
+                })
+                .collect())
+        })
+        .await?;
+
+        let mut schedules = self.schedules.write().await;
+
+        let task_ids: HashSet<i64> = task_packages.iter().map(|t| t.id).collect();
+        let removed_task_ids: Vec<i64> = schedules
+            .keys()
+            .filter(|task_id| !task_ids.contains(task_id))
+            .copied()
+            .collect();
+        for task_id in removed_task_ids {
+            self.delay_timer
+                .remove_task(task_id as u64)
+                .map_err(|e| error!(target:"central-trigger", "Failed to remove the schedule of task {}: {}", task_id, e))
+                .ok();
+            schedules.remove(&task_id);
+        }
+
+        for task_package in task_packages {
+            let task_id = task_package.id;
+            let schedule = (
+                task_package.frequency.clone(),
+                task_package.cron_expression.clone(),
+                task_package.schedule_offset().ok().flatten(),
+            );
+            let scheduled = schedules.get(&task_id);
+            if scheduled == Some(&schedule) {
+                continue;
+            }
+
+            let context = self.context.clone();
+            let result = task_package
+                .build_schedule_task(move || trigger_task(context.clone(), task_id))
+                .and_then(|task| {
+                    if scheduled.is_some() {
+                        Ok(self.delay_timer.update_task(task)?)
+                    } else {
+                        Ok(self.delay_timer.add_task(task)?)
+                    }
+                });
+
+            match result {
+                Ok(_) => {
+                    schedules.insert(task_id, schedule);
+                }
+                Err(e) => {
+                    error!(target:"central-trigger", "Failed to schedule task {}: {}", task_id, e)
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run a task triggered by the scheduler once, now.
+    pub(crate) async fn trigger_now(&self, task_id: i64) -> Result<(), CommonError> {
+        trigger_run(&self.context, task_id).await
+    }
+}
+
+pub(crate) async fn loop_sync_central_tasks(central_trigger: Arc<CentralTrigger>) {
+    // The tasks changed through another scheduler are picked up here.
+    let mut interval = interval(Duration::from_secs(30));
+    loop {
+        interval.tick().await;
+        central_trigger
+            .sync()
+            .await
+            .map_err(|e| error!(target:"loop-sync-central-tasks", "{}", e.to_string()))
+            .ok();
+    }
+}
+
+async fn trigger_task(context: Arc<TriggerContext>, task_id: i64) {
+    if !context.election.is_leader() {
+        return;
+    }
+
+    trigger_run(&context, task_id)
+        .await
+        .map_err(|e| error!(target:"central-trigger", "Failed to trigger task {}: {}", task_id, e))
+        .ok();
+}
+
+async fn trigger_run(context: &TriggerContext, task_id: i64) -> Result<(), CommonError> {
+    let (task_package, routing_strategy, members): (TaskPackage, i16, Vec<BindProcessor>) =
+        db::blocking(&context.storage, move |storage| {
+            let (task_package, _, routing_strategy, _) = storage
+                .task_packages(vec![task_id])?
+                .into_iter()
+                .find(|(_, status, _, trigger_mode)| {
+                    *status == TaskState::Enabled as i16
+                        && *trigger_mode == TriggerMode::Scheduler as i16
+                })
+                .ok_or_else(|| {
+                    CommonError::DisPass(format!(
+                        "Task {} is not enabled or not triggered by the scheduler.",
+                        task_id
+                    ))
+                })?;
+
+            let members = storage
+                .task_members(vec![task_id], true)?
+                .into_iter()
+                .map(|(_, member)| member)
+                .collect();
+
+            Ok((task_package, routing_strategy, members))
+        })
+        .await?;
+
+    let chosen_members = context
+        .router
+        .route(task_id, RoutingStrategy::from(routing_strategy), members)
+        .await?;
+
+    if chosen_members.is_empty() {
+        return Err(CommonError::DisPass(format!(
+            "No healthy member can run task {}.",
+            task_id
+        )));
+    }
+
+    for member in chosen_members {
+        info!(target:"central-trigger", "Trigger task{} at executor {}.", &task_package, member.executor_id);
+        let signed_task_trigger = TaskTrigger::default()
+            .set_task_package(task_package.clone())
+            .set_time(timestamp())
+            .sign(Some(&member.token))?;
+
+        send_to_executor(
+            &context.request_client,
+            &member.host,
+            "/api/task/trigger",
+            &signed_task_trigger,
+        )
+        .await;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use super::prelude::*;
+
+// The origins allowed by CORS can be changed on `SIGHUP`,
+// So the `Cors` middleware is built for each request from the origins in use.
+pub(crate) fn cors_middleware(reloadable_conf: watch::Receiver<ReloadableConf>) -> ReloadableCors {
+    ReloadableCors { reloadable_conf }
+}
+
+fn cors(cors_origins: &[String]) -> Cors {
+    cors_origins
+        .iter()
+        .fold(Cors::new(), |cors, origin| cors.allow_origin(origin))
+        .allow_method(HttpMethod::GET)
+        .allow_method(HttpMethod::POST)
+        .allow_method(HttpMethod::OPTIONS)
+        .allow_header("content-type")
+        .allow_credentials(true)
+        .max_age(3600)
+}
+
+pub struct ReloadableCors {
+    reloadable_conf: watch::Receiver<ReloadableConf>,
+}
+
+impl<E: Endpoint> Middleware<E> for ReloadableCors {
+    type Output = ReloadableCorsMiddleware<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        ReloadableCorsMiddleware {
+            ep: Arc::new(ep),
+            reloadable_conf: self.reloadable_conf.clone(),
+        }
+    }
+}
+
+pub struct ReloadableCorsMiddleware<E> {
+    ep: Arc<E>,
+    reloadable_conf: watch::Receiver<ReloadableConf>,
+}
+
+#[poem::async_trait]
+impl<E: Endpoint> Endpoint for ReloadableCorsMiddleware<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> PoemResult<Self::Output> {
+        let cors = cors(&self.reloadable_conf.borrow().cors_origins);
+
+        Ok(cors
+            .transform(self.ep.clone())
+            .call(req)
+            .await?
+            .into_response())
+    }
+}
@@ -0,0 +1,308 @@
+use super::prelude::*;
+use delicate_utils_task::{TaskPackage, TaskTrigger};
+use model::{BindProcessor, NewTaskDependencyTrigger, TaskDependency};
+use state::task::RoutingStrategy;
+use state::task_dependency::TriggerCondition;
+use state::task_log::State as TaskLogState;
+
+// A downstream task to run, with the members it is dispatched to.
+type TriggerPlan = Vec<(TaskPackage, i16, i64, Vec<BindProcessor>)>;
+
+// `edges` are (upstream-task-id, downstream-task-id) pairs,
+// Depending on an upstream task forms a cycle when that task is reachable from `task_id`.
+pub(crate) fn find_cycle(
+    edges: &[(i64, i64)],
+    task_id: i64,
+    upstream_task_ids: &[i64],
+) -> Option<i64> {
+    let mut downstream_tasks: HashMap<i64, Vec<i64>> = HashMap::new();
+    for (upstream_task_id, downstream_task_id) in edges {
+        downstream_tasks
+            .entry(*upstream_task_id)
+            .or_default()
+            .push(*downstream_task_id);
+    }
+
+    let mut reachable: HashSet<i64> = HashSet::new();
+    let mut pending: Vec<i64> = vec![task_id];
+    while let Some(current) = pending.pop() {
+        if !reachable.insert(current) {
+            continue;
+        }
+
+        if let Some(next) = downstream_tasks.get(&current) {
+            pending.extend(next.iter().copied());
+        }
+    }
+
+    upstream_task_ids
+        .iter()
+        .find(|upstream_task_id| reachable.contains(upstream_task_id))
+        .copied()
+}
+
+/// The dependencies reachable from the tasks, following them downstream.
+pub(crate) fn load_reachable_dependencies(
+    storage: &dyn db::Storage,
+    task_ids: Vec<i64>,
+) -> Result<Vec<TaskDependency>, CommonError> {
+    let mut visited: HashSet<i64> = task_ids.iter().copied().collect();
+    let mut frontier: Vec<i64> = task_ids;
+    let mut dependencies: Vec<TaskDependency> = Vec::new();
+
+    while !frontier.is_empty() {
+        let next_dependencies = storage.downstream_dependencies(frontier)?;
+
+        frontier = next_dependencies
+            .iter()
+            .map(|d| d.task_id)
+            .filter(|task_id| visited.insert(*task_id))
+            .collect();
+        dependencies.extend(next_dependencies);
+    }
+
+    Ok(dependencies)
+}
+
+/// Trigger the downstream tasks of the task-logs that just ended.
+///
+/// A success triggers the `Success` dependencies, and a failure that will not be retried
+/// Triggers the `Failure` dependencies. Each downstream task runs at most once per run,
+/// However many of its upstream tasks end in that run.
+pub(crate) async fn trigger_downstream_tasks(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    router: &ExecutorRouter,
+    ended_record_ids: Vec<i64>,
+) -> Result<(), CommonError> {
+    if ended_record_ids.is_empty() {
+        return Ok(());
+    }
+
+    let plan: TriggerPlan = db::blocking(storage, move |storage| {
+        let ended_logs = storage.ended_task_logs(ended_record_ids)?;
+
+        let upstream_task_ids: Vec<i64> = ended_logs.iter().map(|l| l.1).collect();
+        let dependencies = storage.downstream_dependencies(upstream_task_ids.clone())?;
+
+        if dependencies.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let retry_times: HashMap<i64, i16> = storage
+            .task_retry_times(upstream_task_ids)?
+            .into_iter()
+            .collect();
+
+        let new_triggers = plan_dependency_triggers(&ended_logs, &dependencies, &retry_times);
+
+        let downstream_task_ids: Vec<i64> = new_triggers.iter().map(|t| t.task_id).collect();
+        let downstream_tasks: HashMap<i64, (TaskPackage, i16)> = storage
+            .task_packages(downstream_task_ids.clone())?
+            .into_iter()
+            .filter(|(_, status, ..)| *status == state::task::State::Enabled as i16)
+            .map(|(task_package, _, routing_strategy, _)| {
+                (task_package.id, (task_package, routing_strategy))
+            })
+            .collect();
+
+        let mut members: HashMap<i64, Vec<BindProcessor>> = HashMap::new();
+        storage
+            .task_members(downstream_task_ids, true)?
+            .into_iter()
+            .for_each(|(task_id, member)| members.entry(task_id).or_default().push(member));
+
+        let mut plan: TriggerPlan = Vec::new();
+        for new_trigger in new_triggers {
+            let (task_package, routing_strategy) = match downstream_tasks.get(&new_trigger.task_id)
+            {
+                Some(downstream_task) => downstream_task.clone(),
+                None => continue,
+            };
+
+            // The unique key on (run-id, task-id) keeps a task from running twice in a run,
+            // Even when several scheduler instances receive the events.
+            let (task_id, run_id) = (new_trigger.task_id, new_trigger.run_id);
+            if !storage.insert_dependency_trigger(new_trigger)? {
+                continue;
+            }
+
+            let mut task_members = members.get(&task_id).cloned().unwrap_or_default();
+            task_members.extend(
+                storage
+                    .task_failover_processors(task_id)?
+                    .into_iter()
+                    .map(|(_, p)| p),
+            );
+
+            plan.push((task_package, routing_strategy, run_id, task_members));
+        }
+
+        Ok(plan)
+    })
+    .await?;
+
+    for (task_package, routing_strategy, run_id, members) in plan {
+        let task_id = task_package.id;
+        let is_routed = !matches!(
+            RoutingStrategy::from(routing_strategy),
+            RoutingStrategy::Broadcast | RoutingStrategy::Unknown
+        );
+
+        // A routed task runs once, on the member holding it when it is known.
+        let chosen_members: Vec<BindProcessor> = if is_routed {
+            let assignment = router.get_assignment(task_id).await?;
+            match members
+                .iter()
+                .find(|m| assignment.map_or(false, |bind_id| bind_id == m.bind_id))
+            {
+                Some(holder) => vec![holder.clone()],
+                None => router.pick_standby(members).await.into_iter().collect(),
+            }
+        } else {
+            members
+        };
+
+        if chosen_members.is_empty() {
+            error!(target:"dependency", "No healthy member can run task {} in run {}.", task_id, run_id);
+            continue;
+        }
+
+        for member in chosen_members {
+            info!(target:"dependency", "Trigger task{} in run {} at executor {}.", &task_package, run_id, member.executor_id);
+            match TaskTrigger::default()
+                .set_task_package(task_package.clone())
+                .set_run_id(run_id)
+                .set_time(timestamp())
+                .sign(Some(&member.token))
+            {
+                Ok(signed_task_trigger) => {
+                    send_to_executor(
+                        request_client,
+                        &member.host,
+                        "/api/task/trigger",
+                        &signed_task_trigger,
+                    )
+                    .await;
+                }
+                Err(e) => error!(target:"dependency", "{}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// The downstream tasks triggered by the ended records,
+// `ended_logs` are (record-id, task-id, status, attempt, run-id) tuples.
+fn plan_dependency_triggers(
+    ended_logs: &[(i64, i64, i16, i16, i64)],
+    dependencies: &[TaskDependency],
+    retry_times: &HashMap<i64, i16>,
+) -> Vec<NewTaskDependencyTrigger> {
+    let mut new_triggers: Vec<NewTaskDependencyTrigger> = Vec::new();
+    for &(record_id, task_id, status, attempt, run_id) in ended_logs {
+        let condition = match TaskLogState::from(status) {
+            TaskLogState::NormalEnding => TriggerCondition::Success,
+            // The failure branch waits for the last attempt.
+            _ if attempt > retry_times.get(&task_id).copied().unwrap_or_default() => {
+                TriggerCondition::Failure
+            }
+            _ => continue,
+        };
+
+        let run_id = if run_id != 0 { run_id } else { record_id };
+        new_triggers.extend(
+            dependencies
+                .iter()
+                .filter(|d| d.upstream_task_id == task_id)
+                .filter(|d| TriggerCondition::from(d.trigger_condition) == condition)
+                .map(|d| NewTaskDependencyTrigger {
+                    run_id,
+                    task_id: d.task_id,
+                    upstream_task_id: task_id,
+                    upstream_record_id: record_id,
+                }),
+        );
+    }
+
+    new_triggers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 1 -> 2 -> 3
+        let edges = [(1, 2), (2, 3)];
+
+        assert_eq!(find_cycle(&edges, 1, &[3]), Some(3));
+        assert_eq!(find_cycle(&edges, 4, &[3]), None);
+        assert_eq!(find_cycle(&edges, 3, &[3]), Some(3));
+        assert_eq!(find_cycle(&edges, 3, &[1]), None);
+    }
+
+    // 1 -> 2 on success, 1 -> 3 on failure, task 1 is retried twice.
+    fn plan(ended_logs: &[(i64, i64, i16, i16, i64)]) -> Vec<(i64, i64, i64, i64)> {
+        let dependencies: Vec<TaskDependency> = [
+            (2, TriggerCondition::Success),
+            (3, TriggerCondition::Failure),
+        ]
+        .iter()
+        .map(|&(task_id, condition)| TaskDependency {
+            id: task_id,
+            task_id,
+            upstream_task_id: 1,
+            trigger_condition: condition as i16,
+            created_time: Local::now().naive_local(),
+        })
+        .collect();
+        let retry_times: HashMap<i64, i16> = [(1, 2)].iter().copied().collect();
+
+        plan_dependency_triggers(ended_logs, &dependencies, &retry_times)
+            .into_iter()
+            .map(|t| {
+                (
+                    t.run_id,
+                    t.task_id,
+                    t.upstream_task_id,
+                    t.upstream_record_id,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_dependency_triggers_on_success() {
+        let ended_logs = [(10, 1, TaskLogState::NormalEnding as i16, 1, 5)];
+
+        assert_eq!(plan(&ended_logs), vec![(5, 2, 1, 10)]);
+    }
+
+    #[test]
+    fn test_plan_dependency_triggers_on_failure() {
+        for status in [TaskLogState::AbnormalEnding, TaskLogState::TimeoutEnding] {
+            let status = status as i16;
+
+            // The failure branch waits for the last attempt.
+            assert_eq!(plan(&[(10, 1, status, 1, 5)]), vec![]);
+            assert_eq!(plan(&[(11, 1, status, 2, 5)]), vec![]);
+            assert_eq!(plan(&[(12, 1, status, 3, 5)]), vec![(5, 3, 1, 12)]);
+        }
+    }
+
+    #[test]
+    fn test_plan_dependency_triggers_run_id() {
+        // A record without a run starts one of its own.
+        let ended_logs = [
+            (10, 1, TaskLogState::NormalEnding as i16, 1, 0),
+            (20, 1, TaskLogState::NormalEnding as i16, 1, 7),
+            // Task 4 has no downstream tasks.
+            (30, 4, TaskLogState::NormalEnding as i16, 1, 7),
+        ];
+
+        assert_eq!(plan(&ended_logs), vec![(10, 2, 1, 10), (7, 2, 1, 20)]);
+    }
+}
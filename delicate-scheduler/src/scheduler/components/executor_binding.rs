@@ -0,0 +1,66 @@
+use super::prelude::*;
+
+/// Bind the executor of the processor to this scheduler,
+/// Returns the token they sign the messages between them with from now on.
+pub(crate) async fn bind_executor(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    scheduler: &SchedulerMetaInfo,
+    executor_processor_id: i64,
+) -> Result<String, CommonError> {
+    let model::UpdateExecutorProcessor {
+        id,
+        name,
+        host,
+        machine_id,
+        ..
+    }: model::UpdateExecutorProcessor = db::blocking(storage, move |storage| {
+        storage.find_executor_processor(executor_processor_id)
+    })
+    .await?;
+
+    let url = "http://".to_string() + (host.deref()) + "/api/executor/bind";
+
+    let private_key = scheduler.get_app_security_key();
+    let scheduler_host = scheduler.get_app_host_name().clone();
+    let signed_scheduler = service_binding::BindRequest::default()
+        .set_scheduler_host(scheduler_host)
+        .set_executor_processor_id(id)
+        .set_executor_processor_host(host)
+        .set_executor_processor_name(name)
+        .set_executor_machine_id(machine_id)
+        .set_time(timestamp())
+        .sign(private_key)?;
+
+    let response: Result<service_binding::EncryptedBindResponse, CommonError> = request_client
+        .post(url)
+        .json(&signed_scheduler)
+        .send()
+        .await?
+        .json::<UnifiedResponseMessages<service_binding::EncryptedBindResponse>>()
+        .await?
+        .into();
+
+    let bind_info = response?.decrypt_self(private_key)?;
+    Ok(bind_info.token.unwrap_or_default())
+}
+
+/// Push every task bound to the executor again, e.g. after it was restarted and lost them.
+pub(crate) async fn sync_executor_tasks(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    router: &ExecutorRouter,
+    executor_processor_id: i64,
+) -> Result<(), CommonError> {
+    let handed_back_task_ids: HashSet<i64> =
+        failback_tasks(storage, request_client, router, vec![executor_processor_id])
+            .await?
+            .into_iter()
+            .map(|(_, task_id)| task_id)
+            .collect();
+
+    // Only the tasks handed back are reported as held, so every other task the executor should hold is created.
+    let mut reports: HashMap<i64, HashSet<i64>> = HashMap::new();
+    reports.insert(executor_processor_id, handed_back_task_ids);
+    reconcile_tasks(storage, request_client, router, reports).await
+}
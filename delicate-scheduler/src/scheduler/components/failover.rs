@@ -0,0 +1,368 @@
+use super::prelude::*;
+use delicate_utils_task::{TaskPackage, TaskUnit};
+use model::{BindProcessor, NewTaskFailover, TaskFailover};
+use state::operation_log::OperationType;
+use state::task::RoutingStrategy;
+
+// A task that has to leave an abnormal executor.
+#[derive(Debug)]
+struct FailoverMove {
+    task_package: TaskPackage,
+    routing_strategy: RoutingStrategy,
+    origin_bind_id: i64,
+    origin_executor_id: i64,
+    group_id: i64,
+    // Set when the member that took over the task became abnormal as well.
+    failover_id: Option<i64>,
+}
+
+type FailoverPlan = (
+    Vec<FailoverMove>,
+    HashMap<i64, Vec<BindProcessor>>,
+    HashMap<i64, HashSet<i64>>,
+);
+
+/// Make sure the enabled tasks on abnormal executors run on a healthy member of the same group.
+///
+/// It runs after every health check, so a task enabled or updated while its executor is abnormal
+/// Is taken over as well, tasks that are already taken over are left alone.
+/// Returns the (executor-processor-id, task-id) pairs of the tasks created on the members taking over.
+pub(crate) async fn failover_tasks(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    router: &ExecutorRouter,
+) -> Result<Vec<(i64, i64)>, CommonError> {
+    let (moves, mut standby_members, mut occupied_executors): FailoverPlan =
+        db::blocking(storage, plan_failovers).await?;
+
+    let mut created_tasks: Vec<(i64, i64)> = Vec::new();
+    let mut completed_moves: Vec<(Option<i64>, NewTaskFailover)> = Vec::new();
+    for failover_move in moves {
+        let task_id = failover_move.task_package.id;
+
+        // A routed task only needs to move when the abnormal executor is the one holding it.
+        let is_routed = !matches!(
+            failover_move.routing_strategy,
+            RoutingStrategy::Broadcast | RoutingStrategy::Unknown
+        );
+        if is_routed
+            && router
+                .get_assignment(task_id)
+                .await?
+                .map_or(false, |bind_id| bind_id != failover_move.origin_bind_id)
+        {
+            continue;
+        }
+
+        let occupied = occupied_executors.entry(task_id).or_default();
+        let candidates: Vec<BindProcessor> = standby_members
+            .entry(failover_move.group_id)
+            .or_default()
+            .iter()
+            .filter(|m| !occupied.contains(&m.executor_id))
+            .cloned()
+            .collect();
+
+        let target = match router.pick_standby(candidates).await {
+            Some(target) => target,
+            None => {
+                error!(target:"failover", "No healthy member can take over task {} from executor {}.", task_id, failover_move.origin_executor_id);
+                continue;
+            }
+        };
+
+        info!(target:"failover", "Move task{} from executor {} to executor {}.", &failover_move.task_package, failover_move.origin_executor_id, target.executor_id);
+        let accepted = match failover_move.task_package.clone().sign(Some(&target.token)) {
+            Ok(signed_task_package) => {
+                send_to_executor(
+                    request_client,
+                    &target.host,
+                    "/api/task/create",
+                    &signed_task_package,
+                )
+                .await
+            }
+            Err(e) => {
+                error!(target:"failover", "{}", e);
+                false
+            }
+        };
+
+        if !accepted {
+            continue;
+        }
+
+        // The runs missed since the abnormal executor stopped running the task.
+        catch_up_missed_runs(
+            storage,
+            request_client,
+            &failover_move.task_package,
+            &target.host,
+            &target.token,
+        )
+        .await;
+
+        created_tasks.push((target.executor_id, task_id));
+        occupied.insert(target.executor_id);
+        completed_moves.push((
+            failover_move.failover_id,
+            NewTaskFailover {
+                task_id,
+                origin_bind_id: failover_move.origin_bind_id,
+                origin_executor_id: failover_move.origin_executor_id,
+                target_bind_id: target.bind_id,
+                target_executor_id: target.executor_id,
+            },
+        ));
+    }
+
+    if completed_moves.is_empty() {
+        return Ok(created_tasks);
+    }
+
+    for (failover_id, new_task_failover) in completed_moves.iter() {
+        let operation_type = if failover_id.is_some() {
+            OperationType::Modify
+        } else {
+            OperationType::Addition
+        };
+        let operation_log_pair_option =
+            generate_system_operation_log("task_failover", operation_type, new_task_failover, "")
+                .ok();
+        send_option_operation_log_pair(operation_log_pair_option).await;
+    }
+
+    db::blocking(storage, move |storage| {
+        storage.save_task_failovers(completed_moves)
+    })
+    .await?;
+
+    Ok(created_tasks)
+}
+
+type FailbackPlan = (
+    Vec<(TaskFailover, BindProcessor)>,
+    HashMap<i64, (TaskPackage, i16, i16)>,
+    HashMap<(i64, i64), BindProcessor>,
+);
+
+/// Hand the tasks taken over from recovered executors back to them.
+/// Returns the (executor-processor-id, task-id) pairs of the tasks created on the recovered executors.
+pub(crate) async fn failback_tasks(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    router: &ExecutorRouter,
+    recovered_executor_ids: Vec<i64>,
+) -> Result<Vec<(i64, i64)>, CommonError> {
+    if recovered_executor_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let (failovers, tasks, origin_members): FailbackPlan = db::blocking(storage, move |storage| {
+        let failovers = storage.failovers_from_executors(recovered_executor_ids)?;
+
+        let task_ids: Vec<i64> = failovers.iter().map(|(f, _)| f.task_id).collect();
+
+        let tasks: HashMap<i64, (TaskPackage, i16, i16)> = storage
+            .task_packages(task_ids.clone())?
+            .into_iter()
+            .map(|(task_package, status, routing_strategy, _)| {
+                (task_package.id, (task_package, status, routing_strategy))
+            })
+            .collect();
+
+        // Only bindings that still exist get the task back.
+        let origin_members: HashMap<(i64, i64), BindProcessor> = storage
+            .task_members(task_ids, false)?
+            .into_iter()
+            .map(|(task_id, member)| ((task_id, member.bind_id), member))
+            .collect();
+
+        Ok((failovers, tasks, origin_members))
+    })
+    .await?;
+
+    let mut created_tasks: Vec<(i64, i64)> = Vec::new();
+    let mut completed_failovers: Vec<TaskFailover> = Vec::new();
+    for (failover, target) in failovers {
+        let origin = origin_members.get(&(failover.task_id, failover.origin_bind_id));
+        if let (Some((task_package, status, routing_strategy)), Some(origin)) =
+            (tasks.get(&failover.task_id), origin)
+        {
+            let is_routed = !matches!(
+                RoutingStrategy::from(*routing_strategy),
+                RoutingStrategy::Broadcast | RoutingStrategy::Unknown
+            );
+            let is_holder = !is_routed
+                || router
+                    .get_assignment(failover.task_id)
+                    .await?
+                    .map_or(true, |bind_id| bind_id == origin.bind_id);
+
+            if *status == state::task::State::Enabled as i16 && is_holder {
+                info!(target:"failback", "Move task{} from executor {} back to executor {}.", task_package, target.executor_id, origin.executor_id);
+                let created = match task_package.clone().sign(Some(&origin.token)) {
+                    Ok(signed_task_package) => {
+                        send_to_executor(
+                            request_client,
+                            &origin.host,
+                            "/api/task/create",
+                            &signed_task_package,
+                        )
+                        .await
+                    }
+                    Err(e) => {
+                        error!(target:"failback", "{}", e);
+                        false
+                    }
+                };
+
+                // The standby keeps the task until the origin holds it again,
+                // And the record is kept so that the next recovery check tries again.
+                if !created {
+                    continue;
+                }
+
+                // The runs missed while neither of them ran the task, e.g. when the standby was down too.
+                catch_up_missed_runs(
+                    storage,
+                    request_client,
+                    task_package,
+                    &origin.host,
+                    &origin.token,
+                )
+                .await;
+                created_tasks.push((origin.executor_id, failover.task_id));
+            }
+        }
+
+        let removed = match TaskUnit::default()
+            .set_task_id(failover.task_id)
+            .set_time(timestamp())
+            .sign(Some(&target.token))
+        {
+            Ok(signed_task_unit) => {
+                send_to_executor(
+                    request_client,
+                    &target.host,
+                    "/api/task/remove",
+                    &signed_task_unit,
+                )
+                .await
+            }
+            Err(e) => {
+                error!(target:"failback", "{}", e);
+                false
+            }
+        };
+
+        // Keep the record, so the next recovery check tries again,
+        // Creating the task on the origin once more is harmless as the executor replaces it.
+        if !removed {
+            continue;
+        }
+
+        completed_failovers.push(failover);
+    }
+
+    if completed_failovers.is_empty() {
+        return Ok(created_tasks);
+    }
+
+    for failover in completed_failovers.iter() {
+        let operation_log_pair_option =
+            generate_system_operation_log("task_failover", OperationType::Delete, failover, "")
+                .ok();
+        send_option_operation_log_pair(operation_log_pair_option).await;
+    }
+
+    let failover_ids: Vec<i64> = completed_failovers.iter().map(|f| f.id).collect();
+    db::blocking(storage, move |storage| {
+        storage.delete_task_failovers(failover_ids)
+    })
+    .await?;
+
+    Ok(created_tasks)
+}
+
+// The tasks on abnormal executors that no healthy member runs yet,
+// Along with the healthy members of their groups and the executors that already run each task.
+fn plan_failovers(storage: &dyn db::Storage) -> Result<FailoverPlan, CommonError> {
+    use state::executor_processor::State;
+
+    let abnormal_executor_ids: HashSet<i64> = storage
+        .executors_in_status(vec![State::Abnormal as i16])?
+        .into_iter()
+        .map(|(id, ..)| id)
+        .collect();
+
+    if abnormal_executor_ids.is_empty() {
+        return Ok((Vec::new(), HashMap::new(), HashMap::new()));
+    }
+
+    // Tasks triggered by the scheduler pick a healthy executor on each run.
+    let origin_tasks =
+        storage.tasks_bound_to_executors(abnormal_executor_ids.iter().copied().collect())?;
+
+    let task_ids: Vec<i64> = origin_tasks.iter().map(|(t, ..)| t.id).collect();
+
+    let failovers: HashMap<(i64, i64), TaskFailover> = storage
+        .failovers_of_tasks(task_ids.clone())?
+        .into_iter()
+        .map(|f| ((f.task_id, f.origin_bind_id), f))
+        .collect();
+
+    // Executors that already run each task, through a binding or an earlier failover.
+    let mut occupied_executors: HashMap<i64, HashSet<i64>> = HashMap::new();
+    storage
+        .task_executors(task_ids)?
+        .into_iter()
+        .chain(
+            failovers
+                .values()
+                .map(|f| (f.task_id, f.target_executor_id)),
+        )
+        .for_each(|(task_id, executor_id)| {
+            occupied_executors
+                .entry(task_id)
+                .or_default()
+                .insert(executor_id);
+        });
+
+    let moves: Vec<FailoverMove> = origin_tasks
+        .into_iter()
+        .filter_map(
+            |(task_package, routing_strategy, origin_bind_id, origin_executor_id, group_id)| {
+                let failover_id = match failovers.get(&(task_package.id, origin_bind_id)) {
+                    // The member that took over the task is still healthy.
+                    Some(f) if !abnormal_executor_ids.contains(&f.target_executor_id) => {
+                        return None;
+                    }
+                    Some(f) => Some(f.id),
+                    None => None,
+                };
+
+                Some(FailoverMove {
+                    task_package,
+                    routing_strategy: routing_strategy.into(),
+                    origin_bind_id,
+                    origin_executor_id,
+                    group_id,
+                    failover_id,
+                })
+            },
+        )
+        .collect();
+
+    let group_ids: Vec<i64> = moves.iter().map(|m| m.group_id).collect();
+
+    let mut standby_members: HashMap<i64, Vec<BindProcessor>> = HashMap::new();
+    storage
+        .enabled_group_members(group_ids)?
+        .into_iter()
+        .for_each(|(group_id, member)| {
+            standby_members.entry(group_id).or_default().push(member);
+        });
+
+    Ok((moves, standby_members, occupied_executors))
+}
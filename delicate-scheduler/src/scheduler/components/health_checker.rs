@@ -0,0 +1,438 @@
+use super::prelude::*;
+use model::NewExecutorProcessorHealth;
+
+/// How often the health history older than its retention is deleted.
+const HEALTH_HISTORY_PRUNE_PERIOD: Duration = Duration::from_secs(60 * 60);
+
+/// The consecutive probes of a processor with the same result.
+#[derive(Debug, Default, Clone, Copy)]
+struct Streak {
+    misses: u32,
+    successes: u32,
+}
+
+impl Streak {
+    // The streak of the latest results in the health history, The newest first.
+    fn from_history(results: &[i16]) -> Streak {
+        use state::executor_processor_health::ProbeResult;
+
+        let mut streak = Streak::default();
+        for result in results.iter().rev() {
+            streak.record(*result == ProbeResult::Healthy as i16);
+        }
+        streak
+    }
+
+    fn record(&mut self, healthy: bool) {
+        if healthy {
+            self.successes += 1;
+            self.misses = 0;
+        } else {
+            self.misses += 1;
+            self.successes = 0;
+        }
+    }
+}
+
+/// A probe of an executor, along with how long it took.
+struct Probe {
+    executor_processor_id: i64,
+    response_time: Duration,
+    outcome: Result<delicate_utils_health_check::HealthCheckPackage, String>,
+    // The executor answered but is bound to no scheduler, e.g. it was restarted.
+    unbound: bool,
+}
+
+pub(crate) async fn loop_health_check(
+    storage: Arc<dyn db::Storage>,
+    request_client: RequestClient,
+    router: Arc<ExecutorRouter>,
+    election: Arc<LeaderElection>,
+    scheduler: Arc<SchedulerMetaInfo>,
+    reloadable_conf: watch::Receiver<ReloadableConf>,
+) {
+    let mut conf = reloadable_conf.borrow().health_check;
+    let mut interval = interval(conf.interval);
+    let mut last_pruned: Option<Instant> = None;
+    loop {
+        interval.tick().await;
+
+        // The health check can be changed on `SIGHUP`, it takes effect from the next round.
+        let reloaded_conf = reloadable_conf.borrow().health_check;
+        if reloaded_conf.interval != conf.interval {
+            interval = interval_at(
+                Instant::now() + reloaded_conf.interval,
+                reloaded_conf.interval,
+            );
+        }
+        conf = reloaded_conf;
+
+        // Executors are probed once per round whatever the number of schedulers,
+        // Followers route without snapshots, rather than with the ones left from their leadership.
+        if !election.is_leader() {
+            router.refresh_snapshots(HashMap::new()).await;
+            continue;
+        }
+
+        health_check(&storage, &request_client, &router, &scheduler, &conf)
+            .await
+            .map_err(|e| error!(target:"loop-health-check", "{}", e.to_string()))
+            .ok();
+
+        if let Some(retention) = conf.history_retention {
+            if last_pruned.map_or(true, |t| t.elapsed() >= HEALTH_HISTORY_PRUNE_PERIOD) {
+                last_pruned = Some(Instant::now());
+                prune_health_history(&storage, retention)
+                    .await
+                    .map_err(|e| error!(target:"loop-health-check", "{}", e.to_string()))
+                    .ok();
+            }
+        }
+    }
+}
+
+async fn health_check(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    router: &ExecutorRouter,
+    scheduler: &SchedulerMetaInfo,
+    conf: &HealthCheckConf,
+) -> Result<(), CommonError> {
+    use state::executor_processor::State;
+    use state::executor_processor_health::ProbeResult;
+
+    // Abnormal executors are probed as well, so that they can recover.
+    let executor_packages = db::blocking(storage, move |storage| {
+        storage.executors_in_status(vec![State::Enabled as i16, State::Abnormal as i16])
+    })
+    .await?;
+    let statuses: HashMap<i64, i16> = executor_packages
+        .iter()
+        .map(|(id, _, _, status)| (*id, *status))
+        .collect();
+
+    let hosts: HashMap<i64, String> = executor_packages
+        .iter()
+        .map(|(id, host, _, _)| (*id, host.clone()))
+        .collect();
+
+    let mut probes: Vec<Probe> = executor_packages
+        .into_iter()
+        .map(|(id, host, token, _)| probe(request_client, conf.timeout, id, host, token))
+        .collect::<JoinAll<_>>()
+        .instrument(span!(Level::INFO, "health-check"))
+        .await;
+
+    // A restarted executor answers without a binding, it is bound again and probed once more,
+    // So that the rest of the round reconciles its tasks as for any other healthy executor.
+    for probe in probes.iter_mut().filter(|probe| probe.unbound) {
+        let id = probe.executor_processor_id;
+        *probe = rebind(
+            storage,
+            request_client,
+            scheduler,
+            conf.timeout,
+            id,
+            hosts[&id].clone(),
+        )
+        .await;
+    }
+
+    // The streaks are counted from the health history, So a new leader goes on with them.
+    let executor_processor_ids: Vec<i64> = statuses.keys().copied().collect();
+    let history_limit = conf.failure_threshold.max(conf.success_threshold) as i64;
+    let recent_health_history = db::blocking(storage, move |storage| {
+        storage.recent_health_history(executor_processor_ids, history_limit)
+    })
+    .await?;
+
+    let mut abnormal_processor: Vec<i64> = Vec::new();
+    let mut recovered_processor: Vec<i64> = Vec::new();
+    let mut health_history: Vec<NewExecutorProcessorHealth> = Vec::with_capacity(probes.len());
+    let mut enabled_processor: HashSet<i64> = HashSet::new();
+    for probe in probes.iter() {
+        let id = probe.executor_processor_id;
+        let mut streak = recent_health_history
+            .get(&id)
+            .map(|results| Streak::from_history(results))
+            .unwrap_or_default();
+        streak.record(probe.outcome.is_ok());
+
+        let mut status = statuses[&id];
+        if status == State::Enabled as i16 && streak.misses >= conf.failure_threshold {
+            status = State::Abnormal as i16;
+            abnormal_processor.push(id);
+        } else if status == State::Abnormal as i16 && streak.successes >= conf.success_threshold {
+            status = State::Enabled as i16;
+            recovered_processor.push(id);
+        }
+        if status == State::Enabled as i16 {
+            enabled_processor.insert(id);
+        }
+
+        let (result, error) = match probe.outcome.as_ref() {
+            Ok(_) => (ProbeResult::Healthy, String::new()),
+            Err(e) => {
+                warn!(target:"loop-health-check", "Executor {} missed a probe ({}/{}): {}", id, streak.misses, conf.failure_threshold, e);
+                (ProbeResult::Missed, e.chars().take(255).collect())
+            }
+        };
+        health_history.push(NewExecutorProcessorHealth {
+            executor_processor_id: id,
+            result: result as i16,
+            status,
+            response_time: probe.response_time.as_millis().min(i32::MAX as u128) as i32,
+            error,
+        });
+    }
+
+    let abnormal_processor_cloned = abnormal_processor.clone();
+    let recovered_processor_cloned = recovered_processor.clone();
+    db::blocking(storage, move |storage| {
+        storage.record_health_check(
+            abnormal_processor_cloned,
+            recovered_processor_cloned,
+            health_history,
+        )
+    })
+    .await?;
+
+    if !abnormal_processor.is_empty() {
+        error!(target:"loop-health-check", "Executors {:?} are abnormal.", &abnormal_processor);
+    }
+
+    if !recovered_processor.is_empty() {
+        info!(target:"loop-health-check", "Executors {:?} are recovered.", &recovered_processor);
+    }
+
+    let health_check_packages: Vec<&delicate_utils_health_check::HealthCheckPackage> = probes
+        .iter()
+        .filter_map(|probe| probe.outcome.as_ref().ok())
+        .collect();
+
+    // The latest snapshots are used by the least-loaded routing strategy.
+    let snapshots: HashMap<i64, delicate_utils_health_check::SystemSnapshot> =
+        health_check_packages
+            .iter()
+            .map(|package| {
+                (
+                    package.bind_request.executor_processor_id,
+                    package.system_snapshot.clone(),
+                )
+            })
+            .collect();
+    router.refresh_snapshots(snapshots).await;
+
+    // The task-ids reported by each executor, compared with the bound tasks after failover.
+    let mut reports: HashMap<i64, HashSet<i64>> = health_check_packages
+        .iter()
+        .filter_map(|package| {
+            package.task_ids.as_ref().map(|task_ids| {
+                (
+                    package.bind_request.executor_processor_id,
+                    task_ids.iter().copied().collect(),
+                )
+            })
+        })
+        .collect();
+
+    // Only the executors that answered and are enabled after this round get their tasks back,
+    // An abnormal executor has to reach the success threshold first.
+    let failback_processors: Vec<i64> = probes
+        .iter()
+        .filter(|probe| probe.outcome.is_ok())
+        .map(|probe| probe.executor_processor_id)
+        .filter(|id| enabled_processor.contains(id))
+        .collect();
+
+    // Failback first, so that a recovered executor can take over tasks again in the same round.
+    // Every healthy executor is checked, so an executor activated by hand
+    // Or a failback that failed earlier gets its tasks back as well.
+    let handed_back_tasks =
+        failback_tasks(storage, request_client, router, failback_processors).await?;
+    let taken_over_tasks = failover_tasks(storage, request_client, router).await?;
+
+    // The tasks just created are held now, so they are not created again with their missed runs.
+    for (executor_id, task_id) in handed_back_tasks.into_iter().chain(taken_over_tasks) {
+        if let Some(task_ids) = reports.get_mut(&executor_id) {
+            task_ids.insert(task_id);
+        }
+    }
+    reconcile_tasks(storage, request_client, router, reports).await?;
+
+    Ok(())
+}
+
+async fn probe(
+    request_client: &RequestClient,
+    timeout: Duration,
+    executor_processor_id: i64,
+    host: String,
+    token: String,
+) -> Probe {
+    let start = Instant::now();
+    let mut unbound = false;
+    let outcome =
+        match request_health_check_package(request_client, timeout, &host, &token).await {
+            // An executor that lost its binding rejects the signature of the token it no longer has,
+            // The unsigned probe tells whether it is bound at all.
+            Err(e) if !token.is_empty() && e == CommonError::DisVerify.to_string() => {
+                request_health_check_package(request_client, timeout, &host, "").await
+            }
+            outcome => outcome,
+        }
+        .and_then(|package| {
+            // An executor bound to another processor is not the one registered at this host.
+            let bound_id = package.bind_request.executor_processor_id;
+            if bound_id == executor_processor_id {
+                Ok(package)
+            } else if bound_id == 0 {
+                unbound = true;
+                Err(format!("The executor at {} is not bound.", host))
+            } else {
+                Err(format!(
+                    "The executor at {} is bound to processor {}.",
+                    host, bound_id
+                ))
+            }
+        });
+
+    Probe {
+        executor_processor_id,
+        response_time: start.elapsed(),
+        outcome,
+        unbound,
+    }
+}
+
+// Bind the executor again and probe it with the new token,
+// The probe is a miss if the binding fails.
+async fn rebind(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    scheduler: &SchedulerMetaInfo,
+    timeout: Duration,
+    executor_processor_id: i64,
+    host: String,
+) -> Probe {
+    info!(target:"loop-health-check", "Executor {} at {} is not bound, bind it again.", executor_processor_id, host);
+
+    let start = Instant::now();
+    let rebound = async {
+        let token =
+            bind_executor(storage, request_client, scheduler, executor_processor_id).await?;
+        let saved_token = token.clone();
+        db::blocking(storage, move |storage| {
+            storage.rebind_executor_processor(executor_processor_id, saved_token)
+        })
+        .await?;
+        Ok::<_, CommonError>(token)
+    }
+    .await;
+
+    match rebound {
+        Ok(token) => probe(request_client, timeout, executor_processor_id, host, token).await,
+        Err(e) => Probe {
+            executor_processor_id,
+            response_time: start.elapsed(),
+            outcome: Err(format!(
+                "The executor at {} is not bound, binding it failed: {}",
+                host, e
+            )),
+            unbound: true,
+        },
+    }
+}
+
+async fn request_health_check_package(
+    request_client: &RequestClient,
+    timeout: Duration,
+    host: &str,
+    token: &str,
+) -> Result<delicate_utils_health_check::HealthCheckPackage, String> {
+    let signed_health_screen_unit = delicate_utils_executor_processor::HealthScreenUnit::default()
+        .sign(Some(token))
+        .map_err(|e| e.to_string())?;
+    let executor_host = "http://".to_string() + host + "/api/executor/health_screen";
+
+    let response = request_client
+        .post(executor_host)
+        .json(&signed_health_screen_unit)
+        .timeout(timeout)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let reply = response
+        .json::<UnifiedResponseMessages<delicate_utils_health_check::HealthCheckPackage>>()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if reply.is_err() {
+        return Err(reply.get_msg());
+    }
+    Ok(reply.get_data())
+}
+
+async fn prune_health_history(
+    storage: &Arc<dyn db::Storage>,
+    retention: Duration,
+) -> Result<(), CommonError> {
+    let deadline = Utc::now()
+        - ChronoDuration::from_std(retention).unwrap_or_else(|_| ChronoDuration::days(7));
+
+    let pruned = db::blocking(storage, move |storage| {
+        storage.prune_health_history(deadline)
+    })
+    .await?;
+
+    if pruned > 0 {
+        info!(target:"loop-health-check", "{} health records older than {} are deleted.", pruned, deadline);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use state::executor_processor_health::ProbeResult;
+
+    const HEALTHY: i16 = ProbeResult::Healthy as i16;
+    const MISSED: i16 = ProbeResult::Missed as i16;
+    const UNKNOWN: i16 = ProbeResult::Unknown as i16;
+
+    #[test]
+    fn test_streak_of_an_empty_history() {
+        let streak = Streak::from_history(&[]);
+
+        assert_eq!((streak.misses, streak.successes), (0, 0));
+    }
+
+    #[test]
+    fn test_streak_counts_the_newest_results() {
+        // The newest first, the older results before the last change do not count.
+        let streak = Streak::from_history(&[MISSED, MISSED, HEALTHY, MISSED]);
+        assert_eq!((streak.misses, streak.successes), (2, 0));
+
+        let streak = Streak::from_history(&[HEALTHY, HEALTHY, HEALTHY, MISSED, HEALTHY]);
+        assert_eq!((streak.misses, streak.successes), (0, 3));
+    }
+
+    #[test]
+    fn test_streak_counts_unknown_results_as_misses() {
+        let streak = Streak::from_history(&[UNKNOWN, MISSED, HEALTHY]);
+
+        assert_eq!((streak.misses, streak.successes), (2, 0));
+    }
+
+    #[test]
+    fn test_streak_goes_on_with_a_new_probe() {
+        let mut streak = Streak::from_history(&[MISSED, MISSED]);
+
+        streak.record(false);
+        assert_eq!((streak.misses, streak.successes), (3, 0));
+
+        streak.record(true);
+        assert_eq!((streak.misses, streak.successes), (0, 1));
+    }
+}
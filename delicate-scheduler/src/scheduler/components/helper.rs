@@ -0,0 +1,158 @@
+use super::prelude::*;
+
+pub(crate) async fn handle_response<
+    F: Future<Output = Result<RequestResponse, RequestError>>,
+    T: DeserializeOwned + Trial,
+>(
+    request_all: JoinAll<F>,
+) -> Vec<T> {
+    let response_json_all: JoinAll<_> = request_all
+        .await
+        .into_iter()
+        .map(|response| match response {
+            Ok(r) => Some(r.json::<T>()),
+            Err(e) => {
+                error!("SendRequestError : {}", e);
+                None
+            }
+        })
+        .filter(|r| r.is_some())
+        .map(|r| r.expect(""))
+        .collect();
+
+    response_json_all
+        .await
+        .into_iter()
+        .map(|json_result| {
+            match json_result {
+                Err(ref e) => {
+                    error!("Payload errors: {}", e);
+                }
+                Ok(ref json) if json.is_err() => {
+                    error!("Customized error messages: {}", json.get_msg());
+                }
+                _ => {}
+            }
+            json_result
+        })
+        .filter_map(|r| r.ok())
+        .collect::<Vec<T>>()
+}
+
+/// How an executor replied to a message dispatched to it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DispatchResult {
+    pub(crate) host: String,
+    pub(crate) url: String,
+    pub(crate) ok: bool,
+    pub(crate) message: String,
+}
+
+impl DispatchResult {
+    fn new(host: &str, url: &str, ok: bool, message: String) -> Self {
+        DispatchResult {
+            host: host.to_string(),
+            url: url.to_string(),
+            ok,
+            message,
+        }
+    }
+}
+
+/// The response to an operation dispatched to executors, a partial failure has its own code.
+pub(crate) fn dispatch_response(
+    result: Result<Vec<DispatchResult>, CommonError>,
+) -> UnifiedResponseMessages<Vec<DispatchResult>> {
+    let dispatch_results = match result {
+        Ok(dispatch_results) => dispatch_results,
+        Err(e) => {
+            return UnifiedResponseMessages::<Vec<DispatchResult>>::error()
+                .customized_error_msg(e.to_string())
+        }
+    };
+
+    let failures = dispatch_results.iter().filter(|r| !r.ok).count();
+    if failures == 0 {
+        return UnifiedResponseMessages::success_with_data(dispatch_results);
+    }
+
+    let msg = format!(
+        "{} of {} executors failed.",
+        failures,
+        dispatch_results.len()
+    );
+    if failures == dispatch_results.len() {
+        UnifiedResponseMessages::error_with_data(dispatch_results).customized_error_msg(msg)
+    } else {
+        UnifiedResponseMessages::partial_failure_with_data(dispatch_results)
+            .customized_error_msg(msg)
+    }
+}
+
+/// Send the signed messages to their executors at once and collect how each of them replied,
+/// `messages` are the hosts along with the message signed for them, or why it could not be signed.
+pub(crate) async fn dispatch_to_executors<T: Serialize>(
+    request_client: &RequestClient,
+    url: &'static str,
+    messages: Vec<(String, Result<T, CommonError>)>,
+) -> Vec<DispatchResult> {
+    messages
+        .into_iter()
+        .map(|(host, message)| async move {
+            match message {
+                Ok(message) => dispatch_to_executor(request_client, &host, url, &message).await,
+                Err(e) => {
+                    error!("Failed to sign the message to {}: {}", host, e);
+                    DispatchResult::new(&host, url, false, e.to_string())
+                }
+            }
+        })
+        .collect::<JoinAll<_>>()
+        .await
+}
+
+/// Send a signed message to a single executor, returns true when the executor accepted it.
+pub(crate) async fn send_to_executor<T: Serialize>(
+    request_client: &RequestClient,
+    host: &str,
+    url: &'static str,
+    message: &T,
+) -> bool {
+    dispatch_to_executor(request_client, host, url, message)
+        .await
+        .ok
+}
+
+async fn dispatch_to_executor<T: Serialize>(
+    request_client: &RequestClient,
+    host: &str,
+    url: &'static str,
+    message: &T,
+) -> DispatchResult {
+    let executor_host = "http://".to_string() + host + url;
+
+    let response = match request_client
+        .post(executor_host)
+        .json(message)
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            error!("SendRequestError : {}", e);
+            return DispatchResult::new(host, url, false, e.to_string());
+        }
+    };
+
+    match response.json::<UnifiedResponseMessages<()>>().await {
+        Ok(ref json) if json.is_err() => {
+            error!("Customized error messages: {}", json.get_msg());
+            DispatchResult::new(host, url, false, json.get_msg())
+        }
+        Ok(_) => DispatchResult::new(host, url, true, String::new()),
+        Err(e) => {
+            error!("Payload errors: {}", e);
+            DispatchResult::new(host, url, false, e.to_string())
+        }
+    }
+}
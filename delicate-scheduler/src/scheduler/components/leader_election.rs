@@ -0,0 +1,125 @@
+use super::prelude::*;
+use redis::Script;
+
+// The lock held by the leader, its value is the leader instance as json.
+const LEADER_KEY: &str = "delicate:scheduler:leader";
+// The leader renews the lock well within its lease, a leader that stops renewing is replaced after it.
+const LEADER_LEASE: Duration = Duration::from_secs(30);
+const LEADER_CAMPAIGN_INTERVAL: Duration = Duration::from_secs(10);
+
+lazy_static! {
+    // Only the holder extends the lease, so a leader that was replaced cannot take the lock back.
+    static ref RENEW_LEADER_SCRIPT: Script = Script::new(
+        r"if redis.call('get', KEYS[1]) == ARGV[1] then return redis.call('pexpire', KEYS[1], ARGV[2]) else return 0 end",
+    );
+}
+
+/// One scheduler process, as published in the leader lock.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct SchedulerInstance {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) domain: String,
+}
+
+/// Elects the scheduler that runs the singleton background jobs (health check, sweeper),
+/// Through a lock in the redis of `DELICATE_REDIS_URL`.
+///
+/// Every scheduler keeps serving the api, and starts as a follower until it takes the lock.
+#[derive(Debug)]
+pub(crate) struct LeaderElection {
+    instance: SchedulerInstance,
+    // The lock value of this instance.
+    lock_value: String,
+    redis_client: redis::Client,
+    is_leader: AtomicBool,
+}
+
+impl LeaderElection {
+    pub(crate) fn new(scheduler_meta_info: &SchedulerMetaInfo, redis_url: &str) -> LeaderElection {
+        let instance = SchedulerInstance {
+            id: get_unique_id_string(),
+            name: scheduler_meta_info.get_app_name().clone(),
+            domain: scheduler_meta_info.get_app_host_name().clone(),
+        };
+        let lock_value =
+            to_json_string(&instance).expect("The scheduler instance is serializable.");
+
+        let redis_client = redis::Client::open(redis_url)
+            .expect("The redis client resource could not be initialized.");
+        let is_leader = AtomicBool::new(false);
+
+        LeaderElection {
+            instance,
+            lock_value,
+            redis_client,
+            is_leader,
+        }
+    }
+
+    pub(crate) fn is_leader(&self) -> bool {
+        self.is_leader.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn instance(&self) -> &SchedulerInstance {
+        &self.instance
+    }
+
+    /// The instance holding the lock, `None` while no one does.
+    pub(crate) async fn current_leader(&self) -> Result<Option<SchedulerInstance>, CommonError> {
+        let mut conn = self.redis_client.get_async_connection().await?;
+        let lock_value: Option<String> = redis::cmd("GET")
+            .arg(LEADER_KEY)
+            .query_async(&mut conn)
+            .await?;
+
+        Ok(match lock_value {
+            Some(lock_value) => Some(from_json_str(&lock_value)?),
+            None => None,
+        })
+    }
+
+    // Take the lock when it is free or renew it when it is ours.
+    async fn campaign(&self) -> Result<bool, CommonError> {
+        let mut conn = self.redis_client.get_async_connection().await?;
+        let lease = LEADER_LEASE.as_millis() as u64;
+
+        let acquired: Option<String> = redis::cmd("SET")
+            .arg(LEADER_KEY)
+            .arg(&self.lock_value)
+            .arg("NX")
+            .arg("PX")
+            .arg(lease)
+            .query_async(&mut conn)
+            .await?;
+        if acquired.is_some() {
+            return Ok(true);
+        }
+
+        let renewed: i64 = RENEW_LEADER_SCRIPT
+            .key(LEADER_KEY)
+            .arg(&self.lock_value)
+            .arg(lease)
+            .invoke_async(&mut conn)
+            .await?;
+        Ok(renewed == 1)
+    }
+}
+
+pub(crate) async fn loop_leader_election(election: Arc<LeaderElection>) {
+    let mut interval = interval(LEADER_CAMPAIGN_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        // A leader that cannot renew steps down at once, another one takes over when the lease ends.
+        let is_leader = election
+            .campaign()
+            .await
+            .map_err(|e| error!(target:"loop-leader-election", "{}", e.to_string()))
+            .unwrap_or(false);
+
+        if election.is_leader.swap(is_leader, Ordering::AcqRel) != is_leader {
+            info!(target:"loop-leader-election", "Scheduler {} is leader: {}.", &election.instance.id, is_leader);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use super::prelude::*;
+use delicate_utils_task::{TaskPackage, TaskTrigger};
+use state::task::MisfirePolicy;
+
+/// The runs a task missed since its last run, following its misfire policy.
+///
+/// The last run is the latest record of the task, so a task that never ran missed nothing.
+/// A failure only loses the missed runs, it is logged rather than returned.
+pub(crate) fn count_missed_runs(storage: &dyn db::Storage, task_package: &TaskPackage) -> usize {
+    let task_id = task_package.id;
+    let result = storage
+        .misfire_settings(task_id)
+        .and_then(|misfire_settings| {
+            missed_runs(task_package, misfire_settings, || {
+                storage.last_run_time(task_id)
+            })
+        });
+
+    result.unwrap_or_else(|e| {
+        error!(target:"misfire", "Failed to count the missed runs of task {}: {}", task_id, e);
+        0
+    })
+}
+
+// The missed runs to fire under the misfire policy, `last_run_time` is only looked up when some are fired.
+fn missed_runs(
+    task_package: &TaskPackage,
+    (misfire_policy, misfire_limit): (i16, i16),
+    last_run_time: impl FnOnce() -> Result<Option<DateTime<Utc>>, CommonError>,
+) -> Result<usize, CommonError> {
+    let limit = match MisfirePolicy::from(misfire_policy) {
+        MisfirePolicy::FireOnce => 1,
+        MisfirePolicy::FireAll => misfire_limit.max(0) as usize,
+        MisfirePolicy::Ignore | MisfirePolicy::Unknown => return Ok(0),
+    };
+
+    match last_run_time()? {
+        Some(last_run) => task_package.count_fires_since(last_run, limit),
+        None => Ok(0),
+    }
+}
+
+/// Send the missed runs of a task that was just created on an executor,
+/// Such as when it is taken over, handed back or created again on an executor that lost it.
+pub(crate) async fn catch_up_missed_runs(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    task_package: &TaskPackage,
+    host: &str,
+    token: &str,
+) {
+    let counted_task_package = task_package.clone();
+    let missed_runs = db::blocking(storage, move |storage| {
+        Ok(count_missed_runs(storage, &counted_task_package))
+    })
+    .await
+    .unwrap_or_else(|e| {
+        error!(target:"misfire", "Failed to count the missed runs of task {}: {}", task_package.id, e);
+        0
+    });
+
+    fire_missed_runs(
+        request_client,
+        task_package,
+        missed_runs,
+        &[(host.to_string(), token.to_string())],
+    )
+    .await;
+}
+
+/// Send the missed runs of a task to the members now holding it,
+/// `members` are (host, token) pairs. Each missed run is a one-shot run on every member.
+pub(crate) async fn fire_missed_runs(
+    request_client: &RequestClient,
+    task_package: &TaskPackage,
+    missed_runs: usize,
+    members: &[(String, String)],
+) {
+    if missed_runs == 0 {
+        return;
+    }
+
+    info!(target:"misfire", "Fire {} missed runs of task{}.", missed_runs, task_package);
+    for (host, token) in members {
+        for _ in 0..missed_runs {
+            match TaskTrigger::default()
+                .set_task_package(task_package.clone())
+                .set_time(timestamp())
+                .sign(Some(token))
+            {
+                Ok(signed_task_trigger) => {
+                    send_to_executor(
+                        request_client,
+                        host,
+                        "/api/task/trigger",
+                        &signed_task_trigger,
+                    )
+                    .await;
+                }
+                Err(e) => error!(target:"misfire", "{}", e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A task firing every minute in utc.
+    fn task_package() -> TaskPackage {
+        TaskPackage {
+            id: 1,
+            frequency: String::from(r#"{"mode":3,"extend":{"count":0},"time_zone":1}"#),
+            cron_expression: String::from("0 * * * * * *"),
+            ..TaskPackage::default()
+        }
+    }
+
+    fn missed_runs_since(misfire_settings: (i16, i16), last_run: DateTime<Utc>) -> usize {
+        missed_runs(&task_package(), misfire_settings, || Ok(Some(last_run))).unwrap()
+    }
+
+    #[test]
+    fn test_missed_runs_of_each_policy() {
+        // About 60 runs were missed.
+        let last_run = Utc::now() - ChronoDuration::hours(1);
+
+        assert_eq!(
+            missed_runs_since((MisfirePolicy::Ignore as i16, 5), last_run),
+            0
+        );
+        assert_eq!(
+            missed_runs_since((MisfirePolicy::FireOnce as i16, 5), last_run),
+            1
+        );
+        assert_eq!(
+            missed_runs_since((MisfirePolicy::FireAll as i16, 5), last_run),
+            5
+        );
+        assert_eq!(missed_runs_since((0, 5), last_run), 0);
+    }
+
+    #[test]
+    fn test_missed_runs_are_bounded_by_the_fires() {
+        let last_run = Utc::now() - ChronoDuration::minutes(3);
+
+        // 2 or 3 fires fell in the 3 minutes, depending on the second now.
+        let missed_runs = missed_runs_since((MisfirePolicy::FireAll as i16, 100), last_run);
+        assert!((2..=3).contains(&missed_runs));
+
+        assert_eq!(
+            missed_runs_since((MisfirePolicy::FireOnce as i16, 100), last_run),
+            1
+        );
+        assert_eq!(
+            missed_runs_since((MisfirePolicy::FireAll as i16, 0), last_run),
+            0
+        );
+        assert_eq!(
+            missed_runs_since((MisfirePolicy::FireAll as i16, 100), Utc::now()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_missed_runs_without_a_last_run() {
+        for misfire_policy in [MisfirePolicy::FireOnce, MisfirePolicy::FireAll] {
+            let missed_runs = missed_runs(&task_package(), (misfire_policy as i16, 5), || Ok(None));
+            assert_eq!(missed_runs.unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn test_missed_runs_look_up_the_last_run_only_to_fire() {
+        let missed_runs = missed_runs(
+            &task_package(),
+            (MisfirePolicy::Ignore as i16, 5),
+            || -> Result<Option<DateTime<Utc>>, CommonError> {
+                panic!("The last run is looked up.")
+            },
+        );
+
+        assert_eq!(missed_runs.unwrap(), 0);
+    }
+}
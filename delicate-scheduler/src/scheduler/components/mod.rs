@@ -0,0 +1,19 @@
+pub(crate) use super::prelude;
+
+pub(crate) mod auth;
+pub(crate) mod base;
+pub(crate) mod central_trigger;
+pub(crate) mod cors;
+pub(crate) mod dependency;
+pub(crate) mod executor_binding;
+pub(crate) mod failover;
+pub(crate) mod health_checker;
+pub(crate) mod helper;
+pub(crate) mod leader_election;
+pub(crate) mod logger_id;
+pub(crate) mod misfire;
+pub(crate) mod operation_log_consumer;
+pub(crate) mod reconciliation;
+pub(crate) mod routing;
+pub(crate) mod session;
+pub(crate) mod sweeper;
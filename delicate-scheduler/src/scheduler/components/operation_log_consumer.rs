@@ -0,0 +1,55 @@
+use super::prelude::*;
+
+lazy_static! {
+    pub static ref OPERATION_LOG_CONSUMERS: (
+        AsyncSender<NewOperationLogPair>,
+        AsyncReceiver<NewOperationLogPair>
+    ) = async_channel::unbounded::<NewOperationLogPair>();
+}
+
+// Operation log asynchronous consumer
+//
+// The user's operations in the system are logged to track,
+// But in order not to affect the performance of the system,
+// These logs go through the channel with the asynchronous state machine to consume.
+pub(crate) async fn loop_operate_logs(storage: Arc<dyn db::Storage>) {
+    loop {
+        let mut operation_log_pairs: Vec<NewOperationLogPair> = Vec::new();
+
+        'logs_collection: for _ in 0..512 {
+            let logs_future: TokioTimeout<_> =
+                tokio_timeout(Duration::from_secs(3), OPERATION_LOG_CONSUMERS.1.recv());
+
+            match logs_future.await {
+                // No new events and timeout.
+                Err(_) => break 'logs_collection,
+                // Internal runtime exception.
+                Ok(Err(_)) => {
+                    return;
+                }
+                Ok(Ok(log_pair)) => {
+                    operation_log_pairs.push(log_pair);
+                }
+            }
+        }
+
+        if operation_log_pairs.is_empty() {
+            continue;
+        }
+
+        db::blocking(&storage, move |storage| {
+            storage.insert_operation_logs(operation_log_pairs)
+        })
+        .await
+        .map_err(|e| error!("operate-logs: {}", e))
+        .ok();
+    }
+}
+
+pub(crate) async fn send_option_operation_log_pair(
+    option_operation_log_pair: NewOperationLogPairOption,
+) {
+    if let Some(log_pair) = option_operation_log_pair {
+        OPERATION_LOG_CONSUMERS.0.send(log_pair).await.ok();
+    }
+}
@@ -0,0 +1,244 @@
+use super::prelude::*;
+use delicate_utils_task::{TaskPackage, TaskUnit};
+use model::{BindProcessor, TaskFailover};
+use state::task::RoutingStrategy;
+
+type ReconcilePlan = (
+    HashMap<i64, (String, String)>,
+    Vec<(TaskPackage, i16, i64, i64)>,
+    Vec<TaskFailover>,
+    HashMap<i64, TaskPackage>,
+);
+
+// The tasks an executor should hold, and the tasks it is left alone with.
+#[derive(Debug, Default)]
+struct ExpectedTasks {
+    task_packages: HashMap<i64, TaskPackage>,
+    ignored_task_ids: HashSet<i64>,
+}
+
+/// Make the tasks held by each executor match the tasks bound to it.
+///
+/// `reports` maps an executor-processor-id to the task-ids the executor holds,
+/// Missing tasks are created on the executor and the tasks it should not hold are removed.
+pub(crate) async fn reconcile_tasks(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    router: &ExecutorRouter,
+    reports: HashMap<i64, HashSet<i64>>,
+) -> Result<(), CommonError> {
+    if reports.is_empty() {
+        return Ok(());
+    }
+
+    let executor_ids: Vec<i64> = reports.keys().copied().collect();
+    let (executors, bound_tasks, failovers, failover_tasks): ReconcilePlan =
+        db::blocking(storage, move |storage| {
+            let executors: HashMap<i64, (String, String)> = storage
+                .executors_by_ids(executor_ids.clone())?
+                .into_iter()
+                .filter(|(.., status)| *status == state::executor_processor::State::Enabled as i16)
+                .map(|(id, host, token, _)| (id, (host, token)))
+                .collect();
+
+            // Executors hold no tasks triggered by the scheduler, left-overs are removed.
+            let bound_tasks: Vec<(TaskPackage, i16, i64, i64)> = storage
+                .tasks_bound_to_executors(executor_ids.clone())?
+                .into_iter()
+                .map(
+                    |(task_package, routing_strategy, bind_id, executor_id, _)| {
+                        (task_package, routing_strategy, bind_id, executor_id)
+                    },
+                )
+                .collect();
+
+            let failovers = storage.failovers_of_executors(executor_ids)?;
+
+            let failover_task_ids: Vec<i64> = failovers.iter().map(|f| f.task_id).collect();
+            let failover_tasks: HashMap<i64, TaskPackage> = storage
+                .task_packages(failover_task_ids)?
+                .into_iter()
+                .filter(|(_, status, ..)| *status == state::task::State::Enabled as i16)
+                .map(|(task_package, ..)| (task_package.id, task_package))
+                .collect();
+
+            Ok((executors, bound_tasks, failovers, failover_tasks))
+        })
+        .await?;
+
+    let mut expected_tasks: HashMap<i64, ExpectedTasks> = HashMap::new();
+
+    // Tasks taken over from an executor are handed back by failback, not here.
+    for failover in failovers.iter() {
+        expected_tasks
+            .entry(failover.origin_executor_id)
+            .or_default()
+            .ignored_task_ids
+            .insert(failover.task_id);
+    }
+
+    let failover_task_ids: HashSet<i64> = failovers.iter().map(|f| f.task_id).collect();
+    let mut assignments: HashMap<i64, Option<i64>> = HashMap::new();
+    let mut unassigned_tasks: HashMap<i64, (TaskPackage, RoutingStrategy)> = HashMap::new();
+    for (task_package, routing_strategy, bind_id, executor_id) in bound_tasks {
+        let task_id = task_package.id;
+        let expected = expected_tasks.entry(executor_id).or_default();
+        if expected.ignored_task_ids.contains(&task_id) {
+            continue;
+        }
+
+        let routing_strategy = RoutingStrategy::from(routing_strategy);
+        let is_routed = !matches!(
+            routing_strategy,
+            RoutingStrategy::Broadcast | RoutingStrategy::Unknown
+        );
+        let is_holder = if !is_routed {
+            true
+        } else {
+            let assignment = match assignments.get(&task_id) {
+                Some(assignment) => *assignment,
+                None => {
+                    let assignment = router.get_assignment(task_id).await?;
+                    assignments.insert(task_id, assignment);
+                    assignment
+                }
+            };
+
+            match assignment {
+                Some(holder_bind_id) => holder_bind_id == bind_id,
+                // The holder is unknown, adopt the member that reports the task.
+                None => {
+                    let reported = reports
+                        .get(&executor_id)
+                        .map_or(false, |task_ids| task_ids.contains(&task_id));
+
+                    if !reported {
+                        expected.ignored_task_ids.insert(task_id);
+                        unassigned_tasks.insert(task_id, (task_package, routing_strategy));
+                        continue;
+                    }
+
+                    let holder_bind_id = router.adopt_assignment(task_id, bind_id).await?;
+                    assignments.insert(task_id, Some(holder_bind_id));
+                    holder_bind_id == bind_id
+                }
+            }
+        };
+
+        if is_holder {
+            expected.task_packages.insert(task_id, task_package);
+        }
+    }
+
+    // A routed task that no member holds is dispatched again,
+    // As long as every member answered, otherwise the holder may be one that did not.
+    for (task_id, (task_package, routing_strategy)) in unassigned_tasks {
+        if assignments.get(&task_id).copied().flatten().is_some()
+            || failover_task_ids.contains(&task_id)
+        {
+            continue;
+        }
+
+        let members = load_members(storage, task_id).await?;
+        if members.is_empty() || !members.iter().all(|m| reports.contains_key(&m.executor_id)) {
+            continue;
+        }
+
+        for member in router.route(task_id, routing_strategy, members).await? {
+            info!(target:"reconciliation", "No member holds task {}, dispatch it to executor {}.", task_id, member.executor_id);
+            let expected = expected_tasks.entry(member.executor_id).or_default();
+            expected.ignored_task_ids.remove(&task_id);
+            expected.task_packages.insert(task_id, task_package.clone());
+        }
+    }
+
+    for failover in failovers {
+        if let Some(task_package) = failover_tasks.get(&failover.task_id) {
+            expected_tasks
+                .entry(failover.target_executor_id)
+                .or_default()
+                .task_packages
+                .insert(failover.task_id, task_package.clone());
+        }
+    }
+
+    for (executor_id, reported_task_ids) in reports {
+        let (host, token) = match executors.get(&executor_id) {
+            Some(executor) => executor,
+            None => continue,
+        };
+        let ExpectedTasks {
+            task_packages,
+            ignored_task_ids,
+        } = expected_tasks.remove(&executor_id).unwrap_or_default();
+
+        let unexpected_task_ids: Vec<i64> = reported_task_ids
+            .iter()
+            .filter(|task_id| {
+                !task_packages.contains_key(task_id) && !ignored_task_ids.contains(task_id)
+            })
+            .copied()
+            .collect();
+
+        for (task_id, task_package) in task_packages {
+            if reported_task_ids.contains(&task_id) {
+                continue;
+            }
+
+            info!(target:"reconciliation", "Executor {} is missing task{}, create it.", executor_id, &task_package);
+            let created = match task_package.clone().sign(Some(token)) {
+                Ok(signed_task_package) => {
+                    send_to_executor(
+                        request_client,
+                        host,
+                        "/api/task/create",
+                        &signed_task_package,
+                    )
+                    .await
+                }
+                Err(e) => {
+                    error!(target:"reconciliation", "{}", e);
+                    false
+                }
+            };
+
+            // The executor may have lost the task a while ago, e.g. it was restarted.
+            if created {
+                catch_up_missed_runs(storage, request_client, &task_package, host, token).await;
+            }
+        }
+
+        for task_id in unexpected_task_ids {
+            info!(target:"reconciliation", "Executor {} holds task {} that is not bound to it, remove it.", executor_id, task_id);
+            match TaskUnit::default()
+                .set_task_id(task_id)
+                .set_time(timestamp())
+                .sign(Some(token))
+            {
+                Ok(signed_task_unit) => {
+                    send_to_executor(request_client, host, "/api/task/remove", &signed_task_unit)
+                        .await;
+                }
+                Err(e) => error!(target:"reconciliation", "{}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// The enabled members bound to a task.
+async fn load_members(
+    storage: &Arc<dyn db::Storage>,
+    task_id: i64,
+) -> Result<Vec<BindProcessor>, CommonError> {
+    let members = db::blocking(storage, move |storage| {
+        storage.task_members(vec![task_id], true)
+    })
+    .await?
+    .into_iter()
+    .map(|(_, member)| member)
+    .collect();
+
+    Ok(members)
+}
@@ -0,0 +1,421 @@
+use super::prelude::*;
+use delicate_utils_health_check::SystemSnapshot;
+use model::BindProcessor;
+use state::task::RoutingStrategy;
+
+// The number of virtual nodes on the hash ring for each unit of weight.
+const VIRTUAL_NODES_PER_WEIGHT: usize = 16;
+
+/// Picks the executors a task is dispatched to, according to the routing strategy of the task.
+///
+/// The load of each executor comes from the last round of health checks,
+/// And the member chosen for each routed task is kept in `task_bind.holder`,
+/// So that every scheduler instance finds it again, also after a restart.
+pub(crate) struct ExecutorRouter {
+    storage: Arc<dyn db::Storage>,
+    // executor-processor-id -> the system snapshot of the last health check.
+    snapshots: RwLock<HashMap<i64, SystemSnapshot>>,
+}
+
+impl ExecutorRouter {
+    pub(crate) fn new(storage: Arc<dyn db::Storage>) -> Self {
+        ExecutorRouter {
+            storage,
+            snapshots: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Replace the snapshots with the result of the last health check,
+    /// Executors that did not respond no longer take part in least-loaded routing.
+    pub(crate) async fn refresh_snapshots(&self, snapshots: HashMap<i64, SystemSnapshot>) {
+        *self.snapshots.write().await = snapshots;
+    }
+
+    /// Returns the members that should hold the task.
+    ///
+    /// Broadcast (and unknown strategies) keep all the members,
+    /// Every other strategy keeps exactly one member.
+    ///
+    /// The member is chosen once per dispatch, when the task is created, updated or found without a holder,
+    /// And it runs every fire of the task until the next dispatch. So round-robin and weighted-random
+    /// Spread the tasks over the members rather than the runs of one task.
+    /// Tasks triggered by the scheduler are the exception, they are routed again on each fire.
+    pub(crate) async fn route(
+        &self,
+        task_id: i64,
+        strategy: RoutingStrategy,
+        mut candidates: Vec<BindProcessor>,
+    ) -> Result<Vec<BindProcessor>, CommonError> {
+        if candidates.is_empty() {
+            self.set_assignment(task_id, None).await?;
+            return Ok(candidates);
+        }
+
+        // Keep the order stable so round-robin and the hash ring do not depend on the query.
+        candidates.sort_by_key(|c| c.bind_id);
+
+        // Members that failed the last health check are only chosen when no member passed it.
+        if !matches!(
+            strategy,
+            RoutingStrategy::Broadcast | RoutingStrategy::Unknown
+        ) {
+            let snapshots = self.snapshots.read().await;
+            if candidates
+                .iter()
+                .any(|c| snapshots.contains_key(&c.executor_id))
+            {
+                candidates.retain(|c| snapshots.contains_key(&c.executor_id));
+            }
+        }
+
+        let index = match strategy {
+            RoutingStrategy::Broadcast | RoutingStrategy::Unknown => {
+                self.set_assignment(task_id, None).await?;
+                return Ok(candidates);
+            }
+            RoutingStrategy::WeightedRandom => weighted_random_index(&candidates),
+            RoutingStrategy::RoundRobin => {
+                round_robin_index(self.get_assignment(task_id).await?, &candidates)
+            }
+            RoutingStrategy::ConsistentHash => consistent_hash_index(task_id, &candidates),
+            RoutingStrategy::LeastLoaded => {
+                let snapshots = self.snapshots.read().await;
+                least_loaded_index(&snapshots, &candidates)
+                    .unwrap_or_else(|| weighted_random_index(&candidates))
+            }
+        };
+
+        let chosen = candidates.swap_remove(index);
+        self.set_assignment(task_id, Some(chosen.bind_id)).await?;
+
+        Ok(vec![chosen])
+    }
+
+    /// Pick the member that takes over a task from an abnormal executor,
+    /// The least loaded member is preferred, and the weight decides when the load is unknown.
+    pub(crate) async fn pick_standby(
+        &self,
+        mut candidates: Vec<BindProcessor>,
+    ) -> Option<BindProcessor> {
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let index = {
+            let snapshots = self.snapshots.read().await;
+            least_loaded_index(&snapshots, &candidates)
+                .unwrap_or_else(|| weighted_random_index(&candidates))
+        };
+
+        Some(candidates.swap_remove(index))
+    }
+
+    /// The bind-id currently holding a routed task, `None` for broadcast or unknown tasks.
+    pub(crate) async fn get_assignment(&self, task_id: i64) -> Result<Option<i64>, CommonError> {
+        db::blocking(&self.storage, move |storage| storage.task_holder(task_id)).await
+    }
+
+    /// Adopt the member reported to hold a routed task whose holder is unknown,
+    /// E.g. after the task was dispatched by hand, returns the bind-id that holds the task afterwards.
+    pub(crate) async fn adopt_assignment(
+        &self,
+        task_id: i64,
+        bind_id: i64,
+    ) -> Result<i64, CommonError> {
+        match self.get_assignment(task_id).await? {
+            Some(holder_bind_id) => Ok(holder_bind_id),
+            None => {
+                self.set_assignment(task_id, Some(bind_id)).await?;
+                Ok(bind_id)
+            }
+        }
+    }
+
+    /// Forget the routing state of a task that is no longer dispatched.
+    pub(crate) async fn forget(&self, task_id: i64) -> Result<(), CommonError> {
+        self.set_assignment(task_id, None).await
+    }
+
+    // Mark the member holding the task, `None` clears the holder.
+    async fn set_assignment(&self, task_id: i64, bind_id: Option<i64>) -> Result<(), CommonError> {
+        db::blocking(&self.storage, move |storage| {
+            storage.set_task_holder(task_id, bind_id)
+        })
+        .await
+    }
+}
+
+// Round-robin goes on with the member after the holder, so the holder is the cursor as well.
+fn round_robin_index(holder_bind_id: Option<i64>, candidates: &[BindProcessor]) -> usize {
+    holder_bind_id
+        .and_then(|holder_bind_id| candidates.iter().position(|c| c.bind_id > holder_bind_id))
+        .unwrap_or_default()
+}
+
+// Members with a weight of zero or less are only chosen when all members are.
+fn weighted_random_index(candidates: &[BindProcessor]) -> usize {
+    let total_weight: u64 = candidates.iter().map(|c| c.weight.max(0) as u64).sum();
+
+    if total_weight == 0 {
+        return fastrand::usize(..candidates.len());
+    }
+
+    let mut point = fastrand::u64(..total_weight);
+    for (index, candidate) in candidates.iter().enumerate() {
+        let weight = candidate.weight.max(0) as u64;
+        if point < weight {
+            return index;
+        }
+        point -= weight;
+    }
+
+    candidates.len() - 1
+}
+
+fn consistent_hash_index(task_id: i64, candidates: &[BindProcessor]) -> usize {
+    let mut ring: Vec<(u64, usize)> = candidates
+        .iter()
+        .enumerate()
+        .flat_map(|(index, candidate)| {
+            let virtual_nodes = candidate.weight.max(1) as usize * VIRTUAL_NODES_PER_WEIGHT;
+            (0..virtual_nodes)
+                .map(move |node| (hash_key(&format!("{}-{}", candidate.bind_id, node)), index))
+        })
+        .collect();
+    ring.sort_unstable();
+
+    let key = hash_key(&task_id.to_string());
+    ring.iter()
+        .find(|(node_hash, _)| *node_hash >= key)
+        .or_else(|| ring.first())
+        .map(|(_, index)| *index)
+        .unwrap_or_default()
+}
+
+// Sha256 is stable across processes, so every scheduler instance builds the same ring.
+fn hash_key(key: &str) -> u64 {
+    let digest = digest(&SHA256, key.as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest.as_ref()[..8]);
+    u64::from_be_bytes(bytes)
+}
+
+// The load of a member is its cpu usage plus its memory usage, divided by its weight.
+fn least_loaded_index(
+    snapshots: &HashMap<i64, SystemSnapshot>,
+    candidates: &[BindProcessor],
+) -> Option<usize> {
+    candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| {
+            snapshots.get(&candidate.executor_id).map(|snapshot| {
+                let cpu_load = snapshot.processor.cpu_usage as f64 / 100f64;
+                let memory_load = if snapshot.memory.total_memory == 0 {
+                    0f64
+                } else {
+                    snapshot.memory.used_memory as f64 / snapshot.memory.total_memory as f64
+                };
+
+                let load = (cpu_load + memory_load) / candidate.weight.max(1) as f64;
+                (index, load)
+            })
+        })
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(weights: &[i16]) -> Vec<BindProcessor> {
+        weights
+            .iter()
+            .enumerate()
+            .map(|(index, weight)| BindProcessor {
+                bind_id: index as i64 + 1,
+                executor_id: index as i64 + 1,
+                weight: *weight,
+                host: String::new(),
+                token: String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_weighted_random_skips_zero_weight() {
+        let candidates = candidates(&[0, 3, 0]);
+
+        for _ in 0..64 {
+            assert_eq!(weighted_random_index(&candidates), 1);
+        }
+    }
+
+    #[test]
+    fn test_round_robin_follows_the_holder() {
+        let candidates = candidates(&[1, 1, 1]);
+
+        assert_eq!(round_robin_index(None, &candidates), 0);
+        assert_eq!(round_robin_index(Some(1), &candidates), 1);
+        assert_eq!(round_robin_index(Some(2), &candidates), 2);
+        assert_eq!(round_robin_index(Some(3), &candidates), 0);
+    }
+
+    // The bind-id the hash ring gives the task among the candidates.
+    fn consistent_hash_holder(task_id: i64, candidates: &[BindProcessor]) -> i64 {
+        candidates[consistent_hash_index(task_id, candidates)].bind_id
+    }
+
+    #[test]
+    fn test_consistent_hash_ignores_the_order_of_the_candidates() {
+        let candidates = candidates(&[1, 2, 3, 1]);
+        let mut reversed_candidates = candidates.clone();
+        reversed_candidates.reverse();
+        let mut rotated_candidates = candidates.clone();
+        rotated_candidates.rotate_left(1);
+
+        for task_id in 0..256 {
+            let holder = consistent_hash_holder(task_id, &candidates);
+            assert_eq!(
+                consistent_hash_holder(task_id, &reversed_candidates),
+                holder
+            );
+            assert_eq!(consistent_hash_holder(task_id, &rotated_candidates), holder);
+        }
+    }
+
+    #[test]
+    fn test_consistent_hash_only_remaps_the_tasks_of_a_removed_member() {
+        let candidates = candidates(&[1, 2, 3]);
+        let remaining_candidates: Vec<BindProcessor> = candidates
+            .iter()
+            .filter(|c| c.bind_id != 2)
+            .cloned()
+            .collect();
+
+        let mut remapped = 0;
+        for task_id in 0..256 {
+            let holder = consistent_hash_holder(task_id, &candidates);
+            let remaining_holder = consistent_hash_holder(task_id, &remaining_candidates);
+
+            if holder == 2 {
+                remapped += 1;
+                assert_ne!(remaining_holder, 2);
+            } else {
+                assert_eq!(remaining_holder, holder);
+            }
+        }
+
+        assert!(remapped > 0);
+    }
+
+    // A router on an in-memory database, one connection keeps the database alive.
+    #[cfg(feature = "sqlite")]
+    fn router() -> ExecutorRouter {
+        let conf = SchedulerConfig {
+            database_url: String::from(":memory:"),
+            connection_pool_max_size: Some(1),
+            connection_pool_min_idle: Some(1),
+            ..SchedulerConfig::default()
+        };
+        let storage = crate::backends::sqlite::open_storage(&conf);
+        storage.run_migrations().unwrap();
+
+        ExecutorRouter::new(storage)
+    }
+
+    #[cfg(feature = "sqlite")]
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_route_keeps_one_member_of_a_routed_task() {
+        block_on(async {
+            let router = router();
+
+            assert!(router
+                .route(1, RoutingStrategy::WeightedRandom, Vec::new())
+                .await
+                .unwrap()
+                .is_empty());
+
+            for strategy in [
+                RoutingStrategy::WeightedRandom,
+                RoutingStrategy::RoundRobin,
+                RoutingStrategy::ConsistentHash,
+                RoutingStrategy::LeastLoaded,
+            ] {
+                let chosen = router.route(1, strategy, candidates(&[1, 2, 3])).await;
+                assert_eq!(chosen.unwrap().len(), 1);
+            }
+
+            let chosen = router
+                .route(1, RoutingStrategy::Broadcast, candidates(&[1, 2, 3]))
+                .await;
+            assert_eq!(chosen.unwrap().len(), 3);
+        });
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_route_leaves_out_the_members_that_failed_the_health_check() {
+        block_on(async {
+            let router = router();
+            let snapshots = vec![(2, SystemSnapshot::default())].into_iter().collect();
+            router.refresh_snapshots(snapshots).await;
+
+            for strategy in [
+                RoutingStrategy::WeightedRandom,
+                RoutingStrategy::RoundRobin,
+                RoutingStrategy::ConsistentHash,
+                RoutingStrategy::LeastLoaded,
+            ] {
+                for task_id in 0..16 {
+                    let chosen = router
+                        .route(task_id, strategy, candidates(&[1, 1, 1]))
+                        .await
+                        .unwrap();
+                    assert_eq!(chosen[0].bind_id, 2);
+                }
+            }
+
+            // Broadcast is not filtered.
+            let chosen = router
+                .route(1, RoutingStrategy::Broadcast, candidates(&[1, 1, 1]))
+                .await;
+            assert_eq!(chosen.unwrap().len(), 3);
+        });
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_route_keeps_the_failed_members_when_none_passed() {
+        block_on(async {
+            let router = router();
+            let snapshots = vec![(4, SystemSnapshot::default())].into_iter().collect();
+            router.refresh_snapshots(snapshots).await;
+
+            let mut chosen_bind_ids = HashSet::new();
+            for task_id in 0..64 {
+                let chosen = router
+                    .route(
+                        task_id,
+                        RoutingStrategy::ConsistentHash,
+                        candidates(&[1, 1, 1]),
+                    )
+                    .await
+                    .unwrap();
+                chosen_bind_ids.insert(chosen[0].bind_id);
+            }
+
+            assert_eq!(chosen_bind_ids, vec![1, 2, 3].into_iter().collect());
+        });
+    }
+}
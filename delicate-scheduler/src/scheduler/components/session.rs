@@ -8,50 +8,52 @@ use super::prelude::*;
 // And when the request is processed `CookieSession` gets the latest state content
 // in the `Session` and sets it to the client
 // (all this is done in the middleware of `CookieSession`).
-pub(crate) fn session_middleware() -> CookieSession {
-    // Be sure to use a uniform `SESSION_TOKEN` here,
+pub(crate) fn session_middleware(conf: &SchedulerConfig) -> CookieSession {
+    // Be sure to use a uniform `DELICATE_SESSION_TOKEN` here,
     // If each server generates a random key, it will cause inconsistency and the login status will continue to fail.
-    let token_bytes = env::var("SESSION_TOKEN")
-        .expect("Without `SESSION_TOKEN` set in .env")
-        .into_bytes();
+    let token_bytes = conf.session_token.as_bytes();
 
-    let cookie_config = CookieConfig::signed(CookieKey::derive_from(&token_bytes))
-        .domain(
-            env::var("SCHEDULER_COOKIE_DOMAIN")
-                .expect("Without `SCHEDULER_COOKIE_DOMAIN` set in .env"),
-        )
-        .name(env::var("SCHEDULER_NAME").expect("Without `SCHEDULER_NAME` set in .env"))
+    let cookie_config = CookieConfig::signed(CookieKey::derive_from(token_bytes))
+        .domain(conf.scheduler_cookie_domain.clone())
+        .name(conf.scheduler_name.clone())
         .http_only(true)
         .secure(false);
     CookieSession::new(cookie_config)
 }
 
-pub(crate) fn cookie_middleware() -> CookieJarManager {
-    // Be sure to use a uniform `SESSION_TOKEN` here,
+pub(crate) fn cookie_middleware(conf: &SchedulerConfig) -> CookieJarManager {
+    // Be sure to use a uniform `DELICATE_SESSION_TOKEN` here,
     // If each server generates a random key, it will cause inconsistency and the login status will continue to fail.
-    let token_bytes = env::var("SESSION_TOKEN")
-        .expect("Without `SESSION_TOKEN` set in .env")
-        .into_bytes();
+    let token_bytes = conf.session_token.as_bytes();
 
-    CookieJarManager::with_key(CookieKey::derive_from(&token_bytes))
+    CookieJarManager::with_key(CookieKey::derive_from(token_bytes))
 }
 
-pub(crate) fn auth_middleware() -> SessionAuth {
-    SessionAuth
+pub(crate) fn auth_middleware(conf: &SchedulerConfig) -> SessionAuth {
+    SessionAuth {
+        debug: conf.app_debug,
+    }
 }
 
-pub struct SessionAuth;
+pub struct SessionAuth {
+    // Debug switch (For Developers), the session is not checked.
+    debug: bool,
+}
 
 impl<E: Endpoint> Middleware<E> for SessionAuth {
     type Output = SessionAuthMiddleware<E>;
 
     fn transform(&self, ep: E) -> Self::Output {
-        SessionAuthMiddleware { ep }
+        SessionAuthMiddleware {
+            ep,
+            debug: self.debug,
+        }
     }
 }
 
 pub struct SessionAuthMiddleware<E> {
     ep: E,
+    debug: bool,
 }
 
 #[poem::async_trait]
@@ -59,9 +61,8 @@ impl<E: Endpoint> Endpoint for SessionAuthMiddleware<E> {
     type Output = Response;
 
     async fn call(&self, req: Request) -> PoemResult<Self::Output> {
-        #[cfg(APP_DEBUG_MODE)]
-        {
-            return self.ep.call(req).await.into_response();
+        if self.debug {
+            return Ok(self.ep.call(req).await?.into_response());
         }
 
         let session = req.get_session();
@@ -77,7 +78,7 @@ impl<E: Endpoint> Endpoint for SessionAuthMiddleware<E> {
                 Ok(self.ep.call(req).await?.into_response())
             }
             _ => {
-                let user_id = session.get::<u64>("user_id");
+                let user_id = session.get::<i64>("user_id");
                 if user_id.is_some() {
                     Ok(self.ep.call(req).await?.into_response())
                 } else {
@@ -0,0 +1,192 @@
+use super::prelude::*;
+use state::executor_processor::State as ExecutorState;
+use state::operation_log::OperationType;
+
+// How long a run may go past its timeout before the executor is asked about it.
+const LOST_RECORD_GRACE_PERIOD: i64 = 5 * 60;
+
+// A running record that should have ended by now, or whose executor is abnormal.
+#[derive(Debug)]
+struct SuspectRecord {
+    record_id: i64,
+    executor_processor_id: i64,
+}
+
+pub(crate) async fn loop_sweep_lost_records(
+    storage: Arc<dyn db::Storage>,
+    request_client: RequestClient,
+    router: Arc<ExecutorRouter>,
+    election: Arc<LeaderElection>,
+) {
+    let mut interval = interval(Duration::from_secs(60));
+    loop {
+        interval.tick().await;
+        if !election.is_leader() {
+            continue;
+        }
+
+        sweep_lost_records(&storage, &request_client, &router)
+            .await
+            .map_err(|e| error!(target:"loop-sweep-lost-records", "{}", e.to_string()))
+            .ok();
+    }
+}
+
+// Events can be lost and executors can crash, leaving records `Running` forever.
+// The records the executor no longer runs are moved to `Lost`.
+async fn sweep_lost_records(
+    storage: &Arc<dyn db::Storage>,
+    request_client: &RequestClient,
+    router: &ExecutorRouter,
+) -> Result<(), CommonError> {
+    let (suspect_records, executors) = db::blocking(storage, move |storage| {
+        let now = Local::now().naive_local();
+        let running_records = storage
+            .running_records_before(now - ChronoDuration::seconds(LOST_RECORD_GRACE_PERIOD))?;
+
+        if running_records.is_empty() {
+            return Ok((Vec::new(), HashMap::new()));
+        }
+
+        let task_ids: Vec<i64> = running_records.iter().map(|r| r.1).collect();
+        // A task without a timeout is bounded by its wall-clock limit, if any.
+        let timeouts: HashMap<i64, i64> = storage
+            .task_timeouts(task_ids)?
+            .into_iter()
+            .map(|(id, timeout, wall_clock_limit)| {
+                let timeout = if timeout > 0 {
+                    timeout as i64
+                } else {
+                    wall_clock_limit as i64
+                };
+                (id, timeout)
+            })
+            .collect();
+
+        let executor_ids: Vec<i64> = running_records.iter().map(|r| r.2).collect();
+        let executors: HashMap<i64, (String, String, i16)> = storage
+            .executors_by_ids(executor_ids)?
+            .into_iter()
+            .map(|(id, host, token, status)| (id, (host, token, status)))
+            .collect();
+
+        let suspect_records: Vec<SuspectRecord> = running_records
+            .into_iter()
+            .filter(|(_, task_id, executor_processor_id, created_time)| {
+                let executor_abnormal = executors
+                    .get(executor_processor_id)
+                    .map_or(true, |(_, _, status)| {
+                        *status != ExecutorState::Enabled as i16
+                    });
+                let timed_out = timeouts
+                    .get(task_id)
+                    .filter(|timeout| **timeout > 0)
+                    .map_or(false, |timeout| {
+                        *created_time + ChronoDuration::seconds(timeout + LOST_RECORD_GRACE_PERIOD)
+                            < now
+                    });
+
+                executor_abnormal || timed_out
+            })
+            .map(|(record_id, _, executor_processor_id, _)| SuspectRecord {
+                record_id,
+                executor_processor_id,
+            })
+            .collect();
+
+        Ok((suspect_records, executors))
+    })
+    .await?;
+
+    if suspect_records.is_empty() {
+        return Ok(());
+    }
+
+    let mut records_by_executor: HashMap<i64, Vec<i64>> = HashMap::new();
+    for suspect_record in suspect_records {
+        records_by_executor
+            .entry(suspect_record.executor_processor_id)
+            .or_default()
+            .push(suspect_record.record_id);
+    }
+
+    let mut lost_record_ids: Vec<i64> = Vec::new();
+    for (executor_processor_id, record_ids) in records_by_executor {
+        let running_record_ids: HashSet<i64> = match executors.get(&executor_processor_id) {
+            Some((host, token, status)) => {
+                match probe_task_records(request_client, host, token, record_ids.clone()).await {
+                    Ok(running_record_ids) => running_record_ids.into_iter().collect(),
+                    // An executor that cannot be reached only loses its records once it is abnormal.
+                    Err(e) if *status == ExecutorState::Enabled as i16 => {
+                        error!(target:"loop-sweep-lost-records", "Failed to probe executor {}: {}", executor_processor_id, e);
+                        continue;
+                    }
+                    Err(_) => HashSet::new(),
+                }
+            }
+            // The executor was deleted.
+            None => HashSet::new(),
+        };
+
+        lost_record_ids.extend(
+            record_ids
+                .into_iter()
+                .filter(|record_id| !running_record_ids.contains(record_id)),
+        );
+    }
+
+    if lost_record_ids.is_empty() {
+        return Ok(());
+    }
+
+    // Only the records still running are moved, an end event may have arrived in the meantime.
+    let lost_record_ids: Vec<i64> = db::blocking(storage, move |storage| {
+        storage.mark_lost_records(lost_record_ids)
+    })
+    .await?;
+
+    if lost_record_ids.is_empty() {
+        return Ok(());
+    }
+
+    info!(target:"loop-sweep-lost-records", "Records {:?} are lost.", &lost_record_ids);
+    for record_id in lost_record_ids.iter() {
+        let operation_log_pair_option = generate_system_operation_log(
+            "task_log",
+            OperationType::Modify,
+            CommonTableRecord::default()
+                .set_id(*record_id)
+                .set_description("lost task instance."),
+            "",
+        )
+        .ok();
+        send_option_operation_log_pair(operation_log_pair_option).await;
+    }
+
+    // A lost run counts as a failed run for the tasks depending on it.
+    trigger_downstream_tasks(storage, request_client, router, lost_record_ids).await
+}
+
+// Returns the records the executor still runs.
+async fn probe_task_records(
+    request_client: &RequestClient,
+    host: &str,
+    token: &str,
+    record_ids: Vec<i64>,
+) -> Result<Vec<i64>, CommonError> {
+    let url = "http://".to_string() + host + "/api/task_instance/probe";
+
+    let task_record_probe = delicate_utils_task_log::TaskRecordProbe::default()
+        .set_record_ids(record_ids)
+        .set_time(timestamp())
+        .sign(Some(token))?;
+
+    request_client
+        .post(url)
+        .json(&task_record_probe)
+        .send()
+        .await?
+        .json::<UnifiedResponseMessages<Vec<i64>>>()
+        .await?
+        .into()
+}
@@ -13,7 +13,7 @@ pub(crate) type NewOperationLogPairOption = Option<NewOperationLogPair>;
 // operates!( name, session , operation_type, values);
 
 pub trait SeekTableId {
-    fn seek_table_id(&self) -> u64 {
+    fn seek_table_id(&self) -> i64 {
         0
     }
 }
@@ -51,18 +51,18 @@ macro_rules! impl_seek_table_id_unify{
     ($($target:ty),+) => {
         $(impl SeekTableId for $target {
 
-            fn seek_table_id(&self) -> u64
+            fn seek_table_id(&self) -> i64
             {
-                self.id as u64
+                self.id
             }
 
         }
 
         impl SeekTableId for &$target {
 
-            fn seek_table_id(&self) -> u64
+            fn seek_table_id(&self) -> i64
             {
-                self.id as u64
+                self.id
             }
 
         }
@@ -73,7 +73,7 @@ macro_rules! impl_seek_table_id_unify{
     ($($target:ty => $id:expr),+) => {
         $(impl SeekTableId for $target {
 
-            fn seek_table_id(&self) -> u64
+            fn seek_table_id(&self) -> i64
             {
                 $id
             }
@@ -82,7 +82,7 @@ macro_rules! impl_seek_table_id_unify{
 
         impl SeekTableId for &$target {
 
-            fn seek_table_id(&self) -> u64
+            fn seek_table_id(&self) -> i64
             {
                 $id
             }
@@ -107,10 +107,16 @@ impl_seek_table_id_unify!(
     ExecutorProcessorBind,
     UpdateExecutorProcessorBind,
     ExecutorGroup,
-    UpdateExecutorGroup
+    UpdateExecutorGroup,
+    TaskFailover,
+    Calendar,
+    UpdateCalendar
 );
 impl_seek_table_id_unify!(NewTaskLog=>0, NewTask=>0, NewUser=>0, NewTaskBind=>0, NewExecutorProcessor=>0, NewExecutorProcessorBind=>0, NewExecutorGroup=>0, NewExecutorProcessorBinds=>0, DeleteParamsTaskLog=>0, 
-    UserAndRoles=>0, UserAndPermissions=>0);
+    UserAndRoles=>0, UserAndPermissions=>0, NewTaskFailover=>0, NewCalendar=>0);
+
+// Operations made by the scheduler itself (e.g. failover) are recorded under this user name.
+pub(crate) const SYSTEM_OPERATOR_NAME: &str = "system";
 
 #[inline(always)]
 pub(crate) fn generate_operation_log(
@@ -119,12 +125,46 @@ pub(crate) fn generate_operation_log(
     operation_type: OperationType,
     value: impl Serialize + SeekTableId,
     column_comment: impl Serialize,
+) -> Result<(NewOperationLog, NewOperationLogDetail), CommonError> {
+    let user_id = session.get::<i64>("user_id").unwrap_or_default();
+    let user_name = session.get::<String>("user_name").unwrap_or_default();
+
+    generate_operation_log_by_user(
+        operation_name,
+        (user_id, user_name),
+        operation_type,
+        value,
+        column_comment,
+    )
+}
+
+#[inline(always)]
+pub(crate) fn generate_system_operation_log(
+    operation_name: impl ToString,
+    operation_type: OperationType,
+    value: impl Serialize + SeekTableId,
+    column_comment: impl Serialize,
+) -> Result<(NewOperationLog, NewOperationLogDetail), CommonError> {
+    generate_operation_log_by_user(
+        operation_name,
+        (0, SYSTEM_OPERATOR_NAME.to_string()),
+        operation_type,
+        value,
+        column_comment,
+    )
+}
+
+#[inline(always)]
+fn generate_operation_log_by_user(
+    operation_name: impl ToString,
+    (user_id, user_name): (i64, String),
+    operation_type: OperationType,
+    value: impl Serialize + SeekTableId,
+    column_comment: impl Serialize,
 ) -> Result<(NewOperationLog, NewOperationLogDetail), CommonError> {
     let name = operation_name.to_string();
     let table_id = value.seek_table_id();
-    let operation_type = operation_type as i8;
-    let user_id = session.get::<u64>("user_id").unwrap_or_default();
-    let user_name = session.get::<String>("user_name").unwrap_or_default();
+    let operation_type = operation_type as i16;
     let operation_log_id = 0;
     let column_comment = to_json_string(&column_comment)?;
     let values = to_json_string(&value)?;
@@ -226,4 +266,4 @@ macro_rules! generate_operation_log_fn{
 }
 
 // TODO: `column_comment` can generated by const fn.
-generate_operation_log_fn!(("task"=>""), ("task_log"=>""), ("executor_processor"=>""), ("executor_group"=>""), ("executor_processor_bind"=>""), ("user"=>""), ("user_role"=>""), ("user_permission"=>"") );
+generate_operation_log_fn!(("task"=>""), ("task_log"=>""), ("executor_processor"=>""), ("executor_group"=>""), ("executor_processor_bind"=>""), ("user"=>""), ("user_role"=>""), ("user_permission"=>""), ("calendar"=>"") );
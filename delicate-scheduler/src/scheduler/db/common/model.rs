@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use super::prelude::*;
 
 // In order to be compatible with the front-end components,
 // some of the return values have to be named with small humps when they are returned
@@ -35,7 +35,7 @@ impl<T> PaginateData<T> {
         self
     }
 
-    pub(crate) fn set_state_desc<State: crate::db::state::DescribeState>(mut self) -> Self {
+    pub(crate) fn set_state_desc<State: db::state::DescribeState>(mut self) -> Self {
         self.state_desc.insert(State::state_name(), State::desc());
         self
     }
@@ -1,5 +1,5 @@
 #[allow(unused_imports)]
-pub use crate::prelude::*;
+pub use super::prelude::*;
 
 pub mod task {
 
@@ -23,6 +23,137 @@ pub mod task {
             }
         }
     }
+
+    // How a task is dispatched across the executors it is bound to,
+    // The chosen executor keeps the task until it is dispatched again, see `ExecutorRouter::route`.
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, StrumToString, Debug, EnumIter, AsRefStr, IntoStaticStr)]
+    pub enum RoutingStrategy {
+        Broadcast = 1,
+        WeightedRandom = 2,
+        RoundRobin = 3,
+        ConsistentHash = 4,
+        LeastLoaded = 5,
+        Unknown = 81,
+    }
+
+    impl From<i16> for RoutingStrategy {
+        fn from(v: i16) -> RoutingStrategy {
+            match v {
+                1 => RoutingStrategy::Broadcast,
+                2 => RoutingStrategy::WeightedRandom,
+                3 => RoutingStrategy::RoundRobin,
+                4 => RoutingStrategy::ConsistentHash,
+                5 => RoutingStrategy::LeastLoaded,
+                _ => RoutingStrategy::Unknown,
+            }
+        }
+    }
+
+    // What a task does when it runs.
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, StrumToString, Debug, EnumIter, AsRefStr, IntoStaticStr)]
+    pub enum Kind {
+        Shell = 1,
+        Http = 2,
+        Unknown = 81,
+    }
+
+    impl From<i16> for Kind {
+        fn from(v: i16) -> Kind {
+            match v {
+                1 => Kind::Shell,
+                2 => Kind::Http,
+                _ => Kind::Unknown,
+            }
+        }
+    }
+
+    // Who evaluates the schedule of a task, the executors holding it
+    // Or the scheduler, which then sends one-shot runs to the chosen executor.
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, StrumToString, Debug, EnumIter, AsRefStr, IntoStaticStr, PartialEq)]
+    pub enum TriggerMode {
+        Executor = 1,
+        Scheduler = 2,
+        Unknown = 81,
+    }
+
+    impl From<i16> for TriggerMode {
+        fn from(v: i16) -> TriggerMode {
+            match v {
+                1 => TriggerMode::Executor,
+                2 => TriggerMode::Scheduler,
+                _ => TriggerMode::Unknown,
+            }
+        }
+    }
+
+    // The runs fired for the schedules a task missed while it was not scheduled,
+    // E.g. while it was suspended or its executor was down.
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, StrumToString, Debug, EnumIter, AsRefStr, IntoStaticStr, PartialEq)]
+    pub enum MisfirePolicy {
+        Ignore = 1,
+        FireOnce = 2,
+        FireAll = 3,
+        Unknown = 81,
+    }
+
+    impl From<i16> for MisfirePolicy {
+        fn from(v: i16) -> MisfirePolicy {
+            match v {
+                1 => MisfirePolicy::Ignore,
+                2 => MisfirePolicy::FireOnce,
+                3 => MisfirePolicy::FireAll,
+                _ => MisfirePolicy::Unknown,
+            }
+        }
+    }
+}
+
+pub mod task_dependency {
+    use super::*;
+
+    // When a downstream task is triggered by the end of its upstream task.
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, StrumToString, Debug, EnumIter, AsRefStr, IntoStaticStr, PartialEq)]
+    pub enum TriggerCondition {
+        Success = 1,
+        Failure = 2,
+        Unknown = 81,
+    }
+
+    impl From<i16> for TriggerCondition {
+        fn from(v: i16) -> TriggerCondition {
+            match v {
+                1 => TriggerCondition::Success,
+                2 => TriggerCondition::Failure,
+                _ => TriggerCondition::Unknown,
+            }
+        }
+    }
+}
+
+pub mod task_bind {
+    use super::*;
+
+    // Whether the member holds a routed task, i.e. it is the one the task is dispatched to.
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, StrumToString, Debug, EnumIter, AsRefStr, IntoStaticStr, PartialEq)]
+    pub enum Holder {
+        No = 0,
+        Yes = 1,
+    }
+
+    impl From<i16> for Holder {
+        fn from(v: i16) -> Holder {
+            match v {
+                1 => Holder::Yes,
+                _ => Holder::No,
+            }
+        }
+    }
 }
 
 pub mod task_log {
@@ -36,6 +167,11 @@ pub mod task_log {
         AbnormalEnding = 3,
         TimeoutEnding = 4,
         TmanualCancellation = 5,
+        ResourceExceeded = 6,
+        // The run was never reported to end and the executor no longer has it.
+        Lost = 7,
+        // The calendar of the task excludes the time the run was due.
+        Skipped = 8,
         Unknown = 81,
     }
 
@@ -47,6 +183,9 @@ pub mod task_log {
                 3 => State::AbnormalEnding,
                 4 => State::TimeoutEnding,
                 5 => State::TmanualCancellation,
+                6 => State::ResourceExceeded,
+                7 => State::Lost,
+                8 => State::Skipped,
                 _ => State::Unknown,
             }
         }
@@ -121,6 +260,28 @@ pub mod executor_processor {
     }
 }
 
+pub mod executor_processor_health {
+    use super::*;
+
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, StrumToString, Debug, EnumIter, AsRefStr, IntoStaticStr)]
+    pub enum ProbeResult {
+        Healthy = 1,
+        Missed = 2,
+        Unknown = 81,
+    }
+
+    impl From<i16> for ProbeResult {
+        fn from(v: i16) -> ProbeResult {
+            match v {
+                1 => ProbeResult::Healthy,
+                2 => ProbeResult::Missed,
+                _ => ProbeResult::Unknown,
+            }
+        }
+    }
+}
+
 pub mod executor_group {
     use super::*;
 
@@ -244,4 +405,4 @@ macro_rules! impl_state_desc_unify{
     }
 }
 
-impl_state_desc_unify!(task::State=>"task", task_log::State=>"taskLog", user::State=>"user", user_auth::State=>"userAuth", executor_processor::State=>"executorProcessor", executor_group::State=>"executorGroup", operation_log::OperationType=>"operationType", user_login_log::LoginType=>"userLoginType", user_login_log::LoginCommand=>"userLoginCommand");
+impl_state_desc_unify!(task::State=>"task", task::RoutingStrategy=>"taskRoutingStrategy", task::Kind=>"taskKind", task_dependency::TriggerCondition=>"taskDependencyTriggerCondition", task_log::State=>"taskLog", user::State=>"user", user_auth::State=>"userAuth", executor_processor::State=>"executorProcessor", executor_processor_health::ProbeResult=>"executorProcessorHealthResult", executor_group::State=>"executorGroup", operation_log::OperationType=>"operationType", user_login_log::LoginType=>"userLoginType", user_login_log::LoginCommand=>"userLoginCommand");
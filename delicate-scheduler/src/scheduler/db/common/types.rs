@@ -1,5 +1,5 @@
+use super::prelude::*;
 use super::state::task_log::State;
-use crate::prelude::*;
 use delicate_utils_task_log::EventType;
 
 pub(crate) enum IdentityType {
@@ -14,6 +14,8 @@ impl From<EventType> for State {
             EventType::TaskPerform => State::Running,
             EventType::TaskFinish => State::NormalEnding,
             EventType::TaskTimeout => State::TimeoutEnding,
+            EventType::ResourceExceeded => State::ResourceExceeded,
+            EventType::TaskSkipped => State::Skipped,
             EventType::Unknown => State::Unknown,
         }
     }
@@ -0,0 +1,70 @@
+pub(crate) use super::prelude;
+use super::*;
+
+// The tables are the same on every backend, the repositories are implemented in `backends`.
+pub(crate) mod schema;
+
+pub(crate) mod model;
+pub(crate) mod repository;
+
+pub(crate) use repository::*;
+
+pub(crate) mod common;
+
+joinable!(schema::task_bind -> schema::task (task_id));
+joinable!(schema::task_bind -> schema::executor_processor_bind (bind_id));
+joinable!(schema::task_failover -> schema::executor_processor_bind (target_bind_id));
+joinable!(schema::executor_processor_bind -> schema::executor_processor (executor_id));
+joinable!(schema::executor_processor_bind -> schema::executor_group (group_id));
+joinable!(schema::user_auth -> schema::user (user_id));
+joinable!(schema::task_log_extend -> schema::task_log (id));
+
+pub(crate) fn init(conf: &SchedulerConfig, storage: &dyn Storage) {
+    // This will run the necessary migrations.
+    storage.run_migrations().expect("Migration execution failed, please check the database account permission and database service availability.");
+    init_admin_account(conf, storage);
+}
+
+pub(crate) fn init_admin_account(conf: &SchedulerConfig, storage: &dyn Storage) {
+    use model::QueryNewUser;
+
+    let user_name = conf
+        .initial_administrator_user_name
+        .clone()
+        .unwrap_or_else(|| {
+            println!(
+                r"`DELICATE_INITIAL_ADMINISTRATOR_USER_NAME` may not set in the configuration.
+        The default login user-name will as `admin`  .
+        Please ignore this error if you have set or initialized delicate."
+            );
+            "admin".into()
+        });
+
+    let certificate = conf
+        .initial_administrator_password
+        .clone()
+        .unwrap_or_else(|| {
+            println!(
+                r"`DELICATE_INITIAL_ADMINISTRATOR_PASSWORD` may not set in the configuration.
+        The default login user-password will as `admin`  .
+        Please ignore this error if you have set or initialized delicate."
+            );
+
+            "admin".into()
+        });
+    let nick_name = conf.initial_administrator_nick_name.clone();
+    let mobile = conf.initial_administrator_mobile.clone();
+    let email = conf.initial_administrator_email.clone();
+
+    let admin = QueryNewUser {
+        user_name,
+        nick_name,
+        mobile,
+        email,
+        certificate,
+    };
+
+    storage
+        .init_admin_account(admin)
+        .expect("Init admin-account fail.");
+}
@@ -0,0 +1,173 @@
+use super::prelude::*;
+use super::schema::{calendar, calendar_window};
+
+#[derive(Queryable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "calendar"]
+
+pub struct Calendar {
+    pub(crate) id: i64,
+    pub(crate) name: String,
+    description: String,
+    pub(crate) time_zone: i16,
+    pub(crate) business_days_only: i16,
+    created_time: NaiveDateTime,
+    updated_time: NaiveDateTime,
+}
+
+#[derive(Queryable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "calendar_window"]
+
+pub struct CalendarWindow {
+    id: i64,
+    pub(crate) calendar_id: i64,
+    pub(crate) start_time: NaiveDateTime,
+    pub(crate) end_time: NaiveDateTime,
+    pub(crate) description: String,
+}
+
+#[derive(Insertable, Debug, Default, Serialize, Deserialize)]
+#[table_name = "calendar"]
+pub struct NewCalendar {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) description: String,
+    #[serde(default = "default_time_zone")]
+    pub(crate) time_zone: i16,
+    #[serde(default)]
+    pub(crate) business_days_only: i16,
+}
+
+#[derive(Identifiable, AsChangeset, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "calendar"]
+pub struct UpdateCalendar {
+    pub(crate) id: i64,
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default = "default_time_zone")]
+    pub(crate) time_zone: i16,
+    #[serde(default)]
+    business_days_only: i16,
+}
+
+fn default_time_zone() -> i16 {
+    2
+}
+
+#[derive(Insertable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "calendar_window"]
+pub struct NewCalendarWindow {
+    pub(crate) calendar_id: i64,
+    pub(crate) start_time: NaiveDateTime,
+    pub(crate) end_time: NaiveDateTime,
+    pub(crate) description: String,
+}
+
+// An excluded window declared in the calendar body.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CalendarWindowItem {
+    pub(crate) start_time: NaiveDateTime,
+    pub(crate) end_time: NaiveDateTime,
+    #[serde(default)]
+    pub(crate) description: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NewCalendarBody {
+    pub(crate) calendar: NewCalendar,
+    #[serde(default)]
+    pub(crate) excluded_windows: Vec<CalendarWindowItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateCalendarBody {
+    pub(crate) calendar: UpdateCalendar,
+    // The windows replace the ones of the calendar.
+    #[serde(default)]
+    pub(crate) excluded_windows: Vec<CalendarWindowItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarDetail {
+    pub(crate) inner: Calendar,
+    pub(crate) excluded_windows: Vec<CalendarWindow>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct QueryParamsCalendar {
+    pub(crate) id: Option<i64>,
+    pub(crate) name: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) per_page: i64,
+    pub(crate) page: i64,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+
+pub struct CalendarId {
+    pub(crate) calendar_id: i64,
+}
+
+/// Calendars are refused when their windows could not be evaluated.
+pub(crate) fn check_calendar(
+    time_zone: i16,
+    excluded_windows: &[CalendarWindowItem],
+) -> Result<(), CommonError> {
+    if !matches!(time_zone, 1 | 2) {
+        return Err(CommonError::DisPass(String::from(
+            "Ineffective time-zone mode.",
+        )));
+    }
+
+    if let Some(window) = excluded_windows
+        .iter()
+        .find(|window| window.start_time >= window.end_time)
+    {
+        return Err(CommonError::DisPass(format!(
+            "The excluded window `{}` ends before it starts.",
+            window.description
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(start_time: &str, end_time: &str, description: &str) -> CalendarWindowItem {
+        CalendarWindowItem {
+            start_time: start_time.parse().unwrap(),
+            end_time: end_time.parse().unwrap(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_check_calendar_accepts_the_time_zones() {
+        assert!(check_calendar(1, &[]).is_ok());
+        assert!(check_calendar(2, &[]).is_ok());
+        assert!(check_calendar(0, &[]).is_err());
+        assert!(check_calendar(3, &[]).is_err());
+    }
+
+    #[test]
+    fn test_check_calendar_refuses_empty_and_reversed_windows() {
+        let holiday = window("2022-01-31T00:00:00", "2022-02-07T00:00:00", "holiday");
+        assert!(check_calendar(2, std::slice::from_ref(&holiday)).is_ok());
+
+        let empty = window("2022-01-31T00:00:00", "2022-01-31T00:00:00", "empty");
+        assert!(check_calendar(2, &[holiday.clone(), empty]).is_err());
+
+        let reversed = window("2022-02-07T00:00:00", "2022-01-31T00:00:00", "reversed");
+        assert!(check_calendar(2, &[reversed, holiday]).is_err());
+    }
+
+    #[test]
+    fn test_check_calendar_accepts_overlapping_windows() {
+        let holiday = window("2022-01-31T00:00:00", "2022-02-07T00:00:00", "holiday");
+        let blackout = window("2022-02-06T22:00:00", "2022-02-07T02:00:00", "blackout");
+
+        assert!(check_calendar(1, &[holiday, blackout]).is_ok());
+    }
+}
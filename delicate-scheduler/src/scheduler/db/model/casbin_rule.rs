@@ -1,9 +1,9 @@
-use crate::db::schema::casbin_rule;
+use super::schema::casbin_rule;
 
 #[derive(Queryable, Identifiable, Clone, Debug, Serialize, Deserialize, Default)]
 #[table_name = "casbin_rule"]
 pub(crate) struct CasbinRule {
-    pub id: u64,
+    pub id: i64,
     pub ptype: String,
     pub v0: String,
     pub v1: String,
@@ -26,6 +26,6 @@ pub(crate) struct NewCasbinRule {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
-pub struct RoleId{
-   pub role_id:usize
-}
\ No newline at end of file
+pub struct RoleId {
+    pub role_id: usize,
+}
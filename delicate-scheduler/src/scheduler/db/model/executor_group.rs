@@ -0,0 +1,65 @@
+use super::prelude::*;
+use super::schema::executor_group;
+
+#[derive(Queryable, Identifiable, AsChangeset, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "executor_group"]
+
+pub struct ExecutorGroup {
+    pub(crate) id: i64,
+    name: String,
+    description: String,
+    tag: String,
+    created_time: NaiveDateTime,
+    deleted_time: Option<NaiveDateTime>,
+}
+
+#[derive(Queryable, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExecutorGroupBinding {
+    bingding_id: i64,
+    bingding_name: String,
+    executor_id: i64,
+    weight: i16,
+    executor_name: String,
+    host: String,
+    machine_id: i16,
+}
+
+#[derive(Queryable, Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutorGroupDetail {
+    pub(crate) inner: ExecutorGroup,
+    pub(crate) bindings: Vec<ExecutorGroupBinding>,
+}
+
+#[derive(Queryable, Identifiable, AsChangeset, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "executor_group"]
+
+pub struct UpdateExecutorGroup {
+    pub(crate) id: i64,
+    name: String,
+    description: String,
+    tag: String,
+}
+
+#[derive(Insertable, Debug, Default, Serialize, Deserialize)]
+#[table_name = "executor_group"]
+pub struct NewExecutorGroup {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) tag: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct QueryParamsExecutorGroup {
+    pub(crate) id: Option<i64>,
+    pub(crate) name: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) tag: Option<String>,
+    pub(crate) per_page: i64,
+    pub(crate) page: i64,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+
+pub struct ExecutorGroupId {
+    pub(crate) executor_group_id: i64,
+}
@@ -0,0 +1,82 @@
+use super::prelude::*;
+use super::schema::executor_processor;
+
+#[derive(Queryable, Identifiable, AsChangeset, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "executor_processor"]
+
+pub struct ExecutorProcessor {
+    pub(crate) id: i64,
+    pub(crate) name: String,
+    pub(crate) host: String,
+    pub(crate) machine_id: i16,
+    description: String,
+    tag: String,
+    status: i16,
+    token: String,
+    created_time: NaiveDateTime,
+    deleted_time: Option<NaiveDateTime>,
+}
+
+#[derive(Insertable, Debug, Serialize, Deserialize)]
+#[table_name = "executor_processor"]
+pub struct NewExecutorProcessor {
+    name: String,
+    description: String,
+    host: String,
+    machine_id: i16,
+    tag: String,
+}
+
+#[derive(Queryable, Identifiable, AsChangeset, Debug, Default, Clone, Serialize, Deserialize)]
+#[table_name = "executor_processor"]
+
+pub struct UpdateExecutorProcessor {
+    pub(crate) id: i64,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) host: String,
+    pub(crate) machine_id: i16,
+    pub(crate) tag: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct QueryParamsExecutorProcessor {
+    pub(crate) id: Option<i64>,
+    pub(crate) name: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) host: Option<String>,
+    pub(crate) tag: Option<String>,
+    pub(crate) machine_id: Option<i16>,
+    pub(crate) per_page: i64,
+    pub(crate) page: i64,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+
+pub struct ExecutorProcessorId {
+    pub(crate) executor_processor_id: i64,
+}
+
+#[derive(Queryable, AsChangeset, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "executor_processor"]
+
+pub struct ExecutorSelection {
+    id: i64,
+    #[serde(rename(serialize = "title"))]
+    name: String,
+}
+
+#[cached(
+    type = "TimedSizedCache<i64, Option<String>>",
+    create = "{ TimedSizedCache::with_size_and_lifespan(1024, 60) }",
+    convert = r#"{ id }"#
+)]
+pub(crate) async fn get_executor_token_by_id(
+    id: i64,
+    storage: Arc<dyn db::Storage>,
+) -> Option<String> {
+    db::blocking(&storage, move |storage| storage.executor_token(id))
+        .await
+        .ok()
+        .flatten()
+}
@@ -0,0 +1,66 @@
+use super::prelude::*;
+use super::schema::executor_processor_bind;
+
+#[derive(Queryable, AsChangeset, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "executor_processor_bind"]
+
+pub struct ExecutorProcessorBind {
+    pub(crate) id: i64,
+    name: String,
+    group_id: i64,
+    executor_id: i64,
+    weight: i16,
+    created_time: NaiveDateTime,
+}
+
+#[derive(Queryable, AsChangeset, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "executor_processor_bind"]
+
+pub struct UpdateExecutorProcessorBind {
+    pub(crate) id: i64,
+    pub(crate) name: String,
+    pub(crate) group_id: i64,
+    pub(crate) executor_id: i64,
+    pub(crate) weight: i16,
+}
+
+#[derive(Queryable, AsChangeset, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "executor_processor_bind"]
+
+pub struct BindingSelection {
+    id: i64,
+    #[serde(rename(serialize = "title"))]
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewExecutorProcessorBinds {
+    pub(crate) name: String,
+    pub(crate) group_id: i64,
+    pub(crate) executor_ids: Vec<i64>,
+    pub(crate) weight: i16,
+}
+
+#[derive(Insertable, AsChangeset, Debug, Serialize, Deserialize)]
+#[table_name = "executor_processor_bind"]
+pub struct NewExecutorProcessorBind {
+    pub(crate) name: String,
+    pub(crate) group_id: i64,
+    pub(crate) executor_id: i64,
+    pub(crate) weight: i16,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct QueryParamsExecutorProcessorBind {
+    pub(crate) id: Option<i64>,
+    pub(crate) group_id: Option<i64>,
+    pub(crate) executor_id: Option<i64>,
+    pub(crate) name: Option<String>,
+    pub(crate) per_page: i64,
+    pub(crate) page: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecutorProcessorBindId {
+    pub(crate) executor_processor_bind_id: i64,
+}
@@ -0,0 +1,79 @@
+use super::prelude::*;
+use super::schema::executor_processor_health;
+
+#[derive(Queryable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "executor_processor_health"]
+
+pub struct ExecutorProcessorHealth {
+    id: i64,
+    executor_processor_id: i64,
+    result: i16,
+    status: i16,
+    response_time: i32,
+    error: String,
+    created_time: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+
+pub struct FrontEndExecutorProcessorHealth {
+    id: i64,
+    executor_processor_id: i64,
+    result: i16,
+    result_desc: &'static str,
+    status: i16,
+    status_desc: &'static str,
+    response_time: i32,
+    error: String,
+    created_time: NaiveDateTime,
+}
+
+impl From<ExecutorProcessorHealth> for FrontEndExecutorProcessorHealth {
+    fn from(health: ExecutorProcessorHealth) -> Self {
+        let ExecutorProcessorHealth {
+            id,
+            executor_processor_id,
+            result,
+            status,
+            response_time,
+            error,
+            created_time,
+        } = health;
+        let result_desc =
+            Into::<state::executor_processor_health::ProbeResult>::into(result).into();
+        let status_desc = Into::<state::executor_processor::State>::into(status).into();
+
+        FrontEndExecutorProcessorHealth {
+            id,
+            executor_processor_id,
+            result,
+            result_desc,
+            status,
+            status_desc,
+            response_time,
+            error,
+            created_time,
+        }
+    }
+}
+
+/// A probe of the health check, along with the status of the processor after it.
+#[derive(Insertable, Debug, Default, Clone, Serialize, Deserialize)]
+#[table_name = "executor_processor_health"]
+pub struct NewExecutorProcessorHealth {
+    pub(crate) executor_processor_id: i64,
+    pub(crate) result: i16,
+    pub(crate) status: i16,
+    pub(crate) response_time: i32,
+    pub(crate) error: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct QueryParamsExecutorProcessorHealth {
+    pub(crate) executor_processor_id: Option<i64>,
+    pub(crate) result: Option<i16>,
+    pub(crate) start_time: Option<String>,
+    pub(crate) end_time: Option<String>,
+    pub(crate) per_page: i64,
+    pub(crate) page: i64,
+}
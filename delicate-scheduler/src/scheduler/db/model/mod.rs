@@ -1,27 +1,32 @@
 pub(crate) use super::schema;
+pub(crate) mod calendar;
+pub(crate) mod casbin_rule;
 pub(crate) mod data_reports;
 pub(crate) mod executor_group;
 pub(crate) mod executor_processor;
 pub(crate) mod executor_processor_bind;
+pub(crate) mod executor_processor_health;
+pub(crate) mod operation_log;
 pub(crate) mod task;
 pub(crate) mod task_bind;
+pub(crate) mod task_dependency;
+pub(crate) mod task_failover;
 pub(crate) mod task_log;
 pub(crate) mod user;
-pub(crate) mod operation_log;
 pub(crate) mod user_login_log;
-pub(crate) mod casbin_rule;
-
-
 
 pub(crate) use super::prelude;
+pub(crate) use calendar::*;
 pub(crate) use data_reports::*;
 pub(crate) use executor_group::*;
 pub(crate) use executor_processor::*;
 pub(crate) use executor_processor_bind::*;
+pub(crate) use executor_processor_health::*;
+pub(crate) use operation_log::*;
 pub(crate) use task::*;
 pub(crate) use task_bind::*;
+pub(crate) use task_dependency::*;
+pub(crate) use task_failover::*;
 pub(crate) use task_log::*;
 pub(crate) use user::*;
-pub(crate) use operation_log::*;
 pub(crate) use user_login_log::*;
-
@@ -0,0 +1,103 @@
+use super::prelude::*;
+use super::schema::{operation_log, operation_log_detail};
+
+#[derive(Queryable, Identifiable, AsChangeset, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "operation_log"]
+
+pub struct OperationLog {
+    id: i64,
+    name: String,
+    table_id: i64,
+    operation_type: i16,
+    user_id: i64,
+    user_name: String,
+    operation_time: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FrontEndOperationLog {
+    id: i64,
+    name: String,
+    table_id: i64,
+    operation_type: i16,
+    operation_type_desc: &'static str,
+    user_id: i64,
+    user_name: String,
+    operation_time: NaiveDateTime,
+}
+
+impl From<OperationLog> for FrontEndOperationLog {
+    fn from(log: OperationLog) -> Self {
+        let OperationLog {
+            id,
+            name,
+            table_id,
+            operation_type,
+            user_id,
+            user_name,
+            operation_time,
+        } = log;
+        let operation_type_desc =
+            Into::<state::operation_log::OperationType>::into(operation_type as i16).into();
+
+        Self {
+            id,
+            name,
+            table_id,
+            operation_type,
+            operation_type_desc,
+            user_id,
+            user_name,
+            operation_time,
+        }
+    }
+}
+
+#[derive(Queryable, Identifiable, AsChangeset, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "operation_log_detail"]
+
+pub struct OperationLogDetail {
+    id: i64,
+    operation_log_id: i64,
+    column_comment: String,
+    values: String,
+}
+
+#[derive(Insertable, Debug, Default, Serialize, Deserialize)]
+#[table_name = "operation_log"]
+pub struct NewOperationLog {
+    pub(crate) name: String,
+    pub(crate) table_id: i64,
+    pub(crate) operation_type: i16,
+    pub(crate) user_id: i64,
+    pub(crate) user_name: String,
+}
+
+#[derive(Insertable, Debug, Default, Serialize, Deserialize)]
+#[table_name = "operation_log_detail"]
+
+pub struct NewOperationLogDetail {
+    pub(crate) operation_log_id: i64,
+    pub(crate) column_comment: String,
+    pub(crate) values: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct QueryParamsOperationLog {
+    pub(crate) id: Option<i64>,
+    pub(crate) name: Option<String>,
+    pub(crate) table_id: Option<i64>,
+    pub(crate) operation_type: Option<i16>,
+    pub(crate) user_id: Option<i64>,
+    pub(crate) user_name: Option<String>,
+    pub(crate) start_time: Option<String>,
+    pub(crate) end_time: Option<String>,
+    pub(crate) per_page: i64,
+    pub(crate) page: i64,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+
+pub struct OperationLogId {
+    pub(crate) operation_log_id: i64,
+}
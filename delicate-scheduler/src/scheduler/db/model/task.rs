@@ -0,0 +1,491 @@
+use super::prelude::*;
+use super::schema::task;
+use super::task_dependency::TaskDependencyItem;
+use delicate_utils::consensus_message::resource_limit::ResourceLimits;
+use delicate_utils::consensus_message::task_routine::ProcessOptions;
+
+#[derive(
+    Queryable, Insertable, Clone, Identifiable, AsChangeset, Debug, Serialize, Deserialize,
+)]
+#[table_name = "task"]
+
+pub struct Task {
+    pub(crate) id: i64,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) command: String,
+    pub(crate) frequency: String,
+    pub(crate) cron_expression: String,
+    pub(crate) timeout: i16,
+    pub(crate) retry_times: i16,
+    pub(crate) retry_interval: i16,
+    pub(crate) maximum_parallel_runnable_num: i16,
+    pub(crate) tag: String,
+    pub(crate) status: i16,
+    pub(crate) created_time: NaiveDateTime,
+    pub(crate) deleted_time: Option<NaiveDateTime>,
+    pub(crate) routing_strategy: i16,
+    pub(crate) kind: i16,
+    pub(crate) env: String,
+    pub(crate) working_directory: String,
+    pub(crate) run_as_user: String,
+    pub(crate) run_as_group: String,
+    pub(crate) stdin: String,
+    pub(crate) cpu_limit: i32,
+    pub(crate) memory_limit: i32,
+    pub(crate) pids_limit: i32,
+    pub(crate) wall_clock_limit: i32,
+    pub(crate) output_limit: i32,
+    pub(crate) trigger_mode: i16,
+    pub(crate) misfire_policy: i16,
+    pub(crate) misfire_limit: i16,
+    pub(crate) calendar_id: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FrontEndTask {
+    pub(crate) id: i64,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) command: String,
+    pub(crate) frequency: String,
+    pub(crate) cron_expression: String,
+    pub(crate) timeout: i16,
+    pub(crate) retry_times: i16,
+    pub(crate) retry_interval: i16,
+    pub(crate) maximum_parallel_runnable_num: i16,
+    pub(crate) tag: String,
+    pub(crate) status: i16,
+    pub(crate) created_time: NaiveDateTime,
+    pub(crate) deleted_time: Option<NaiveDateTime>,
+    pub(crate) routing_strategy: i16,
+    pub(crate) kind: i16,
+    pub(crate) env: String,
+    pub(crate) working_directory: String,
+    pub(crate) run_as_user: String,
+    pub(crate) run_as_group: String,
+    pub(crate) stdin: String,
+    pub(crate) cpu_limit: i32,
+    pub(crate) memory_limit: i32,
+    pub(crate) pids_limit: i32,
+    pub(crate) wall_clock_limit: i32,
+    pub(crate) output_limit: i32,
+    pub(crate) trigger_mode: i16,
+    pub(crate) misfire_policy: i16,
+    pub(crate) misfire_limit: i16,
+    pub(crate) calendar_id: i64,
+    pub(crate) binding_ids: Vec<i64>,
+    pub(crate) dependencies: Vec<TaskDependencyItem>,
+}
+
+impl From<Task> for FrontEndTask {
+    fn from(task: Task) -> Self {
+        let Task {
+            id,
+            name,
+            description,
+            command,
+            frequency,
+            cron_expression,
+            timeout,
+            retry_times,
+            retry_interval,
+            maximum_parallel_runnable_num,
+            tag,
+            status,
+            created_time,
+            deleted_time,
+            routing_strategy,
+            kind,
+            env,
+            working_directory,
+            run_as_user,
+            run_as_group,
+            stdin,
+            cpu_limit,
+            memory_limit,
+            pids_limit,
+            wall_clock_limit,
+            output_limit,
+            trigger_mode,
+            misfire_policy,
+            misfire_limit,
+            calendar_id,
+        } = task;
+
+        let binding_ids: Vec<i64> = Vec::new();
+        let dependencies: Vec<TaskDependencyItem> = Vec::new();
+
+        FrontEndTask {
+            id,
+            name,
+            description,
+            command,
+            frequency,
+            cron_expression,
+            timeout,
+            retry_times,
+            retry_interval,
+            maximum_parallel_runnable_num,
+            tag,
+            status,
+            created_time,
+            deleted_time,
+            routing_strategy,
+            kind,
+            env,
+            working_directory,
+            run_as_user,
+            run_as_group,
+            stdin,
+            cpu_limit,
+            memory_limit,
+            pids_limit,
+            wall_clock_limit,
+            output_limit,
+            trigger_mode,
+            misfire_policy,
+            misfire_limit,
+            calendar_id,
+            binding_ids,
+            dependencies,
+        }
+    }
+}
+
+#[derive(Insertable, Debug, Default, Serialize, Deserialize)]
+#[table_name = "task"]
+pub struct NewTask {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) command: String,
+    pub(crate) frequency: String,
+    pub(crate) cron_expression: String,
+    pub(crate) timeout: i16,
+    pub(crate) retry_times: i16,
+    pub(crate) retry_interval: i16,
+    pub(crate) maximum_parallel_runnable_num: i16,
+    pub(crate) tag: String,
+    #[serde(default = "default_routing_strategy")]
+    pub(crate) routing_strategy: i16,
+    #[serde(default = "default_kind")]
+    pub(crate) kind: i16,
+    #[serde(default)]
+    pub(crate) env: String,
+    #[serde(default)]
+    pub(crate) working_directory: String,
+    #[serde(default)]
+    pub(crate) run_as_user: String,
+    #[serde(default)]
+    pub(crate) run_as_group: String,
+    #[serde(default)]
+    pub(crate) stdin: String,
+    #[serde(default)]
+    pub(crate) cpu_limit: i32,
+    #[serde(default)]
+    pub(crate) memory_limit: i32,
+    #[serde(default)]
+    pub(crate) pids_limit: i32,
+    #[serde(default)]
+    pub(crate) wall_clock_limit: i32,
+    #[serde(default)]
+    pub(crate) output_limit: i32,
+    #[serde(default = "default_trigger_mode")]
+    pub(crate) trigger_mode: i16,
+    #[serde(default = "default_misfire_policy")]
+    pub(crate) misfire_policy: i16,
+    #[serde(default)]
+    pub(crate) misfire_limit: i16,
+    #[serde(default)]
+    pub(crate) calendar_id: i64,
+}
+
+#[derive(
+    Queryable, Insertable, Clone, Identifiable, AsChangeset, Debug, Serialize, Deserialize,
+)]
+#[table_name = "task"]
+
+pub struct UpdateTask {
+    pub(crate) id: i64,
+    name: String,
+    description: String,
+    pub(crate) command: String,
+    pub(crate) frequency: String,
+    pub(crate) cron_expression: String,
+    timeout: i16,
+    retry_times: i16,
+    retry_interval: i16,
+    maximum_parallel_runnable_num: i16,
+    tag: String,
+    #[serde(default = "default_routing_strategy")]
+    routing_strategy: i16,
+    #[serde(default = "default_kind")]
+    pub(crate) kind: i16,
+    #[serde(default)]
+    pub(crate) env: String,
+    #[serde(default)]
+    pub(crate) working_directory: String,
+    #[serde(default)]
+    pub(crate) run_as_user: String,
+    #[serde(default)]
+    pub(crate) run_as_group: String,
+    #[serde(default)]
+    pub(crate) stdin: String,
+    #[serde(default)]
+    pub(crate) cpu_limit: i32,
+    #[serde(default)]
+    pub(crate) memory_limit: i32,
+    #[serde(default)]
+    pub(crate) pids_limit: i32,
+    #[serde(default)]
+    pub(crate) wall_clock_limit: i32,
+    #[serde(default)]
+    pub(crate) output_limit: i32,
+    #[serde(default = "default_trigger_mode")]
+    pub(crate) trigger_mode: i16,
+    #[serde(default = "default_misfire_policy")]
+    pub(crate) misfire_policy: i16,
+    #[serde(default)]
+    pub(crate) misfire_limit: i16,
+    #[serde(default)]
+    pub(crate) calendar_id: i64,
+}
+
+// Tasks saved by an older front-end keep today's behavior.
+fn default_routing_strategy() -> i16 {
+    state::task::RoutingStrategy::Broadcast as i16
+}
+
+fn default_kind() -> i16 {
+    state::task::Kind::Shell as i16
+}
+
+fn default_trigger_mode() -> i16 {
+    state::task::TriggerMode::Executor as i16
+}
+
+fn default_misfire_policy() -> i16 {
+    state::task::MisfirePolicy::Ignore as i16
+}
+
+macro_rules! impl_process_options {
+    ($($target:ty),+) => {
+        $(impl $target {
+            pub(crate) fn process_options(&self) -> ProcessOptions {
+                ProcessOptions {
+                    env: self.env.clone(),
+                    working_directory: self.working_directory.clone(),
+                    run_as_user: self.run_as_user.clone(),
+                    run_as_group: self.run_as_group.clone(),
+                    stdin: self.stdin.clone(),
+                    resource_limits: ResourceLimits {
+                        cpu_limit: self.cpu_limit,
+                        memory_limit: self.memory_limit,
+                        pids_limit: self.pids_limit,
+                        wall_clock_limit: self.wall_clock_limit,
+                        output_limit: self.output_limit,
+                    },
+                }
+            }
+        })+
+    };
+}
+
+impl_process_options!(NewTask, UpdateTask);
+
+/// Tasks are refused when the executor would not be able to run them,
+/// E.g. an http task whose command is not an http request, or an env that is not a json object.
+pub(crate) fn check_task_command(
+    kind: i16,
+    command: &str,
+    process_options: &ProcessOptions,
+) -> Result<(), CommonError> {
+    use delicate_utils::consensus_message::http_task::{HttpRequest, TaskKind};
+
+    match TaskKind::from(kind) {
+        TaskKind::Shell => process_options.check(),
+        TaskKind::Http => command.parse::<HttpRequest>().map(|_| ()),
+        TaskKind::Unknown => Err(CommonError::DisPass(String::from("Ineffective task kind."))),
+    }
+}
+
+/// Tasks are refused when their schedule could not be evaluated, the time zone named included.
+pub(crate) fn check_task_frequency(
+    frequency: &str,
+    cron_expression: &str,
+) -> Result<(), CommonError> {
+    delicate_utils::consensus_message::task::check_frequency(frequency, cron_expression)
+}
+
+/// Tasks are refused when nobody would evaluate their schedule.
+pub(crate) fn check_trigger_mode(trigger_mode: i16) -> Result<(), CommonError> {
+    if state::task::TriggerMode::from(trigger_mode) == state::task::TriggerMode::Unknown {
+        return Err(CommonError::DisPass(String::from(
+            "Ineffective trigger mode.",
+        )));
+    }
+    Ok(())
+}
+
+/// `FireAll` needs the most missed runs it may fire.
+pub(crate) fn check_misfire_policy(
+    misfire_policy: i16,
+    misfire_limit: i16,
+) -> Result<(), CommonError> {
+    use state::task::MisfirePolicy;
+
+    match MisfirePolicy::from(misfire_policy) {
+        MisfirePolicy::Unknown => Err(CommonError::DisPass(String::from(
+            "Ineffective misfire policy.",
+        ))),
+        MisfirePolicy::FireAll if misfire_limit <= 0 => Err(CommonError::DisPass(String::from(
+            "The misfire limit should be positive to fire all missed runs.",
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+
+pub struct TaskId {
+    pub(crate) task_id: i64,
+}
+
+// A schedule as it would be saved with a task, and the number of fire times to preview.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TaskSchedulePreview {
+    pub(crate) frequency: String,
+    pub(crate) cron_expression: String,
+    #[serde(default = "default_preview_count")]
+    pub(crate) count: usize,
+}
+
+fn default_preview_count() -> usize {
+    5
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NewTaskBody {
+    pub(crate) task: NewTask,
+    pub(crate) binding_ids: Vec<i64>,
+    #[serde(default)]
+    pub(crate) dependencies: Vec<TaskDependencyItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateTaskBody {
+    pub(crate) task: UpdateTask,
+    pub(crate) binding_ids: Vec<i64>,
+    // The dependencies are kept as they are when omitted.
+    #[serde(default)]
+    pub(crate) dependencies: Option<Vec<TaskDependencyItem>>,
+}
+
+#[derive(Queryable, Identifiable, AsChangeset, Debug, Default, Serialize, Deserialize)]
+#[table_name = "task"]
+pub struct SupplyTask {
+    pub(crate) id: i64,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) command: String,
+    pub(crate) frequency: String,
+    pub(crate) cron_expression: String,
+    pub(crate) tag: String,
+    pub(crate) maximum_parallel_runnable_num: i16,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct QueryParamsTask {
+    pub(crate) id: Option<i64>,
+    pub(crate) name: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) command: Option<String>,
+    pub(crate) cron_expression: Option<String>,
+    pub(crate) tag: Option<String>,
+    pub(crate) status: Option<i16>,
+    pub(crate) per_page: i64,
+    pub(crate) page: i64,
+}
+
+/// The columns a `TaskPackage` is loaded from, in the order of its fields.
+pub(crate) type TaskPackageColumns = (
+    task::id,
+    task::command,
+    task::frequency,
+    task::cron_expression,
+    task::timeout,
+    task::maximum_parallel_runnable_num,
+    task::retry_times,
+    task::retry_interval,
+    task::kind,
+    (
+        task::env,
+        task::working_directory,
+        task::run_as_user,
+        task::run_as_group,
+        task::stdin,
+        (
+            task::cpu_limit,
+            task::memory_limit,
+            task::pids_limit,
+            task::wall_clock_limit,
+            task::output_limit,
+        ),
+    ),
+    task::calendar_id,
+);
+
+/// Select these to load a `TaskPackage`, its calendars are attached by `attach_calendars`.
+pub(crate) fn task_package_columns() -> TaskPackageColumns {
+    (
+        task::id,
+        task::command,
+        task::frequency,
+        task::cron_expression,
+        task::timeout,
+        task::maximum_parallel_runnable_num,
+        task::retry_times,
+        task::retry_interval,
+        task::kind,
+        (
+            task::env,
+            task::working_directory,
+            task::run_as_user,
+            task::run_as_group,
+            task::stdin,
+            (
+                task::cpu_limit,
+                task::memory_limit,
+                task::pids_limit,
+                task::wall_clock_limit,
+                task::output_limit,
+            ),
+        ),
+        task::calendar_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use state::task::MisfirePolicy;
+
+    #[test]
+    fn test_check_misfire_policy() {
+        assert!(check_misfire_policy(MisfirePolicy::Ignore as i16, 0).is_ok());
+        assert!(check_misfire_policy(MisfirePolicy::FireOnce as i16, 0).is_ok());
+        assert!(check_misfire_policy(MisfirePolicy::FireAll as i16, 10).is_ok());
+    }
+
+    #[test]
+    fn test_check_misfire_policy_refuses_an_unbounded_catch_up() {
+        assert!(check_misfire_policy(MisfirePolicy::FireAll as i16, 0).is_err());
+        assert!(check_misfire_policy(MisfirePolicy::FireAll as i16, -1).is_err());
+    }
+
+    #[test]
+    fn test_check_misfire_policy_refuses_unknown_policies() {
+        assert!(check_misfire_policy(0, 10).is_err());
+        assert!(check_misfire_policy(4, 10).is_err());
+    }
+}
@@ -9,6 +9,7 @@ pub struct TaskBind {
     task_id: i64,
     bind_id: i64,
     created_time: NaiveDateTime,
+    holder: i16,
 }
 
 #[derive(
@@ -23,12 +24,8 @@ pub struct NewTaskBind {
 #[derive(Clone, Queryable, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
 pub struct BindProcessor {
     pub(crate) bind_id: i64,
+    pub(crate) executor_id: i64,
+    pub(crate) weight: i16,
     pub(crate) host: String,
     pub(crate) token: String,
 }
-
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
-
-pub struct TaskBindId {
-    pub(crate) task_bind_id: i64,
-}
@@ -0,0 +1,99 @@
+use super::prelude::*;
+use super::schema::{task_dependency, task_dependency_trigger};
+use super::task_log::{FrontEndRecordId, FrontEndTaskLog};
+
+#[derive(Queryable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "task_dependency"]
+
+pub struct TaskDependency {
+    pub(crate) id: i64,
+    pub(crate) task_id: i64,
+    pub(crate) upstream_task_id: i64,
+    pub(crate) trigger_condition: i16,
+    pub(crate) created_time: NaiveDateTime,
+}
+
+#[derive(Insertable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "task_dependency"]
+pub struct NewTaskDependency {
+    pub(crate) task_id: i64,
+    pub(crate) upstream_task_id: i64,
+    pub(crate) trigger_condition: i16,
+}
+
+// An upstream task declared in the task body.
+#[derive(Queryable, Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TaskDependencyItem {
+    pub(crate) upstream_task_id: i64,
+    #[serde(default = "default_trigger_condition")]
+    pub(crate) trigger_condition: i16,
+}
+
+fn default_trigger_condition() -> i16 {
+    state::task_dependency::TriggerCondition::Success as i16
+}
+
+#[derive(Queryable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "task_dependency_trigger"]
+pub struct TaskDependencyTrigger {
+    pub(crate) id: i64,
+    pub(crate) run_id: i64,
+    pub(crate) task_id: i64,
+    pub(crate) upstream_task_id: i64,
+    pub(crate) upstream_record_id: i64,
+    created_time: NaiveDateTime,
+}
+
+#[derive(Insertable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "task_dependency_trigger"]
+pub struct NewTaskDependencyTrigger {
+    pub(crate) run_id: i64,
+    pub(crate) task_id: i64,
+    pub(crate) upstream_task_id: i64,
+    pub(crate) upstream_record_id: i64,
+}
+
+// The front-end int64 is not convenient to be compatible, and the server side helps to handle it.
+#[derive(Debug, Clone, Serialize)]
+pub struct FrontEndTaskDependencyTrigger {
+    run_id: FrontEndRecordId,
+    task_id: i64,
+    upstream_task_id: i64,
+    upstream_record_id: FrontEndRecordId,
+    created_time: NaiveDateTime,
+}
+
+impl From<TaskDependencyTrigger> for FrontEndTaskDependencyTrigger {
+    fn from(trigger: TaskDependencyTrigger) -> Self {
+        let TaskDependencyTrigger {
+            run_id,
+            task_id,
+            upstream_task_id,
+            upstream_record_id,
+            created_time,
+            ..
+        } = trigger;
+
+        FrontEndTaskDependencyTrigger {
+            run_id: FrontEndRecordId(run_id),
+            task_id,
+            upstream_task_id,
+            upstream_record_id: FrontEndRecordId(upstream_record_id),
+            created_time,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct RunId {
+    pub(crate) run_id: FrontEndRecordId,
+}
+
+/// Everything the front-end needs to draw one run of a dependency chain.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DagRun {
+    pub(crate) task_logs: Vec<FrontEndTaskLog>,
+    // The dependencies reachable from the tasks of the run, including tasks that have not run yet.
+    pub(crate) dependencies: Vec<TaskDependency>,
+    pub(crate) triggers: Vec<FrontEndTaskDependencyTrigger>,
+}
@@ -0,0 +1,25 @@
+use super::prelude::*;
+use super::schema::task_failover;
+
+#[derive(Queryable, Identifiable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "task_failover"]
+
+pub struct TaskFailover {
+    pub(crate) id: i64,
+    pub(crate) task_id: i64,
+    pub(crate) origin_bind_id: i64,
+    pub(crate) origin_executor_id: i64,
+    pub(crate) target_bind_id: i64,
+    pub(crate) target_executor_id: i64,
+    created_time: NaiveDateTime,
+}
+
+#[derive(Insertable, AsChangeset, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "task_failover"]
+pub struct NewTaskFailover {
+    pub(crate) task_id: i64,
+    pub(crate) origin_bind_id: i64,
+    pub(crate) origin_executor_id: i64,
+    pub(crate) target_bind_id: i64,
+    pub(crate) target_executor_id: i64,
+}
@@ -1,30 +1,13 @@
 use super::prelude::*;
 use super::schema::{task_log, task_log_extend};
-use delicate_utils_task_log::{ChildOutput, ExecutorEvent, ExecutorEventCollection, FinishOutput};
+use delicate_utils::consensus_message::output_limit::truncate_output;
+use delicate_utils_task_log::{
+    ChildOutput, ExecutorEvent, FinishOutput, HttpResponseOutput, ResourceExceededOutput,
+};
 
-pub(crate) struct TaskLogQueryBuilder;
-impl TaskLogQueryBuilder {
-    pub(crate) fn query_all_columns() -> task_log::BoxedQuery<'static, Mysql> {
-        task_log::table.into_boxed().select(task_log::all_columns)
-    }
-
-    pub(crate) fn query_id_column() -> task_log::BoxedQuery<'static, Mysql, diesel::sql_types::Bigint> {
-        task_log::table.into_boxed().select(task_log::id)
-    }
-
-    pub(crate) fn query_count() -> task_log::BoxedQuery<'static, Mysql, diesel::sql_types::Bigint> {
-        task_log::table.into_boxed().count()
-    }
-}
-
-pub struct NewTaskLogs(pub Vec<NewTaskLog>);
-
-impl From<ExecutorEventCollection> for NewTaskLogs {
-    fn from(value: ExecutorEventCollection) -> Self {
-        let ExecutorEventCollection { events, .. } = value;
-        NewTaskLogs(events.into_iter().map(|e| e.into()).collect())
-    }
-}
+// The capacity of the `text` columns of `task_log_extend`,
+// Executors configured with a larger output limit are truncated again here.
+const OUTPUT_COLUMN_CAPACITY: usize = 65535;
 
 impl From<ExecutorEvent> for NewTaskLog {
     fn from(
@@ -35,6 +18,8 @@ impl From<ExecutorEvent> for NewTaskLog {
             executor_processor_id,
             executor_processor_name,
             executor_processor_host,
+            attempt,
+            run_id,
             ..
         }: ExecutorEvent,
     ) -> Self {
@@ -50,6 +35,8 @@ impl From<ExecutorEvent> for NewTaskLog {
             executor_processor_id,
             executor_processor_name,
             executor_processor_host,
+            attempt,
+            run_id,
             ..Default::default()
         }
     }
@@ -69,6 +56,8 @@ impl From<ExecutorEvent> for SupplyTaskLogTuple {
     ) -> Self {
         let mut stdout: String = String::new();
         let mut stderr: String = String::new();
+        let mut stdout_spool: String = String::new();
+        let mut stderr_spool: String = String::new();
         let mut state: state::task_log::State =
             Into::<delicate_utils_task_log::EventType>::into(event_type).into();
 
@@ -78,9 +67,13 @@ impl From<ExecutorEvent> for SupplyTaskLogTuple {
                     child_status,
                     child_stdout,
                     child_stderr,
+                    stdout_spool: child_stdout_spool,
+                    stderr_spool: child_stderr_spool,
                 }) => {
                     stdout = child_stdout;
                     stderr = child_stderr;
+                    stdout_spool = child_stdout_spool;
+                    stderr_spool = child_stderr_spool;
 
                     if child_status != 0 {
                         state = state::task_log::State::AbnormalEnding;
@@ -90,6 +83,42 @@ impl From<ExecutorEvent> for SupplyTaskLogTuple {
                     stderr = exception_output;
                     state = state::task_log::State::AbnormalEnding;
                 }
+                // The status leads the response body, so it is kept along with the body.
+                FinishOutput::HttpOutput(HttpResponseOutput {
+                    status,
+                    body,
+                    body_spool,
+                    expected,
+                }) => {
+                    stdout = format!("HTTP {}\n{}", status, body);
+                    stdout_spool = body_spool;
+
+                    if !expected {
+                        stderr = format!("Unexpected http status: {}", status);
+                        state = state::task_log::State::AbnormalEnding;
+                    }
+                }
+                FinishOutput::ResourceExceededOutput(ResourceExceededOutput {
+                    resource,
+                    child_output:
+                        ChildOutput {
+                            child_stdout,
+                            child_stderr,
+                            stdout_spool: child_stdout_spool,
+                            stderr_spool: child_stderr_spool,
+                            ..
+                        },
+                }) => {
+                    stdout = child_stdout;
+                    stdout_spool = child_stdout_spool;
+                    stderr_spool = child_stderr_spool;
+                    stderr = format!("{}\nExceeded the {} limit.", child_stderr, resource);
+                    state = state::task_log::State::ResourceExceeded;
+                }
+                FinishOutput::SkippedOutput(reason) => {
+                    stdout = reason;
+                    state = state::task_log::State::Skipped;
+                }
             };
         }
 
@@ -100,8 +129,10 @@ impl From<ExecutorEvent> for SupplyTaskLogTuple {
             SupplyTaskLogExtend {
                 id,
                 task_id,
-                stdout,
-                stderr,
+                stdout: truncate_output(stdout, OUTPUT_COLUMN_CAPACITY),
+                stderr: truncate_output(stderr, OUTPUT_COLUMN_CAPACITY),
+                stdout_spool,
+                stderr_spool,
             },
         )
     }
@@ -125,13 +156,15 @@ pub struct TaskLog {
     executor_processor_id: i64,
     executor_processor_name: String,
     executor_processor_host: String,
+    attempt: i16,
+    run_id: i64,
 }
 
 // The front-end int64 is not convenient to be compatible, and the server side helps to handle it.
 #[derive(Debug, Clone, Serialize)]
 pub struct FrontEndTaskLog {
     id: FrontEndRecordId,
-    task_id: i64,
+    pub(crate) task_id: i64,
     name: String,
     description: String,
     command: String,
@@ -146,6 +179,8 @@ pub struct FrontEndTaskLog {
     executor_processor_id: i64,
     executor_processor_name: String,
     executor_processor_host: String,
+    attempt: i16,
+    run_id: FrontEndRecordId,
 }
 
 impl From<TaskLog> for FrontEndTaskLog {
@@ -167,9 +202,12 @@ impl From<TaskLog> for FrontEndTaskLog {
             executor_processor_id,
             executor_processor_name,
             executor_processor_host,
+            attempt,
+            run_id,
         } = log;
 
         let id = FrontEndRecordId(id);
+        let run_id = FrontEndRecordId(run_id);
         let status_desc = Into::<state::task_log::State>::into(status as i16).into();
 
         FrontEndTaskLog {
@@ -189,6 +227,8 @@ impl From<TaskLog> for FrontEndTaskLog {
             executor_processor_id,
             executor_processor_name,
             executor_processor_host,
+            attempt,
+            run_id,
         }
     }
 }
@@ -202,6 +242,8 @@ pub struct TaskLogExtend {
     task_id: i64,
     stdout: String,
     stderr: String,
+    stdout_spool: String,
+    stderr_spool: String,
 }
 
 #[derive(
@@ -222,13 +264,16 @@ pub struct NewTaskLog {
     executor_processor_id: i64,
     executor_processor_name: String,
     executor_processor_host: String,
+    attempt: i16,
+    // A run that does not belong to an upstream run starts its own, keyed on its record-id.
+    pub(crate) run_id: i64,
 }
 
 #[derive(Queryable, Identifiable, Default, AsChangeset, Debug, Clone, Serialize, Deserialize)]
 #[table_name = "task_log"]
 pub struct SupplyTaskLog {
-    id: i64,
-    status: i16,
+    pub(crate) id: i64,
+    pub(crate) status: i16,
 }
 
 #[derive(
@@ -240,6 +285,8 @@ pub struct SupplyTaskLogExtend {
     task_id: i64,
     stdout: String,
     stderr: String,
+    stdout_spool: String,
+    stderr_spool: String,
 }
 
 // The front-end int64 is not convenient to be compatible, and the server side helps to handle it.
@@ -270,14 +317,15 @@ pub struct RecordId {
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct QueryParamsTaskLog {
-    id: Option<FrontEndRecordId>,
-    task_id: Option<i64>,
-    name: Option<String>,
-    description: Option<String>,
-    command: Option<String>,
-    tag: Option<String>,
-    status: Option<i16>,
-    executor_processor_id: Option<i64>,
+    pub(crate) id: Option<FrontEndRecordId>,
+    pub(crate) task_id: Option<i64>,
+    pub(crate) name: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) command: Option<String>,
+    pub(crate) tag: Option<String>,
+    pub(crate) status: Option<i16>,
+    pub(crate) executor_processor_id: Option<i64>,
+    pub(crate) run_id: Option<FrontEndRecordId>,
     pub(crate) start_time: Option<String>,
     pub(crate) end_time: Option<String>,
     pub(crate) per_page: i64,
@@ -292,89 +340,21 @@ pub struct TaskRecord {
     pub(crate) executor_processor_id: i64,
 }
 
-impl QueryParamsTaskLog {
-    pub(crate) fn query_filter<ST>(
-        self,
-        mut statement_builder: task_log::BoxedQuery<'static, Mysql, ST>,
-    ) -> task_log::BoxedQuery<'static, Mysql, ST> {
-        if let Some(task_id) = self.task_id {
-            statement_builder = statement_builder.filter(task_log::task_id.eq(task_id));
-        }
-
-        if let Some(id) = self.id {
-            statement_builder = statement_builder.filter(task_log::id.eq(id.0));
-        }
-
-        if let Some(status) = self.status {
-            statement_builder = statement_builder.filter(task_log::status.eq(status));
-        }
-
-        if let Some(task_name) = self.name {
-            statement_builder = statement_builder.filter(task_log::name.like(task_name));
-        }
-
-        if let Some(task_description) = self.description {
-            statement_builder =
-                statement_builder.filter(task_log::description.like(task_description));
-        }
-
-        if let Some(task_command) = self.command {
-            statement_builder = statement_builder.filter(task_log::command.like(task_command));
-        }
-
-        if let Some(task_tag) = self.tag {
-            statement_builder = statement_builder.filter(task_log::tag.like(task_tag));
-        }
-
-        if let Some(Ok(start_time)) = self.start_time.map(|s|NaiveDateTime::parse_from_str(&s,  "%Y-%m-%d %H:%M:%S")) {
-            let end_time = self.end_time.map(|s|NaiveDateTime::parse_from_str(&s,  "%Y-%m-%d %H:%M:%S").unwrap_or_else(|_| start_time + ChronoDuration::days(3))).unwrap_or_else(|| start_time + ChronoDuration::days(3));
-
-            statement_builder =
-                statement_builder.filter(task_log::created_time.between(start_time, end_time));
-        }
+// Where to continue following the output of a running record.
+#[derive(Copy, Clone, Default, Debug, Serialize, Deserialize)]
 
-        statement_builder.order(task_log::id.desc())
-    }
+pub struct RecordOutputOffset {
+    pub(crate) record_id: FrontEndRecordId,
+    #[serde(default)]
+    pub(crate) offset: u64,
 }
 
-
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct DeleteParamsTaskLog {
-    task_id: Option<i64>,
-    status: Option<i16>,
-    executor_processor_id: Option<i64>,
-    limit: Option<u64>,
+    pub(crate) task_id: Option<i64>,
+    pub(crate) status: Option<i16>,
+    pub(crate) executor_processor_id: Option<i64>,
+    pub(crate) limit: Option<u64>,
     pub(crate) start_time: Option<String>,
     pub(crate) end_time: Option<String>,
 }
-impl DeleteParamsTaskLog {
-    pub(crate) fn query_filter<ST>(
-        self,
-        mut statement_builder: task_log::BoxedQuery<'static, Mysql, ST>,
-    ) -> task_log::BoxedQuery<'static, Mysql, ST> {
-
-        if let Some(task_id) = self.task_id {
-            statement_builder = statement_builder.filter(task_log::task_id.eq(task_id));
-        }
-
-        if let Some(status) = self.status {
-            statement_builder = statement_builder.filter(task_log::status.eq(status));
-        }
-
-        if let Some(executor_processor_id) = self.executor_processor_id {
-            statement_builder = statement_builder.filter(task_log::executor_processor_id.eq(executor_processor_id));
-        }
-
-
-        if let Some(Ok(start_time)) = self.start_time.map(|s|NaiveDateTime::parse_from_str(&s,  "%Y-%m-%d %H:%M:%S")) {
-            let end_time = self.end_time.map(|s|NaiveDateTime::parse_from_str(&s,  "%Y-%m-%d %H:%M:%S").unwrap_or_else(|_| start_time + ChronoDuration::days(3))).unwrap_or_else(|| start_time + ChronoDuration::days(3));
-
-            statement_builder =
-                statement_builder.filter(task_log::created_time.between(start_time, end_time));
-        }
-
-        let limit = self.limit.unwrap_or(524_288) as i64;
-        statement_builder.limit(limit)
-
-    }
-}
@@ -26,13 +26,13 @@ pub struct QueryNewUser {
 #[table_name = "user"]
 
 pub struct User {
-    pub id: u64,
+    pub id: i64,
     pub user_name: String,
     pub nick_name: String,
     pub mobile: String,
     pub email: String,
     pub face: String,
-    pub status: i8,
+    pub status: i16,
     pub created_time: NaiveDateTime,
     pub updated_time: NaiveDateTime,
 }
@@ -50,20 +50,19 @@ pub struct NewUser {
 #[table_name = "user"]
 
 pub struct UpdateUser {
-    pub(crate) id: u64,
+    pub(crate) id: i64,
     user_name: String,
     nick_name: String,
     mobile: String,
     email: String,
 }
 
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 
 pub struct UserChangePassword {
     pub current_password: String,
     pub modified_password: String,
-    pub identity_type: u8,
+    pub identity_type: i16,
 }
 
 impl From<&QueryNewUser> for NewUser {
@@ -80,7 +79,7 @@ impl From<&QueryNewUser> for NewUser {
 pub struct NewUserAuths(pub [NewUserAuth; 3]);
 //QueryNewUser
 
-impl From<(QueryNewUser, u64)> for NewUserAuths {
+impl From<(QueryNewUser, i64)> for NewUserAuths {
     fn from(
         (
             QueryNewUser {
@@ -91,34 +90,33 @@ impl From<(QueryNewUser, u64)> for NewUserAuths {
                 ..
             },
             user_id,
-        ): (QueryNewUser, u64),
+        ): (QueryNewUser, i64),
     ) -> NewUserAuths {
-
         let encrypted_certificate = get_encrypted_certificate_by_raw_certificate(&certificate);
 
         let mobile_auth = NewUserAuth {
             user_id,
-            identity_type: types::IdentityType::Mobile as u8,
+            identity_type: types::IdentityType::Mobile as i16,
             identifier: mobile,
             certificate: encrypted_certificate.clone(),
-            status: state::user_auth::State::Health as i8,
+            status: state::user_auth::State::Health as i16,
         };
         let email_auth = NewUserAuth {
             user_id,
-            identity_type: types::IdentityType::Email as u8,
+            identity_type: types::IdentityType::Email as i16,
             identifier: email,
             certificate: encrypted_certificate.clone(),
-            status: state::user_auth::State::Health as i8,
+            status: state::user_auth::State::Health as i16,
         };
         let username_auth = NewUserAuth {
             user_id,
-            identity_type: types::IdentityType::Username as u8,
+            identity_type: types::IdentityType::Username as i16,
             identifier: user_name,
             certificate: encrypted_certificate,
-            status: state::user_auth::State::Health as i8,
+            status: state::user_auth::State::Health as i16,
         };
 
-       let  user_auth_arr : [NewUserAuth; 3] = [mobile_auth, email_auth, username_auth];
+        let user_auth_arr: [NewUserAuth; 3] = [mobile_auth, email_auth, username_auth];
 
         NewUserAuths(user_auth_arr)
     }
@@ -134,11 +132,11 @@ pub fn get_encrypted_certificate_by_raw_certificate(certificate: &str) -> String
 #[table_name = "user_auth"]
 pub struct UserAuth {
     pub id: i64,
-    pub user_id: u64,
-    pub identity_type: u8,
+    pub user_id: i64,
+    pub identity_type: i16,
     pub identifier: String,
     pub certificate: String,
-    pub status: i8,
+    pub status: i16,
     pub created_time: NaiveDateTime,
     pub updated_time: NaiveDateTime,
 }
@@ -146,88 +144,43 @@ pub struct UserAuth {
 #[derive(Insertable, Debug, Serialize, Deserialize)]
 #[table_name = "user_auth"]
 pub struct NewUserAuth {
-    user_id: u64,
-    identity_type: u8,
+    user_id: i64,
+    identity_type: i16,
     identifier: String,
     certificate: String,
-    status: i8,
+    status: i16,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserAuthLogin {
-    pub(crate) login_type: u8,
+    pub(crate) login_type: i16,
     pub(crate) account: String,
     pub(crate) password: String,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub(crate) struct QueryParamsUser {
-    id: Option<u64>,
-    user_name: Option<String>,
-    nick_name: Option<String>,
-    mobile: Option<String>,
-    email: Option<String>,
-    status: Option<i8>,
+    pub(crate) id: Option<i64>,
+    pub(crate) user_name: Option<String>,
+    pub(crate) nick_name: Option<String>,
+    pub(crate) mobile: Option<String>,
+    pub(crate) email: Option<String>,
+    pub(crate) status: Option<i16>,
     pub(crate) per_page: i64,
     pub(crate) page: i64,
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub(crate) struct UserId {
-    pub(crate) user_id: u64,
-}
-
-pub(crate) struct UserQueryBuilder;
-impl UserQueryBuilder {
-    pub(crate) fn query_all_columns() -> user::BoxedQuery<'static, Mysql> {
-        user::table.into_boxed().select(user::all_columns)
-    }
-
-    pub(crate) fn query_count() -> user::BoxedQuery<'static, Mysql, diesel::sql_types::Bigint> {
-        user::table.into_boxed().count()
-    }
-}
-
-impl QueryParamsUser {
-    pub(crate) fn query_filter<ST>(
-        self,
-        mut statement_builder: user::BoxedQuery<'static, Mysql, ST>,
-    ) -> user::BoxedQuery<'static, Mysql, ST> {
-        if let Some(user_id) = self.id {
-            statement_builder = statement_builder.filter(user::id.eq(user_id));
-        }
-
-        if let Some(status) = self.status {
-            statement_builder = statement_builder.filter(user::status.eq(status));
-        } else {
-            statement_builder =
-                statement_builder.filter(user::status.ne(state::user::State::Forbidden as i8));
-        }
-
-        if let Some(user_name) = self.user_name {
-            statement_builder = statement_builder.filter(user::user_name.like(user_name));
-        }
-
-        if let Some(mobile) = self.mobile {
-            statement_builder = statement_builder.filter(user::mobile.eq(mobile));
-        }
-
-        if let Some(email) = self.email {
-            statement_builder = statement_builder.filter(user::email.eq(email));
-        }
-
-        statement_builder.order(user::id.desc())
-    }
+    pub(crate) user_id: i64,
 }
 
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 
 pub struct UserName {
     pub user_name: String,
 }
 
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 
 pub struct UserAndRoles {
@@ -241,6 +194,3 @@ pub struct UserAndPermissions {
     pub user_name: String,
     pub operate_permissions: Vec<Vec<String>>,
 }
-
-
-
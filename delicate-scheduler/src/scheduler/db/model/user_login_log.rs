@@ -0,0 +1,111 @@
+use super::prelude::*;
+use super::schema::user_login_log;
+
+#[derive(Queryable, Identifiable, AsChangeset, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "user_login_log"]
+
+pub struct UserLoginLog {
+    id: i64,
+    user_id: i64,
+    login_type: i16,
+    command: i16,
+    lastip: String,
+    created_time: NaiveDateTime,
+    user_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+
+pub struct FrontEndUserLoginLog {
+    id: i64,
+    user_id: i64,
+    login_type: i16,
+    login_type_desc: &'static str,
+    command: i16,
+    command_desc: &'static str,
+    lastip: String,
+    created_time: NaiveDateTime,
+    user_name: String,
+}
+
+impl From<UserLoginLog> for FrontEndUserLoginLog {
+    fn from(log: UserLoginLog) -> Self {
+        let UserLoginLog {
+            id,
+            user_id,
+            login_type,
+            command,
+            lastip,
+            created_time,
+            user_name,
+        } = log;
+        let login_type_desc =
+            Into::<state::user_login_log::LoginType>::into(login_type as i16).into();
+        let command_desc = Into::<state::user_login_log::LoginCommand>::into(command as i16).into();
+
+        FrontEndUserLoginLog {
+            id,
+            user_id,
+            login_type,
+            login_type_desc,
+            command,
+            command_desc,
+            lastip,
+            created_time,
+            user_name,
+        }
+    }
+}
+
+#[derive(Insertable, Debug, Default, Serialize, Deserialize)]
+#[table_name = "user_login_log"]
+pub struct NewUserLoginLog {
+    user_id: i64,
+    login_type: i16,
+    command: i16,
+    lastip: String,
+    user_name: String,
+}
+
+impl NewUserLoginLog {
+    pub fn set_user_id(&mut self, user_id: i64) -> &mut Self {
+        self.user_id = user_id;
+        self
+    }
+
+    pub fn set_login_type(&mut self, login_type: i16) -> &mut Self {
+        self.login_type = login_type;
+        self
+    }
+
+    pub fn set_command(&mut self, command: i16) -> &mut Self {
+        self.command = command;
+        self
+    }
+
+    pub fn set_lastip(&mut self, lastip: Option<String>) -> &mut Self {
+        if let Some(ip) = lastip {
+            self.lastip = ip;
+        }
+        self
+    }
+
+    pub fn set_user_name(&mut self, user_name: String) -> &mut Self {
+        self.user_name = user_name;
+        self
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct QueryParamsUserLoginLog {
+    pub(crate) id: Option<i64>,
+    pub(crate) user_id: Option<i64>,
+    pub(crate) login_type: Option<i16>,
+    pub(crate) command: Option<i16>,
+    pub(crate) lastip: Option<String>,
+    pub(crate) user_name: Option<String>,
+    pub(crate) start_time: Option<String>,
+    pub(crate) end_time: Option<String>,
+    pub(crate) per_page: i64,
+    pub(crate) page: i64,
+}
@@ -0,0 +1,138 @@
+use super::prelude::*;
+use model::{
+    BindProcessor, BindingSelection, ExecutorGroup, ExecutorGroupDetail, ExecutorProcessor,
+    ExecutorProcessorBind, ExecutorSelection, FrontEndExecutorProcessorHealth, NewExecutorGroup,
+    NewExecutorProcessor, NewExecutorProcessorBind, NewExecutorProcessorHealth,
+    QueryParamsExecutorGroup, QueryParamsExecutorProcessor, QueryParamsExecutorProcessorBind,
+    QueryParamsExecutorProcessorHealth, UpdateExecutorGroup, UpdateExecutorProcessor,
+    UpdateExecutorProcessorBind,
+};
+
+/// The executor processors, their groups and the bindings between them.
+pub(crate) trait ExecutorRepository {
+    fn create_executor_group(&self, executor_group: NewExecutorGroup) -> Result<i64, CommonError>;
+
+    fn list_executor_groups(
+        &self,
+        query_params: QueryParamsExecutorGroup,
+    ) -> Result<PaginateData<ExecutorGroup>, CommonError>;
+
+    /// The group along with the bindings in it and the processors behind them.
+    fn executor_group_detail(
+        &self,
+        executor_group_id: i64,
+    ) -> Result<ExecutorGroupDetail, CommonError>;
+
+    fn update_executor_group(
+        &self,
+        executor_group: UpdateExecutorGroup,
+    ) -> Result<usize, CommonError>;
+
+    fn delete_executor_group(&self, executor_group_id: i64) -> Result<usize, CommonError>;
+
+    fn create_executor_processor(
+        &self,
+        executor_processor: NewExecutorProcessor,
+    ) -> Result<usize, CommonError>;
+
+    fn list_executor_processors(
+        &self,
+        query_params: QueryParamsExecutorProcessor,
+    ) -> Result<PaginateData<ExecutorProcessor>, CommonError>;
+
+    fn list_executor_processor_health(
+        &self,
+        query_params: QueryParamsExecutorProcessorHealth,
+    ) -> Result<PaginateData<FrontEndExecutorProcessorHealth>, CommonError>;
+
+    fn find_executor_processor(
+        &self,
+        executor_processor_id: i64,
+    ) -> Result<UpdateExecutorProcessor, CommonError>;
+
+    fn update_executor_processor(
+        &self,
+        executor_processor: UpdateExecutorProcessor,
+    ) -> Result<usize, CommonError>;
+
+    fn delete_executor_processor(&self, executor_processor_id: i64) -> Result<usize, CommonError>;
+
+    /// Keep the token the processor is bound with, and enable it.
+    fn activate_executor_processor(
+        &self,
+        executor_processor_id: i64,
+        token: String,
+    ) -> Result<usize, CommonError>;
+
+    /// Keep the token the processor is bound with again, its status is left to the health check.
+    fn rebind_executor_processor(
+        &self,
+        executor_processor_id: i64,
+        token: String,
+    ) -> Result<usize, CommonError>;
+
+    fn executor_token(&self, executor_processor_id: i64) -> Result<Option<String>, CommonError>;
+
+    fn executor_selections(&self) -> Result<Vec<ExecutorSelection>, CommonError>;
+
+    fn create_executor_processor_binds(
+        &self,
+        executor_processor_binds: Vec<NewExecutorProcessorBind>,
+    ) -> Result<usize, CommonError>;
+
+    fn list_executor_processor_binds(
+        &self,
+        query_params: QueryParamsExecutorProcessorBind,
+    ) -> Result<PaginateData<ExecutorProcessorBind>, CommonError>;
+
+    /// Update the binding, returns the id, the host and the token of the executor bound before.
+    fn update_executor_processor_bind(
+        &self,
+        executor_processor_bind: UpdateExecutorProcessorBind,
+    ) -> Result<(i64, String, String), CommonError>;
+
+    fn delete_executor_processor_bind(
+        &self,
+        executor_processor_bind_id: i64,
+    ) -> Result<usize, CommonError>;
+
+    fn binding_selections(&self) -> Result<Vec<BindingSelection>, CommonError>;
+
+    /// The id, the host, the token and the status of the processors in one of the statuses.
+    fn executors_in_status(
+        &self,
+        statuses: Vec<i16>,
+    ) -> Result<Vec<(i64, String, String, i16)>, CommonError>;
+
+    /// The id, the host, the token and the status of the processors.
+    fn executors_by_ids(
+        &self,
+        executor_processor_ids: Vec<i64>,
+    ) -> Result<Vec<(i64, String, String, i16)>, CommonError>;
+
+    /// Keep the result of a round of health checks in one transaction,
+    /// The status of the abnormal and the recovered processors along with the probes.
+    fn record_health_check(
+        &self,
+        abnormal_processors: Vec<i64>,
+        recovered_processors: Vec<i64>,
+        health_history: Vec<NewExecutorProcessorHealth>,
+    ) -> Result<(), CommonError>;
+
+    /// Delete the health history created before the deadline,
+    /// Which is compared in the time zone the database records the times in.
+    fn prune_health_history(&self, deadline: DateTime<Utc>) -> Result<usize, CommonError>;
+
+    /// The results of the latest probes of each processor, up to `limit` of them, The newest first.
+    fn recent_health_history(
+        &self,
+        executor_processor_ids: Vec<i64>,
+        limit: i64,
+    ) -> Result<HashMap<i64, Vec<i16>>, CommonError>;
+
+    /// The enabled members of the groups, along with the group they are in.
+    fn enabled_group_members(
+        &self,
+        group_ids: Vec<i64>,
+    ) -> Result<Vec<(i64, BindProcessor)>, CommonError>;
+}
@@ -0,0 +1,34 @@
+//! The repositories the scheduler reaches the database through.
+//!
+//! Each backend in `backends` implements them once, on its own connections and queries,
+//! So that the scheduler is compiled once whichever backends are compiled in.
+
+use super::prelude::*;
+
+pub(crate) mod executor;
+pub(crate) mod task;
+pub(crate) mod task_log;
+pub(crate) mod user;
+
+pub(crate) use executor::ExecutorRepository;
+pub(crate) use task::{TaskBindChanges, TaskRepository};
+pub(crate) use task_log::TaskLogRepository;
+pub(crate) use user::UserRepository;
+
+/// The storage of the scheduler, all the repositories on one backend.
+pub(crate) trait Storage:
+    ExecutorRepository + TaskRepository + TaskLogRepository + UserRepository + Send + Sync
+{
+    /// Run the migrations of the backend that are not run yet.
+    fn run_migrations(&self) -> Result<(), CommonError>;
+}
+
+/// Run the blocking calls on the storage in the blocking thread pool.
+pub(crate) async fn blocking<T, F>(storage: &Arc<dyn Storage>, f: F) -> Result<T, CommonError>
+where
+    T: Send + 'static,
+    F: FnOnce(&dyn Storage) -> Result<T, CommonError> + Send + 'static,
+{
+    let storage = storage.clone();
+    spawn_blocking(move || f(storage.as_ref())).await?
+}
@@ -0,0 +1,154 @@
+use super::prelude::*;
+use delicate_utils_task::TaskPackage;
+use model::{
+    BindProcessor, Calendar, CalendarDetail, CalendarWindowItem, FrontEndTask, NewCalendar,
+    NewTask, NewTaskDependencyTrigger, NewTaskFailover, QueryParamsCalendar, QueryParamsTask,
+    TaskDependency, TaskDependencyItem, TaskFailover, UpdateCalendar, UpdateTask,
+};
+
+/// The members a task is removed from, appended to and kept on by an update.
+pub(crate) type TaskBindChanges = (Vec<BindProcessor>, Vec<BindProcessor>, Vec<BindProcessor>);
+
+/// The tasks, their bindings, dependencies, failovers and calendars.
+///
+/// The task packages are returned along with their calendars.
+pub(crate) trait TaskRepository {
+    /// Create the task along with its bindings and dependencies, in one transaction.
+    fn create_task(
+        &self,
+        task: NewTask,
+        binding_ids: Vec<i64>,
+        dependencies: Vec<TaskDependencyItem>,
+    ) -> Result<usize, CommonError>;
+
+    fn list_tasks(
+        &self,
+        query_params: QueryParamsTask,
+    ) -> Result<PaginateData<FrontEndTask>, CommonError>;
+
+    /// Update the task, its bindings and (when given) its dependencies in one transaction,
+    /// Returns the members the task is removed from, appended to and kept on.
+    fn update_task(
+        &self,
+        task: UpdateTask,
+        binding_ids: Vec<i64>,
+        dependencies: Option<Vec<TaskDependencyItem>>,
+    ) -> Result<TaskBindChanges, CommonError>;
+
+    /// Delete the task along with its bindings, failovers and dependencies.
+    fn delete_task(&self, task_id: i64) -> Result<(), CommonError>;
+
+    fn set_task_status(&self, task_id: i64, status: i16) -> Result<usize, CommonError>;
+
+    /// The packages of the tasks, along with their status, routing strategy and trigger mode.
+    fn task_packages(
+        &self,
+        task_ids: Vec<i64>,
+    ) -> Result<Vec<(TaskPackage, i16, i16, i16)>, CommonError>;
+
+    /// The enabled tasks held by the executors, along with their routing strategy,
+    /// The binding, the executor and the group that each one is bound through.
+    fn tasks_bound_to_executors(
+        &self,
+        executor_processor_ids: Vec<i64>,
+    ) -> Result<Vec<(TaskPackage, i16, i64, i64, i64)>, CommonError>;
+
+    /// The enabled tasks held through the binding, along with their routing strategy
+    /// And the host and the token of the executor bound.
+    fn tasks_bound_to_binding(
+        &self,
+        executor_processor_bind_id: i64,
+    ) -> Result<Vec<(TaskPackage, i16, (String, String))>, CommonError>;
+
+    /// The members bound to the tasks, along with the task each one is bound to.
+    fn task_members(
+        &self,
+        task_ids: Vec<i64>,
+        enabled_only: bool,
+    ) -> Result<Vec<(i64, BindProcessor)>, CommonError>;
+
+    /// The executors the tasks are bound to, as (task-id, executor-processor-id) pairs.
+    fn task_executors(&self, task_ids: Vec<i64>) -> Result<Vec<(i64, i64)>, CommonError>;
+
+    /// The id, the frequency and the cron-expression of the enabled tasks triggered by the scheduler.
+    fn central_task_schedules(&self) -> Result<Vec<(i64, String, String)>, CommonError>;
+
+    /// The id, the timeout and the wall-clock limit of the tasks.
+    fn task_timeouts(&self, task_ids: Vec<i64>) -> Result<Vec<(i64, i16, i32)>, CommonError>;
+
+    fn task_retry_times(&self, task_ids: Vec<i64>) -> Result<Vec<(i64, i16)>, CommonError>;
+
+    /// The misfire policy and the misfire limit of the task.
+    fn misfire_settings(&self, task_id: i64) -> Result<(i16, i16), CommonError>;
+
+    /// The bind-id holding a routed task.
+    fn task_holder(&self, task_id: i64) -> Result<Option<i64>, CommonError>;
+
+    /// Mark the member holding the task, `None` clears the holder.
+    fn set_task_holder(&self, task_id: i64, bind_id: Option<i64>) -> Result<(), CommonError>;
+
+    fn failovers_of_tasks(&self, task_ids: Vec<i64>) -> Result<Vec<TaskFailover>, CommonError>;
+
+    /// The failovers from or to the executors.
+    fn failovers_of_executors(
+        &self,
+        executor_processor_ids: Vec<i64>,
+    ) -> Result<Vec<TaskFailover>, CommonError>;
+
+    /// The failovers from the executors, along with the members that took over the tasks.
+    fn failovers_from_executors(
+        &self,
+        origin_executor_ids: Vec<i64>,
+    ) -> Result<Vec<(TaskFailover, BindProcessor)>, CommonError>;
+
+    /// The members that took over a task from abnormal executors.
+    fn task_failover_processors(
+        &self,
+        task_id: i64,
+    ) -> Result<Vec<(TaskFailover, BindProcessor)>, CommonError>;
+
+    /// Keep the completed failovers, a failover with an id replaces the record.
+    fn save_task_failovers(
+        &self,
+        failovers: Vec<(Option<i64>, NewTaskFailover)>,
+    ) -> Result<(), CommonError>;
+
+    fn delete_task_failovers(&self, failover_ids: Vec<i64>) -> Result<usize, CommonError>;
+
+    /// The dependencies on the upstream tasks.
+    fn downstream_dependencies(
+        &self,
+        upstream_task_ids: Vec<i64>,
+    ) -> Result<Vec<TaskDependency>, CommonError>;
+
+    /// Keep the trigger of a downstream task in a run,
+    /// Returns false when the task is already triggered in the run.
+    fn insert_dependency_trigger(
+        &self,
+        trigger: NewTaskDependencyTrigger,
+    ) -> Result<bool, CommonError>;
+
+    fn create_calendar(
+        &self,
+        calendar: NewCalendar,
+        excluded_windows: Vec<CalendarWindowItem>,
+    ) -> Result<i64, CommonError>;
+
+    fn list_calendars(
+        &self,
+        query_params: QueryParamsCalendar,
+    ) -> Result<PaginateData<Calendar>, CommonError>;
+
+    fn calendar_detail(&self, calendar_id: i64) -> Result<CalendarDetail, CommonError>;
+
+    /// Update the calendar and replace its windows,
+    /// Returns the enabled tasks attached to it that the executors hold.
+    fn update_calendar(
+        &self,
+        calendar: UpdateCalendar,
+        excluded_windows: Vec<CalendarWindowItem>,
+    ) -> Result<Vec<i64>, CommonError>;
+
+    /// Delete the calendar along with its windows, unless tasks are attached to it.
+    fn delete_calendar(&self, calendar_id: i64) -> Result<usize, CommonError>;
+}
@@ -0,0 +1,64 @@
+use super::prelude::*;
+use model::{
+    DeleteParamsTaskLog, FrontEndTaskLog, NewTaskLog, QueryParamsTaskLog, SupplyTaskLogTuple,
+    TaskDependencyTrigger, TaskLog, TaskLogExtend, TaskState,
+};
+
+/// The records of the task runs, kept from the events of the executors.
+pub(crate) trait TaskLogRepository {
+    /// Start and end the records in one transaction, skipping the events already recorded,
+    /// Returns the number of records affected and the ids of the records ended.
+    fn record_task_events(
+        &self,
+        new_task_logs: Vec<NewTaskLog>,
+        supply_task_logs: Vec<SupplyTaskLogTuple>,
+    ) -> Result<(usize, Vec<i64>), CommonError>;
+
+    fn list_task_logs(
+        &self,
+        query_params: QueryParamsTaskLog,
+    ) -> Result<PaginateData<FrontEndTaskLog>, CommonError>;
+
+    fn task_log_detail(&self, record_id: i64) -> Result<TaskLogExtend, CommonError>;
+
+    /// The records and the dependency triggers of a run.
+    fn run_records(
+        &self,
+        run_id: i64,
+    ) -> Result<(Vec<TaskLog>, Vec<TaskDependencyTrigger>), CommonError>;
+
+    /// Mark the running record as cancelled, returns the host of the executor running it.
+    fn cancel_running_record(&self, record_id: i64) -> Result<String, CommonError>;
+
+    /// The host and the id of the executor that runs the record.
+    fn record_executor(&self, record_id: i64) -> Result<(String, i64), CommonError>;
+
+    /// Delete the records matching the parameters along with their extends.
+    fn delete_task_logs(&self, delete_params: DeleteParamsTaskLog) -> Result<(), CommonError>;
+
+    /// The id, the task-id, the status, the attempt and the run-id of the records that ended.
+    fn ended_task_logs(
+        &self,
+        record_ids: Vec<i64>,
+    ) -> Result<Vec<(i64, i64, i16, i16, i64)>, CommonError>;
+
+    /// The time of the latest record of the task.
+    fn last_run_time(&self, task_id: i64) -> Result<Option<DateTime<Utc>>, CommonError>;
+
+    /// The id, the task-id, the executor-processor-id and the created time
+    /// Of the records running since before the deadline.
+    fn running_records_before(
+        &self,
+        deadline: NaiveDateTime,
+    ) -> Result<Vec<(i64, i64, i64, NaiveDateTime)>, CommonError>;
+
+    /// Mark the records that are still running as lost, returns their ids.
+    fn mark_lost_records(&self, record_ids: Vec<i64>) -> Result<Vec<i64>, CommonError>;
+
+    /// The runs created and the runs ended in each hour between the times.
+    fn hourly_task_states(
+        &self,
+        start_time: NaiveDateTime,
+        end_time: NaiveDateTime,
+    ) -> Result<(Vec<TaskState>, Vec<TaskState>), CommonError>;
+}
@@ -0,0 +1,83 @@
+use super::prelude::*;
+use model::casbin_rule::{CasbinRule, NewCasbinRule};
+use model::{
+    FrontEndOperationLog, FrontEndUserLoginLog, NewUserLoginLog, OperationLogDetail, QueryNewUser,
+    QueryParamsOperationLog, QueryParamsUser, QueryParamsUserLoginLog, UpdateUser, User, UserAuth,
+    UserAuthLogin, UserChangePassword,
+};
+
+/// The users, their logs and the rules of their permissions.
+pub(crate) trait UserRepository {
+    /// Create the user along with its authentications, in one transaction.
+    fn create_user(&self, user: QueryNewUser) -> Result<(), CommonError>;
+
+    /// Create the user unless one with the same name exists.
+    fn init_admin_account(&self, admin: QueryNewUser) -> Result<(), CommonError>;
+
+    fn list_users(&self, query_params: QueryParamsUser) -> Result<PaginateData<User>, CommonError>;
+
+    fn update_user(&self, user: UpdateUser) -> Result<usize, CommonError>;
+
+    fn change_password(
+        &self,
+        user_id: i64,
+        password: UserChangePassword,
+    ) -> Result<usize, CommonError>;
+
+    /// Delete the user along with its authentications.
+    fn delete_user(&self, user_id: i64) -> Result<(), CommonError>;
+
+    /// Authenticate the user, the attempt is logged whether it succeeds or not.
+    fn login_user(
+        &self,
+        login: UserAuthLogin,
+        login_log: NewUserLoginLog,
+    ) -> Result<(UserAuth, User), CommonError>;
+
+    fn find_user(&self, user_id: i64) -> Result<User, CommonError>;
+
+    fn list_user_login_logs(
+        &self,
+        query_params: QueryParamsUserLoginLog,
+    ) -> Result<PaginateData<FrontEndUserLoginLog>, CommonError>;
+
+    fn list_operation_logs(
+        &self,
+        query_params: QueryParamsOperationLog,
+    ) -> Result<PaginateData<FrontEndOperationLog>, CommonError>;
+
+    fn operation_log_detail(
+        &self,
+        operation_log_id: i64,
+    ) -> Result<Vec<OperationLogDetail>, CommonError>;
+
+    /// Keep the operation logs along with their details.
+    fn insert_operation_logs(&self, logs: Vec<NewOperationLogPair>) -> Result<(), CommonError>;
+
+    /// The permission groups and the permissions of the admin roles.
+    fn permission_list(&self) -> Result<Vec<(String, String)>, CommonError>;
+
+    fn load_casbin_rules(&self) -> AuthServiceResult<Vec<CasbinRule>>;
+
+    fn clear_casbin_rules(&self) -> AuthServiceResult<()>;
+
+    /// Replace all the rules, in one transaction.
+    fn save_casbin_rules(&self, rules: Vec<NewCasbinRule>) -> AuthServiceResult<()>;
+
+    fn add_casbin_rule(&self, rule: NewCasbinRule) -> AuthServiceResult<bool>;
+
+    fn add_casbin_rules(&self, rules: Vec<NewCasbinRule>) -> AuthServiceResult<bool>;
+
+    fn remove_casbin_rule(&self, ptype: &str, rule: Vec<String>) -> AuthServiceResult<bool>;
+
+    /// Remove all the rules or none of them.
+    fn remove_casbin_rules(&self, ptype: &str, rules: Vec<Vec<String>>) -> AuthServiceResult<bool>;
+
+    /// Remove the rules matching the values from the field on, empty values match any.
+    fn remove_filtered_casbin_rules(
+        &self,
+        ptype: &str,
+        field_index: usize,
+        field_values: Vec<String>,
+    ) -> AuthServiceResult<bool>;
+}
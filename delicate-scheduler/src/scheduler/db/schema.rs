@@ -1,3 +1,91 @@
+table! {
+    /// Representation of the `calendar` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    calendar (id) {
+        /// The `id` column of the `calendar` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Bigint,
+        /// The `name` column of the `calendar` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        name -> Varchar,
+        /// The `description` column of the `calendar` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        description -> Varchar,
+        /// The `time_zone` column of the `calendar` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        time_zone -> Smallint,
+        /// The `business_days_only` column of the `calendar` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        business_days_only -> Smallint,
+        /// The `created_time` column of the `calendar` table.
+        ///
+        /// Its SQL type is `Timestamp`.
+        ///
+        /// (Automatically generated by Diesel.)
+        created_time -> Timestamp,
+        /// The `updated_time` column of the `calendar` table.
+        ///
+        /// Its SQL type is `Timestamp`.
+        ///
+        /// (Automatically generated by Diesel.)
+        updated_time -> Timestamp,
+    }
+}
+
+table! {
+    /// Representation of the `calendar_window` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    calendar_window (id) {
+        /// The `id` column of the `calendar_window` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Bigint,
+        /// The `calendar_id` column of the `calendar_window` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        calendar_id -> Bigint,
+        /// The `start_time` column of the `calendar_window` table.
+        ///
+        /// Its SQL type is `Timestamp`.
+        ///
+        /// (Automatically generated by Diesel.)
+        start_time -> Timestamp,
+        /// The `end_time` column of the `calendar_window` table.
+        ///
+        /// Its SQL type is `Timestamp`.
+        ///
+        /// (Automatically generated by Diesel.)
+        end_time -> Timestamp,
+        /// The `description` column of the `calendar_window` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        description -> Varchar,
+    }
+}
+
 table! {
     /// Representation of the `casbin_rule` table.
     ///
@@ -5,10 +93,10 @@ table! {
     casbin_rule (id) {
         /// The `id` column of the `casbin_rule` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        id -> Unsigned<Bigint>,
+        id -> Bigint,
         /// The `ptype` column of the `casbin_rule` table.
         ///
         /// Its SQL type is `Varchar`.
@@ -210,6 +298,56 @@ table! {
     }
 }
 
+table! {
+    /// Representation of the `executor_processor_health` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    executor_processor_health (id) {
+        /// The `id` column of the `executor_processor_health` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Bigint,
+        /// The `executor_processor_id` column of the `executor_processor_health` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        executor_processor_id -> Bigint,
+        /// The `result` column of the `executor_processor_health` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        result -> Smallint,
+        /// The `status` column of the `executor_processor_health` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        status -> Smallint,
+        /// The `response_time` column of the `executor_processor_health` table.
+        ///
+        /// Its SQL type is `Integer`.
+        ///
+        /// (Automatically generated by Diesel.)
+        response_time -> Integer,
+        /// The `error` column of the `executor_processor_health` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        error -> Varchar,
+        /// The `created_time` column of the `executor_processor_health` table.
+        ///
+        /// Its SQL type is `Timestamp`.
+        ///
+        /// (Automatically generated by Diesel.)
+        created_time -> Timestamp,
+    }
+}
+
 table! {
     /// Representation of the `operation_log` table.
     ///
@@ -217,10 +355,10 @@ table! {
     operation_log (id) {
         /// The `id` column of the `operation_log` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        id -> Unsigned<Bigint>,
+        id -> Bigint,
         /// The `name` column of the `operation_log` table.
         ///
         /// Its SQL type is `Varchar`.
@@ -229,22 +367,22 @@ table! {
         name -> Varchar,
         /// The `table_id` column of the `operation_log` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        table_id -> Unsigned<Bigint>,
+        table_id -> Bigint,
         /// The `operation_type` column of the `operation_log` table.
         ///
-        /// Its SQL type is `Tinyint`.
+        /// Its SQL type is `Smallint`.
         ///
         /// (Automatically generated by Diesel.)
-        operation_type -> Tinyint,
+        operation_type -> Smallint,
         /// The `user_id` column of the `operation_log` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        user_id -> Unsigned<Bigint>,
+        user_id -> Bigint,
         /// The `user_name` column of the `operation_log` table.
         ///
         /// Its SQL type is `Varchar`.
@@ -267,16 +405,16 @@ table! {
     operation_log_detail (id) {
         /// The `id` column of the `operation_log_detail` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        id -> Unsigned<Bigint>,
+        id -> Bigint,
         /// The `operation_log_id` column of the `operation_log_detail` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        operation_log_id -> Unsigned<Bigint>,
+        operation_log_id -> Bigint,
         /// The `column_comment` column of the `operation_log_detail` table.
         ///
         /// Its SQL type is `Text`.
@@ -381,6 +519,102 @@ table! {
         ///
         /// (Automatically generated by Diesel.)
         deleted_time -> Nullable<Timestamp>,
+        /// The `routing_strategy` column of the `task` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        routing_strategy -> Smallint,
+        /// The `kind` column of the `task` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        kind -> Smallint,
+        /// The `env` column of the `task` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        env -> Varchar,
+        /// The `working_directory` column of the `task` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        working_directory -> Varchar,
+        /// The `run_as_user` column of the `task` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        run_as_user -> Varchar,
+        /// The `run_as_group` column of the `task` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        run_as_group -> Varchar,
+        /// The `stdin` column of the `task` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        stdin -> Varchar,
+        /// The `cpu_limit` column of the `task` table.
+        ///
+        /// Its SQL type is `Integer`.
+        ///
+        /// (Automatically generated by Diesel.)
+        cpu_limit -> Integer,
+        /// The `memory_limit` column of the `task` table.
+        ///
+        /// Its SQL type is `Integer`.
+        ///
+        /// (Automatically generated by Diesel.)
+        memory_limit -> Integer,
+        /// The `pids_limit` column of the `task` table.
+        ///
+        /// Its SQL type is `Integer`.
+        ///
+        /// (Automatically generated by Diesel.)
+        pids_limit -> Integer,
+        /// The `wall_clock_limit` column of the `task` table.
+        ///
+        /// Its SQL type is `Integer`.
+        ///
+        /// (Automatically generated by Diesel.)
+        wall_clock_limit -> Integer,
+        /// The `output_limit` column of the `task` table.
+        ///
+        /// Its SQL type is `Integer`.
+        ///
+        /// (Automatically generated by Diesel.)
+        output_limit -> Integer,
+        /// The `trigger_mode` column of the `task` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        trigger_mode -> Smallint,
+        /// The `misfire_policy` column of the `task` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        misfire_policy -> Smallint,
+        /// The `misfire_limit` column of the `task` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        misfire_limit -> Smallint,
+        /// The `calendar_id` column of the `task` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        calendar_id -> Bigint,
     }
 }
 
@@ -413,6 +647,144 @@ table! {
         ///
         /// (Automatically generated by Diesel.)
         created_time -> Timestamp,
+        /// The `holder` column of the `task_bind` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        holder -> Smallint,
+    }
+}
+
+table! {
+    /// Representation of the `task_dependency` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    task_dependency (id) {
+        /// The `id` column of the `task_dependency` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Bigint,
+        /// The `task_id` column of the `task_dependency` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        task_id -> Bigint,
+        /// The `upstream_task_id` column of the `task_dependency` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        upstream_task_id -> Bigint,
+        /// The `trigger_condition` column of the `task_dependency` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        trigger_condition -> Smallint,
+        /// The `created_time` column of the `task_dependency` table.
+        ///
+        /// Its SQL type is `Timestamp`.
+        ///
+        /// (Automatically generated by Diesel.)
+        created_time -> Timestamp,
+    }
+}
+
+table! {
+    /// Representation of the `task_dependency_trigger` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    task_dependency_trigger (id) {
+        /// The `id` column of the `task_dependency_trigger` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Bigint,
+        /// The `run_id` column of the `task_dependency_trigger` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        run_id -> Bigint,
+        /// The `task_id` column of the `task_dependency_trigger` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        task_id -> Bigint,
+        /// The `upstream_task_id` column of the `task_dependency_trigger` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        upstream_task_id -> Bigint,
+        /// The `upstream_record_id` column of the `task_dependency_trigger` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        upstream_record_id -> Bigint,
+        /// The `created_time` column of the `task_dependency_trigger` table.
+        ///
+        /// Its SQL type is `Timestamp`.
+        ///
+        /// (Automatically generated by Diesel.)
+        created_time -> Timestamp,
+    }
+}
+
+table! {
+    /// Representation of the `task_failover` table.
+    ///
+    /// (Automatically generated by Diesel.)
+    task_failover (id) {
+        /// The `id` column of the `task_failover` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        id -> Bigint,
+        /// The `task_id` column of the `task_failover` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        task_id -> Bigint,
+        /// The `origin_bind_id` column of the `task_failover` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        origin_bind_id -> Bigint,
+        /// The `origin_executor_id` column of the `task_failover` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        origin_executor_id -> Bigint,
+        /// The `target_bind_id` column of the `task_failover` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        target_bind_id -> Bigint,
+        /// The `target_executor_id` column of the `task_failover` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        target_executor_id -> Bigint,
+        /// The `created_time` column of the `task_failover` table.
+        ///
+        /// Its SQL type is `Timestamp`.
+        ///
+        /// (Automatically generated by Diesel.)
+        created_time -> Timestamp,
     }
 }
 
@@ -511,6 +883,18 @@ table! {
         ///
         /// (Automatically generated by Diesel.)
         executor_processor_host -> Varchar,
+        /// The `attempt` column of the `task_log` table.
+        ///
+        /// Its SQL type is `Smallint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        attempt -> Smallint,
+        /// The `run_id` column of the `task_log` table.
+        ///
+        /// Its SQL type is `Bigint`.
+        ///
+        /// (Automatically generated by Diesel.)
+        run_id -> Bigint,
     }
 }
 
@@ -543,6 +927,18 @@ table! {
         ///
         /// (Automatically generated by Diesel.)
         stderr -> Text,
+        /// The `stdout_spool` column of the `task_log_extend` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        stdout_spool -> Varchar,
+        /// The `stderr_spool` column of the `task_log_extend` table.
+        ///
+        /// Its SQL type is `Varchar`.
+        ///
+        /// (Automatically generated by Diesel.)
+        stderr_spool -> Varchar,
     }
 }
 
@@ -553,10 +949,10 @@ table! {
     user (id) {
         /// The `id` column of the `user` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        id -> Unsigned<Bigint>,
+        id -> Bigint,
         /// The `user_name` column of the `user` table.
         ///
         /// Its SQL type is `Varchar`.
@@ -589,10 +985,10 @@ table! {
         face -> Varchar,
         /// The `status` column of the `user` table.
         ///
-        /// Its SQL type is `Tinyint`.
+        /// Its SQL type is `Smallint`.
         ///
         /// (Automatically generated by Diesel.)
-        status -> Tinyint,
+        status -> Smallint,
         /// The `created_time` column of the `user` table.
         ///
         /// Its SQL type is `Timestamp`.
@@ -621,16 +1017,16 @@ table! {
         id -> Bigint,
         /// The `user_id` column of the `user_auth` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        user_id -> Unsigned<Bigint>,
+        user_id -> Bigint,
         /// The `identity_type` column of the `user_auth` table.
         ///
-        /// Its SQL type is `Unsigned<Tinyint>`.
+        /// Its SQL type is `Smallint`.
         ///
         /// (Automatically generated by Diesel.)
-        identity_type -> Unsigned<Tinyint>,
+        identity_type -> Smallint,
         /// The `identifier` column of the `user_auth` table.
         ///
         /// Its SQL type is `Varchar`.
@@ -645,10 +1041,10 @@ table! {
         certificate -> Varchar,
         /// The `status` column of the `user_auth` table.
         ///
-        /// Its SQL type is `Tinyint`.
+        /// Its SQL type is `Smallint`.
         ///
         /// (Automatically generated by Diesel.)
-        status -> Tinyint,
+        status -> Smallint,
         /// The `created_time` column of the `user_auth` table.
         ///
         /// Its SQL type is `Timestamp`.
@@ -677,10 +1073,10 @@ table! {
         id -> Bigint,
         /// The `user_id` column of the `user_info_update` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        user_id -> Unsigned<Bigint>,
+        user_id -> Bigint,
         /// The `attribute_name` column of the `user_info_update` table.
         ///
         /// Its SQL type is `Varchar`.
@@ -721,22 +1117,22 @@ table! {
         id -> Bigint,
         /// The `user_id` column of the `user_login_log` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        user_id -> Unsigned<Bigint>,
+        user_id -> Bigint,
         /// The `login_type` column of the `user_login_log` table.
         ///
-        /// Its SQL type is `Unsigned<Tinyint>`.
+        /// Its SQL type is `Smallint`.
         ///
         /// (Automatically generated by Diesel.)
-        login_type -> Unsigned<Tinyint>,
+        login_type -> Smallint,
         /// The `command` column of the `user_login_log` table.
         ///
-        /// Its SQL type is `Unsigned<Tinyint>`.
+        /// Its SQL type is `Smallint`.
         ///
         /// (Automatically generated by Diesel.)
-        command -> Unsigned<Tinyint>,
+        command -> Smallint,
         /// The `lastip` column of the `user_login_log` table.
         ///
         /// Its SQL type is `Varchar`.
@@ -771,16 +1167,16 @@ table! {
         id -> Bigint,
         /// The `user_id` column of the `user_register_log` table.
         ///
-        /// Its SQL type is `Unsigned<Bigint>`.
+        /// Its SQL type is `Bigint`.
         ///
         /// (Automatically generated by Diesel.)
-        user_id -> Unsigned<Bigint>,
+        user_id -> Bigint,
         /// The `register_method` column of the `user_register_log` table.
         ///
-        /// Its SQL type is `Unsigned<Tinyint>`.
+        /// Its SQL type is `Smallint`.
         ///
         /// (Automatically generated by Diesel.)
-        register_method -> Unsigned<Tinyint>,
+        register_method -> Smallint,
         /// The `register_time` column of the `user_register_log` table.
         ///
         /// Its SQL type is `Timestamp`.
@@ -797,14 +1193,20 @@ table! {
 }
 
 allow_tables_to_appear_in_same_query!(
+    calendar,
+    calendar_window,
     casbin_rule,
     executor_group,
     executor_processor,
     executor_processor_bind,
+    executor_processor_health,
     operation_log,
     operation_log_detail,
     task,
     task_bind,
+    task_dependency,
+    task_dependency_trigger,
+    task_failover,
     task_log,
     task_log_extend,
     user,
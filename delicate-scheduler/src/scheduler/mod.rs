@@ -0,0 +1,315 @@
+//! The scheduler, on the storage of one of the backends in `backends`.
+//!
+//! The modules below only reach the backend through the repositories in `db`,
+//! So that one binary serves any backend compiled in, the one in `DATABASE` is run.
+
+pub(crate) mod actions;
+pub(crate) mod components;
+pub(crate) mod db;
+pub(crate) mod prelude;
+
+pub(crate) use prelude::*;
+
+/// Run the scheduler on the storage of the backend in `DATABASE`.
+pub(crate) fn run(conf: SchedulerConfig, storage: Arc<dyn db::Storage>) -> AnyResut<()> {
+    // Automatic execution of database migration
+    db::init(&conf, storage.as_ref());
+
+    // Automatic initialization of log consumers
+    let (_fw_handle, log_level_reloader) = init_logger(conf.reloadable().log_level);
+
+    // Initialize custom asynchronous runtime
+    let raw_runtime = Builder::new_multi_thread()
+        .thread_name_fn(|| {
+            static ATOMIC_ID: AtomicUsize = AtomicUsize::new(0);
+            let id = ATOMIC_ID.fetch_add(1, Ordering::SeqCst);
+            format!("scheduler-{}", id)
+        })
+        .thread_stack_size(4 * 1024 * 1024)
+        .enable_all()
+        .build()
+        .expect("Init Tokio runtime failed.");
+    let arc_runtime = Arc::new(raw_runtime);
+    let arc_runtime_cloned = arc_runtime.clone();
+
+    arc_runtime.block_on(async {
+        let app = Route::new().nest_no_strip(
+            "/api",
+            Route::new()
+                .nest_no_strip("/api/task", actions::task::route_config())
+                .nest_no_strip("/api/calendar", actions::calendar::route_config())
+                .nest_no_strip("/api/user", actions::user::route_config())
+                .nest_no_strip("/api/role", actions::role::route_config())
+                .nest_no_strip("/api/scheduler", actions::scheduler::route_config())
+                .nest_no_strip("/api/task_log", actions::task_log::route_config())
+                .nest_no_strip("/api/tasks_state", actions::data_reports::route_config())
+                .nest_no_strip("/api/task_instance", actions::task_instance::route_config())
+                .nest_no_strip("/api/binding", actions::components::binding::route_config())
+                .nest_no_strip("/api/operation_log", actions::operation_log::route_config())
+                .nest_no_strip(
+                    "/api/executor_group",
+                    actions::executor_group::route_config(),
+                )
+                .nest_no_strip(
+                    "/api/executor_processor",
+                    actions::executor_processor::route_config(),
+                )
+                .nest_no_strip(
+                    "/api/executor_processor_bind",
+                    actions::executor_processor_bind::route_config(),
+                )
+                .nest_no_strip(
+                    "/api/executor",
+                    actions::components::executor::route_config(),
+                )
+                .nest_no_strip(
+                    "/api/permission",
+                    actions::components::permission::route_config(),
+                )
+                .nest_no_strip(
+                    "/api/user_login_log",
+                    actions::user_login_log::route_config(),
+                ),
+        );
+
+        let app =
+            init_scheduler(app, arc_runtime_cloned, &conf, storage, log_level_reloader).await?;
+
+        let listener = TcpListener::bind(conf.scheduler_listening_address.clone());
+        let server = Server::new(listener);
+        Ok(server.run(app).await?)
+    })
+}
+
+// Applies a log level other than the one the logger is initialized with.
+type LogLevelReloader = Box<dyn Fn(Level) + Send + Sync>;
+
+fn init_logger(log_level: Level) -> (FileLogWriterHandle, LogLevelReloader) {
+    // Prepare a `FileLogWriter` and a handle to it, and keep the handle alive
+    // until the program ends (it will flush and shutdown the `FileLogWriter` when dropped).
+    // For the `FileLogWriter`, use the settings that fit your needs
+    let (file_writer, _fw_handle) = FileLogWriter::builder(FileSpec::default())
+        .rotate(
+            // If the program runs long enough,
+            Criterion::Age(Age::Day),  // - create a new file every day
+            Naming::Timestamps,        // - let the rotated files have a timestamp in their name
+            Cleanup::KeepLogFiles(15), // - keep at most seven log files
+        )
+        .write_mode(WriteMode::Async)
+        .try_build_with_handle()
+        .expect("flexi_logger init failed");
+
+    let builder = FmtSubscriber::builder()
+        // will be written to file_writer.
+        .with_env_filter(level_filter(log_level))
+        .with_thread_names(true)
+        .with_writer(move || file_writer.clone())
+        .with_filter_reloading();
+    let reload_handle = builder.reload_handle();
+
+    // completes the builder.
+    builder.init();
+
+    let log_level_reloader: LogLevelReloader = Box::new(move |log_level| {
+        reload_handle
+            .reload(level_filter(log_level))
+            .map_err(|e| error!("Failed to reload the log level: {}", e))
+            .ok();
+    });
+    (_fw_handle, log_level_reloader)
+}
+
+// Only the reloadable `EnvFilter` is swapped on `SIGHUP`, the rest of the subscriber stays.
+fn level_filter(log_level: Level) -> EnvFilter {
+    EnvFilter::default().add_directive(LevelFilter::from_level(log_level).into())
+}
+
+async fn init_scheduler(
+    app: Route,
+    arc_runtime_cloned: Arc<Runtime>,
+    conf: &SchedulerConfig,
+    storage: Arc<dyn db::Storage>,
+    log_level_reloader: LogLevelReloader,
+) -> AnyResut<impl Endpoint> {
+    let request_client = RequestClient::new();
+
+    // The part of the configuration applied again on `SIGHUP`.
+    let (reloadable_conf_sender, reloadable_conf) = watch::channel(conf.reloadable());
+
+    // It evaluates the schedules of the tasks triggered by the scheduler.
+    let delay_timer = DelayTimerBuilder::default()
+        .tokio_runtime_shared_by_custom(arc_runtime_cloned)
+        .build();
+    let arc_delay_timer = Arc::new(delay_timer);
+
+    let shared_delay_timer = AddData::new(arc_delay_timer.clone());
+    let shared_storage = AddData::new(storage.clone());
+    let scheduler_meta_info = SchedulerMetaInfo::new(conf)?;
+    let arc_leader_election = Arc::new(LeaderElection::new(&scheduler_meta_info, &conf.redis_url));
+    let arc_scheduler_meta_info = Arc::new(scheduler_meta_info);
+    let shared_scheduler_meta_info: AddData<Arc<SchedulerMetaInfo>> =
+        AddData::new(arc_scheduler_meta_info.clone());
+    let shared_leader_election = AddData::new(arc_leader_election.clone());
+    let shared_request_client = AddData::new(request_client.clone());
+    let arc_executor_router = Arc::new(ExecutorRouter::new(storage.clone()));
+    let shared_executor_router = AddData::new(arc_executor_router.clone());
+    let arc_central_trigger = Arc::new(CentralTrigger::new(
+        arc_delay_timer.clone(),
+        storage.clone(),
+        request_client.clone(),
+        arc_executor_router.clone(),
+        arc_leader_election.clone(),
+    ));
+    let shared_central_trigger = AddData::new(arc_central_trigger.clone());
+
+    // Authentication-Model, currently optional value `casbin`, it is chosen at startup.
+    let shared_enforcer = match conf.casbin_model_conf.as_ref() {
+        Some(model_conf_path) if conf.casbin_enabled() => Some(Arc::new(RwLock::new(
+            get_casbin_enforcer(storage.clone(), model_conf_path).await,
+        ))),
+        _ => None,
+    };
+
+    let app = match shared_enforcer.clone() {
+        Some(shared_enforcer) => app
+            .with(CasbinService {
+                debug: conf.app_debug,
+            })
+            .with(AddData::new(shared_enforcer))
+            .boxed(),
+        None => app.boxed(),
+    };
+
+    // All ready work when the delicate-application starts.
+    launch_ready_operation(
+        storage.clone(),
+        request_client,
+        arc_executor_router,
+        arc_leader_election,
+        arc_central_trigger,
+        arc_scheduler_meta_info,
+        shared_enforcer,
+        conf.redis_url.clone(),
+        reloadable_conf.clone(),
+    )
+    .await;
+    launch_config_reloader(reloadable_conf_sender, log_level_reloader);
+
+    Ok(app
+        .with(shared_delay_timer)
+        .with(shared_storage)
+        .with(shared_scheduler_meta_info)
+        .with(shared_request_client)
+        .with(shared_executor_router)
+        .with(shared_leader_election)
+        .with(shared_central_trigger)
+        .with(components::session::auth_middleware(conf))
+        .with(components::session::cookie_middleware(conf))
+        .with(components::session::session_middleware(conf))
+        .with(components::cors::cors_middleware(reloadable_conf))
+        .with(components::logger_id::logger_id_middleware()))
+}
+
+// All ready work when the delicate-application starts.
+async fn launch_ready_operation(
+    storage: Arc<dyn db::Storage>,
+    request_client: RequestClient,
+    router: Arc<ExecutorRouter>,
+    election: Arc<LeaderElection>,
+    central_trigger: Arc<CentralTrigger>,
+    scheduler: Arc<SchedulerMetaInfo>,
+    enforcer: Option<Arc<RwLock<Enforcer>>>,
+    redis_url: String,
+    reloadable_conf: watch::Receiver<ReloadableConf>,
+) {
+    launch_leader_election(election.clone());
+    launch_central_trigger(central_trigger);
+    launch_health_check(
+        storage.clone(),
+        request_client.clone(),
+        router.clone(),
+        election.clone(),
+        scheduler,
+        reloadable_conf,
+    );
+    launch_lost_record_sweeper(storage.clone(), request_client, router, election);
+    launch_operation_log_consumer(storage);
+
+    if let Some(enforcer) = enforcer {
+        // When the delicate starts, it checks if the resource acquisition is normal.
+        let redis_client = redis::Client::open(redis_url)
+            .expect("The redis client resource could not be initialized.");
+        launch_casbin_rule_events_consumer(redis_client, enforcer);
+    }
+}
+
+// Leader election among the schedulers sharing the database,
+// Only the leader runs the health check and the sweeper, every scheduler serves the api.
+fn launch_leader_election(election: Arc<LeaderElection>) {
+    tokio_spawn(loop_leader_election(election));
+}
+
+// Schedules of the tasks triggered by the scheduler,
+// Kept by every scheduler and synchronized with the database, the leader sends the runs.
+fn launch_central_trigger(central_trigger: Arc<CentralTrigger>) {
+    tokio_spawn(loop_sync_central_tasks(central_trigger));
+}
+
+// Heartbeat checker
+// That constantly goes to detect whether the machine survives with the machine's indicators.
+fn launch_health_check(
+    storage: Arc<dyn db::Storage>,
+    request_client: RequestClient,
+    router: Arc<ExecutorRouter>,
+    election: Arc<LeaderElection>,
+    scheduler: Arc<SchedulerMetaInfo>,
+    reloadable_conf: watch::Receiver<ReloadableConf>,
+) {
+    tokio_spawn(loop_health_check(
+        storage,
+        request_client,
+        router,
+        election,
+        scheduler,
+        reloadable_conf,
+    ));
+}
+
+// Sweeper of the records left `Running`
+// Whose end was never reported, they are moved to `Lost` once their executor no longer runs them.
+fn launch_lost_record_sweeper(
+    storage: Arc<dyn db::Storage>,
+    request_client: RequestClient,
+    router: Arc<ExecutorRouter>,
+    election: Arc<LeaderElection>,
+) {
+    tokio_spawn(loop_sweep_lost_records(
+        storage,
+        request_client,
+        router,
+        election,
+    ));
+}
+
+// Operation log asynchronous consumer
+//
+// The user's operations in the system are logged to track,
+// But in order not to affect the performance of the system,
+// These logs go through the channel with the asynchronous state machine to consume.
+// The channel is local to each scheduler, so every scheduler consumes its own logs.
+fn launch_operation_log_consumer(storage: Arc<dyn db::Storage>) {
+    tokio_spawn(loop_operate_logs(storage));
+}
+
+// The configuration is loaded again on `SIGHUP`,
+// The log level, the interval of the health check and the origins allowed by CORS are applied without a restart.
+fn launch_config_reloader(
+    reloadable_conf_sender: watch::Sender<ReloadableConf>,
+    log_level_reloader: LogLevelReloader,
+) {
+    launch_reload_on_hangup(SCHEDULER_CONFIG_FILE, move |conf: SchedulerConfig| {
+        let reloadable_conf = conf.reloadable();
+        log_level_reloader(reloadable_conf.log_level);
+        reloadable_conf_sender.send(reloadable_conf).ok();
+    });
+}
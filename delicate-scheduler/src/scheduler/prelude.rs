@@ -5,8 +5,23 @@ pub(crate) use super::components::auth::casbin::casbin_event_consumer::{
 #[allow(unused_imports)]
 pub(crate) use super::components::auth::casbin::*;
 pub(crate) use super::components::base::SchedulerMetaInfo;
+pub(crate) use super::components::central_trigger::{loop_sync_central_tasks, CentralTrigger};
+pub(crate) use super::components::dependency::{
+    load_reachable_dependencies, trigger_downstream_tasks,
+};
+pub(crate) use super::components::executor_binding::{bind_executor, sync_executor_tasks};
+pub(crate) use super::components::failover::{failback_tasks, failover_tasks};
 pub(crate) use super::components::health_checker::loop_health_check;
 pub(crate) use super::components::helper::*;
+pub(crate) use super::components::leader_election::{
+    loop_leader_election, LeaderElection, SchedulerInstance,
+};
+pub(crate) use super::components::misfire::{
+    catch_up_missed_runs, count_missed_runs, fire_missed_runs,
+};
+pub(crate) use super::components::reconciliation::reconcile_tasks;
+pub(crate) use super::components::routing::ExecutorRouter;
+pub(crate) use super::components::sweeper::loop_sweep_lost_records;
 
 pub(crate) use super::components::operation_log_consumer::{
     loop_operate_logs, send_option_operation_log_pair,
@@ -14,11 +29,14 @@ pub(crate) use super::components::operation_log_consumer::{
 pub(crate) use super::db;
 pub(crate) use super::db::common::helper::*;
 pub(crate) use super::db::common::{model as common_model, state, types};
-pub(crate) use super::db::extension::*;
 pub(crate) use super::db::model;
+pub(crate) use crate::config::{
+    HealthCheckConf, RecordTimeZone, ReloadableConf, SchedulerConfig, SCHEDULER_CONFIG_FILE,
+};
 
 pub(crate) use common_model::PaginateData;
 
+pub(crate) use delicate_utils::conf::launch_reload_on_hangup;
 pub(crate) use delicate_utils::consensus_message::security::{self, SecurityLevel};
 pub(crate) use delicate_utils::consensus_message::service_binding;
 pub(crate) use delicate_utils::consensus_message::{
@@ -33,7 +51,6 @@ pub(crate) use delicate_utils::uniform_data::*;
 
 pub(crate) use std::collections::{HashMap, HashSet};
 pub(crate) use std::convert::{AsRef, TryFrom};
-pub(crate) use std::env;
 pub(crate) use std::fmt::Debug;
 pub(crate) use std::future::Future;
 pub(crate) use std::iter::Iterator;
@@ -41,9 +58,8 @@ pub(crate) use std::ops::Deref;
 
 pub(crate) use std::str::FromStr;
 pub(crate) use std::string::ToString;
-pub(crate) use std::sync::atomic::{AtomicUsize, Ordering};
+pub(crate) use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 pub(crate) use std::sync::Arc;
-pub(crate) use std::vec::IntoIter;
 
 pub(crate) use std::time::Duration;
 pub(crate) use std::time::SystemTime;
@@ -58,10 +74,11 @@ pub(crate) use casbin::{
     Watcher as CasbinWatcher,
 };
 
-pub(crate) use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDateTime, Timelike};
+pub(crate) use chrono::{
+    DateTime, Duration as ChronoDuration, FixedOffset, Local, NaiveDateTime, Timelike, Utc,
+};
 
 pub(crate) use delay_timer::prelude::*;
-pub(crate) use diesel::mysql::Mysql;
 pub(crate) use diesel::prelude::*;
 pub(crate) use diesel::query_builder::{AsQuery, AstPass, Query, QueryFragment};
 pub(crate) use diesel::query_dsl::methods::LoadQuery;
@@ -69,21 +86,23 @@ pub(crate) use diesel::query_dsl::RunQueryDsl;
 pub(crate) use diesel::r2d2::CustomizeConnection;
 pub(crate) use diesel::result::Error as DieselError;
 pub(crate) use diesel::sql_types;
-pub(crate) use dotenv::dotenv;
 pub(crate) use flexi_logger::writers::FileLogWriterHandle;
 pub(crate) use flexi_logger::{
     writers::FileLogWriter, Age, Cleanup, Criterion, FileSpec, Naming, WriteMode,
 };
 pub(crate) use futures::future::{join, join3, JoinAll};
+pub(crate) use futures::stream::unfold as stream_unfold;
 
 pub(crate) use tokio::runtime::Builder;
 pub(crate) use tokio::runtime::Runtime;
 pub(crate) use tokio::spawn as tokio_spawn;
+pub(crate) use tokio::sync::watch;
 pub(crate) use tokio::task::spawn_blocking;
-pub(crate) use tokio::time::{interval, sleep};
+pub(crate) use tokio::time::{interval, interval_at, sleep, Instant};
 pub(crate) use tokio::time::{timeout as tokio_timeout, Timeout as TokioTimeout};
-pub(crate) use tracing::{debug, error, info, info_span, span, Instrument, Level};
-pub(crate) use tracing_subscriber::FmtSubscriber;
+pub(crate) use tracing::level_filters::LevelFilter;
+pub(crate) use tracing::{debug, error, info, info_span, span, warn, Instrument, Level};
+pub(crate) use tracing_subscriber::{EnvFilter, FmtSubscriber};
 
 pub(crate) use regex::Regex;
 pub(crate) use reqwest::{
@@ -106,6 +125,7 @@ pub(crate) use poem::Result as PoemResult;
 pub(crate) use poem::middleware::Cors;
 pub(crate) use poem::session::{CookieConfig, CookieSession, Session};
 pub(crate) use poem::web::cookie::CookieKey;
+pub(crate) use poem::web::sse::{Event as SseEvent, SSE};
 pub(crate) use poem::web::{Data, IntoResponse, Json};
 pub(crate) use poem::{
     get, handler, post, Endpoint, EndpointExt, Middleware, Request, Response, Route, Server,
@@ -0,0 +1,195 @@
+//! Loading of the typed configuration of the scheduler and the executor.
+//!
+//! The values are read from an optional file (toml, yaml or json, told by its extension),
+//! And the environment variables prefixed with `DELICATE_` override them,
+//! `DELICATE_DATABASE_URL` sets the key `database_url` and so on,
+//! So that a `.env` keeps working with or without the file. The rest of the environment is left alone.
+//!
+//! The keys read without the prefix before it was introduced (`DATABASE_URL`, `LOG_LEVEL` ...)
+//! Are still read under their old names when the prefixed variable is not set, with a deprecation warning.
+
+use crate::error::InitConfigError;
+use crate::prelude::*;
+
+use ::config::{Config, Environment, File};
+use log::info;
+use serde::de::DeserializeOwned;
+use std::path::Path;
+
+/// The prefix of the environment variables that make up the configuration.
+const ENV_PREFIX: &str = "DELICATE";
+
+/// A configuration checked as a whole once it is loaded.
+pub trait ValidateConfig {
+    /// Note every problem of the configuration, they are reported together.
+    fn validate(&self, problems: &mut ConfigProblems);
+
+    /// The keys that used to be read from the environment without the `DELICATE_` prefix.
+    fn legacy_keys() -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// The problems found in a configuration.
+#[derive(Debug, Default)]
+pub struct ConfigProblems(Vec<String>);
+
+impl ConfigProblems {
+    /// Note that `key` is missing when `value` is blank.
+    pub fn require(&mut self, key: &str, value: &str) {
+        if value.trim().is_empty() {
+            self.0.push(format!("`{}` is required.", key));
+        }
+    }
+
+    /// Note `problem` unless `ok`.
+    pub fn check(&mut self, ok: bool, problem: impl Into<String>) {
+        if !ok {
+            self.0.push(problem.into());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The problems noted so far.
+    pub fn problems(&self) -> &[String] {
+        &self.0
+    }
+}
+
+/// Load the configuration from the file named in the environment variable `file_var` and the environment,
+/// It is only returned when it is valid.
+pub fn load_config<T: DeserializeOwned + ValidateConfig>(
+    file_var: &str,
+) -> Result<T, InitConfigError> {
+    let mut config = Config::default();
+
+    if let Some(file) = env::var_os(file_var).filter(|file| !file.is_empty()) {
+        config.merge(File::from(Path::new(&file)))?;
+    }
+    config.merge(Environment::with_prefix(ENV_PREFIX).ignore_empty(true))?;
+    for (key, value) in legacy_env_values(T::legacy_keys()) {
+        config.set(key, value)?;
+    }
+
+    let conf: T = config.try_into()?;
+    let mut problems = ConfigProblems::default();
+    conf.validate(&mut problems);
+
+    if problems.is_empty() {
+        Ok(conf)
+    } else {
+        Err(InitConfigError::DisValidate(problems.0))
+    }
+}
+
+// The values of the legacy keys whose prefixed variable is not set.
+// The logger is not set up while the configuration loads, so the warnings go to stderr.
+fn legacy_env_values(legacy_keys: &[&'static str]) -> Vec<(&'static str, String)> {
+    let is_set = |name: &str| {
+        env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .is_some()
+    };
+
+    legacy_keys
+        .iter()
+        .filter_map(|key| {
+            let legacy_name = key.to_uppercase();
+            let name = format!("{}_{}", ENV_PREFIX, legacy_name);
+            if is_set(&name) || !is_set(&legacy_name) {
+                return None;
+            }
+
+            eprintln!("`{}` is deprecated, rename it to `{}`.", legacy_name, name);
+            env::var(&legacy_name).ok().map(|value| (*key, value))
+        })
+        .collect()
+}
+
+/// Load the configuration again each time the process receives `SIGHUP` and hand it to `apply`,
+/// A configuration that fails to load or is invalid is reported, and the one in use is kept.
+///
+/// The environment of the process does not change, so the keys set through it keep their values.
+#[cfg(unix)]
+pub fn launch_reload_on_hangup<T, F>(file_var: &'static str, mut apply: F)
+where
+    T: DeserializeOwned + ValidateConfig + Send + 'static,
+    F: FnMut(T) + Send + 'static,
+{
+    use tokio::signal::unix::{signal, SignalKind};
+
+    tokio::spawn(async move {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                error!(
+                    "The configuration cannot be reloaded, listening to `SIGHUP` fails: {}",
+                    e
+                );
+                return;
+            }
+        };
+
+        while hangup.recv().await.is_some() {
+            match load_config::<T>(file_var) {
+                Ok(conf) => {
+                    info!("The configuration is reloaded.");
+                    apply(conf);
+                }
+                Err(e) => error!("The configuration is not reloaded: {}", e),
+            }
+        }
+    });
+}
+
+/// There is no `SIGHUP` on this platform, the configuration is only loaded at startup.
+#[cfg(not(unix))]
+pub fn launch_reload_on_hangup<T, F>(_file_var: &'static str, _apply: F)
+where
+    T: DeserializeOwned + ValidateConfig + Send + 'static,
+    F: FnMut(T) + Send + 'static,
+{
+    info!("The configuration is not reloaded on this platform, it has no `SIGHUP`.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, Deserialize)]
+    #[serde(default)]
+    struct LegacyConf {
+        legacy_probe_name: String,
+        legacy_probe_level: String,
+        legacy_probe_port: u16,
+    }
+
+    impl ValidateConfig for LegacyConf {
+        fn validate(&self, _problems: &mut ConfigProblems) {}
+
+        fn legacy_keys() -> &'static [&'static str] {
+            &[
+                "legacy_probe_name",
+                "legacy_probe_level",
+                "legacy_probe_port",
+            ]
+        }
+    }
+
+    #[test]
+    fn test_load_config_reads_legacy_keys() {
+        env::set_var("LEGACY_PROBE_NAME", "legacy");
+        env::set_var("LEGACY_PROBE_LEVEL", "legacy");
+        env::set_var("DELICATE_LEGACY_PROBE_LEVEL", "prefixed");
+        env::set_var("LEGACY_PROBE_PORT", "9080");
+
+        let conf: LegacyConf = load_config("LEGACY_PROBE_CONFIG_FILE").unwrap();
+        assert_eq!(conf.legacy_probe_name, "legacy");
+        // The prefixed variable wins over the legacy one.
+        assert_eq!(conf.legacy_probe_level, "prefixed");
+        assert_eq!(conf.legacy_probe_port, 9080);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::consensus_message::live_output::start_live_output;
+use crate::consensus_message::task_log::FinishOutput;
+use crate::consensus_message::task_routine::leave_routine_output;
+use crate::prelude::*;
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, Utc, Weekday};
+use diesel::backend::Backend;
+use diesel::sql_types::BigInt;
+use diesel::Queryable;
+use std::future::Future;
+use std::sync::Arc;
+
+/// The times a task does not run in, from the calendar the scheduler attached to it.
+///
+/// The windows are wall-clock times in the `time_zone` of the calendar (1: Utc, 2: Local),
+/// The calendar with id 0 excludes nothing.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TaskCalendar {
+    pub id: i64,
+    pub name: String,
+    pub time_zone: i16,
+    /// Saturdays and Sundays are excluded.
+    pub business_days_only: bool,
+    /// The holidays and the blackout windows.
+    pub excluded_windows: Vec<ExcludedWindow>,
+}
+
+/// An excluded window, from `start_time` (inclusive) to `end_time` (exclusive).
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExcludedWindow {
+    pub start_time: NaiveDateTime,
+    pub end_time: NaiveDateTime,
+    pub description: String,
+}
+
+// Only the calendar-id is stored along with the task, the scheduler fills in the rest before sending it.
+impl<DB> Queryable<BigInt, DB> for TaskCalendar
+where
+    DB: Backend,
+    i64: Queryable<BigInt, DB>,
+{
+    type Row = <i64 as Queryable<BigInt, DB>>::Row;
+
+    fn build(row: Self::Row) -> Self {
+        TaskCalendar {
+            id: <i64 as Queryable<BigInt, DB>>::build(row),
+            ..Default::default()
+        }
+    }
+}
+
+impl TaskCalendar {
+    /// Why the task may not run at `time`, `None` when it may.
+    pub fn exclusion(&self, time: DateTime<Utc>) -> Option<String> {
+        if self.id == 0 {
+            return None;
+        }
+
+        let wall_time = match self.time_zone {
+            1 => time.naive_utc(),
+            _ => time.with_timezone(&Local).naive_local(),
+        };
+
+        if self.business_days_only && matches!(wall_time.weekday(), Weekday::Sat | Weekday::Sun) {
+            return Some(format!(
+                "{} is not a business day in calendar {}.",
+                wall_time.date(),
+                self.name
+            ));
+        }
+
+        self.excluded_windows
+            .iter()
+            .find(|window| window.start_time <= wall_time && wall_time < window.end_time)
+            .map(|window| {
+                format!(
+                    "{} falls in the excluded window `{}` of calendar {}.",
+                    wall_time, window.description, self.name
+                )
+            })
+    }
+}
+
+/// Run `routine` unless the calendar excludes the current time,
+/// A skipped run ends at once and leaves the reason as its output.
+pub(crate) async fn run_unless_excluded<F>(
+    calendar: Arc<TaskCalendar>,
+    timer_task_id: u64,
+    routine: F,
+) where
+    F: Future<Output = ()>,
+{
+    if let Some(reason) = calendar.exclusion(Utc::now()) {
+        // The skipped run is paired with its record all the same, so that it is not regarded as running.
+        drop(start_live_output(timer_task_id));
+        leave_routine_output(timer_task_id, FinishOutput::SkippedOutput(reason));
+        return;
+    }
+
+    routine.await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(start_time: &str, end_time: &str, description: &str) -> ExcludedWindow {
+        ExcludedWindow {
+            start_time: start_time.parse().unwrap(),
+            end_time: end_time.parse().unwrap(),
+            description: description.to_string(),
+        }
+    }
+
+    fn utc(time: &str) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&time.parse().unwrap())
+    }
+
+    fn calendar(time_zone: i16, excluded_windows: Vec<ExcludedWindow>) -> TaskCalendar {
+        TaskCalendar {
+            id: 1,
+            name: String::from("release"),
+            time_zone,
+            business_days_only: false,
+            excluded_windows,
+        }
+    }
+
+    #[test]
+    fn test_exclusion_of_the_windows() {
+        let calendar = calendar(
+            1,
+            vec![window(
+                "2022-01-31T00:00:00",
+                "2022-02-07T00:00:00",
+                "holiday",
+            )],
+        );
+
+        assert!(calendar.exclusion(utc("2022-01-30T23:59:59")).is_none());
+        // The start is in the window, the end is not.
+        assert!(calendar.exclusion(utc("2022-01-31T00:00:00")).is_some());
+        assert!(calendar.exclusion(utc("2022-02-06T23:59:59")).is_some());
+        assert!(calendar.exclusion(utc("2022-02-07T00:00:00")).is_none());
+    }
+
+    #[test]
+    fn test_exclusion_of_overlapping_windows() {
+        let calendar = calendar(
+            1,
+            vec![
+                window("2022-01-31T00:00:00", "2022-02-07T00:00:00", "holiday"),
+                window("2022-02-06T22:00:00", "2022-02-07T02:00:00", "blackout"),
+            ],
+        );
+
+        // The first window holding the time is reported.
+        let reason = calendar.exclusion(utc("2022-02-06T23:00:00")).unwrap();
+        assert!(reason.contains("`holiday`"));
+
+        let reason = calendar.exclusion(utc("2022-02-07T01:00:00")).unwrap();
+        assert!(reason.contains("`blackout`"));
+
+        assert!(calendar.exclusion(utc("2022-02-07T02:00:00")).is_none());
+    }
+
+    #[test]
+    fn test_exclusion_in_the_time_zone_of_the_calendar() {
+        let time = utc("2022-02-01T12:00:00");
+        let local_time = time.with_timezone(&Local).naive_local();
+        let local_window = ExcludedWindow {
+            start_time: local_time,
+            end_time: local_time + chrono::Duration::minutes(1),
+            description: String::from("local"),
+        };
+
+        assert!(calendar(2, vec![local_window]).exclusion(time).is_some());
+
+        let utc_window = window("2022-02-01T12:00:00", "2022-02-01T12:01:00", "utc");
+        assert!(calendar(1, vec![utc_window]).exclusion(time).is_some());
+    }
+
+    #[test]
+    fn test_exclusion_of_the_weekends() {
+        let mut calendar = calendar(1, Vec::new());
+        calendar.business_days_only = true;
+
+        // 2022-01-08 is a Saturday.
+        assert!(calendar.exclusion(utc("2022-01-08T12:00:00")).is_some());
+        assert!(calendar.exclusion(utc("2022-01-09T12:00:00")).is_some());
+        assert!(calendar.exclusion(utc("2022-01-10T12:00:00")).is_none());
+
+        calendar.business_days_only = false;
+        assert!(calendar.exclusion(utc("2022-01-08T12:00:00")).is_none());
+    }
+
+    #[test]
+    fn test_no_exclusion_without_a_calendar() {
+        let mut calendar = calendar(
+            1,
+            vec![window(
+                "2022-01-31T00:00:00",
+                "2022-02-07T00:00:00",
+                "holiday",
+            )],
+        );
+        calendar.id = 0;
+        calendar.business_days_only = true;
+
+        assert!(calendar.exclusion(utc("2022-02-05T12:00:00")).is_none());
+    }
+}
@@ -4,6 +4,10 @@ use crate::prelude::*;
 pub struct HealthCheckPackage {
     pub system_snapshot: SystemSnapshot,
     pub bind_request: service_binding::BindRequest,
+    // The ids of the tasks held by the executor,
+    // `None` when the executor does not report them.
+    #[serde(default)]
+    pub task_ids: Option<Vec<i64>>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
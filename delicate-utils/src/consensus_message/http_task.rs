@@ -0,0 +1,157 @@
+use crate::consensus_message::live_output::start_live_output;
+use crate::consensus_message::output_limit::CappedOutput;
+use crate::consensus_message::task_log::{
+    ChildOutput, FinishOutput, HttpResponseOutput, OutputStream, ResourceExceededOutput,
+};
+use crate::consensus_message::task_routine::leave_routine_output;
+use crate::prelude::*;
+
+use reqwest::{Client, Method, Url};
+use std::sync::Arc;
+use std::time::Duration;
+
+// A server that cannot be reached fails the run rather than holding it until the task times out.
+const HTTP_TASK_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+lazy_static! {
+    static ref HTTP_TASK_CLIENT: Client = Client::builder()
+        .connect_timeout(HTTP_TASK_CONNECT_TIMEOUT)
+        .build()
+        .expect("The http client of the tasks is built.");
+}
+
+/// What a task does when it runs.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TaskKind {
+    /// The `command` is a shell command.
+    Shell = 1,
+    /// The `command` is a json `HttpRequest`.
+    Http = 2,
+    Unknown = 81,
+}
+
+impl From<i16> for TaskKind {
+    fn from(value: i16) -> Self {
+        match value {
+            1 => TaskKind::Shell,
+            2 => TaskKind::Http,
+            _ => TaskKind::Unknown,
+        }
+    }
+}
+
+/// The http call made by an http task, kept as json in the `command` of the task.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HttpRequest {
+    #[serde(default = "default_method")]
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    #[serde(default)]
+    pub body: String,
+    /// Any `2xx` status is expected when it is empty.
+    #[serde(default)]
+    pub expected_status_codes: Vec<u16>,
+}
+
+fn default_method() -> String {
+    String::from("GET")
+}
+
+impl FromStr for HttpRequest {
+    type Err = CommonError;
+
+    fn from_str(command: &str) -> Result<Self, Self::Err> {
+        let http_request: HttpRequest = json_from_slice(command.as_bytes())?;
+
+        http_request.method()?;
+        Url::parse(&http_request.url)
+            .map_err(|e| CommonError::DisPass(format!("Ineffective http url: {}", e)))?;
+
+        Ok(http_request)
+    }
+}
+
+impl HttpRequest {
+    fn method(&self) -> Result<Method, CommonError> {
+        Method::from_bytes(self.method.to_uppercase().as_bytes())
+            .map_err(|_| CommonError::DisPass(format!("Ineffective http method: {}", self.method)))
+    }
+
+    fn is_expected(&self, status: u16) -> bool {
+        if self.expected_status_codes.is_empty() {
+            return (200..300).contains(&status);
+        }
+
+        self.expected_status_codes.contains(&status)
+    }
+
+    // Only the head and the tail of the body are kept beyond `output_limit`.
+    async fn send(
+        &self,
+        output_limit: usize,
+        timer_task_id: u64,
+    ) -> Result<HttpResponseOutput, CommonError> {
+        let mut request_builder = HTTP_TASK_CLIENT.request(self.method()?, &self.url);
+        for (name, value) in self.headers.iter() {
+            request_builder = request_builder.header(name.as_str(), value.as_str());
+        }
+        if !self.body.is_empty() {
+            request_builder = request_builder.body(self.body.clone());
+        }
+
+        let mut response = request_builder.send().await?;
+        let status = response.status().as_u16();
+        let mut body_output =
+            CappedOutput::new(output_limit, OutputStream::Stdout, timer_task_id).await;
+        while let Some(chunk) = response.chunk().await? {
+            body_output.push(&chunk).await;
+        }
+        let (body, body_spool) = body_output.finish().await;
+
+        Ok(HttpResponseOutput {
+            status,
+            body,
+            body_spool,
+            expected: self.is_expected(status),
+        })
+    }
+}
+
+/// The routine of an http task, its output is taken by `take_routine_output` when the run ends.
+///
+/// The request and the reading of the body are stopped at `wall_clock`, as the child of a shell task is.
+pub async fn run_http_request(
+    http_request: Arc<HttpRequest>,
+    output_limit: usize,
+    wall_clock: Option<Duration>,
+    timer_task_id: u64,
+) {
+    let live_output = start_live_output(timer_task_id);
+    let send_result = match wall_clock {
+        Some(wall_clock) => {
+            tokio::time::timeout(wall_clock, http_request.send(output_limit, timer_task_id))
+                .await
+                .ok()
+        }
+        None => Some(http_request.send(output_limit, timer_task_id).await),
+    };
+
+    let output = match send_result {
+        None => FinishOutput::ResourceExceededOutput(ResourceExceededOutput {
+            resource: String::from("wall-clock"),
+            child_output: ChildOutput::default(),
+        }),
+        Some(Ok(http_output)) => {
+            live_output.push(
+                OutputStream::Stdout,
+                format!("HTTP {}\n{}", http_output.status, http_output.body).as_bytes(),
+            );
+            FinishOutput::HttpOutput(http_output)
+        }
+        Some(Err(e)) => FinishOutput::ExceptionOutput(e.to_string()),
+    };
+
+    leave_routine_output(timer_task_id, output);
+}
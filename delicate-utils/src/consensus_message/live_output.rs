@@ -0,0 +1,250 @@
+use crate::consensus_message::task_log::{OutputChunk, OutputStream, TaskRecordOutput};
+use crate::prelude::*;
+
+use std::collections::HashSet;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::sleep;
+
+// The output kept for one run, the oldest chunks are dropped beyond it.
+const LIVE_OUTPUT_CAPACITY: usize = 1024 * 1024;
+// How long the output of a finished run can still be read.
+const LIVE_OUTPUT_RETENTION: Duration = Duration::from_secs(60);
+
+lazy_static! {
+    static ref LIVE_OUTPUTS: Mutex<LiveOutputs> = Mutex::new(LiveOutputs::default());
+}
+
+// A run only learns its record id when `DelayTimer` reports it as running,
+// Which can happen before or after the routine starts, so both sides queue up by timer-task-id.
+#[derive(Default)]
+struct LiveOutputs {
+    unbound_runs: HashMap<u64, VecDeque<Arc<LiveOutput>>>,
+    unbound_records: HashMap<u64, VecDeque<i64>>,
+    records: HashMap<i64, Arc<LiveOutput>>,
+}
+
+/// The output a run has produced so far.
+#[derive(Debug, Default)]
+pub struct LiveOutput {
+    inner: Mutex<LiveOutputInner>,
+}
+
+#[derive(Debug, Default)]
+struct LiveOutputInner {
+    record_id: Option<i64>,
+    chunks: VecDeque<OutputChunk>,
+    size: usize,
+    next_offset: u64,
+    finished: bool,
+}
+
+impl LiveOutput {
+    pub(crate) fn push(&self, stream: OutputStream, content: &[u8]) {
+        if content.is_empty() {
+            return;
+        }
+
+        if let Ok(mut inner) = self.inner.lock() {
+            let chunk = OutputChunk {
+                offset: inner.next_offset,
+                stream,
+                content: String::from_utf8_lossy(content).into_owned(),
+            };
+            inner.next_offset += content.len() as u64;
+            inner.size += chunk.content.len();
+            inner.chunks.push_back(chunk);
+
+            while inner.size > LIVE_OUTPUT_CAPACITY {
+                match inner.chunks.pop_front() {
+                    Some(chunk) => inner.size -= chunk.content.len(),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    fn read(&self, offset: u64) -> TaskRecordOutput {
+        let inner = match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(_) => return TaskRecordOutput::default(),
+        };
+
+        TaskRecordOutput {
+            chunks: inner
+                .chunks
+                .iter()
+                .filter(|chunk| chunk.offset >= offset)
+                .cloned()
+                .collect(),
+            next_offset: inner.next_offset,
+            finished: inner.finished,
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.inner.lock().map_or(true, |inner| inner.finished)
+    }
+
+    // Returns the record to clean up once both the run has finished and its record is known.
+    fn finish(&self) -> Option<i64> {
+        let mut inner = self.inner.lock().ok()?;
+        inner.finished = true;
+        inner.record_id
+    }
+
+    fn bind(&self, record_id: i64) -> bool {
+        self.inner
+            .lock()
+            .map(|mut inner| {
+                inner.record_id = Some(record_id);
+                inner.finished
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Held by a routine while it runs, the output is marked finished when it is dropped,
+/// Including when `DelayTimer` cancels the run or it times out.
+#[derive(Debug)]
+pub(crate) struct LiveOutputGuard {
+    live_output: Arc<LiveOutput>,
+}
+
+impl Deref for LiveOutputGuard {
+    type Target = LiveOutput;
+
+    fn deref(&self) -> &LiveOutput {
+        &self.live_output
+    }
+}
+
+impl Drop for LiveOutputGuard {
+    fn drop(&mut self) {
+        if let Some(record_id) = self.live_output.finish() {
+            schedule_removal(record_id);
+        }
+    }
+}
+
+/// Called by a routine when a run of the task starts.
+pub(crate) fn start_live_output(timer_task_id: u64) -> LiveOutputGuard {
+    let live_output = Arc::new(LiveOutput::default());
+
+    if let Ok(mut live_outputs) = LIVE_OUTPUTS.lock() {
+        let record_id = live_outputs
+            .unbound_records
+            .get_mut(&timer_task_id)
+            .and_then(|records| records.pop_front());
+        live_outputs.unbound_records.retain(|_, r| !r.is_empty());
+
+        match record_id {
+            Some(record_id) => {
+                live_output.bind(record_id);
+                live_outputs.records.insert(record_id, live_output.clone());
+            }
+            None => live_outputs
+                .unbound_runs
+                .entry(timer_task_id)
+                .or_default()
+                .push_back(live_output.clone()),
+        }
+    }
+
+    LiveOutputGuard { live_output }
+}
+
+/// Pair the record `DelayTimer` reports as running with the run of the task that started earliest.
+pub fn bind_live_output(timer_task_id: u64, record_id: i64) {
+    let mut live_outputs = match LIVE_OUTPUTS.lock() {
+        Ok(live_outputs) => live_outputs,
+        Err(_) => return,
+    };
+
+    let live_output = live_outputs
+        .unbound_runs
+        .get_mut(&timer_task_id)
+        .and_then(|runs| runs.pop_front());
+    live_outputs.unbound_runs.retain(|_, r| !r.is_empty());
+
+    match live_output {
+        Some(live_output) => {
+            let finished = live_output.bind(record_id);
+            live_outputs.records.insert(record_id, live_output);
+            if finished {
+                drop(live_outputs);
+                schedule_removal(record_id);
+            }
+        }
+        None => live_outputs
+            .unbound_records
+            .entry(timer_task_id)
+            .or_default()
+            .push_back(record_id),
+    }
+}
+
+/// The output of a running or recently finished record since `offset`,
+/// `None` when the executor knows nothing about the record.
+pub fn read_live_output(record_id: i64, offset: u64) -> Option<TaskRecordOutput> {
+    let live_output = LIVE_OUTPUTS.lock().ok()?.records.get(&record_id).cloned()?;
+
+    Some(live_output.read(offset))
+}
+
+/// The records among `record_ids` whose run has not finished yet.
+pub fn running_records(record_ids: &[i64]) -> Vec<i64> {
+    let live_outputs = match LIVE_OUTPUTS.lock() {
+        Ok(live_outputs) => live_outputs,
+        Err(_) => return Vec::new(),
+    };
+
+    // A record waiting for its routine to start is running as well.
+    let unbound_records: HashSet<i64> = live_outputs
+        .unbound_records
+        .values()
+        .flatten()
+        .copied()
+        .collect();
+
+    record_ids
+        .iter()
+        .filter(|record_id| {
+            unbound_records.contains(record_id)
+                || matches!(
+                    live_outputs.records.get(record_id),
+                    Some(live_output) if !live_output.is_finished()
+                )
+        })
+        .copied()
+        .collect()
+}
+
+/// Drop the runs that were never paired, e.g. when the task is removed.
+pub fn forget_live_outputs(timer_task_id: u64) {
+    if let Ok(mut live_outputs) = LIVE_OUTPUTS.lock() {
+        live_outputs.unbound_runs.remove(&timer_task_id);
+        live_outputs.unbound_records.remove(&timer_task_id);
+    }
+}
+
+fn schedule_removal(record_id: i64) {
+    let removal = async move {
+        sleep(LIVE_OUTPUT_RETENTION).await;
+        if let Ok(mut live_outputs) = LIVE_OUTPUTS.lock() {
+            live_outputs.records.remove(&record_id);
+        }
+    };
+
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn(removal);
+        }
+        Err(_) => {
+            if let Ok(mut live_outputs) = LIVE_OUTPUTS.lock() {
+                live_outputs.records.remove(&record_id);
+            }
+        }
+    }
+}
@@ -1,6 +1,12 @@
+pub mod calendar;
 pub mod executor_processor;
 pub mod health_check;
+pub mod http_task;
+pub mod live_output;
+pub mod output_limit;
+pub mod resource_limit;
 pub mod security;
 pub mod service_binding;
 pub mod task;
 pub mod task_log;
+pub mod task_routine;
@@ -0,0 +1,225 @@
+use crate::consensus_message::task_log::OutputStream;
+use crate::prelude::*;
+
+use std::sync::RwLock as StdRwLock;
+use tokio::fs::File as AsyncFile;
+use tokio::io::AsyncWriteExt;
+
+lazy_static! {
+    // The output kept of each stream in bytes when the task sets no limit, from the configuration of the executor,
+    // The default keeps both streams within the `text` columns of `task_log_extend`.
+    static ref DEFAULT_OUTPUT_LIMIT: StdRwLock<usize> = StdRwLock::new(30 * 1024);
+
+    // A directory the whole output of a truncated stream is kept in, from the configuration of the executor.
+    static ref OUTPUT_SPOOL_DIR: StdRwLock<Option<PathBuf>> = StdRwLock::new(None);
+}
+
+/// Set the output kept of each stream in KiB when the task sets no limit,
+/// And the directory the whole output of a truncated stream is kept in.
+pub fn set_output_defaults(output_limit: usize, spool_dir: Option<PathBuf>) {
+    *DEFAULT_OUTPUT_LIMIT
+        .write()
+        .expect("The output limit is not poisoned.") = output_limit * 1024;
+    *OUTPUT_SPOOL_DIR
+        .write()
+        .expect("The output spool directory is not poisoned.") = spool_dir;
+}
+
+/// The output kept of each stream in bytes, for a task limit in KiB.
+pub(crate) fn output_limit(output_limit: i32) -> usize {
+    if output_limit > 0 {
+        return output_limit as usize * 1024;
+    }
+
+    *DEFAULT_OUTPUT_LIMIT
+        .read()
+        .expect("The output limit is not poisoned.")
+}
+
+/// Keep the head and the tail of `content` within `limit` bytes,
+/// The middle is replaced by a marker telling how much was dropped.
+pub fn truncate_output(content: String, limit: usize) -> String {
+    if content.len() <= limit {
+        return content;
+    }
+
+    // The marker is counted in the limit as well.
+    let kept = limit.saturating_sub(truncation_marker(content.len() as u64).len());
+    let mut head_end = kept / 2;
+    while !content.is_char_boundary(head_end) {
+        head_end -= 1;
+    }
+    let mut tail_start = content.len() - (kept - kept / 2);
+    while !content.is_char_boundary(tail_start) {
+        tail_start += 1;
+    }
+
+    format!(
+        "{}{}{}",
+        &content[..head_end],
+        truncation_marker((tail_start - head_end) as u64),
+        &content[tail_start..]
+    )
+}
+
+fn truncation_marker(dropped: u64) -> String {
+    format!("\n... [{} bytes truncated] ...\n", dropped)
+}
+
+/// The output of a stream as it is read, only the head and the tail are held in memory.
+#[derive(Debug)]
+pub(crate) struct CappedOutput {
+    limit: usize,
+    head: Vec<u8>,
+    tail: VecDeque<u8>,
+    dropped: u64,
+    spool: Option<OutputSpool>,
+}
+
+impl CappedOutput {
+    pub(crate) async fn new(limit: usize, stream: OutputStream, timer_task_id: u64) -> Self {
+        CappedOutput {
+            limit,
+            head: Vec::new(),
+            tail: VecDeque::new(),
+            dropped: 0,
+            spool: OutputSpool::create(stream, timer_task_id).await,
+        }
+    }
+
+    pub(crate) async fn push(&mut self, content: &[u8]) {
+        let spool_failed = match self.spool.as_mut() {
+            Some(spool) => spool
+                .file
+                .write_all(content)
+                .await
+                .map_err(|e| error!("Failed to spool the output to {:?}: {}", spool.path, e))
+                .is_err(),
+            None => false,
+        };
+        if spool_failed {
+            if let Some(spool) = self.spool.take() {
+                spool.remove();
+            }
+        }
+
+        let head_limit = self.limit / 2;
+        let head_len = (head_limit.saturating_sub(self.head.len())).min(content.len());
+        self.head.extend_from_slice(&content[..head_len]);
+
+        let tail_limit = self.limit - head_limit;
+        self.tail.extend(&content[head_len..]);
+        while self.tail.len() > tail_limit {
+            let excess = self.tail.len() - tail_limit;
+            self.tail.drain(..excess);
+            self.dropped += excess as u64;
+        }
+    }
+
+    /// The kept output and the file holding the whole output, empty when nothing was dropped.
+    pub(crate) async fn finish(mut self) -> (String, String) {
+        let mut spool_path = String::new();
+        if let Some(mut spool) = self.spool.take() {
+            if self.dropped > 0 && spool.file.flush().await.is_ok() {
+                spool_path = spool.path.to_string_lossy().into_owned();
+            } else {
+                spool.remove();
+            }
+        }
+
+        let mut output = String::from_utf8_lossy(&self.head).into_owned();
+        if self.dropped > 0 {
+            output.push_str(&truncation_marker(self.dropped));
+        }
+        output.push_str(&String::from_utf8_lossy(self.tail.make_contiguous()));
+
+        (output, spool_path)
+    }
+}
+
+#[derive(Debug)]
+struct OutputSpool {
+    path: PathBuf,
+    file: AsyncFile,
+}
+
+impl OutputSpool {
+    async fn create(stream: OutputStream, timer_task_id: u64) -> Option<OutputSpool> {
+        let spool_dir = OUTPUT_SPOOL_DIR
+            .read()
+            .expect("The output spool directory is not poisoned.")
+            .clone()?;
+        let stream = match stream {
+            OutputStream::Stdout => "stdout",
+            OutputStream::Stderr => "stderr",
+        };
+        let path = spool_dir.join(format!(
+            "task-{}-{}.{}",
+            timer_task_id,
+            Uuid::new_v4(),
+            stream
+        ));
+
+        match AsyncFile::create(&path).await {
+            Ok(file) => Some(OutputSpool { path, file }),
+            Err(e) => {
+                error!("Failed to create the output spool {:?}: {}", path, e);
+                None
+            }
+        }
+    }
+
+    fn remove(self) {
+        fs::remove_file(&self.path)
+            .map_err(|e| error!("Failed to remove the output spool {:?}: {}", self.path, e))
+            .ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_output_keeps_the_head_and_the_tail() {
+        let content = format!("{}{}{}", "a".repeat(100), "b".repeat(100), "c".repeat(100));
+
+        // 29 bytes are kept beside the marker of 31 bytes, 14 of the head and 15 of the tail.
+        let output = truncate_output(content, 60);
+        assert_eq!(
+            output,
+            format!(
+                "{}{}{}",
+                "a".repeat(14),
+                truncation_marker(271),
+                "c".repeat(15)
+            )
+        );
+        assert_eq!(output.len(), 60);
+    }
+
+    #[test]
+    fn test_truncate_output_keeps_the_content_within_the_limit() {
+        let content = "a".repeat(60);
+
+        assert_eq!(truncate_output(content.clone(), 60), content);
+        assert_eq!(truncate_output(content.clone(), 61), content);
+        assert_eq!(truncate_output(String::new(), 0), "");
+    }
+
+    #[test]
+    fn test_truncate_output_with_no_limit_keeps_the_marker_only() {
+        let output = truncate_output("a".repeat(60), 0);
+
+        assert_eq!(output, truncation_marker(60));
+    }
+
+    #[test]
+    fn test_truncate_output_cuts_at_char_boundaries() {
+        // 2 bytes a char, the 10 bytes kept would split a char at both ends of the cut.
+        let output = truncate_output("é".repeat(50), 41);
+
+        assert_eq!(output, format!("éé{}éé", truncation_marker(92)));
+        assert!(output.len() <= 41);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::prelude::*;
+
+use diesel::backend::Backend;
+use diesel::Queryable;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::RwLock as StdRwLock;
+use std::time::Duration;
+use tokio::process::Command;
+
+lazy_static! {
+    // A cgroup v2 directory delegated to the executor, from the configuration of the executor,
+    // The `cpu`, `memory` and `pids` controllers should be enabled in its `cgroup.subtree_control`.
+    static ref CGROUP_ROOT: StdRwLock<Option<PathBuf>> = StdRwLock::new(None);
+}
+
+/// Set the cgroup v2 directory delegated to the executor.
+pub fn set_cgroup_root(cgroup_root: Option<PathBuf>) {
+    *CGROUP_ROOT
+        .write()
+        .expect("The cgroup root is not poisoned.") = cgroup_root;
+}
+
+/// The resources the child process of a task may use, 0 means unlimited.
+///
+/// The memory and the processes are only held exactly through cgroup v2 (`executor_cgroup_root`),
+/// Otherwise they are a best effort through rlimits, see `memory_limit` and `pids_limit`.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// Cpu in millicores (1000 is one core), only enforced through cgroup v2.
+    pub cpu_limit: i32,
+    /// Memory in MiB.
+    /// Without a cgroup it bounds the virtual memory of each process (`RLIMIT_AS`), not the resident memory.
+    pub memory_limit: i32,
+    /// Number of processes.
+    /// Without a cgroup it bounds the processes of the whole user running the child (`RLIMIT_NPROC`).
+    pub pids_limit: i32,
+    /// Wall-clock seconds.
+    pub wall_clock_limit: i32,
+    /// The output kept of each stream in KiB, 0 means the executor default.
+    #[serde(default)]
+    pub output_limit: i32,
+}
+
+type ResourceLimitsRow = (i32, i32, i32, i32, i32);
+
+// Implemented by hand, so that no impl is left inside the named constant diesel 1.4 derives it in.
+impl<DB, ST> Queryable<ST, DB> for ResourceLimits
+where
+    DB: Backend,
+    ResourceLimitsRow: Queryable<ST, DB>,
+{
+    type Row = <ResourceLimitsRow as Queryable<ST, DB>>::Row;
+
+    fn build(row: Self::Row) -> Self {
+        let (cpu_limit, memory_limit, pids_limit, wall_clock_limit, output_limit) =
+            <ResourceLimitsRow as Queryable<ST, DB>>::build(row);
+
+        ResourceLimits {
+            cpu_limit,
+            memory_limit,
+            pids_limit,
+            wall_clock_limit,
+            output_limit,
+        }
+    }
+}
+
+impl ResourceLimits {
+    pub fn is_empty(&self) -> bool {
+        self == &ResourceLimits::default()
+    }
+
+    pub fn check(&self) -> Result<(), CommonError> {
+        if self.cpu_limit < 0
+            || self.memory_limit < 0
+            || self.pids_limit < 0
+            || self.wall_clock_limit < 0
+            || self.output_limit < 0
+        {
+            return Err(CommonError::DisPass(String::from(
+                "Resource limits cannot be negative.",
+            )));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn wall_clock(&self) -> Option<Duration> {
+        (self.wall_clock_limit > 0).then(|| Duration::from_secs(self.wall_clock_limit as u64))
+    }
+
+    fn needs_cgroup(&self) -> bool {
+        self.cpu_limit > 0 || self.memory_limit > 0 || self.pids_limit > 0
+    }
+
+    /// The limit a child held by rlimits failed to start on, if any.
+    ///
+    /// The exec of the child fails with `ENOMEM` when its image does not fit in `RLIMIT_AS`,
+    /// And with `EAGAIN` when the user already runs more processes than `RLIMIT_NPROC`.
+    /// A child that hits them later only sees its allocations or forks fail, which is not told apart.
+    pub(crate) fn rlimit_exceeded(&self, spawn_error: &std::io::Error) -> Option<&'static str> {
+        match spawn_error.raw_os_error() {
+            Some(libc::ENOMEM) if self.memory_limit > 0 => Some("memory"),
+            Some(libc::EAGAIN) if self.pids_limit > 0 => Some("pids"),
+            _ => None,
+        }
+    }
+
+    /// Hold the child to the limits, through its own cgroup when a cgroup root is configured
+    /// And through rlimits otherwise, the returned cgroup tells which limit was exceeded.
+    pub(crate) fn apply(
+        &self,
+        child_command: &mut Command,
+        timer_task_id: u64,
+    ) -> Result<Option<TaskCgroup>, CommonError> {
+        if !self.needs_cgroup() {
+            return Ok(None);
+        }
+
+        let cgroup_root = CGROUP_ROOT
+            .read()
+            .expect("The cgroup root is not poisoned.")
+            .clone();
+        match cgroup_root {
+            Some(cgroup_root) => {
+                let task_cgroup = TaskCgroup::create(&cgroup_root, self, timer_task_id)?;
+                task_cgroup.attach(child_command)?;
+                Ok(Some(task_cgroup))
+            }
+            None => {
+                self.apply_rlimits(child_command);
+                Ok(None)
+            }
+        }
+    }
+
+    // Without cgroups the memory and the processes are bounded by rlimits,
+    // Which only tell when they are hit as the child starts, and the cpu is not bounded.
+    #[cfg(unix)]
+    fn apply_rlimits(&self, child_command: &mut Command) {
+        if self.cpu_limit > 0 {
+            error!("The cpu limit is ignored, it needs `EXECUTOR_CGROUP_ROOT`.");
+        }
+
+        let memory_limit = self.memory_limit as libc::rlim_t * 1024 * 1024;
+        let pids_limit = self.pids_limit as libc::rlim_t;
+
+        // Only async-signal-safe calls are made between fork and exec.
+        unsafe {
+            child_command.pre_exec(move || {
+                if memory_limit > 0 {
+                    set_rlimit(libc::RLIMIT_AS, memory_limit)?;
+                }
+                if pids_limit > 0 {
+                    set_rlimit(libc::RLIMIT_NPROC, pids_limit)?;
+                }
+                Ok(())
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn apply_rlimits(&self, _child_command: &mut Command) {
+        error!("Resource limits are only supported on unix.");
+    }
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+type RlimitResource = libc::__rlimit_resource_t;
+#[cfg(all(unix, not(all(target_os = "linux", target_env = "gnu"))))]
+type RlimitResource = libc::c_int;
+
+#[cfg(unix)]
+fn set_rlimit(resource: RlimitResource, limit: libc::rlim_t) -> std::io::Result<()> {
+    let rlimit = libc::rlimit {
+        rlim_cur: limit,
+        rlim_max: limit,
+    };
+
+    if unsafe { libc::setrlimit(resource, &rlimit) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// The cgroup of one run, removed when the run ends.
+#[derive(Debug)]
+pub(crate) struct TaskCgroup {
+    path: PathBuf,
+}
+
+impl TaskCgroup {
+    fn create(
+        cgroup_root: &Path,
+        resource_limits: &ResourceLimits,
+        timer_task_id: u64,
+    ) -> Result<TaskCgroup, CommonError> {
+        let path = cgroup_root.join(format!("task-{}-{}", timer_task_id, Uuid::new_v4()));
+        fs::create_dir(&path)?;
+        let task_cgroup = TaskCgroup { path };
+
+        if resource_limits.cpu_limit > 0 {
+            // The quota is in microseconds per period of 100ms.
+            task_cgroup.write(
+                "cpu.max",
+                &format!("{} 100000", resource_limits.cpu_limit * 100),
+            )?;
+        }
+        if resource_limits.memory_limit > 0 {
+            let memory_limit = resource_limits.memory_limit as u64 * 1024 * 1024;
+            task_cgroup.write("memory.max", &memory_limit.to_string())?;
+            task_cgroup.write("memory.swap.max", "0").ok();
+        }
+        if resource_limits.pids_limit > 0 {
+            task_cgroup.write("pids.max", &resource_limits.pids_limit.to_string())?;
+        }
+
+        Ok(task_cgroup)
+    }
+
+    // The child moves itself into the cgroup before exec,
+    // So that none of its descendants escape the limits.
+    #[cfg(unix)]
+    fn attach(&self, child_command: &mut Command) -> Result<(), CommonError> {
+        let procs: File = OpenOptions::new()
+            .write(true)
+            .open(self.path.join("cgroup.procs"))?;
+
+        unsafe {
+            child_command.pre_exec(move || (&procs).write_all(b"0"));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn attach(&self, _child_command: &mut Command) -> Result<(), CommonError> {
+        Err(CommonError::DisPass(String::from(
+            "Cgroups are only supported on unix.",
+        )))
+    }
+
+    /// The limit the run was stopped by, if any.
+    pub(crate) fn exceeded(&self) -> Option<&'static str> {
+        if self.read_event("memory.events", "oom_kill") > 0 {
+            return Some("memory");
+        }
+        if self.read_event("pids.events", "max") > 0 {
+            return Some("pids");
+        }
+        None
+    }
+
+    /// Kill every process left in the cgroup.
+    pub(crate) fn kill(&self) {
+        self.write("cgroup.kill", "1")
+            .map_err(|e| error!("Failed to kill the cgroup {:?}: {}", self.path, e))
+            .ok();
+    }
+
+    fn write(&self, file: &str, value: &str) -> Result<(), CommonError> {
+        Ok(fs::write(self.path.join(file), value)?)
+    }
+
+    fn read_event(&self, file: &str, key: &str) -> u64 {
+        fs::read_to_string(self.path.join(file))
+            .ok()
+            .and_then(|events| {
+                events.lines().find_map(|line| {
+                    let mut fields = line.split_whitespace();
+                    (fields.next() == Some(key))
+                        .then(|| fields.next())
+                        .flatten()
+                        .and_then(|count| count.parse::<u64>().ok())
+                })
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for TaskCgroup {
+    fn drop(&mut self) {
+        fs::remove_dir(&self.path)
+            .map_err(|e| error!("Failed to remove the cgroup {:?}: {}", self.path, e))
+            .ok();
+    }
+}
@@ -1,15 +1,16 @@
 use crate::error::InitSchedulerError;
 use crate::prelude::*;
 use service_binding::BindRequest;
+use std::path::Path;
 
 pub trait SecurityRsaKey<T: TryFrom<pem::Pem>>
 where
     InitSchedulerError: From<<T as std::convert::TryFrom<pem::Pem>>::Error>,
 {
-    /// Get delicate-executor's security key from env.
-    fn get_app_rsa_key(key_name: &str) -> Result<T, InitSchedulerError> {
-        let key_path = env::var_os(key_name)
-            .ok_or_else(|| InitSchedulerError::MisEnvVar(String::from(key_name)))?;
+    /// Get delicate's security key from the pem file configured as `key_name`.
+    fn get_app_rsa_key(key_path: Option<&Path>, key_name: &str) -> Result<T, InitSchedulerError> {
+        let key_path =
+            key_path.ok_or_else(|| InitSchedulerError::MisEnvVar(String::from(key_name)))?;
 
         let key_pem = fs::read(key_path)?;
         let key: T = pem::parse(key_pem)?.try_into()?;
@@ -31,11 +32,8 @@ pub struct CookieConf {
     pub secure: bool,
 }
 
-impl Default for CookieConf {
-    fn default() -> Self {
-        let domain = env::var("SCHEDULER_COOKIE_DOMAIN")
-            .expect("Without `SCHEDULER_COOKIE_DOMAIN` set in .env");
-
+impl CookieConf {
+    pub fn new(domain: String) -> Self {
         let http_only = true;
         let secure = false;
         Self {
@@ -53,29 +51,29 @@ pub struct SchedulerSecurityConf {
     pub rsa_private_key: Option<SecurityeKey<RSAPrivateKey>>,
 }
 
-impl Default for SchedulerSecurityConf {
-    fn default() -> Self {
-        let cookie_conf = CookieConf::default();
-        let security_level = SecurityLevel::get_app_security_level();
-        let rsa_private_key =
-            SecurityeKey::<RSAPrivateKey>::get_app_rsa_key("DELICATE_SECURITY_PRIVATE_KEY");
-
-        if matches!(security_level, SecurityLevel::Normal if rsa_private_key.is_err()) {
-            error!(
-                "{}",
-                rsa_private_key
-                    .err()
-                    .map(|e| "Initialization failed because: ".to_owned() + (e.to_string().as_ref()))
-                    .unwrap_or_default()
-            );
-            unreachable!("When the security level is Normal, the initialization `delicate-scheduler` must contain the secret key (DELICATE_SECURITY_PRIVATE_KEY)");
-        }
-
-        Self {
+impl SchedulerSecurityConf {
+    /// The private key is required when the security level is Normal.
+    pub fn new(
+        cookie_domain: String,
+        security_level: SecurityLevel,
+        private_key_path: Option<&Path>,
+    ) -> Result<Self, InitSchedulerError> {
+        let cookie_conf = CookieConf::new(cookie_domain);
+        let rsa_private_key = SecurityeKey::<RSAPrivateKey>::get_app_rsa_key(
+            private_key_path,
+            "DELICATE_SECURITY_PRIVATE_KEY",
+        );
+
+        let rsa_private_key = match security_level {
+            SecurityLevel::Normal => Some(SecurityeKey(rsa_private_key?)),
+            SecurityLevel::ZeroRestriction => rsa_private_key.map(SecurityeKey).ok(),
+        };
+
+        Ok(Self {
             cookie_conf,
-            security_level: SecurityLevel::get_app_security_level(),
-            rsa_private_key: rsa_private_key.map(SecurityeKey).ok(),
-        }
+            security_level,
+            rsa_private_key,
+        })
     }
 }
 
@@ -126,30 +124,29 @@ impl Default for BindScheduler {
     }
 }
 
-impl Default for ExecutorSecurityConf {
-    fn default() -> Self {
-        let security_level = SecurityLevel::get_app_security_level();
-        let rsa_public_key =
-            SecurityeKey::<RSAPublicKey>::get_app_rsa_key("DELICATE_SECURITY_PUBLIC_KEY");
-
-        if matches!(security_level, SecurityLevel::Normal if rsa_public_key.is_err()) {
-            error!(
-                "{}",
-                rsa_public_key
-                    .err()
-                    .map(|e| "Initialization failed because: ".to_owned() + (e.to_string().as_ref()))
-                    .unwrap_or_default()
-            );
-            unreachable!("When the security level is Normal, the initialization `delicate-executor` must contain the secret key (DELICATE_SECURITY_PUBLIC_KEY)");
-        }
+impl ExecutorSecurityConf {
+    /// The public key is required when the security level is Normal.
+    pub fn new(
+        security_level: SecurityLevel,
+        public_key_path: Option<&Path>,
+    ) -> Result<Self, InitSchedulerError> {
+        let rsa_public_key = SecurityeKey::<RSAPublicKey>::get_app_rsa_key(
+            public_key_path,
+            "DELICATE_SECURITY_PUBLIC_KEY",
+        );
+
+        let rsa_public_key = match security_level {
+            SecurityLevel::Normal => Some(SecurityeKey(rsa_public_key?)),
+            SecurityLevel::ZeroRestriction => rsa_public_key.map(SecurityeKey).ok(),
+        };
 
         let bind_scheduler = BindScheduler::default();
 
-        Self {
-            security_level: SecurityLevel::get_app_security_level(),
-            rsa_public_key: rsa_public_key.map(SecurityeKey).ok(),
+        Ok(Self {
+            security_level,
+            rsa_public_key,
             bind_scheduler,
-        }
+        })
     }
 }
 
@@ -169,18 +166,6 @@ pub enum SecurityLevel {
     Normal,
 }
 
-impl SecurityLevel {
-    /// Get delicate-scheduler's security level from env.
-    pub fn get_app_security_level() -> Self {
-        env::var_os("DELICATE_SECURITY_LEVEL").map_or(SecurityLevel::default(), |e| {
-            e.to_str()
-                .and_then(|s| u16::from_str(s).ok())
-                .and_then(|e| e.try_into().ok())
-                .expect("Environment Variables `DELICATE_SECURITY_LEVEL` missed.")
-        })
-    }
-}
-
 impl Default for SecurityLevel {
     fn default() -> Self {
         SecurityLevel::ZeroRestriction
@@ -1,4 +1,3 @@
-use crate::error::InitSchedulerError;
 use crate::prelude::*;
 
 #[derive(Debug, Display, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
@@ -152,98 +151,6 @@ impl EncryptedBindResponse {
     }
 }
 
-pub trait SecurityRsaKey<T: TryFrom<pem::Pem>>
-where
-    InitSchedulerError: From<<T as std::convert::TryFrom<pem::Pem>>::Error>,
-{
-    /// Get delicate-executor's security key from env.
-    fn get_app_rsa_key(key_name: &str) -> Result<T, InitSchedulerError> {
-        let key_path = env::var_os(key_name)
-            .ok_or_else(|| InitSchedulerError::MisEnvVar(String::from(key_name)))?;
-
-        let key_pem = fs::read(key_path)?;
-        let key: T = pem::parse(key_pem)?.try_into()?;
-        Ok(key)
-    }
-}
-
-impl SecurityRsaKey<RSAPrivateKey> for SecurityeKey<RSAPrivateKey> {}
-
-impl SecurityRsaKey<RSAPublicKey> for SecurityeKey<RSAPublicKey> {}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SecurityeKey<T>(pub T);
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SchedulerSecurityConf {
-    pub security_level: SecurityLevel,
-    pub rsa_private_key: Option<SecurityeKey<RSAPrivateKey>>,
-}
-
-impl Default for SchedulerSecurityConf {
-    fn default() -> Self {
-        let security_level = SecurityLevel::get_app_security_level();
-        let rsa_private_key =
-            SecurityeKey::<RSAPrivateKey>::get_app_rsa_key("DELICATE_SECURITY_PRIVATE_KEY");
-
-        if matches!(security_level, SecurityLevel::Normal if rsa_private_key.is_err()) {
-            error!(
-                "{}",
-                rsa_private_key
-                    .err()
-                    .map(|e| "Initialization failed because: ".to_owned() + (e.to_string().as_ref()))
-                    .unwrap_or_default()
-            );
-            unreachable!("When the security level is Normal, the initialization `delicate-scheduler` must contain the secret key (DELICATE_SECURITY_PRIVATE_KEY)");
-        }
-
-        Self {
-            security_level: SecurityLevel::get_app_security_level(),
-            rsa_private_key: rsa_private_key.map(SecurityeKey).ok(),
-        }
-    }
-}
-
-/// Delicate's security level.
-/// The distinction in security level is reflected at `bind_executor-api`.
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
-pub enum SecurityLevel {
-    /// There are no strict restrictions.
-    ZeroRestriction,
-    /// Normal security validation, encrypted validation is required at `bind_executor-api`.
-    Normal,
-}
-
-impl SecurityLevel {
-    /// Get delicate-scheduler's security level from env.
-    pub fn get_app_security_level() -> Self {
-        env::var_os("DELICATE_SECURITY_LEVEL").map_or(SecurityLevel::default(), |e| {
-            e.to_str()
-                .and_then(|s| u16::from_str(s).ok())
-                .and_then(|e| e.try_into().ok())
-                .expect("Environment Variables `DELICATE_SECURITY_LEVEL` missed.")
-        })
-    }
-}
-
-impl Default for SecurityLevel {
-    fn default() -> Self {
-        SecurityLevel::ZeroRestriction
-    }
-}
-
-impl TryFrom<u16> for SecurityLevel {
-    type Error = InitSchedulerError;
-
-    fn try_from(value: u16) -> Result<SecurityLevel, InitSchedulerError> {
-        match value {
-            0 => Ok(SecurityLevel::ZeroRestriction),
-            1 => Ok(SecurityLevel::Normal),
-            _ => Err(InitSchedulerError::MisEnvVar(String::from("SecurityLevel"))),
-        }
-    }
-}
-
 #[test]
 fn test_rsa_crypt() {
     use crate::prelude::*;
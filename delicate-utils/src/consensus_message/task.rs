@@ -1,13 +1,26 @@
+use crate::consensus_message::calendar::{run_unless_excluded, TaskCalendar};
+use crate::consensus_message::http_task::{run_http_request, HttpRequest, TaskKind};
+use crate::consensus_message::output_limit::output_limit;
+use crate::consensus_message::task_routine::{run_process, ProcessOptions};
 use crate::prelude::*;
+use chrono::{DateTime, FixedOffset, Local, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use cron_clock::Schedule;
+use std::future::Future;
+use std::sync::Arc;
+
 #[derive(Queryable, Clone, Debug, Default, Serialize, Deserialize, Display)]
 #[display(
-    fmt = "task-id:{} command:{} frequency:{} cron_expression:{} timeout:{} maximum_parallel_runnable_num:{}",
+    fmt = "task-id:{} command:{} frequency:{} cron_expression:{} timeout:{} maximum_parallel_runnable_num:{} retry_times:{} retry_interval:{} kind:{}",
     id,
     command,
     frequency,
     cron_expression,
     timeout,
-    maximum_parallel_runnable_num
+    maximum_parallel_runnable_num,
+    retry_times,
+    retry_interval,
+    kind
 )]
 
 pub struct TaskPackage {
@@ -23,6 +36,25 @@ pub struct TaskPackage {
     pub timeout: i16,
     /// Maximum parallel runnable num (optional).
     pub maximum_parallel_runnable_num: i16,
+    /// The number of times a failed run is retried.
+    pub retry_times: i16,
+    /// Seconds to wait before each retry.
+    pub retry_interval: i16,
+    /// Shell command or http request, see `TaskKind`.
+    #[serde(default = "default_task_kind")]
+    pub kind: i16,
+    /// How the child process of a shell task is spawned, it is left out of the display
+    /// Since the env and the stdin may carry secrets.
+    #[serde(default)]
+    pub process_options: ProcessOptions,
+    /// The runs falling in the excluded times of the calendar are skipped.
+    #[serde(default)]
+    pub calendar: TaskCalendar,
+}
+
+// Packages persisted before http tasks existed are shell tasks.
+fn default_task_kind() -> i16 {
+    TaskKind::Shell as i16
 }
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
@@ -44,11 +76,42 @@ pub struct FrequencyModel<'a> {
     pub cron_expression: &'a str,
 }
 
-#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct FrequencyObject {
     pub mode: i8,
     pub extend: FrequencyExtend,
+    /// 1: Utc, 2: Local, 3: the IANA time zone in `time_zone_name`, such as `Europe/Berlin`.
     pub time_zone: u8,
+    #[serde(default)]
+    pub time_zone_name: String,
+}
+
+/// The time zone the cron-expression of a task is evaluated in.
+#[derive(Copy, Clone, Debug)]
+pub enum ScheduleTimeZone {
+    Utc,
+    Local,
+    Named(Tz),
+}
+
+impl FrequencyObject {
+    pub fn schedule_time_zone(&self) -> Result<ScheduleTimeZone, CommonError> {
+        match self.time_zone {
+            1 => Ok(ScheduleTimeZone::Utc),
+            2 => Ok(ScheduleTimeZone::Local),
+            3 => Tz::from_str(&self.time_zone_name)
+                .map(ScheduleTimeZone::Named)
+                .map_err(|e| {
+                    CommonError::DisPass(format!(
+                        "Ineffective time zone `{}`: {}",
+                        self.time_zone_name, e
+                    ))
+                }),
+            _ => Err(CommonError::DisPass(String::from(
+                "Ineffective time-zone mode.",
+            ))),
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
@@ -64,6 +127,113 @@ pub struct SignedTaskPackage {
 }
 
 impl TaskPackage {
+    /// Build a one-shot task that re-runs the package after `retry_interval` seconds.
+    ///
+    /// The `retry_task_id` only identifies the retry inside `DelayTimer`,
+    /// The executor maps it back to the original task-id when reporting events.
+    pub fn build_retry_task(&self, retry_task_id: u64) -> Result<Task, CommonError> {
+        // A retry belongs to a run the calendar already let through.
+        self.build_delayed_task(
+            retry_task_id,
+            self.retry_interval.max(1) as u64,
+            TaskCalendar::default(),
+        )
+    }
+
+    /// Build a one-shot task that runs the package once after `delay_seconds` seconds,
+    /// It backs the retries and the runs triggered by upstream tasks.
+    pub fn build_one_shot_task(
+        &self,
+        one_shot_task_id: u64,
+        delay_seconds: u64,
+    ) -> Result<Task, CommonError> {
+        self.build_delayed_task(one_shot_task_id, delay_seconds, self.calendar.clone())
+    }
+
+    fn build_delayed_task(
+        &self,
+        one_shot_task_id: u64,
+        delay_seconds: u64,
+        calendar: TaskCalendar,
+    ) -> Result<Task, CommonError> {
+        let mut task_builder = TaskBuilder::default();
+        task_builder
+            .set_task_id(one_shot_task_id)
+            .set_frequency_once_by_seconds(delay_seconds)
+            .set_maximum_running_time(self.timeout as u64)
+            .set_maximum_parallel_runnable_num(self.maximum_parallel_runnable_num as u64);
+
+        spawn_routine(
+            &mut task_builder,
+            self.kind,
+            self.command.clone(),
+            self.process_options.clone(),
+            calendar,
+            one_shot_task_id,
+        )
+    }
+
+    /// Build a task following the schedule of the package that runs `routine` instead of the command,
+    /// The scheduler decides the runs of the tasks it triggers itself with it.
+    pub fn build_schedule_task<F, U>(&self, routine: F) -> Result<Task, CommonError>
+    where
+        F: Fn() -> U + Send + Sync + 'static,
+        U: Future<Output = ()> + Send + 'static,
+    {
+        let mut task_builder = TaskBuilder::default();
+        set_schedule(
+            &mut task_builder,
+            self.id,
+            &self.frequency,
+            &self.cron_expression,
+        )?;
+
+        Ok(task_builder.spawn_async_routine(routine)?)
+    }
+
+    /// The number of times the schedule fired after `since` and before now, evaluated in the time zone of the task.
+    /// At most `limit`. Only repeated tasks are counted, the others fire a known number of times.
+    pub fn count_fires_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<usize, CommonError> {
+        let metadata: FrequencyObject = json_from_slice(self.frequency.as_bytes())?;
+        if metadata.mode != FrequencyModelType::Repeat as i8 || limit == 0 {
+            return Ok(0);
+        }
+
+        let schedule = parse_cron_expression(&self.cron_expression)?;
+        let now = Utc::now();
+
+        let count = match metadata.schedule_time_zone()? {
+            ScheduleTimeZone::Utc => count_fires(&schedule, &since, now, limit),
+            ScheduleTimeZone::Local => {
+                count_fires(&schedule, &since.with_timezone(&Local), now, limit)
+            }
+            ScheduleTimeZone::Named(time_zone) => {
+                count_fires(&schedule, &since.with_timezone(&time_zone), now, limit)
+            }
+        };
+
+        Ok(count)
+    }
+
+    /// The utc offset (in seconds) the schedule follows until its next fire,
+    /// `None` unless the task is in a named time zone.
+    ///
+    /// The offset of a named time zone changes across daylight saving transitions,
+    /// The task should be built again once it differs from the one it was built with.
+    pub fn schedule_offset(&self) -> Result<Option<i32>, CommonError> {
+        let metadata: FrequencyObject = json_from_slice(self.frequency.as_bytes())?;
+        match metadata.schedule_time_zone()? {
+            ScheduleTimeZone::Named(time_zone) => Ok(Some(
+                next_fire_offset(time_zone, &self.cron_expression)?.local_minus_utc(),
+            )),
+            _ => Ok(None),
+        }
+    }
+
     pub fn sign(self, token: Option<&str>) -> Result<SignedTaskPackage, crate::error::CommonError> {
         let signature = make_signature(&self, token)?;
 
@@ -95,6 +265,72 @@ impl SignedTaskPackage {
     }
 }
 
+/// Run a task once on behalf of an upstream task,
+/// The `run_id` is shared by every run triggered in the same dependency chain.
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Display)]
+#[display(fmt = "task-package:{} run-id:{} time:{}", task_package, run_id, time)]
+
+pub struct TaskTrigger {
+    pub task_package: TaskPackage,
+    pub run_id: i64,
+    pub time: u64,
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Display)]
+#[display(fmt = "task-trigger:{} ", task_trigger)]
+
+pub struct SignedTaskTrigger {
+    pub task_trigger: TaskTrigger,
+    #[serde(with = "hex")]
+    pub signature: Vec<u8>,
+}
+
+impl TaskTrigger {
+    pub fn set_task_package(mut self, task_package: TaskPackage) -> Self {
+        self.task_package = task_package;
+        self
+    }
+
+    pub fn set_run_id(mut self, run_id: i64) -> Self {
+        self.run_id = run_id;
+        self
+    }
+
+    pub fn set_time(mut self, time: u64) -> Self {
+        self.time = time;
+        self
+    }
+
+    pub fn sign(self, token: Option<&str>) -> Result<SignedTaskTrigger, crate::error::CommonError> {
+        let signature = make_signature(&self, token)?;
+        Ok(SignedTaskTrigger {
+            task_trigger: self,
+            signature,
+        })
+    }
+}
+
+impl SignedTaskTrigger {
+    pub fn verify(&self, token: Option<&str>) -> Result<(), crate::error::CommonError> {
+        let SignedTaskTrigger {
+            ref task_trigger,
+            ref signature,
+        } = self;
+
+        verify_signature_by_raw_data(task_trigger, token, signature)
+    }
+
+    pub fn get_task_trigger_after_verify(
+        self,
+        token: Option<&str>,
+    ) -> Result<TaskTrigger, crate::error::CommonError> {
+        self.verify(token)?;
+        let SignedTaskTrigger { task_trigger, .. } = self;
+
+        Ok(task_trigger)
+    }
+}
+
 #[derive(Copy, Clone, Default, Debug, Serialize, Deserialize, Display)]
 #[display(fmt = "task-id:{} time:{}", task_id, time)]
 
@@ -162,54 +398,209 @@ impl TryFrom<TaskPackage> for Task {
             cron_expression,
             timeout,
             maximum_parallel_runnable_num,
+            kind,
+            process_options,
+            calendar,
             ..
         } = task_package;
 
-        let metadata: FrequencyObject = json_from_slice(frequency.as_bytes())?;
-        let cron_expression = &cron_expression;
+        let mut task_builder = TaskBuilder::default();
+        set_schedule(&mut task_builder, id, &frequency, &cron_expression)?;
 
-        let time_zone: ScheduleIteratorTimeZone = match metadata.time_zone {
-            1 => ScheduleIteratorTimeZone::Utc,
+        task_builder
+            .set_maximum_running_time(timeout as u64)
+            .set_maximum_parallel_runnable_num(maximum_parallel_runnable_num as u64);
 
-            2 => ScheduleIteratorTimeZone::Local,
+        spawn_routine(
+            &mut task_builder,
+            kind,
+            command,
+            process_options,
+            calendar,
+            id as u64,
+        )
+    }
+}
 
-            _ => {
-                return Err(CommonError::DisPass(String::from(
-                    "Ineffective time-zone mode.",
-                )))
-            }
-        };
+// The task-id, the frequency and the time-zone of a task following its cron-expression.
+fn set_schedule<'a>(
+    task_builder: &mut TaskBuilder<'a>,
+    id: i64,
+    frequency: &str,
+    cron_expression: &'a str,
+) -> Result<(), CommonError> {
+    let metadata: FrequencyObject = json_from_slice(frequency.as_bytes())?;
 
-        let mut task_builder = TaskBuilder::default();
+    let time_zone: ScheduleIteratorTimeZone = match metadata.schedule_time_zone()? {
+        ScheduleTimeZone::Utc => ScheduleIteratorTimeZone::Utc,
 
-        task_builder.set_task_id(id as u64);
+        ScheduleTimeZone::Local => ScheduleIteratorTimeZone::Local,
 
-        match metadata.mode {
-            1 => {
-                task_builder.set_frequency_once_by_cron_str(cron_expression);
-            }
-            2 => {
-                task_builder
-                    .set_frequency_count_down_by_cron_str(cron_expression, metadata.extend.count);
-            }
-            3 => {
-                task_builder.set_frequency_repeated_by_cron_str(cron_expression);
-            }
-            _ => {
-                return Err(CommonError::DisPass(String::from(
-                    "Ineffective frequency mode.",
-                )));
-            }
+        ScheduleTimeZone::Named(time_zone) => {
+            ScheduleIteratorTimeZone::FixedOffset(next_fire_offset(time_zone, cron_expression)?)
         }
+    };
 
-        let task = task_builder
-            .set_schedule_iterator_time_zone(time_zone)
-            .set_maximum_running_time(timeout as u64)
-            .set_maximum_parallel_runnable_num(maximum_parallel_runnable_num as u64)
-            .spawn_async_routine({
-                move || tokio_unblock_process_task_fn(command.clone(), id as u64)
-            })?;
+    task_builder.set_task_id(id as u64);
 
-        Ok(task)
+    match metadata.mode {
+        1 => {
+            task_builder.set_frequency_once_by_cron_str(cron_expression);
+        }
+        2 => {
+            task_builder
+                .set_frequency_count_down_by_cron_str(cron_expression, metadata.extend.count);
+        }
+        3 => {
+            task_builder.set_frequency_repeated_by_cron_str(cron_expression);
+        }
+        _ => {
+            return Err(CommonError::DisPass(String::from(
+                "Ineffective frequency mode.",
+            )));
+        }
     }
+
+    task_builder.set_schedule_iterator_time_zone(time_zone);
+    Ok(())
+}
+
+/// Check that a task can be scheduled with the frequency and the cron-expression,
+/// Before they are saved rather than when an executor fails to add the task.
+pub fn check_frequency(frequency: &str, cron_expression: &str) -> Result<(), CommonError> {
+    parse_frequency(frequency, cron_expression).map(|_| ())
+}
+
+/// The next `count` fire times of the schedule, in the time zone of the frequency.
+///
+/// Fewer are returned when the frequency fires fewer times, once or counting down.
+pub fn preview_fire_times(
+    frequency: &str,
+    cron_expression: &str,
+    count: usize,
+) -> Result<Vec<DateTime<FixedOffset>>, CommonError> {
+    let (metadata, time_zone, schedule) = parse_frequency(frequency, cron_expression)?;
+
+    let count = match metadata.mode {
+        1 => count.min(1),
+        2 => count.min(metadata.extend.count as usize),
+        _ => count,
+    };
+
+    let fire_times = match time_zone {
+        ScheduleTimeZone::Utc => upcoming_fires(&schedule, Utc, count),
+        ScheduleTimeZone::Local => upcoming_fires(&schedule, Local, count),
+        ScheduleTimeZone::Named(time_zone) => upcoming_fires(&schedule, time_zone, count),
+    };
+    Ok(fire_times)
+}
+
+fn parse_frequency(
+    frequency: &str,
+    cron_expression: &str,
+) -> Result<(FrequencyObject, ScheduleTimeZone, Schedule), CommonError> {
+    let metadata: FrequencyObject = json_from_slice(frequency.as_bytes())?;
+    if !(1..=3).contains(&metadata.mode) {
+        return Err(CommonError::DisPass(String::from(
+            "Ineffective frequency mode.",
+        )));
+    }
+
+    let time_zone = metadata.schedule_time_zone()?;
+    let schedule = parse_cron_expression(cron_expression)?;
+    Ok((metadata, time_zone, schedule))
+}
+
+fn parse_cron_expression(cron_expression: &str) -> Result<Schedule, CommonError> {
+    Schedule::from_str(cron_expression)
+        .map_err(|e| CommonError::DisPass(format!("Ineffective cron-expression: {}", e)))
+}
+
+// `DelayTimer` evaluates the schedules in a fixed utc offset,
+// So a named time zone is followed with the offset it has at the next fire.
+// The executor builds the task again with the new offset as each run starts,
+// So every fire is computed with the offset of the zone at that fire.
+fn next_fire_offset(time_zone: Tz, cron_expression: &str) -> Result<FixedOffset, CommonError> {
+    let schedule = parse_cron_expression(cron_expression)?;
+    let now = Utc::now().with_timezone(&time_zone);
+    let next_fire = schedule.after(&now).next().unwrap_or(now);
+
+    Ok(next_fire.offset().fix())
+}
+
+// The fires of the schedule after `since` and before `now`, at most `limit`.
+fn count_fires<Z: TimeZone>(
+    schedule: &Schedule,
+    since: &DateTime<Z>,
+    now: DateTime<Utc>,
+    limit: usize,
+) -> usize {
+    schedule
+        .after(since)
+        .take_while(|fire_time| fire_time.naive_utc() < now.naive_utc())
+        .take(limit)
+        .count()
+}
+
+// The fire times keep the offset they have in the time zone, daylight saving included.
+fn upcoming_fires<Z: TimeZone>(
+    schedule: &Schedule,
+    time_zone: Z,
+    count: usize,
+) -> Vec<DateTime<FixedOffset>> {
+    schedule
+        .upcoming(time_zone)
+        .take(count)
+        .map(|fire_time| fire_time.with_timezone(&fire_time.offset().fix()))
+        .collect()
+}
+
+// Shell tasks run the command in a child process, http tasks send the request in the command.
+// Both leave their output aside instead of `DelayTimer`, so that it can be followed while they run.
+// The runs the calendar excludes are skipped before anything is run.
+fn spawn_routine(
+    task_builder: &mut TaskBuilder<'_>,
+    kind: i16,
+    command: String,
+    process_options: ProcessOptions,
+    calendar: TaskCalendar,
+    timer_task_id: u64,
+) -> Result<Task, CommonError> {
+    let calendar = Arc::new(calendar);
+    let task = match TaskKind::from(kind) {
+        TaskKind::Shell => {
+            process_options.check()?;
+            let command = Arc::new(command);
+            let process_options = Arc::new(process_options);
+            task_builder.spawn_async_routine(move || {
+                run_unless_excluded(
+                    calendar.clone(),
+                    timer_task_id,
+                    run_process(command.clone(), process_options.clone(), timer_task_id),
+                )
+            })?
+        }
+        TaskKind::Http => {
+            let http_request: Arc<HttpRequest> = Arc::new(command.parse()?);
+            let output_limit = output_limit(process_options.resource_limits.output_limit);
+            let wall_clock = process_options.resource_limits.wall_clock();
+            task_builder.spawn_async_routine(move || {
+                run_unless_excluded(
+                    calendar.clone(),
+                    timer_task_id,
+                    run_http_request(
+                        http_request.clone(),
+                        output_limit,
+                        wall_clock,
+                        timer_task_id,
+                    ),
+                )
+            })?
+        }
+        TaskKind::Unknown => {
+            return Err(CommonError::DisPass(String::from("Ineffective task kind.")));
+        }
+    };
+
+    Ok(task)
 }
@@ -1,3 +1,4 @@
+use crate::consensus_message::output_limit::{output_limit, truncate_output};
 use crate::prelude::*;
 
 #[derive(Copy, Clone, Default, Debug, Serialize, Deserialize, Display)]
@@ -69,6 +70,172 @@ impl SignedCancelTaskRecord {
     }
 }
 
+/// Ask the executor for the output a running record has produced since `offset`.
+#[derive(Copy, Clone, Default, Debug, Serialize, Deserialize, Display)]
+#[display(fmt = "record-id:{} offset:{} time:{}", record_id, offset, time)]
+
+pub struct TailTaskRecord {
+    pub record_id: i64,
+    pub offset: u64,
+    pub time: u64,
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Display)]
+#[display(fmt = "tail-task-record:{} ", tail_task_record)]
+
+pub struct SignedTailTaskRecord {
+    pub tail_task_record: TailTaskRecord,
+    #[serde(with = "hex")]
+    pub signature: Vec<u8>,
+}
+
+impl TailTaskRecord {
+    pub fn set_record_id(mut self, record_id: i64) -> Self {
+        self.record_id = record_id;
+        self
+    }
+
+    pub fn set_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn set_time(mut self, time: u64) -> Self {
+        self.time = time;
+        self
+    }
+
+    pub fn sign(
+        self,
+        token: Option<&str>,
+    ) -> Result<SignedTailTaskRecord, crate::error::CommonError> {
+        let signature = make_signature(&self, token)?;
+
+        Ok(SignedTailTaskRecord {
+            tail_task_record: self,
+            signature,
+        })
+    }
+}
+
+impl SignedTailTaskRecord {
+    pub fn verify(&self, token: Option<&str>) -> Result<(), crate::error::CommonError> {
+        let SignedTailTaskRecord {
+            ref tail_task_record,
+            ref signature,
+        } = self;
+
+        verify_signature_by_raw_data(tail_task_record, token, signature)
+    }
+
+    pub fn get_tail_task_record_after_verify(
+        self,
+        token: Option<&str>,
+    ) -> Result<TailTaskRecord, crate::error::CommonError> {
+        self.verify(token)?;
+        let SignedTailTaskRecord {
+            tail_task_record, ..
+        } = self;
+
+        Ok(tail_task_record)
+    }
+}
+
+/// Ask the executor which of the records are still running.
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Display)]
+#[display(fmt = "record-ids:{:?} time:{}", record_ids, time)]
+
+pub struct TaskRecordProbe {
+    pub record_ids: Vec<i64>,
+    pub time: u64,
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Display)]
+#[display(fmt = "task-record-probe:{} ", task_record_probe)]
+
+pub struct SignedTaskRecordProbe {
+    pub task_record_probe: TaskRecordProbe,
+    #[serde(with = "hex")]
+    pub signature: Vec<u8>,
+}
+
+impl TaskRecordProbe {
+    pub fn set_record_ids(mut self, record_ids: Vec<i64>) -> Self {
+        self.record_ids = record_ids;
+        self
+    }
+
+    pub fn set_time(mut self, time: u64) -> Self {
+        self.time = time;
+        self
+    }
+
+    pub fn sign(
+        self,
+        token: Option<&str>,
+    ) -> Result<SignedTaskRecordProbe, crate::error::CommonError> {
+        let signature = make_signature(&self, token)?;
+
+        Ok(SignedTaskRecordProbe {
+            task_record_probe: self,
+            signature,
+        })
+    }
+}
+
+impl SignedTaskRecordProbe {
+    pub fn verify(&self, token: Option<&str>) -> Result<(), crate::error::CommonError> {
+        let SignedTaskRecordProbe {
+            ref task_record_probe,
+            ref signature,
+        } = self;
+
+        verify_signature_by_raw_data(task_record_probe, token, signature)
+    }
+
+    pub fn get_task_record_probe_after_verify(
+        self,
+        token: Option<&str>,
+    ) -> Result<TaskRecordProbe, crate::error::CommonError> {
+        self.verify(token)?;
+        let SignedTaskRecordProbe {
+            task_record_probe, ..
+        } = self;
+
+        Ok(task_record_probe)
+    }
+}
+
+/// The output of a record since the offset that was asked for.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRecordOutput {
+    pub chunks: Vec<OutputChunk>,
+    /// The offset to ask for next time.
+    pub next_offset: u64,
+    /// No more output will come.
+    pub finished: bool,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct OutputChunk {
+    pub offset: u64,
+    pub stream: OutputStream,
+    pub content: String,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+impl Default for OutputStream {
+    fn default() -> Self {
+        OutputStream::Stdout
+    }
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutorEventCollection {
     pub events: Vec<ExecutorEvent>,
@@ -135,6 +302,12 @@ pub struct ExecutorEvent {
     pub executor_processor_name: String,
     pub executor_processor_host: String,
     pub output: Option<FinishOutput>,
+    /// The attempt number of the run, the first run is 1 and each retry adds 1.
+    #[serde(default)]
+    pub attempt: i16,
+    /// The run shared with the upstream tasks, 0 when the run starts a new one.
+    #[serde(default)]
+    pub run_id: i64,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -142,6 +315,8 @@ pub enum EventType {
     TaskPerform = 1,
     TaskFinish = 2,
     TaskTimeout = 3,
+    ResourceExceeded = 4,
+    TaskSkipped = 5,
     Unknown = 81,
 }
 
@@ -151,6 +326,8 @@ impl From<i16> for EventType {
             1 => EventType::TaskPerform,
             2 => EventType::TaskFinish,
             3 => EventType::TaskTimeout,
+            4 => EventType::ResourceExceeded,
+            5 => EventType::TaskSkipped,
             _ => EventType::Unknown,
         }
     }
@@ -175,14 +352,18 @@ impl From<StdOutput> for ChildOutput {
             stderr,
         } = value;
 
+        let output_limit = output_limit(0);
         let child_status = status.code().unwrap_or(81);
-        let child_stdout = String::from_utf8_lossy(&stdout).into_owned();
-        let child_stderr = String::from_utf8_lossy(&stderr).into_owned();
+        let child_stdout =
+            truncate_output(String::from_utf8_lossy(&stdout).into_owned(), output_limit);
+        let child_stderr =
+            truncate_output(String::from_utf8_lossy(&stderr).into_owned(), output_limit);
 
         ChildOutput {
             child_status,
             child_stdout,
             child_stderr,
+            ..Default::default()
         }
     }
 }
@@ -191,6 +372,24 @@ impl From<StdOutput> for ChildOutput {
 pub enum FinishOutput {
     ProcessOutput(ChildOutput),
     ExceptionOutput(String),
+    HttpOutput(HttpResponseOutput),
+    ResourceExceededOutput(ResourceExceededOutput),
+    /// The run was skipped since the calendar of the task excludes it, with the reason.
+    SkippedOutput(String),
+}
+
+impl FinishOutput {
+    /// A non-zero exit of the child, an unexpected http status, an exceeded resource limit
+    /// Or an exception is regarded as a failed run.
+    pub fn is_failure(&self) -> bool {
+        match self {
+            FinishOutput::ProcessOutput(child_output) => child_output.child_status != 0,
+            FinishOutput::ExceptionOutput(_) => true,
+            FinishOutput::HttpOutput(http_output) => !http_output.expected,
+            FinishOutput::ResourceExceededOutput(_) => true,
+            FinishOutput::SkippedOutput(_) => false,
+        }
+    }
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -198,4 +397,28 @@ pub struct ChildOutput {
     pub child_status: i32,
     pub child_stdout: String,
     pub child_stderr: String,
+    /// The file on the executor holding the whole stdout, when it was truncated and spooled.
+    #[serde(default)]
+    pub stdout_spool: String,
+    /// The file on the executor holding the whole stderr, when it was truncated and spooled.
+    #[serde(default)]
+    pub stderr_spool: String,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct HttpResponseOutput {
+    pub status: u16,
+    pub body: String,
+    /// The file on the executor holding the whole body, when it was truncated and spooled.
+    #[serde(default)]
+    pub body_spool: String,
+    /// Whether the status is one of the status codes expected by the task.
+    pub expected: bool,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceExceededOutput {
+    /// The limit the child was stopped by, `memory`, `pids` or `wall-clock`.
+    pub resource: String,
+    pub child_output: ChildOutput,
 }
@@ -0,0 +1,348 @@
+use crate::consensus_message::live_output::{start_live_output, LiveOutput};
+use crate::consensus_message::output_limit::{output_limit, CappedOutput};
+use crate::consensus_message::resource_limit::ResourceLimits;
+use crate::consensus_message::task_log::{
+    ChildOutput, FinishOutput, OutputStream, ResourceExceededOutput,
+};
+use crate::prelude::*;
+
+use diesel::backend::Backend;
+use diesel::Queryable;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::process::Command;
+
+lazy_static! {
+    // `DelayTimer` only reports the output of the processes it spawns itself,
+    // So the routines spawned here leave their output until the executor reports the end of the run.
+    // timer-task-id -> the outputs of the runs that have ended, in the order they ended.
+    static ref ROUTINE_OUTPUTS: Mutex<HashMap<u64, VecDeque<FinishOutput>>> =
+        Mutex::new(HashMap::new());
+}
+
+pub(crate) fn leave_routine_output(timer_task_id: u64, output: FinishOutput) {
+    if let Ok(mut outputs) = ROUTINE_OUTPUTS.lock() {
+        outputs.entry(timer_task_id).or_default().push_back(output);
+    }
+}
+
+/// Take the output of the earliest run of the task that has ended,
+/// `None` when `DelayTimer` reports the output itself.
+pub fn take_routine_output(timer_task_id: u64) -> Option<FinishOutput> {
+    let mut outputs = ROUTINE_OUTPUTS.lock().ok()?;
+    let task_outputs = outputs.get_mut(&timer_task_id)?;
+    let output = task_outputs.pop_front();
+
+    if task_outputs.is_empty() {
+        outputs.remove(&timer_task_id);
+    }
+    output
+}
+
+/// Drop the outputs that were never reported, e.g. when the task is removed.
+pub fn forget_routine_outputs(timer_task_id: u64) {
+    if let Ok(mut outputs) = ROUTINE_OUTPUTS.lock() {
+        outputs.remove(&timer_task_id);
+    }
+}
+
+/// How the child process of a shell task is spawned and the resources it may use.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProcessOptions {
+    /// Environment variables, a json object of strings (optional).
+    pub env: String,
+    /// The working directory (optional).
+    pub working_directory: String,
+    /// The unix user to run as, a name or an uid (optional).
+    pub run_as_user: String,
+    /// The unix group to run as, a name or a gid (optional),
+    /// The primary group of `run_as_user` is used when it is empty.
+    pub run_as_group: String,
+    /// Written to the stdin of the child (optional).
+    pub stdin: String,
+    /// The resources the child may use.
+    #[serde(default)]
+    pub resource_limits: ResourceLimits,
+}
+
+type ProcessOptionsRow = (String, String, String, String, String, ResourceLimits);
+
+// Implemented by hand, the one derived by diesel 1.4 is nested in a named constant.
+impl<DB, ST> Queryable<ST, DB> for ProcessOptions
+where
+    DB: Backend,
+    ProcessOptionsRow: Queryable<ST, DB>,
+{
+    type Row = <ProcessOptionsRow as Queryable<ST, DB>>::Row;
+
+    fn build(row: Self::Row) -> Self {
+        let (env, working_directory, run_as_user, run_as_group, stdin, resource_limits) =
+            <ProcessOptionsRow as Queryable<ST, DB>>::build(row);
+
+        ProcessOptions {
+            env,
+            working_directory,
+            run_as_user,
+            run_as_group,
+            stdin,
+            resource_limits,
+        }
+    }
+}
+
+impl ProcessOptions {
+    /// Whether no option is set.
+    pub fn is_empty(&self) -> bool {
+        self == &ProcessOptions::default()
+    }
+
+    /// Refuse the options the executor would not be able to apply.
+    pub fn check(&self) -> Result<(), CommonError> {
+        self.envs()?;
+        self.resource_limits.check()?;
+
+        if self.run_as_user.is_empty() && !self.run_as_group.is_empty() {
+            return Err(CommonError::DisPass(String::from(
+                "The `run_as_group` needs a `run_as_user`.",
+            )));
+        }
+        Ok(())
+    }
+
+    fn envs(&self) -> Result<HashMap<String, String>, CommonError> {
+        if self.env.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        json_from_slice(self.env.as_bytes())
+            .map_err(|e| CommonError::DisPass(format!("Ineffective env: {}", e)))
+    }
+
+    async fn spawn(
+        &self,
+        command: &str,
+        timer_task_id: u64,
+        live_output: &LiveOutput,
+    ) -> Result<FinishOutput, CommonError> {
+        let mut child_command = Command::new("sh");
+        child_command
+            .arg("-c")
+            .arg(command)
+            .envs(self.envs()?)
+            .stdin(if self.stdin.is_empty() {
+                Stdio::null()
+            } else {
+                Stdio::piped()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            // The run is dropped by `DelayTimer` when it times out or is cancelled.
+            .kill_on_drop(true);
+
+        if !self.working_directory.is_empty() {
+            child_command.current_dir(&self.working_directory);
+        }
+
+        if !self.run_as_user.is_empty() {
+            self.run_as(&mut child_command)?;
+        }
+
+        let task_cgroup = self
+            .resource_limits
+            .apply(&mut child_command, timer_task_id)?;
+
+        let mut child = match child_command.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                // Without a cgroup, a child already over its rlimits fails to start.
+                let exceeded = task_cgroup
+                    .is_none()
+                    .then(|| self.resource_limits.rlimit_exceeded(&e))
+                    .flatten();
+
+                return match exceeded {
+                    Some(resource) => Ok(FinishOutput::ResourceExceededOutput(
+                        ResourceExceededOutput {
+                            resource: resource.to_string(),
+                            child_output: ChildOutput {
+                                child_stderr: e.to_string(),
+                                ..ChildOutput::default()
+                            },
+                        },
+                    )),
+                    None => Err(e.into()),
+                };
+            }
+        };
+        if let Some(mut child_stdin) = child.stdin.take() {
+            child_stdin.write_all(self.stdin.as_bytes()).await?;
+        }
+
+        // The output is read as it comes, so that it can be followed while the child runs,
+        // Only its head and tail are kept beyond the output limit.
+        let output_limit = output_limit(self.resource_limits.output_limit);
+        let child_stdout = child.stdout.take();
+        let child_stderr = child.stderr.take();
+        let stdout_output =
+            CappedOutput::new(output_limit, OutputStream::Stdout, timer_task_id).await;
+        let stderr_output =
+            CappedOutput::new(output_limit, OutputStream::Stderr, timer_task_id).await;
+        let wait_with_output = async {
+            let (status, (child_stdout, stdout_spool), (child_stderr, stderr_spool)) = tokio::try_join!(
+                child.wait(),
+                read_output(
+                    child_stdout,
+                    stdout_output,
+                    OutputStream::Stdout,
+                    live_output
+                ),
+                read_output(
+                    child_stderr,
+                    stderr_output,
+                    OutputStream::Stderr,
+                    live_output
+                )
+            )?;
+
+            Ok::<_, std::io::Error>(ChildOutput {
+                child_status: status.code().unwrap_or(81),
+                child_stdout,
+                child_stderr,
+                stdout_spool,
+                stderr_spool,
+            })
+        };
+
+        let child_output = match self.resource_limits.wall_clock() {
+            Some(wall_clock) => {
+                match tokio::time::timeout(wall_clock, wait_with_output).await {
+                    Ok(child_output) => child_output?,
+                    // The child has been killed on drop, the processes it left are killed along with its cgroup.
+                    Err(_) => {
+                        if let Some(task_cgroup) = task_cgroup.as_ref() {
+                            task_cgroup.kill();
+                        }
+                        return Ok(FinishOutput::ResourceExceededOutput(
+                            ResourceExceededOutput {
+                                resource: String::from("wall-clock"),
+                                child_output: ChildOutput::default(),
+                            },
+                        ));
+                    }
+                }
+            }
+            None => wait_with_output.await?,
+        };
+
+        match task_cgroup.as_ref().and_then(|c| c.exceeded()) {
+            Some(resource) => Ok(FinishOutput::ResourceExceededOutput(
+                ResourceExceededOutput {
+                    resource: resource.to_string(),
+                    child_output,
+                },
+            )),
+            None => Ok(FinishOutput::ProcessOutput(child_output)),
+        }
+    }
+
+    #[cfg(unix)]
+    fn run_as(&self, child_command: &mut Command) -> Result<(), CommonError> {
+        let (uid, primary_gid) = lookup_user(&self.run_as_user)?;
+        let gid = if self.run_as_group.is_empty() {
+            primary_gid.ok_or_else(|| {
+                CommonError::DisPass(format!(
+                    "The primary group of user {} is unknown, set `run_as_group`.",
+                    self.run_as_user
+                ))
+            })?
+        } else {
+            lookup_group(&self.run_as_group)?
+        };
+
+        // The supplementary groups of the executor are dropped along with the user.
+        child_command.uid(uid).gid(gid);
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn run_as(&self, _child_command: &mut Command) -> Result<(), CommonError> {
+        Err(CommonError::DisPass(String::from(
+            "Running as another user is only supported on unix.",
+        )))
+    }
+}
+
+// Returns the kept output and the file holding the whole output.
+async fn read_output(
+    reader: Option<impl AsyncRead + Unpin>,
+    mut output: CappedOutput,
+    stream: OutputStream,
+    live_output: &LiveOutput,
+) -> std::io::Result<(String, String)> {
+    if let Some(mut reader) = reader {
+        let mut buf = [0u8; 8192];
+        loop {
+            let n = reader.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+
+            live_output.push(stream, &buf[..n]);
+            output.push(&buf[..n]).await;
+        }
+    }
+
+    Ok(output.finish().await)
+}
+
+// Returns the uid and the primary gid, numeric users may not have an entry.
+#[cfg(unix)]
+fn lookup_user(user: &str) -> Result<(u32, Option<u32>), CommonError> {
+    let passwd = fs::read_to_string("/etc/passwd")?;
+    let entry = passwd
+        .lines()
+        .map(|line| line.split(':').collect::<Vec<&str>>())
+        .find(|fields| fields.len() > 3 && (fields[0] == user || fields[2] == user));
+
+    match (entry, user.parse::<u32>()) {
+        (Some(fields), _) => {
+            let uid = fields[2].parse::<u32>().ok();
+            let gid = fields[3].parse::<u32>().ok();
+            uid.map(|uid| (uid, gid))
+                .ok_or_else(|| CommonError::DisPass(format!("Ineffective user {}.", user)))
+        }
+        (None, Ok(uid)) => Ok((uid, None)),
+        (None, Err(_)) => Err(CommonError::DisPass(format!("Unknown user {}.", user))),
+    }
+}
+
+#[cfg(unix)]
+fn lookup_group(group: &str) -> Result<u32, CommonError> {
+    if let Ok(gid) = group.parse::<u32>() {
+        return Ok(gid);
+    }
+
+    fs::read_to_string("/etc/group")?
+        .lines()
+        .map(|line| line.split(':').collect::<Vec<&str>>())
+        .find(|fields| fields.len() > 2 && fields[0] == group)
+        .and_then(|fields| fields[2].parse::<u32>().ok())
+        .ok_or_else(|| CommonError::DisPass(format!("Unknown group {}.", group)))
+}
+
+/// The routine of a shell task, its output can be followed while it runs
+/// And is taken by `take_routine_output` when the run ends.
+pub async fn run_process(
+    command: Arc<String>,
+    process_options: Arc<ProcessOptions>,
+    timer_task_id: u64,
+) {
+    let live_output = start_live_output(timer_task_id);
+    let output = process_options
+        .spawn(&command, timer_task_id, &live_output)
+        .await
+        .unwrap_or_else(|e| FinishOutput::ExceptionOutput(e.to_string()));
+
+    leave_routine_output(timer_task_id, output);
+}
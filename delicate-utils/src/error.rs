@@ -25,6 +25,8 @@ pub enum CommonError {
     JoinError(#[from] JoinError),
     #[error("request fail.")]
     RequestError(#[from] reqwest::Error),
+    #[error("Access fileSystem fail.")]
+    DisAccessFs(#[from] std::io::Error),
 }
 
 #[derive(ThisError, Debug)]
@@ -70,3 +72,11 @@ pub enum InitSchedulerError {
     #[error("Parse pem to Key fail.")]
     DisParseKey(#[from] ras_error::Error),
 }
+
+#[derive(ThisError, Debug)]
+pub enum InitConfigError {
+    #[error("Load configuration fail: {0}")]
+    DisLoad(#[from] ::config::ConfigError),
+    #[error("Invalid configuration:{}", .0.iter().map(|problem| format!("\n  - {}", problem)).collect::<String>())]
+    DisValidate(Vec<String>),
+}
@@ -1,6 +1,7 @@
 #[macro_use]
 extern crate diesel;
 
+pub mod conf;
 pub mod consensus_message;
 pub mod error;
 pub mod helper_utils;
@@ -18,10 +18,10 @@ pub use crate::consensus_message::service_binding;
 pub use crate::error::*;
 pub use crate::helper_utils::*;
 pub(crate) use delay_timer::prelude::*;
-pub(crate) use delay_timer::utils::convenience::functions::tokio_unblock_process_task_fn;
 pub(crate) use delay_timer::utils::status_report::PublicFinishOutput;
 
 pub(crate) use derive_more::Display;
+pub(crate) use lazy_static::lazy_static;
 
 pub(crate) use log::error;
 pub(crate) use rand::rngs::OsRng;
@@ -46,7 +46,7 @@ pub(crate) use uuid::Uuid;
 pub(crate) use poem::{web::IntoResponse, Response};
 pub(crate) use tokio::task::JoinError;
 
-pub(crate) use std::collections::HashMap;
+pub(crate) use std::collections::{HashMap, VecDeque};
 pub(crate) use std::convert::{TryFrom, TryInto};
 pub(crate) use std::env;
 pub(crate) use std::fmt::Debug;
@@ -21,6 +21,9 @@ pub trait Trial {
     }
 }
 
+/// The response code of a request that only partly succeeded, 0 is success and -1 is failure.
+pub const PARTIAL_FAILURE_CODE: i8 = 1;
+
 /// Uniform public message response format.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnifiedResponseMessages<T: UniformData> {
@@ -45,6 +48,14 @@ impl<T: UniformData> UnifiedResponseMessages<T> {
         UnifiedResponseMessages { code, msg, data }
     }
 
+    /// Some of the work was done and some failed, `data` tells which.
+    #[inline(always)]
+    pub fn partial_failure_with_data(data: T) -> Self {
+        let code = PARTIAL_FAILURE_CODE;
+        let msg = String::default();
+        UnifiedResponseMessages { code, msg, data }
+    }
+
     #[inline(always)]
     pub fn customized_error_msg(mut self, msg: String) -> Self {
         self.msg = msg;